@@ -0,0 +1,76 @@
+//! The error type returned when constructing the crate's types from invalid parameters or data.
+
+use std::error::Error;
+use std::fmt;
+
+/// The ways in which the parameters or data that a distribution (or other type) is constructed from can be invalid.
+///
+/// Each variant describes what was wrong with the input, so that a failure can be traced back to its cause, e.g. a
+/// negative scale, a probability outside of `[0, 1]`, or a `NaN` in a data set.
+///
+/// ```ignore
+/// match NormalDist::new(0.0, -1.0) {
+///     Err(error) => println!("{}", error), // prints "invalid scale of -1: expected a positive, finite number"
+///     Ok(dist) => { /* ... */ },
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum PensivError {
+    /// A numeric parameter is outside of its valid range (or is `NaN`); holds the parameter's name & value, and a
+    /// description of the values it can take.
+    InvalidParameter { name: &'static str, value: f64, expected: &'static str },
+    /// A lower bound is above its upper bound (or, where the interval must be nonempty, equal to it), or either bound is
+    /// `NaN`.
+    InvalidBounds { lower: f64, upper: f64 },
+    /// A data set that must contain at least one value is empty.
+    EmptyData,
+    /// A data set contains a `NaN` or infinite value.
+    NonFiniteData,
+    /// Two inputs whose lengths (or dimensions) must agree don't; holds the expected & actual lengths.
+    LengthMismatch { expected: usize, found: usize },
+    /// A matrix that must be symmetric & (numerically) positive-definite, e.g. a covariance matrix, isn't.
+    NotPositiveDefinite,
+    /// An iterative estimate, e.g. a maximum-likelihood fit without a closed form, failed to converge.
+    NotConverged,
+    /// Some other input is invalid, e.g. weights that sum to `0`; holds a description of the problem.
+    InvalidInput(&'static str),
+}
+
+impl fmt::Display for PensivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PensivError::InvalidParameter { name, value, expected } => {
+                write!(f, "invalid {} of {}: expected {}", name, value, expected)
+            },
+            PensivError::InvalidBounds { lower, upper } => write!(f, "invalid bounds: [{}, {}]", lower, upper),
+            PensivError::EmptyData => write!(f, "data is empty"),
+            PensivError::NonFiniteData => write!(f, "data contains NaN or infinite values"),
+            PensivError::LengthMismatch { expected, found } => {
+                write!(f, "length mismatch: expected {}, found {}", expected, found)
+            },
+            PensivError::NotPositiveDefinite => write!(f, "matrix isn't symmetric & positive-definite"),
+            PensivError::NotConverged => write!(f, "failed to converge"),
+            PensivError::InvalidInput(description) => write!(f, "invalid input: {}", description),
+        }
+    }
+}
+
+impl Error for PensivError {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pensiv_error_displays() {
+        let error = PensivError::InvalidParameter { name: "scale", value: -1.0, expected: "a positive, finite number" };
+        assert_eq!(error.to_string(), "invalid scale of -1: expected a positive, finite number");
+
+        assert_eq!(PensivError::InvalidBounds { lower: 2.0, upper: 1.0 }.to_string(), "invalid bounds: [2, 1]");
+        assert_eq!(PensivError::NonFiniteData.to_string(), "data contains NaN or infinite values");
+        assert_eq!(PensivError::LengthMismatch { expected: 2, found: 3 }.to_string(), "length mismatch: expected 2, found 3");
+        assert_eq!(PensivError::NotConverged.to_string(), "failed to converge");
+        assert_eq!(PensivError::InvalidInput("weights sum to 0").to_string(), "invalid input: weights sum to 0");
+    }
+}
@@ -9,6 +9,11 @@
 //! them as you please, but be aware that they are subject-to-change at `pensiv`'s discretion. Upon the first published release 
 //! of the crate, this will no longer be the case, however.
 
+pub mod linalg;
+pub mod numeric;
 pub mod stats;
 
-mod utils;
\ No newline at end of file
+mod error;
+mod utils;
+
+pub use self::error::PensivError;
\ No newline at end of file
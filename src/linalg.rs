@@ -0,0 +1,158 @@
+//! Small dense linear algebra routines on top of `ndarray`.
+//!
+//! Currently implemented are the Cholesky decomposition of symmetric positive-definite matrices, along with the triangular
+//! solves and determinants built on it. These are the building blocks for the multivariate distributions in `stats`.
+
+use ndarray::prelude::*;
+
+/// Returns the lower-triangular Cholesky factor `L` of `matrix`, i.e. the matrix for which `L L^T = matrix`.
+///
+/// Only the lower triangle of `matrix` is read, so it's assumed to be symmetric. Returns `None` if `matrix` isn't square,
+/// contains non-finite values, or isn't (numerically) positive-definite; otherwise returns the factor.
+///
+/// ```ignore
+/// let matrix = array![[4.0, 2.0], [2.0, 5.0]];
+/// let l = cholesky(&matrix).unwrap();
+///
+/// println!("{}", l); // prints "[[2, 0], [1, 2]]"
+/// ```
+///
+/// ```ignore
+/// let matrix = array![[1.0, 2.0], [2.0, 1.0]]; // indefinite
+/// println!("{}", cholesky(&matrix) == None); // prints "true"
+/// ```
+pub fn cholesky(matrix: &Array2<f64>) -> Option<Array2<f64>> {
+    let n = matrix.nrows();
+    if matrix.ncols() != n || matrix.iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+
+    let mut l = Array2::<f64>::zeros((n, n));
+
+    for j in 0..n {
+        let diagonal = matrix[[j, j]] - l.slice(s![j, ..j]).dot(&l.slice(s![j, ..j]));
+        if diagonal <= 0.0 || !diagonal.is_finite() {
+            return None;
+        }
+
+        let l_jj = diagonal.sqrt();
+        l[[j, j]] = l_jj;
+
+        for i in (j + 1)..n {
+            l[[i, j]] = (matrix[[i, j]] - l.slice(s![i, ..j]).dot(&l.slice(s![j, ..j]))) / l_jj;
+        }
+    }
+
+    Some(l)
+}
+
+/// Solves `L x = b` for `x` by forward substitution, where `l` is lower-triangular with a nonzero diagonal.
+///
+/// ```ignore
+/// let l = array![[2.0, 0.0], [1.0, 2.0]];
+/// println!("{}", solve_lower_triangular(&l, &array![2.0, 5.0])); // prints "[1, 2]"
+/// ```
+pub fn solve_lower_triangular(l: &Array2<f64>, b: &Array1<f64>) -> Array1<f64> {
+    let n = b.len();
+    let mut x = Array1::<f64>::zeros(n);
+
+    for i in 0..n {
+        x[i] = (b[i] - l.slice(s![i, ..i]).dot(&x.slice(s![..i]))) / l[[i, i]];
+    }
+
+    x
+}
+
+/// Solves `L^T x = b` for `x` by back substitution, where `l` is lower-triangular with a nonzero diagonal.
+///
+/// ```ignore
+/// let l = array![[2.0, 0.0], [1.0, 2.0]];
+/// println!("{}", solve_lower_triangular_transpose(&l, &array![4.0, 4.0])); // prints "[1, 2]"
+/// ```
+pub fn solve_lower_triangular_transpose(l: &Array2<f64>, b: &Array1<f64>) -> Array1<f64> {
+    let n = b.len();
+    let mut x = Array1::<f64>::zeros(n);
+
+    for i in (0..n).rev() {
+        x[i] = (b[i] - l.slice(s![(i + 1).., i]).dot(&x.slice(s![(i + 1)..]))) / l[[i, i]];
+    }
+
+    x
+}
+
+/// Solves `A x = b` for `x`, where `l` is the Cholesky factor of `A` (see `cholesky()`).
+///
+/// ```ignore
+/// let a = array![[4.0, 2.0], [2.0, 5.0]];
+/// let l = cholesky(&a).unwrap();
+///
+/// println!("{}", cholesky_solve(&l, &array![8.0, 12.0])); // prints "[1, 2]"
+/// ```
+pub fn cholesky_solve(l: &Array2<f64>, b: &Array1<f64>) -> Array1<f64> {
+    solve_lower_triangular_transpose(l, &solve_lower_triangular(l, b))
+}
+
+/// Returns the natural log of the determinant of `A`, where `l` is the Cholesky factor of `A` (see `cholesky()`).
+///
+/// Because the determinant is computed in log-space, it doesn't overflow or underflow for large matrices.
+///
+/// ```ignore
+/// let a = array![[4.0, 2.0], [2.0, 5.0]];
+/// let l = cholesky(&a).unwrap();
+///
+/// println!("{}", cholesky_ln_det(&l).exp()); // prints approximately "16"
+/// ```
+pub fn cholesky_ln_det(l: &Array2<f64>) -> f64 {
+    2.0 * l.diag().mapv(f64::ln).sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cholesky_factor_correct() {
+        let matrix = array![[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let l = cholesky(&matrix).unwrap();
+
+        assert_eq!(l, array![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        assert_eq!(l.dot(&l.t()), matrix);
+    }
+
+    #[test]
+    fn cholesky_invalid_matrix_fails() {
+        assert_eq!(cholesky(&array![[1.0, 2.0], [2.0, 1.0]]), None);
+        assert_eq!(cholesky(&array![[1.0, 1.0], [1.0, 1.0]]), None);
+        assert_eq!(cholesky(&array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]), None);
+        assert_eq!(cholesky(&array![[1.0, 0.0], [0.0, f64::NAN]]), None);
+    }
+
+    #[test]
+    fn triangular_solves_correct() {
+        let l = array![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]];
+        let x = array![1.0, -2.0, 0.5];
+
+        assert_eq!(solve_lower_triangular(&l, &l.dot(&x)), x);
+        assert_eq!(solve_lower_triangular_transpose(&l, &l.t().dot(&x)), x);
+    }
+
+    #[test]
+    fn cholesky_solve_correct() {
+        let matrix = array![[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let l = cholesky(&matrix).unwrap();
+        let x = array![1.0, -2.0, 0.5];
+
+        let solved = cholesky_solve(&l, &matrix.dot(&x));
+        assert!(solved.iter().zip(x.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+
+    #[test]
+    fn cholesky_ln_det_correct() {
+        let matrix = array![[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let l = cholesky(&matrix).unwrap();
+
+        // det = (2 * 1 * 3)^2
+        assert!((cholesky_ln_det(&l) - 36_f64.ln()).abs() < 1e-14);
+    }
+}
@@ -0,0 +1,812 @@
+//! Numerical integration & root finding, for the properties of distributions (and other functions) that have no closed
+//! form.
+//!
+//! Integrals are computed adaptively, with either the 15-point Gauss-Kronrod rule (`integrate()`) or Simpson's rule
+//! (`integrate_simpson()`), repeatedly bisecting the subintervals whose error estimates are too large. Infinite
+//! intervals are mapped onto finite ones before integrating. Roots are found by Brent's method (`brent_root()`) within
+//! a bracket, which `bracket_root()` can search for, or by Newton's method (`newton_root()`) from an initial guess;
+//! `positive_newton_root()` & `positive_newton_root_2d()` keep the estimates positive, for solving for parameters.
+//! Rather than returning `NaN`, each of these returns a `NumericError` describing why it failed.
+
+use std::error::Error;
+use std::fmt;
+
+/// The non-negative nodes of the 15-point Kronrod rule on `[-1, 1]`, in descending order; the nodes at odd indices are
+/// those of the embedded 7-point Gauss rule.
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6, 0.949_107_912_342_758_5, 0.864_864_423_359_769_1, 0.741_531_185_599_394_5,
+    0.586_087_235_467_691_1, 0.405_845_151_377_397_2, 0.207_784_955_007_898_48, 0.0,
+];
+
+/// The weights of the 15-point Kronrod rule, corresponding to `KRONROD_NODES`.
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_224, 0.063_092_092_629_978_56, 0.104_790_010_322_250_19, 0.140_653_259_715_525_92,
+    0.169_004_726_639_267_9, 0.190_350_578_064_785_42, 0.204_432_940_075_298_89, 0.209_482_141_084_727_82,
+];
+
+/// The weights of the 7-point Gauss rule, corresponding to the odd-indexed `KRONROD_NODES`.
+const GAUSS_WEIGHTS: [f64; 4] = [0.129_484_966_168_869_7, 0.279_705_391_489_276_64, 0.381_830_050_505_118_9, 0.417_959_183_673_469_4];
+
+/// Probabilities at whose quantiles the support is split when integrating numerically, so that the integrand's scale &
+/// location (and the bounds of the support, at `0` & `1`) are taken into account.
+const BREAKPOINT_PROBABILITIES: [f64; 13] = [
+    0.0, 1e-12, 1e-6, 1e-3, 0.05, 0.25, 0.5, 0.75, 0.95, 1.0 - 1e-3, 1.0 - 1e-6, 1.0 - 1e-12, 1.0,
+];
+
+/// Largest number of subintervals that a Gauss-Kronrod integral is split into.
+const MAX_SUBINTERVALS: usize = 500;
+
+/// Largest number of subintervals that a Simpson integral evaluates.
+const MAX_SIMPSON_SUBINTERVALS: usize = 100_000;
+
+/// Largest number of times that a subinterval of a Simpson integral is bisected.
+const MAX_SIMPSON_DEPTH: usize = 50;
+
+/// Relative error at which integration stops.
+const RELATIVE_TOLERANCE: f64 = 1e-12;
+
+/// Absolute error at which integration stops, for integrals that are (nearly) `0`.
+const ABSOLUTE_TOLERANCE: f64 = 1e-15;
+
+/// Largest number of iterations taken by Brent's method; bisecting a bracket down to the resolution of `f64` takes at
+/// most about 2100.
+const MAX_BRENT_ITERATIONS: usize = 5000;
+
+/// Largest number of iterations taken by Newton's method.
+const MAX_NEWTON_ITERATIONS: usize = 200;
+
+/// Relative step size below which Newton's method has converged.
+const NEWTON_TOLERANCE: f64 = 1e-14;
+
+/// Relative step size below which Newton's method has also converged if its steps stop shrinking, i.e. once rounding
+/// error in the function (e.g. from summing over a large data set) dominates its value.
+const NEWTON_NOISE_TOLERANCE: f64 = 1e-9;
+
+/// Factor by which the width of a bracket grows at each step of the search for it.
+const BRACKET_GROWTH: f64 = 1.6;
+
+/// A method of integrating a function over a finite interval.
+type FiniteIntegral = dyn Fn(&dyn Fn(f64) -> f64, f64, f64) -> Result<f64, NumericError>;
+
+
+/// The ways in which a numerical method can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericError {
+    /// An argument is outside of the method's domain, e.g. a bound is `NaN`.
+    InvalidArgument,
+    /// The function returned `NaN` (or, when finding roots, an infinite value).
+    NonFinite,
+    /// The method didn't reach its tolerance within its iteration limit, e.g. because the integral diverges.
+    NotConverged,
+    /// The function doesn't change sign over the given bracket, or no bracket over which it does could be found.
+    NoSignChange,
+}
+
+impl fmt::Display for NumericError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            NumericError::InvalidArgument => "invalid argument",
+            NumericError::NonFinite => "function returned a non-finite value",
+            NumericError::NotConverged => "failed to converge",
+            NumericError::NoSignChange => "function doesn't change sign",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+impl Error for NumericError {}
+
+
+/// Returns the integral of `f` from `lower` to `upper`, either of which may be infinite, using adaptive Gauss-Kronrod
+/// quadrature.
+///
+/// If `lower > upper`, the integral is negated as usual. The result is accurate to about 12 significant digits for
+/// smooth integrands, and the integrand is never evaluated at the bounds, so it may have integrable singularities
+/// there.
+///
+/// Returns `InvalidArgument` if either bound is `NaN`, `NonFinite` if the integrand is `NaN` anywhere it's evaluated,
+/// or `NotConverged` if the tolerance isn't reached, e.g. because the integral diverges.
+///
+/// ```ignore
+/// let integral = integrate(|x| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY).unwrap();
+/// println!("{}", integral); // prints approximately "1.7725", i.e. sqrt(pi)
+///
+/// println!("{:?}", integrate(|x| 1.0 / x, 0.0, 1.0)); // prints "Err(NotConverged)"
+/// ```
+pub fn integrate<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> Result<f64, NumericError> {
+    integrate_with(&f, lower, upper, &|g, a, b| match gauss_kronrod_integral(g, a, b) {
+        (value, _) if value.is_nan() => Err(NumericError::NonFinite),
+        (value, true) => Ok(value),
+        (_, false) => Err(NumericError::NotConverged),
+    })
+}
+
+/// Returns the integral of `f` from `lower` to `upper`, either of which may be infinite, using adaptive Simpson
+/// quadrature.
+///
+/// Unlike `integrate()`, the integrand is evaluated at the bounds (where infinite bounds are taken to have an integrand
+/// of `0`), so it must be finite everywhere on `[lower, upper]`. Simpson's rule needs many more evaluations than
+/// Gauss-Kronrod quadrature for the same accuracy, but it's less easily fooled by integrands that aren't smooth.
+///
+/// Returns `InvalidArgument` if either bound is `NaN`, `NonFinite` if the integrand isn't finite anywhere it's
+/// evaluated, or `NotConverged` if the tolerance isn't reached.
+///
+/// ```ignore
+/// let integral = integrate_simpson(|x| x.abs(), -1.0, 2.0).unwrap();
+/// println!("{}", integral); // prints approximately "2.5"
+/// ```
+pub fn integrate_simpson<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> Result<f64, NumericError> {
+    integrate_with(&f, lower, upper, &simpson_integral)
+}
+
+/// Finds a root of `f` within `[lower, upper]` using Brent's method, i.e. a value at which `f` is `0` or changes sign.
+///
+/// `f` must have opposite signs at the bounds (or be `0` at one of them); if it's continuous, there's then a root
+/// between them, which is found to about the resolution of `f64`. Brent's method combines inverse quadratic
+/// interpolation with bisection, so it converges quickly for smooth functions but is never much slower than bisection.
+///
+/// Returns `InvalidArgument` if either bound isn't finite, `NoSignChange` if `f` has the same sign at both bounds,
+/// `NonFinite` if `f` isn't finite anywhere it's evaluated, or `NotConverged` if the iteration limit is reached.
+///
+/// ```ignore
+/// let root = brent_root(|x| x * x - 2.0, 0.0, 2.0).unwrap();
+/// println!("{}", root); // prints approximately "1.4142"
+///
+/// println!("{:?}", brent_root(|x| x * x + 1.0, -1.0, 1.0)); // prints "Err(NoSignChange)"
+/// ```
+pub fn brent_root<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> Result<f64, NumericError> {
+    if !lower.is_finite() || !upper.is_finite() {
+        return Err(NumericError::InvalidArgument);
+    }
+
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (finite(f(a))?, finite(f(b))?);
+
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    else if same_sign(fa, fb) {
+        return Err(NumericError::NoSignChange);
+    }
+
+    // the root is kept between b & c, with b the best estimate of it so far and a its previous value
+    let (mut c, mut fc) = (b, fb);
+    let mut step = b - a;
+    let mut previous_step = step;
+
+    for _ in 0..MAX_BRENT_ITERATIONS {
+        if same_sign(fb, fc) {
+            c = a;
+            fc = fa;
+            step = b - a;
+            previous_step = step;
+        }
+
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tolerance = 2.0 * f64::EPSILON * b.abs() + f64::MIN_POSITIVE;
+        let half_width = (c - b) / 2.0;
+
+        if half_width.abs() <= tolerance || fb == 0.0 {
+            return Ok(b);
+        }
+
+        if previous_step.abs() >= tolerance && fa.abs() > fb.abs() {
+            // inverse quadratic interpolation, or the secant method if only two distinct points are known
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * half_width * s, 1.0 - s)
+            }
+            else {
+                let (t, r) = (fa / fc, fb / fc);
+                (s * (2.0 * half_width * t * (t - r) - (b - a) * (r - 1.0)), (t - 1.0) * (r - 1.0) * (s - 1.0))
+            };
+
+            if p > 0.0 {
+                q = -q;
+            }
+            else {
+                p = -p;
+            }
+
+            // the interpolated step is only taken if it stays within the bracket & shrinks quickly enough
+            if 2.0 * p < (3.0 * half_width * q - (tolerance * q).abs()).min((previous_step * q).abs()) {
+                previous_step = step;
+                step = p / q;
+            }
+            else {
+                step = half_width;
+                previous_step = step;
+            }
+        }
+        else {
+            step = half_width;
+            previous_step = step;
+        }
+
+        a = b;
+        fa = fb;
+        b += if step.abs() > tolerance { step } else { tolerance.copysign(half_width) };
+        fb = finite(f(b))?;
+    }
+
+    Err(NumericError::NotConverged)
+}
+
+/// Finds a root of the function whose value & derivative at `x` are given by `f(x)`, using Newton's method starting
+/// from `guess`.
+///
+/// Newton's method converges very quickly near a simple root, but may diverge or find a different root if `guess` is
+/// far from the one wanted; `brent_root()` is more robust if a bracket of the root is known. Iteration stops once the
+/// step is negligible relative to the estimate, or `f` is exactly `0`, or the steps are small but no longer shrinking
+/// (since rounding error in `f` then dominates).
+///
+/// Returns `InvalidArgument` if `guess` isn't finite, `NonFinite` if `f` returns a non-finite value or `NaN`
+/// derivative, or `NotConverged` if the derivative is `0` or the iteration limit is reached.
+///
+/// ```ignore
+/// let root = newton_root(|x| (x * x - 2.0, 2.0 * x), 1.0).unwrap();
+/// println!("{}", root); // prints approximately "1.4142"
+/// ```
+pub fn newton_root<F: Fn(f64) -> (f64, f64)>(f: F, guess: f64) -> Result<f64, NumericError> {
+    if !guess.is_finite() {
+        return Err(NumericError::InvalidArgument);
+    }
+
+    newton_iterate(f, guess, |x, step| x - step)
+}
+
+/// Finds a positive root of the function whose value & derivative at `x` are given by `f(x)`, using Newton's method
+/// starting from `guess`, like `newton_root()`.
+///
+/// Steps that would leave the positive reals instead divide the estimate by `10`, so `f` is only ever evaluated at
+/// positive values. This suits equations in a scale or shape parameter, whose solutions must be positive.
+///
+/// Returns `InvalidArgument` if `guess` isn't positive & finite, and otherwise fails in the same ways as `newton_root()`.
+///
+/// ```ignore
+/// let root = positive_newton_root(|x| (x.ln() - 1.0, 1.0 / x), 10.0).unwrap();
+/// println!("{}", root); // prints approximately "2.7183"
+/// ```
+pub fn positive_newton_root<F: Fn(f64) -> (f64, f64)>(f: F, guess: f64) -> Result<f64, NumericError> {
+    if !(guess.is_finite() && guess > 0.0) {
+        return Err(NumericError::InvalidArgument);
+    }
+
+    newton_iterate(f, guess, |x, step| if x - step > 0.0 { x - step } else { x / 10.0 })
+}
+
+/// Finds a root of the pair of functions whose values & Jacobian at `x` are given by `f(x)`, using Newton's method
+/// starting from `guess`, where both coordinates of the root are positive.
+///
+/// The Jacobian is indexed as `jacobian[i][j]`, the derivative of the `i`th function with respect to `x[j]`. Steps that
+/// would leave the positive quadrant are halved until they don't. Iteration stops once the step in each coordinate is
+/// negligible relative to that coordinate, or the steps are small but no longer shrinking, like `newton_root()`.
+///
+/// Returns `InvalidArgument` if either coordinate of `guess` isn't positive & finite, `NonFinite` if `f` returns a
+/// non-finite value or `NaN` Jacobian, or `NotConverged` if the Jacobian is singular or the iteration limit is reached.
+///
+/// ```ignore
+/// let f = |[x, y]: [f64; 2]| ([x * y - 2.0, x - y - 1.0], [[y, x], [1.0, -1.0]]);
+/// let [x, y] = positive_newton_root_2d(f, [1.0, 1.0]).unwrap();
+/// println!("{} {}", x, y); // prints approximately "2 1"
+/// ```
+pub fn positive_newton_root_2d<F>(f: F, guess: [f64; 2]) -> Result<[f64; 2], NumericError>
+where
+    F: Fn([f64; 2]) -> ([f64; 2], [[f64; 2]; 2]),
+{
+    if !guess.iter().all(|&x| x.is_finite() && x > 0.0) {
+        return Err(NumericError::InvalidArgument);
+    }
+
+    let [mut x, mut y] = guess;
+    let mut previous_step = f64::INFINITY;
+
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let ([f1, f2], [[a, b], [c, d]]) = f([x, y]);
+
+        if !(f1.is_finite() && f2.is_finite()) || [a, b, c, d].iter().any(|j| j.is_nan()) {
+            return Err(NumericError::NonFinite);
+        }
+        else if f1 == 0.0 && f2 == 0.0 {
+            return Ok([x, y]);
+        }
+
+        let det = a * d - b * c;
+        let mut step_x = (d * f1 - b * f2) / det;
+        let mut step_y = (a * f2 - c * f1) / det;
+
+        if !(step_x.is_finite() && step_y.is_finite()) {
+            return Err(NumericError::NotConverged);
+        }
+
+        while x - step_x <= 0.0 || y - step_y <= 0.0 {
+            step_x /= 2.0;
+            step_y /= 2.0;
+        }
+
+        x -= step_x;
+        y -= step_y;
+
+        let step = (step_x / x).abs().max((step_y / y).abs());
+        if newton_converged(step, previous_step) {
+            return Ok([x, y]);
+        }
+
+        previous_step = step;
+    }
+
+    Err(NumericError::NotConverged)
+}
+
+/// Searches for a bracket of a root of `f`, i.e. an interval at whose bounds `f` has opposite signs (or is `0`),
+/// starting from `[lower, upper]` and repeatedly widening it.
+///
+/// At each step, the bracket is widened past whichever bound has the smaller value of `|f|` (or both, if they're equal)
+/// by a multiple of its width, so it grows geometrically. The bracket found can be passed to `brent_root()`.
+///
+/// Returns `InvalidArgument` if the bounds aren't finite or `lower >= upper`, `NonFinite` if `f` isn't finite anywhere
+/// it's evaluated, or `NoSignChange` if the bracket overflows before `f` changes sign.
+///
+/// ```ignore
+/// let (lower, upper) = bracket_root(|x| x - 100.0, 0.0, 1.0).unwrap();
+/// let root = brent_root(|x| x - 100.0, lower, upper).unwrap();
+///
+/// println!("{}", root); // prints "100"
+/// ```
+pub fn bracket_root<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> Result<(f64, f64), NumericError> {
+    if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        return Err(NumericError::InvalidArgument);
+    }
+
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (finite(f(a))?, finite(f(b))?);
+
+    while same_sign(fa, fb) {
+        let growth = BRACKET_GROWTH * (b - a);
+        let (widen_lower, widen_upper) = (fa.abs() <= fb.abs(), fb.abs() <= fa.abs());
+
+        if widen_lower {
+            a -= growth;
+        }
+        if widen_upper {
+            b += growth;
+        }
+
+        if !a.is_finite() || !b.is_finite() {
+            return Err(NumericError::NoSignChange);
+        }
+
+        if widen_lower {
+            fa = finite(f(a))?;
+        }
+        if widen_upper {
+            fb = finite(f(b))?;
+        }
+    }
+
+    Ok((a, b))
+}
+
+
+/// Returns the integral of `f` over the whole range spanned by `breakpoints`, integrating separately between each pair
+/// of adjacent breakpoints with adaptive Gauss-Kronrod quadrature.
+///
+/// `breakpoints` must be sorted; its first & last elements may be infinite. Splitting the range at points where the
+/// integrand changes scale, e.g. the quantiles of a distribution, makes the integral accurate for integrands that are
+/// concentrated far from the origin or in a very narrow region, which `integrate()` alone can miss. Infinite tails are
+/// rescaled by the width of the adjacent piece before integrating. Returns `0` if there are fewer than 2 breakpoints.
+///
+/// Unlike `integrate()`, this is used where a best estimate is more useful than an error: the estimate is returned even
+/// if it didn't reach the tolerance, and `NaN` is returned if it's `NaN` anywhere it's evaluated.
+pub(crate) fn integrate_piecewise<F: Fn(f64) -> f64>(f: F, breakpoints: &[f64]) -> f64 {
+    let n = breakpoints.len();
+    let tail_scale = |a: f64, b: f64| if b - a > 0.0 && (b - a).is_finite() { b - a } else { 1.0 };
+    let estimate = |g: &dyn Fn(f64) -> f64, a: f64, b: f64| {
+        integrate_with(g, a, b, &|h, a, b| Ok(gauss_kronrod_integral(h, a, b).0)).unwrap_or(f64::NAN)
+    };
+
+    breakpoints.windows(2).enumerate()
+        .map(|(i, pair)| {
+            let (a, b) = (pair[0], pair[1]);
+
+            if a == f64::NEG_INFINITY && b.is_finite() {
+                // x = b - s u for u in [0, inf)
+                let s = if i + 2 < n { tail_scale(b, breakpoints[i + 2]) } else { 1.0 };
+                s * estimate(&|u| f(b - s * u), 0.0, f64::INFINITY)
+            }
+            else if b == f64::INFINITY && a.is_finite() {
+                // x = a + s u for u in [0, inf)
+                let s = if i > 0 { tail_scale(breakpoints[i - 1], a) } else { 1.0 };
+                s * estimate(&|u| f(a + s * u), 0.0, f64::INFINITY)
+            }
+            else {
+                estimate(&f, a, b)
+            }
+        })
+        .sum()
+}
+
+/// Returns the points at which to split `[lower, upper]` when integrating over a distribution with quantile function
+/// `ppf`, i.e. the bounds & the quantiles of `BREAKPOINT_PROBABILITIES` strictly between them, in ascending order.
+pub(crate) fn quantile_breakpoints<F: Fn(f64) -> Option<f64>>(ppf: F, lower: f64, upper: f64) -> Vec<f64> {
+    let mut points = vec![lower];
+
+    points.extend(BREAKPOINT_PROBABILITIES.iter().filter_map(|p| ppf(*p)).filter(|x| lower < *x && *x < upper));
+    points.push(upper);
+
+    points.sort_by(f64::total_cmp);
+    points.dedup();
+
+    points
+}
+
+
+/// Returns the integral of `f` from `lower` to `upper` using `method`, which integrates over a finite interval;
+/// infinite intervals are mapped onto finite ones first.
+fn integrate_with(f: &dyn Fn(f64) -> f64, lower: f64, upper: f64, method: &FiniteIntegral)
+    -> Result<f64, NumericError>
+{
+    if lower.is_nan() || upper.is_nan() {
+        return Err(NumericError::InvalidArgument);
+    }
+    else if lower == upper {
+        return Ok(0.0);
+    }
+    else if lower > upper {
+        return integrate_with(f, upper, lower, method).map(|integral| -integral);
+    }
+
+    // the integrand must vanish at infinity for the integral to converge, so it's taken to be 0 there
+    match (lower.is_finite(), upper.is_finite()) {
+        (true, true) => method(f, lower, upper),
+        // x = lower + t / (1 - t) for t in [0, 1)
+        (true, false) => method(
+            &|t: f64| if t < 1.0 { f(lower + t / (1.0 - t)) / (1.0 - t).powi(2) } else { 0.0 },
+            0.0,
+            1.0,
+        ),
+        // x = upper - (1 - t) / t for t in (0, 1]
+        (false, true) => method(&|t: f64| if t > 0.0 { f(upper - (1.0 - t) / t) / (t * t) } else { 0.0 }, 0.0, 1.0),
+        // x = t / (1 - t^2) for t in (-1, 1)
+        (false, false) => method(
+            &|t: f64| {
+                let u = 1.0 - t * t;
+                if u > 0.0 { f(t / u) * (1.0 + t * t) / (u * u) } else { 0.0 }
+            },
+            -1.0,
+            1.0,
+        ),
+    }
+}
+
+/// Returns the Gauss-Kronrod estimate of the integral of `f` over the finite interval `[lower, upper]`, along with
+/// whether its error estimate reached the tolerance.
+fn gauss_kronrod_integral(f: &dyn Fn(f64) -> f64, lower: f64, upper: f64) -> (f64, bool) {
+    let (estimate, error) = gauss_kronrod(f, lower, upper);
+    let mut subintervals = vec![(lower, upper, estimate, error)];
+
+    let mut total = estimate;
+    let mut total_error = error;
+    let tolerance = |total: f64| ABSOLUTE_TOLERANCE.max(RELATIVE_TOLERANCE * total.abs());
+
+    while total_error > tolerance(total) && subintervals.len() < MAX_SUBINTERVALS {
+        if total.is_nan() {
+            return (f64::NAN, false);
+        }
+
+        let worst = (0..subintervals.len())
+            .max_by(|i, j| subintervals[*i].3.total_cmp(&subintervals[*j].3))
+            .unwrap();
+        let (a, b, estimate, error) = subintervals.swap_remove(worst);
+        let mid = a + (b - a) / 2.0;
+
+        // the interval can't be split any further
+        if mid <= a || mid >= b {
+            subintervals.push((a, b, estimate, 0.0));
+            total_error -= error;
+            continue;
+        }
+
+        let (left, left_error) = gauss_kronrod(f, a, mid);
+        let (right, right_error) = gauss_kronrod(f, mid, b);
+
+        total += left + right - estimate;
+        total_error += left_error + right_error - error;
+
+        subintervals.push((a, mid, left, left_error));
+        subintervals.push((mid, b, right, right_error));
+    }
+
+    // re-sum to avoid accumulating rounding error from the incremental updates
+    let integral = subintervals.iter().map(|interval| interval.2).sum();
+    (integral, total_error <= tolerance(integral))
+}
+
+/// Returns the 15-point Kronrod estimate of the integral of `f` over `[lower, upper]`, along with an estimate of its
+/// error (its difference from the embedded 7-point Gauss estimate).
+fn gauss_kronrod(f: &dyn Fn(f64) -> f64, lower: f64, upper: f64) -> (f64, f64) {
+    let center = (lower + upper) / 2.0;
+    let half_width = (upper - lower) / 2.0;
+
+    let center_value = f(center);
+    let mut kronrod = KRONROD_WEIGHTS[7] * center_value;
+    let mut gauss = GAUSS_WEIGHTS[3] * center_value;
+
+    for i in 0..7 {
+        let offset = half_width * KRONROD_NODES[i];
+        let pair = f(center - offset) + f(center + offset);
+
+        kronrod += KRONROD_WEIGHTS[i] * pair;
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * pair;
+        }
+    }
+
+    (kronrod * half_width, ((kronrod - gauss) * half_width).abs())
+}
+
+/// Returns the integral of `f` over the finite interval `[lower, upper]` using adaptive Simpson quadrature.
+///
+/// Each subinterval is accepted once the Simpson estimates over it & over its two halves agree to within its share of
+/// the tolerance, and the difference between them is then used to extrapolate a more accurate estimate.
+fn simpson_integral(f: &dyn Fn(f64) -> f64, lower: f64, upper: f64) -> Result<f64, NumericError> {
+    let value = |x: f64| finite(f(x));
+    let mid = lower + (upper - lower) / 2.0;
+    let (f_lower, f_mid, f_upper) = (value(lower)?, value(mid)?, value(upper)?);
+
+    let whole = simpson(lower, upper, f_lower, f_mid, f_upper);
+    let tolerance = ABSOLUTE_TOLERANCE.max(RELATIVE_TOLERANCE * whole.abs());
+
+    // each subinterval holds its bounds, the integrand at its bounds & midpoint, its estimate, tolerance & depth
+    let mut pending = vec![(lower, upper, f_lower, f_mid, f_upper, whole, tolerance, 0)];
+    let mut integral = 0.0;
+    let mut evaluated = 0;
+
+    while let Some((a, b, fa, fm, fb, estimate, tolerance, depth)) = pending.pop() {
+        evaluated += 1;
+        if evaluated > MAX_SIMPSON_SUBINTERVALS || depth >= MAX_SIMPSON_DEPTH {
+            return Err(NumericError::NotConverged);
+        }
+
+        let m = a + (b - a) / 2.0;
+        let (left_mid, right_mid) = (a + (m - a) / 2.0, m + (b - m) / 2.0);
+        let (f_left, f_right) = (value(left_mid)?, value(right_mid)?);
+
+        let left = simpson(a, m, fa, f_left, fm);
+        let right = simpson(m, b, fm, f_right, fb);
+        let difference = left + right - estimate;
+
+        if difference.abs() <= 15.0 * tolerance {
+            integral += left + right + difference / 15.0;
+        }
+        else {
+            pending.push((a, m, fa, f_left, fm, left, tolerance / 2.0, depth + 1));
+            pending.push((m, b, fm, f_right, fb, right, tolerance / 2.0, depth + 1));
+        }
+    }
+
+    Ok(integral)
+}
+
+/// Returns Simpson's estimate of the integral over `[lower, upper]` of a function with the given values at the bounds &
+/// midpoint.
+fn simpson(lower: f64, upper: f64, f_lower: f64, f_mid: f64, f_upper: f64) -> f64 {
+    (upper - lower) / 6.0 * (f_lower + 4.0 * f_mid + f_upper)
+}
+
+/// Runs Newton's method on `f` from `start`, taking each step from `x` to `next(x, step)` where `step = f(x) / f'(x)`.
+fn newton_iterate<F, S>(f: F, start: f64, next: S) -> Result<f64, NumericError>
+where
+    F: Fn(f64) -> (f64, f64),
+    S: Fn(f64, f64) -> f64,
+{
+    let mut x = start;
+    let mut previous_step = f64::INFINITY;
+
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let (value, derivative) = f(x);
+
+        if !value.is_finite() || derivative.is_nan() {
+            return Err(NumericError::NonFinite);
+        }
+        else if value == 0.0 {
+            return Ok(x);
+        }
+
+        let step = value / derivative;
+        if !step.is_finite() {
+            return Err(NumericError::NotConverged);
+        }
+
+        let following = next(x, step);
+        let relative_step = ((following - x) / following).abs();
+
+        if newton_converged(relative_step, previous_step) {
+            return Ok(following);
+        }
+
+        x = following;
+        previous_step = relative_step;
+    }
+
+    Err(NumericError::NotConverged)
+}
+
+/// Returns whether Newton's method has converged, given the relative sizes of its latest & previous steps.
+fn newton_converged(step: f64, previous_step: f64) -> bool {
+    step <= NEWTON_TOLERANCE || (step <= NEWTON_NOISE_TOLERANCE && step >= previous_step / 2.0)
+}
+
+/// Returns `value` if it's finite, or `NonFinite` otherwise.
+fn finite(value: f64) -> Result<f64, NumericError> {
+    if value.is_finite() { Ok(value) } else { Err(NumericError::NonFinite) }
+}
+
+/// Returns whether `a` & `b` are both positive or both negative.
+fn same_sign(a: f64, b: f64) -> bool {
+    (a > 0.0 && b > 0.0) || (a < 0.0 && b < 0.0)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::PI;
+
+    #[test]
+    fn integrate_finite() {
+        assert!((integrate(|x| x * x, 0.0, 1.0).unwrap() - 1.0 / 3.0).abs() < 1e-15);
+        assert!((integrate(f64::sin, 0.0, PI).unwrap() - 2.0).abs() < 1e-14);
+        assert!((integrate(|x| x * x, 1.0, 0.0).unwrap() + 1.0 / 3.0).abs() < 1e-15);
+        assert_eq!(integrate(|x| x, 2.0, 2.0), Ok(0.0));
+
+        // an integrable singularity at an endpoint
+        assert!((integrate(|x| 1.0 / x.sqrt(), 0.0, 1.0).unwrap() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn integrate_infinite() {
+        assert!((integrate(|x| (-x).exp(), 0.0, f64::INFINITY).unwrap() - 1.0).abs() < 1e-13);
+        assert!((integrate(f64::exp, f64::NEG_INFINITY, 0.0).unwrap() - 1.0).abs() < 1e-13);
+
+        let normal = |x: f64| (-x * x / 2.0).exp() / (2.0 * PI).sqrt();
+        assert!((integrate(normal, f64::NEG_INFINITY, f64::INFINITY).unwrap() - 1.0).abs() < 1e-13);
+        assert!((integrate(|x| x * x * normal(x), f64::NEG_INFINITY, f64::INFINITY).unwrap() - 1.0).abs() < 1e-12);
+        assert!((integrate(normal, f64::INFINITY, f64::NEG_INFINITY).unwrap() + 1.0).abs() < 1e-13);
+    }
+
+    #[test]
+    fn integrate_piecewise_narrow() {
+        let normal = |x: f64| (-x * x / 2.0).exp() / (2.0 * PI).sqrt();
+        let narrow = |x: f64| normal((x - 50.0) * 1e4) * 1e4;
+        let breakpoints = [f64::NEG_INFINITY, 50.0 - 1e-4, 50.0, 50.0 + 1e-4, f64::INFINITY];
+
+        assert!((integrate_piecewise(narrow, &breakpoints) - 1.0).abs() < 1e-12);
+        assert!((integrate_piecewise(|x| x * narrow(x), &breakpoints) - 50.0).abs() < 1e-10);
+
+        assert!((integrate_piecewise(|x| x * x, &[0.0, 0.5, 1.0]) - 1.0 / 3.0).abs() < 1e-15);
+        assert!((integrate_piecewise(|x| (-x).exp(), &[0.0, f64::INFINITY]) - 1.0).abs() < 1e-13);
+        assert_eq!(integrate_piecewise(|x| x, &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn integrate_nan() {
+        assert_eq!(integrate(|_| f64::NAN, 0.0, 1.0), Err(NumericError::NonFinite));
+        assert_eq!(integrate(|x| x, f64::NAN, 1.0), Err(NumericError::InvalidArgument));
+        assert!(integrate_piecewise(|_| f64::NAN, &[0.0, 1.0]).is_nan());
+        assert!(integrate_piecewise(|x| x, &[f64::NAN, 1.0]).is_nan());
+    }
+
+    #[test]
+    fn integrate_divergent_fails() {
+        assert_eq!(integrate(|x| 1.0 / x, 0.0, 1.0), Err(NumericError::NotConverged));
+        assert_eq!(integrate(|_| 1.0, 0.0, f64::INFINITY), Err(NumericError::NotConverged));
+    }
+
+    #[test]
+    fn integrate_simpson_correct() {
+        assert!((integrate_simpson(|x| x * x, 0.0, 1.0).unwrap() - 1.0 / 3.0).abs() < 1e-15);
+        assert!((integrate_simpson(f64::sin, 0.0, PI).unwrap() - 2.0).abs() < 1e-12);
+        assert!((integrate_simpson(f64::abs, -1.0, 2.0).unwrap() - 2.5).abs() < 1e-12);
+        assert!((integrate_simpson(f64::exp, 1.0, 0.0).unwrap() + 1_f64.exp() - 1.0).abs() < 1e-12);
+
+        let normal = |x: f64| (-x * x / 2.0).exp() / (2.0 * PI).sqrt();
+        assert!((integrate_simpson(normal, f64::NEG_INFINITY, f64::INFINITY).unwrap() - 1.0).abs() < 1e-11);
+        assert!((integrate_simpson(|x| (-x).exp(), 0.0, f64::INFINITY).unwrap() - 1.0).abs() < 1e-11);
+
+        assert_eq!(integrate_simpson(|x| 1.0 / x.sqrt(), 0.0, 1.0), Err(NumericError::NonFinite));
+        assert_eq!(integrate_simpson(|x| x, 0.0, f64::NAN), Err(NumericError::InvalidArgument));
+    }
+
+    #[test]
+    fn brent_root_correct() {
+        assert!((brent_root(|x| x * x - 2.0, 0.0, 2.0).unwrap() - 2_f64.sqrt()).abs() < 1e-15);
+        assert!((brent_root(f64::cos, 0.0, 3.0).unwrap() - PI / 2.0).abs() < 1e-15);
+        assert!((brent_root(|x| x.powi(3) - x - 1.0, 2.0, -3.0).unwrap() - 1.324_717_957_244_746).abs() < 1e-15);
+        assert_eq!(brent_root(|x| x - 1.0, 1.0, 5.0), Ok(1.0));
+
+        // a discontinuous function, whose sign change is found like bisection
+        assert!((brent_root(|x| if x < 0.3 { -1.0 } else { 1.0 }, 0.0, 1.0).unwrap() - 0.3).abs() < 1e-15);
+        assert!(brent_root(|x| x.powi(3), -1.0, 2.0).unwrap().abs() < 1e-100);
+    }
+
+    #[test]
+    fn brent_root_invalid_fails() {
+        assert_eq!(brent_root(|x| x * x + 1.0, -1.0, 1.0), Err(NumericError::NoSignChange));
+        assert_eq!(brent_root(|x| x, -1.0, f64::INFINITY), Err(NumericError::InvalidArgument));
+        assert_eq!(brent_root(|x| x, f64::NAN, 1.0), Err(NumericError::InvalidArgument));
+        assert_eq!(brent_root(|x| x.ln(), -1.0, 2.0), Err(NumericError::NonFinite));
+    }
+
+    #[test]
+    fn newton_root_correct() {
+        assert!((newton_root(|x| (x * x - 2.0, 2.0 * x), 1.0).unwrap() - 2_f64.sqrt()).abs() < 1e-15);
+        assert!((newton_root(|x| (x.cos() - x, -x.sin() - 1.0), 0.0).unwrap() - 0.739_085_133_215_160_6).abs() < 1e-15);
+        assert_eq!(newton_root(|x| (x - 3.0, 1.0), 0.0), Ok(3.0));
+
+        assert_eq!(newton_root(|x| (x * x + 1.0, 0.0), 1.0), Err(NumericError::NotConverged));
+        assert_eq!(newton_root(|x| (x * x + 1.0, 2.0 * x), 1.0), Err(NumericError::NotConverged));
+        assert_eq!(newton_root(|x| (x.ln(), 1.0 / x), -1.0), Err(NumericError::NonFinite));
+        assert_eq!(newton_root(|x| (x, 1.0), f64::INFINITY), Err(NumericError::InvalidArgument));
+
+        // rounding error in f keeps the steps from shrinking below about 1e-10
+        let noisy = |x: f64| (x * x - 2.0 + 1e-10 * (x * 1e12).sin(), 2.0 * x);
+        assert!((newton_root(noisy, 1.0).unwrap() - 2_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn positive_newton_root_correct() {
+        assert!((positive_newton_root(|x| (x.ln() - 1.0, 1.0 / x), 10.0).unwrap() - std::f64::consts::E).abs() < 1e-14);
+        // the first step from 10 would be to a negative value
+        assert!((positive_newton_root(|x| (x * x - 2.0, 2.0 * x), 10.0).unwrap() - 2_f64.sqrt()).abs() < 1e-15);
+
+        assert_eq!(positive_newton_root(|x| (x + 1.0, 1.0), 1.0), Err(NumericError::NotConverged));
+        assert_eq!(positive_newton_root(|x| (x, 1.0), 0.0), Err(NumericError::InvalidArgument));
+        assert_eq!(positive_newton_root(|x| (x, 1.0), f64::NAN), Err(NumericError::InvalidArgument));
+    }
+
+    #[test]
+    fn positive_newton_root_2d_correct() {
+        let f = |[x, y]: [f64; 2]| ([x * y - 2.0, x - y - 1.0], [[y, x], [1.0, -1.0]]);
+        let [x, y] = positive_newton_root_2d(f, [1.0, 1.0]).unwrap();
+        assert!((x - 2.0).abs() < 1e-14 && (y - 1.0).abs() < 1e-14);
+
+        let singular = |[x, y]: [f64; 2]| ([x + y - 1.0, x + y - 2.0], [[1.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(positive_newton_root_2d(singular, [1.0, 1.0]), Err(NumericError::NotConverged));
+        assert_eq!(positive_newton_root_2d(f, [-1.0, 1.0]), Err(NumericError::InvalidArgument));
+    }
+
+    #[test]
+    fn bracket_root_correct() {
+        let (lower, upper) = bracket_root(|x| x - 100.0, 0.0, 1.0).unwrap();
+        assert!(lower <= 100.0 && 100.0 <= upper);
+
+        let (lower, upper) = bracket_root(|x| (x + 1e6).tanh(), -1.0, 1.0).unwrap();
+        assert!(lower <= -1e6 && -1e6 <= upper);
+
+        // only the bound at which |f| is smaller is widened
+        let (lower, upper) = bracket_root(|x| x * x - 50.0, 0.0, 1.0).unwrap();
+        assert!(lower == 0.0 && 50_f64.sqrt() <= upper);
+
+        assert_eq!(bracket_root(|x| x, -1.0, 1.0), Ok((-1.0, 1.0)));
+        assert_eq!(bracket_root(|x| x.atan() + 2.0, -1.0, 1.0), Err(NumericError::NoSignChange));
+        assert_eq!(bracket_root(|x| x, 1.0, -1.0), Err(NumericError::InvalidArgument));
+        assert_eq!(bracket_root(|x| if x < 10.0 { 1.0 } else { f64::NAN }, 1.0, 2.0), Err(NumericError::NonFinite));
+    }
+
+    #[test]
+    fn numeric_error_displays() {
+        assert_eq!(NumericError::NoSignChange.to_string(), "function doesn't change sign");
+        assert_eq!(NumericError::NotConverged.to_string(), "failed to converge");
+    }
+}
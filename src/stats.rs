@@ -1,65 +1,209 @@
 //! Commonly-used probability & statistics functionality.
 //! 
 //! Currently implemented are the properties of several common discrete and continuous distributions, as well as the 
-//! basic combinatorics required to implement them.
+//! basic combinatorics required to implement them. The special functions (error function, gamma function, etc.) that 
+//! the distributions are built on live in the `special` submodule, distributions over vectors live in the 
+//! `multivariate` submodule, mixtures of distributions live in the `mixture` submodule, truncated, shifted & scaled, and 
+//! censored distributions live in the `transform` submodule, kernel density estimates & histograms live in the `kde` & 
+//! `histogram` submodules, descriptive statistics of raw data sets live in the `describe` submodule, hypothesis tests & 
+//! confidence intervals live in the `tests` & `intervals` submodules, the bootstrap & permutation tests live in the 
+//! `resample` submodule, and divergences between distributions & entropy estimates from data live in the `information` 
+//! submodule.
+//! 
+//! The distributions are generic over their float type (`f64` by default), so e.g. `NormalDist<f32>` is a normal 
+//! distribution that works in single precision. Each distribution's `new()` creates it in double precision; `new_in()` 
+//! takes the same parameters but creates it in any float type, e.g. `NormalDist::new_in(0.0_f32, 1.0)` or 
+//! `DiscreteUniformDist::<f32>::new_in(1, 6)`.
+//! 
+//! With the `serde` feature enabled, the distributions (and `Fit`s of them) implement `Serialize` & `Deserialize`, as 
+//! structs of the parameters they're constructed from; deserialization validates the parameters with the same checks as 
+//! the constructors, so it fails rather than producing an invalid distribution.
 
 use ndarray::prelude::*;
 use ndarray::Array;
 
+use num_complex::Complex;
+
+use num_traits::Float;
 use num_traits::Num;
+use num_traits::NumCast;
 use num_traits::identities;
 
+use rand::Rng;
+use rand::RngCore;
+
 use std::collections::BTreeMap;
-use std::f64::consts::PI;
+use std::f64::consts::{E, PI};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use crate::numeric::{self, integrate_piecewise, quantile_breakpoints};
+use crate::utils::{cast, to_f64, ComparableFloat, CountTree};
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+
+pub mod describe;
+pub mod histogram;
+pub mod information;
+pub mod intervals;
+pub mod kde;
+pub mod mixture;
+pub mod multivariate;
+pub mod resample;
+pub mod special;
+pub mod tests;
+pub mod transform;
+
+pub use self::histogram::{Binning, Histogram, HistogramDist};
+pub use self::information::KlDivergence;
+pub use self::intervals::{ConfidenceInterval, ProportionMethod};
+pub use self::kde::{Bandwidth, Kernel, KernelDensityDist};
+pub use self::mixture::MixtureDist;
+pub use self::multivariate::MultivariateNormalDist;
+pub use self::resample::Bootstrap;
+pub use self::special::{erf, ln_gamma};
+pub use self::tests::{Alternative, TestResult};
+pub use self::transform::{Affine, Censored, Truncated};
+
+/// Returns n!, or `None` if the result overflows a `u64` (i.e. if n > 20).
+/// 
+/// ```ignore
+/// println!("{:?}", factorial(5)); // prints "Some(120)"
+/// println!("{:?}", factorial(21)); // prints "None"
+/// ```
+pub fn factorial(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1_u64, |product, i| product.checked_mul(i))
+}
+
+
+/// Returns n!, or `None` if the result overflows a `u128` (i.e. if n > 34).
+pub fn factorial_u128(n: u64) -> Option<u128> {
+    (2..=n as u128).try_fold(1_u128, |product, i| product.checked_mul(i))
+}
+
+
+/// Returns the natural log of n!.
+/// 
+/// The result is exact (up to rounding) for n <= 34 and is computed using `ln_gamma()` otherwise, so it remains finite
+/// and accurate long after n! itself overflows.
+pub fn ln_factorial(n: u64) -> f64 {
+    match factorial_u128(n) {
+        Some(f) => (f as f64).ln(),
+        None => ln_gamma(n as f64 + 1.0),
+    }
+}
+
+
+/// Returns the number of k-permutations of n, or `None` if the result overflows a `u64`.
+/// 
+/// Note that there are no k-permutations of n if k > n, so the function returns `Some(0)` in that case.
+pub fn permutations(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    ((n - k + 1)..=n).try_fold(1_u64, |product, i| product.checked_mul(i))
+}
+
+
+/// Returns the number of k-permutations of n, or `None` if the result overflows a `u128`.
+/// 
+/// As with `permutations()`, `Some(0)` is returned if k > n.
+pub fn permutations_u128(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+
+    ((n - k + 1) as u128..=n as u128).try_fold(1_u128, |product, i| product.checked_mul(i))
+}
 
-use crate::utils::ComparableFloat;
 
-/// Returns n!.
+/// Returns the natural log of the number of k-permutations of n, i.e. `ln(n! / (n - k)!)`.
 /// 
-/// Note that the function returns 0 if n < 0.
-pub fn factorial(n: i32) -> i32 {
-    if n == 0 {
-        return 1;
+/// Returns `-inf` (the log of `0`) if k > n.
+pub fn ln_permutations(n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
     }
 
-    Array::range(1.0, n as f64 + 1.0, 1.0).product() as i32
+    match permutations_u128(n, k) {
+        Some(p) => (p as f64).ln(),
+        None => ln_factorial(n) - ln_factorial(n - k),
+    }
 }
 
 
-/// Returns the number of k-permutations of n.
-pub fn permutations(n: i32, k: i32) -> i32 {
-    factorial(n) / factorial(n - k) // TODO: fix if n - k < 0
+/// Returns n choose k, or `None` if the result overflows a `u64`.
+///
+/// Note that the function returns `Some(0)` if k > n. The result is computed multiplicatively, dividing out common factors 
+/// as it goes, so intermediate values never overflow unless the result itself does.
+/// 
+/// ```ignore
+/// println!("{:?}", choose(5, 2)); // prints "Some(10)"
+/// println!("{:?}", choose(2, 5)); // prints "Some(0)"
+/// println!("{:?}", choose(100, 50)); // prints "None"
+/// ```
+pub fn choose(n: u64, k: u64) -> Option<u64> {
+    let c = choose_u128(n, k)?;
+
+    if c > u64::MAX as u128 { None } else { Some(c as u64) }
 }
 
 
-/// Returns n choose k.
+/// Returns n choose k, or `None` if the result overflows a `u128`.
 ///
-/// Note that the function returns 0 if k < 0 or n - k < 0.
-pub fn choose(n: i32, k: i32) -> i32 {
-    if k >= 0 && (n - k) >= 0 { // denominator can't be 0
-        factorial(n) / (factorial(n - k) * factorial(k))
+/// As with `choose()`, `Some(0)` is returned if k > n.
+pub fn choose_u128(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
     }
-    else {
-        0
+
+    let k = k.min(n - k) as u128; // symmetry keeps the loop (and the intermediate values) short
+    let n = n as u128;
+    let mut c = 1_u128;
+
+    for i in 0..k {
+        // c * (n - i) is always divisible by i + 1, so dividing the common factor out of c first keeps things exact
+        let divisor = i + 1;
+        let g = gcd(c, divisor);
+
+        c = (c / g).checked_mul((n - i) / (divisor / g))?;
     }
+
+    Some(c)
 }
 
 
-/// Returns the error function of x.
+/// Returns the natural log of n choose k.
+///
+/// Returns `-inf` (the log of `0`) if k > n. The result is exact (up to rounding) whenever n choose k fits in a `u128` and 
+/// is otherwise computed using `ln_gamma()`, so it remains accurate for very large n.
 /// 
-/// The error function is approximated using a formula from the book _*Abramowitz and Stegun*_, which approximates the 
-/// function with a maximum error of `1.5e-7`.
-pub fn erf(mut x: f64) -> f64 {
-    let parity = if x > 0.0 { 1.0 } else { -1.0 };
-    x *= parity;
+/// ```ignore
+/// println!("{}", ln_choose(1000000, 300000)); // prints approximately "610857.2557"
+/// ```
+pub fn ln_choose(n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
 
-    let coeffs = array![0.254829592, -0.284496736, 1.421413741, -1.453152027, 1.061405429];
-    let p = 0.3275911;
+    match choose_u128(n, k) {
+        Some(c) => (c as f64).ln(),
+        None => ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k),
+    }
+}
 
-    let t = 1.0 / (1.0 + p * x);
-    let t_values = array![t, t.powi(2), t.powi(3), t.powi(4), t.powi(5)];
 
-    parity * (1.0 - coeffs.dot(&t_values) * (-x.powi(2)).exp())
+/// Returns the greatest common divisor of a and b.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
 }
 
 
@@ -68,77 +212,437 @@ pub fn erf(mut x: f64) -> f64 {
 /// The `DiscreteDist` trait provides a general interface for distributions of discrete random variables, including PMF, CDF, mean/expectation, 
 /// variance, and standard deviation. Default implementations of CDF of an interval and standard deviation are provided.
 /// 
-/// `DiscreteDist` is parametrized by one generic type, `N`, which must conform to the Num trait in the `num-traits` crate; 
-/// it represents the type of the distribution's support.
-pub trait DiscreteDist<N: Num> {
+/// `DiscreteDist` is parametrized by two generic types. The first, `N`, must conform to the Num trait in the `num-traits` 
+/// crate; it represents the type of the distribution's support. The second, `F`, is a float type conforming to the `Float` 
+/// trait, which is the type of the probabilities & moments it returns (like the type parameter of `ContinuousDist`); it 
+/// defaults to `f64`, and e.g. a `BinomDist<f32>` is a `DiscreteDist<i32, f32>`. As with `ContinuousDist`, the default 
+/// implementations that sum over the support do so in double precision, and round the result to `F`.
+pub trait DiscreteDist<N: Num, F: Float = f64> {
     /// Returns the probability mass function (PMF) of `value`; varies by distribution. Convention is to return `0.0` for values that are outside the 
     /// distribution's support.
-    fn pmf(&self, value: N) -> f64;
+    fn pmf(&self, value: N) -> F;
+
+    /// Returns the natural log of the PMF of `value`. Convention is to return `-inf` for values that are outside the 
+    /// distribution's support.
+    ///
+    /// The default implementation takes the log of `pmf()`, so it's `-inf` wherever the PMF underflows to `0.0`; 
+    /// distributions override it to compute the log directly, which stays finite far into the tails.
+    fn log_pmf(&self, value: N) -> F {
+        self.pmf(value).ln()
+    }
+
+    /// Returns the log-likelihood of `data` under the distribution, i.e. the sum of the log PMF of each value in `data`.
+    ///
+    /// Because log PMFs are summed rather than PMFs multiplied, the log-likelihood doesn't underflow for large data sets. 
+    /// If any value in `data` is outside the distribution's support, `-inf` is returned.
+    fn log_likelihood(&self, data: &Array<N, Ix1>) -> F where N: Clone {
+        data.iter().fold(F::zero(), |sum, value| sum + self.log_pmf(value.clone()))
+    }
 
     /// Returns the cumulative density function (CDF) of `value`; varies by distribution.
-    fn cdf(&self, value: N) -> f64; // default implementation?
+    fn cdf(&self, value: N) -> F; // default implementation?
 
     /// Returns the probability that the discrete random variable described by this distribution will fall within the interval 
     /// `lower_bound <= n <= upper_bound` for some n value of the random variable, i.e. the "interval CDF".
     ///
     /// The default implementation of this function simply calculates the difference between the CDFs of `upper_bound` and 
     /// `lower_bound - 1`.
-    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> f64 {
+    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> F {
         self.cdf(upper_bound) - self.cdf(lower_bound - identities::one()) // need to subtract one so that you get the entire interval
     }
 
     /// Returns the mean (expectation) of the distribution.
-    fn mean(&self) -> f64;
+    fn mean(&self) -> F;
 
     /// Returns the variance (average square distance from the mean) of the distribution.
-    fn variance(&self) -> f64;
+    fn variance(&self) -> F;
 
     /// Returns the standard deviation of the distribution. The default implementation of this function returns the square
     /// root of the distribution's variance.
-    fn std(&self) -> f64 {
+    fn std(&self) -> F {
         self.variance().sqrt()
     }
+
+    /// Draws a single random value from the distribution, using `rng` as the source of randomness.
+    ///
+    /// Any generator implementing `rand::RngCore` can be used; seeding it (e.g. via `rand::SeedableRng::seed_from_u64()`) 
+    /// makes the drawn values reproducible. The generator is taken as a trait object so that distributions can still be 
+    /// used as trait objects themselves.
+    fn sample(&self, rng: &mut dyn RngCore) -> N;
+
+    /// Draws `n` independent random values from the distribution and returns them as a 1-dimensional array.
+    ///
+    /// The default implementation simply calls `sample()` `n` times.
+    fn sample_n(&self, rng: &mut dyn RngCore, n: usize) -> Array<N, Ix1> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+
+    /// Returns the quantile function (inverse CDF) of `p`, i.e. the smallest value `k` in the support for which 
+    /// `cdf(k) >= p`. For `p = 0`, the smallest value in the support is returned.
+    ///
+    /// Returns `None` if `p` isn't a valid probability, or if the quantile can't be represented by `N` (e.g. `p = 1` for a 
+    /// distribution whose support is unbounded above).
+    ///
+    /// The default implementation assumes that the support is a set of integers; it brackets the quantile by searching 
+    /// outward from `0` in exponentially growing steps, then binary searches the bracket using `cdf()`.
+    fn ppf(&self, p: F) -> Option<N> where N: NumCast {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        let reached = |k: i64| N::from(k).map(|n| quantile_reached(self.cdf(n), p));
+        let mut lower = -1;
+        let mut upper = 0;
+
+        if reached(upper)? {
+            while reached(lower)? {
+                upper = lower;
+                lower = lower.checked_mul(2)?;
+            }
+        }
+        else {
+            lower = 0;
+            upper = 1;
+
+            while !reached(upper)? {
+                lower = upper;
+                upper = upper.checked_mul(2)?;
+            }
+        }
+
+        N::from(integer_quantile_search(lower, upper, |k| reached(k).unwrap_or(false)))
+    }
+
+    /// Returns the median of the distribution, i.e. the quantile of `1/2`, or `None` if it can't be represented by `N`.
+    ///
+    /// The default implementation returns `ppf(0.5)`.
+    fn median(&self) -> Option<N> where N: NumCast {
+        self.ppf(cast(0.5))
+    }
+
+    /// Returns the mode of the distribution, i.e. the value in the support with the largest PMF (the smallest such value, 
+    /// if there are several), or `None` if it can't be found or represented by `N`.
+    ///
+    /// The default implementation assumes that the support is a set of integers, and searches the range of the support 
+    /// outside of which the distribution has negligible mass (see `skewness()`).
+    fn mode(&self) -> Option<N> where N: NumCast {
+        summation_range(self)?
+            .filter_map(|k| Some((k, self.pmf(N::from(k)?))))
+            .fold(None, |best: Option<(i64, F)>, (k, p)| match best {
+                Some((_, max)) if max >= p => best,
+                _ => Some((k, p)),
+            })
+            .and_then(|(k, _)| N::from(k))
+    }
+
+    /// Returns the skewness of the distribution, i.e. its third standardized moment `E[(X - mean)^3] / std^3`.
+    ///
+    /// Returns `NaN` if the skewness is undefined, e.g. if the variance is `0` or infinite. The default implementation 
+    /// assumes that the support is a set of integers, and sums over the range of the support outside of which the 
+    /// distribution has negligible mass (`1e-16` in each tail); it returns `NaN` if that range is too large to sum over.
+    fn skewness(&self) -> F where N: NumCast {
+        cast(standardized_moment(to_f64(self.mean()), to_f64(self.std()), 3, |f| discrete_expectation(self, f)))
+    }
+
+    /// Returns the excess kurtosis of the distribution, i.e. its fourth standardized moment `E[(X - mean)^4] / std^4` 
+    /// minus `3`, which is `0` for a normal distribution.
+    ///
+    /// Returns `NaN` if the kurtosis is undefined, e.g. if the variance is `0` or infinite. The default implementation sums 
+    /// over the support like that of `skewness()`.
+    fn kurtosis(&self) -> F where N: NumCast {
+        cast(standardized_moment(to_f64(self.mean()), to_f64(self.std()), 4, |f| discrete_expectation(self, f)) - 3.0)
+    }
+
+    /// Returns the (Shannon) entropy of the distribution in nats, i.e. `-E[ln(pmf(X))]`.
+    ///
+    /// The default implementation sums over the support like that of `skewness()`.
+    fn entropy(&self) -> F where N: NumCast {
+        match summation_range(self) {
+            Some(range) => cast(-range
+                .filter_map(|k| Some((to_f64(self.pmf(N::from(k)?)), to_f64(self.log_pmf(N::from(k)?)))))
+                .filter(|(p, _)| *p > 0.0)
+                .map(|(p, ln_p)| p * ln_p)
+                .sum::<f64>()),
+            None => F::nan(),
+        }
+    }
+
+    /// Returns the moment-generating function (MGF) of `t`, i.e. `E[e^(tX)]`, or `None` if it doesn't exist at `t`.
+    ///
+    /// The default implementation sums over the support like that of `skewness()`, so it only detects that the MGF 
+    /// doesn't exist if the sum overflows; distributions whose MGF is known override it.
+    fn mgf(&self, t: F) -> Option<F> where N: NumCast {
+        let t = to_f64(t);
+        let value: F = cast(discrete_expectation(self, |x| (t * x).exp()));
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, i.e. `E[e^(itX)]`, which (unlike the MGF) exists for every distribution.
+    ///
+    /// The default implementation sums over the support like that of `skewness()`.
+    ///
+    /// ```ignore
+    /// let dist = BernoulliDist::new(0.25).unwrap();
+    /// println!("{}", dist.cf(PI)); // prints "0.5+0i", approximately
+    /// ```
+    fn cf(&self, t: F) -> Complex<F> where N: NumCast {
+        let t = to_f64(t);
+        Complex::new(cast(discrete_expectation(self, |x| (t * x).cos())), cast(discrete_expectation(self, |x| (t * x).sin())))
+    }
+}
+
+
+/// Returns whether a CDF value of `cdf` is large enough to reach the quantile of `p`.
+///
+/// This is `cdf >= p`, except for `p = 0`, whose quantile is defined as the bottom of the support rather than `-inf`.
+fn quantile_reached<F: Float>(cdf: F, p: F) -> bool {
+    if p == F::zero() { cdf > F::zero() } else { cdf >= p }
+}
+
+
+/// Returns the smallest integer in `(lower, upper]` for which `reached` is true, assuming that `reached` is monotonic, 
+/// false for `lower`, and true for `upper`.
+fn integer_quantile_search<F: Fn(i64) -> bool>(mut lower: i64, mut upper: i64, reached: F) -> i64 {
+    while upper - lower > 1 {
+        let mid = lower + (upper - lower) / 2;
+
+        if reached(mid) {
+            upper = mid;
+        }
+        else {
+            lower = mid;
+        }
+    }
+
+    upper
+}
+
+/// Returns the `order`-th standardized moment `E[((X - mean) / std)^order]` of a distribution, given a function that 
+/// computes the expectation of a function of its values, or `NaN` if the mean or standard deviation isn't finite or the 
+/// standard deviation is `0`.
+fn standardized_moment<E: Fn(&dyn Fn(f64) -> f64) -> f64>(mean: f64, std: f64, order: i32, expectation: E) -> f64 {
+    if !mean.is_finite() || !std.is_finite() || std <= 0.0 {
+        return f64::NAN;
+    }
+
+    expectation(&|x| ((x - mean) / std).powi(order))
+}
+
+
+/// Returns the range of integers outside of which `dist` has negligible mass, or `None` if it can't be found or is too 
+/// wide to sum over.
+///
+/// The mass left out of each tail is `NEGLIGIBLE_TAIL_MASS`, or the machine epsilon of `F` if that's larger, since a 
+/// smaller tail mass couldn't be told apart from `0` in the quantile of `1` minus it.
+fn summation_range<N, F, D>(dist: &D) -> Option<RangeInclusive<i64>>
+    where N: Num + NumCast, F: Float, D: DiscreteDist<N, F> + ?Sized
+{
+    let tail_mass = NEGLIGIBLE_TAIL_MASS.max(to_f64(F::epsilon()));
+    let lower = dist.ppf(cast(tail_mass))?.to_i64()?;
+    let upper = dist.ppf(cast(1.0 - tail_mass))?.to_i64()?;
+
+    if upper.checked_sub(lower)? > MAX_SUMMATION_TERMS {
+        return None;
+    }
+
+    Some(lower..=upper)
+}
+
+
+/// Returns the expectation of `f` of a value drawn from `dist`, whose support must be a set of integers, by summing over 
+/// `summation_range()`; returns `NaN` if the range can't be found.
+fn discrete_expectation<N, F, D, G>(dist: &D, f: G) -> f64
+    where N: Num + NumCast, F: Float, D: DiscreteDist<N, F> + ?Sized, G: Fn(f64) -> f64
+{
+    match summation_range(dist) {
+        Some(range) => range
+            .filter_map(|k| Some((k, to_f64(dist.pmf(N::from(k)?)))))
+            .filter(|(_, p)| *p > 0.0)
+            .map(|(k, p)| p * f(k as f64))
+            .sum(),
+        None => f64::NAN,
+    }
+}
+
+
+/// Returns the PDF of `dist` at `x` as an `f64`, or `0` if `x` can't be represented by `N`.
+fn density_at<N: Float, D: ContinuousDist<N> + ?Sized>(dist: &D, x: f64) -> f64 {
+    N::from(x).map_or(0.0, |value| to_f64(dist.pdf(value)))
+}
+
+
+/// Returns the integral of `integrand` over the real line, split at several quantiles of `dist` so that the scale & 
+/// location of the distribution are taken into account.
+fn integrate_over<N, D, F>(dist: &D, integrand: F) -> f64
+    where N: Float, D: ContinuousDist<N> + ?Sized, F: Fn(f64) -> f64
+{
+    let breakpoints = quantile_breakpoints(|p| dist.ppf(cast(p))?.to_f64(), f64::NEG_INFINITY, f64::INFINITY);
+    integrate_piecewise(integrand, &breakpoints)
+}
+
+
+/// Returns the expectation of `f` of a value drawn from `dist`, by numerical integration.
+fn continuous_expectation<N, D, F>(dist: &D, f: F) -> f64
+    where N: Float, D: ContinuousDist<N> + ?Sized, F: Fn(f64) -> f64
+{
+    integrate_over(dist, |x| {
+        let density = density_at(dist, x);
+        if density > 0.0 { density * f(x) } else { 0.0 }
+    })
+}
+
+
+/// Returns an `InvalidParameter` error naming `name` unless `value` is a valid probability, i.e. in `[0, 1]`.
+fn check_probability<F: Float>(name: &'static str, value: F) -> Result<(), PensivError> {
+    if (F::zero()..=F::one()).contains(&value) {
+        Ok(())
+    }
+    else {
+        Err(PensivError::InvalidParameter { name, value: to_f64(value), expected: "a probability in [0, 1]" })
+    }
+}
+
+
+/// Returns an `InvalidParameter` error naming `name` unless `value` is a positive, finite number.
+fn check_positive<F: Float>(name: &'static str, value: F) -> Result<(), PensivError> {
+    if value > F::zero() && value.is_finite() {
+        Ok(())
+    }
+    else {
+        Err(PensivError::InvalidParameter { name, value: to_f64(value), expected: "a positive, finite number" })
+    }
+}
+
+
+/// Returns `x ln(x)`, taking `0 ln(0)` to be `0` as in the definition of entropy.
+fn x_ln_x<F: Float>(x: F) -> F {
+    if x > F::zero() { x * x.ln() } else { F::zero() }
+}
+
+
+/// Returns the mode of a discrete distribution whose mode is `floor(m)`, e.g. `m = (n + 1)p` for the binomial 
+/// distribution; if `m` is a positive integer, both `m` and `m - 1` are modes, and the smaller is returned.
+fn integer_mode(m: f64) -> i32 {
+    if m > 0.0 && m.fract() == 0.0 { m as i32 - 1 } else { m.floor() as i32 }
+}
+
+
+
+/// The result of fitting a distribution to data by maximum likelihood.
+///
+/// Along with the fitted distribution, a `Fit` holds the (asymptotic) standard error of each of its parameter estimates, 
+/// in the same order as the parameters of the distribution's constructor. Standard errors are computed from the Fisher 
+/// information of the distribution at the estimated parameters, unless noted otherwise by the distribution's `fit()`.
+///
+/// ```ignore
+/// let data = array![1.2, 0.8, 1.9, 1.4, 0.6];
+/// let fit = NormalDist::fit(&data).unwrap();
+///
+/// println!("{}", fit.dist().loc()); // prints approximately "1.18"
+/// println!("{}", fit.std_errors()); // prints the standard errors of the location & scale
+///
+/// let dist = fit.into_dist();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Fit<D> {
+    dist: D,
+    std_errors: Array<f64, Ix1>,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(Fit<D>, |fit| {
+    dist: D = &fit.dist,
+    std_errors: Vec<f64> = &fit.std_errors.to_vec(),
+} => Ok(Fit { dist, std_errors: Array::from(std_errors) }));
+
+impl<D> Fit<D> {
+    /// Returns the fitted distribution.
+    pub fn dist(&self) -> &D {
+        &self.dist
+    }
+
+    /// Consumes the fit and returns the fitted distribution.
+    pub fn into_dist(self) -> D {
+        self.dist
+    }
+
+    /// Returns the standard errors of the parameter estimates, in the same order as the parameters of the distribution's 
+    /// constructor.
+    pub fn std_errors(&self) -> &Array<f64, Ix1> {
+        &self.std_errors
+    }
+}
+
+
+/// Returns the mean of `data`, or an error if `data` is empty or contains values that aren't finite.
+fn fit_mean(data: &Array<f64, Ix1>) -> Result<f64, PensivError> {
+    if data.iter().any(|x| !x.is_finite()) {
+        return Err(PensivError::NonFiniteData);
+    }
+
+    data.mean().ok_or(PensivError::EmptyData)
+}
+
+
+/// Returns whether every value in `data` is an integer within `[lower, upper]`.
+fn all_integers_within(data: &Array<f64, Ix1>, lower: f64, upper: f64) -> bool {
+    data.iter().all(|x| x.fract() == 0.0 && (lower..=upper).contains(x))
+}
+
+
+/// Returns the standard deviation of the minimum (or maximum) of `n` values drawn uniformly from an interval of width 
+/// `range`, which is used as the standard error of the bounds fitted to uniform distributions.
+fn uniform_bound_std_error(range: f64, n: usize) -> f64 {
+    let n = n as f64;
+    range * n.sqrt() / ((n + 1.0) * (n + 2.0).sqrt())
 }
 
 
+/// Range of degrees of freedom searched when fitting t- & F-distributions.
+const FIT_DOF_RANGE: (f64, f64) = (1e-3, 1e6);
+
+/// Probability mass in each tail of a discrete distribution that's left out when summing over its support.
+const NEGLIGIBLE_TAIL_MASS: f64 = 1e-16;
+
+/// Largest number of terms summed over the support of a discrete distribution.
+const MAX_SUMMATION_TERMS: i64 = 10_000_000;
+
+/// Multiples of the standard deviation either side of the mean at which the default CDF of a continuous distribution 
+/// splits its integral.
+const CDF_BREAKPOINT_DEVIATIONS: [f64; 11] = [-16.0, -8.0, -4.0, -2.0, -1.0, 0.0, 1.0, 2.0, 4.0, 8.0, 16.0];
+
+/// Number of intervals in the grid of quantiles over which the mode of a continuous distribution is first searched for.
+const MODE_GRID_POINTS: usize = 200;
+
+/// Number of golden-section iterations used to refine the mode of a continuous distribution.
+const MODE_REFINEMENTS: usize = 100;
+
+
 /// A discrete uniform distribution.
 ///
 /// A `DiscreteUniformDist` is described by its support's lower and upper bounds; all integer values within that range (inclusive)
 /// have equal likelihood of occurring.
 #[derive(Debug, PartialEq)]
-pub struct DiscreteUniformDist {
+pub struct DiscreteUniformDist<F: Float = f64> {
     lower_bound: i32,
-    upper_bound: i32
+    upper_bound: i32,
+    precision: PhantomData<F>,
 }
 
-impl DiscreteUniformDist {
-    /// Creates a new discrete uniform distribution with the given lower and upper bounds.
-    ///
-    /// Returns `None` if `lower_bound > upper_bound`, otherwise returns `Some` containing 
-    /// the created `DiscreteUniformDist`.
-    ///
-    /// ```ignore
-    /// let a = 1;
-    /// let b = 5;
-    /// 
-    /// let dist = DiscreteUniformDist::new(a, b).unwrap();
-    /// ```
-    ///
-    /// ```ignore
-    /// let a = 5;
-    /// let b = 1;
-    /// 
-    /// match DiscreteUniformDist::new(a, b) {
-    ///     Some(_) => println!("got the distribution!"),
-    ///     None => println!("creation failed"),
-    /// } // prints "creation failed"
-    /// ```
-    pub fn new(lower_bound: i32, upper_bound: i32) -> Option<Self> {
+#[cfg(feature = "serde")]
+serde_via_params!(DiscreteUniformDist<F: Float>, |dist| {
+    lower_bound: i32 = &dist.lower_bound,
+    upper_bound: i32 = &dist.upper_bound,
+} => DiscreteUniformDist::new_in(lower_bound, upper_bound));
+
+impl<F: Float> DiscreteUniformDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(lower_bound: i32, upper_bound: i32) -> Result<DiscreteUniformDist<F>, PensivError> {
         if lower_bound > upper_bound {
-            return None;
+            return Err(PensivError::InvalidBounds { lower: lower_bound as f64, upper: upper_bound as f64 });
         }
 
-        Some(DiscreteUniformDist { lower_bound, upper_bound })
+        Ok(DiscreteUniformDist { lower_bound, upper_bound, precision: PhantomData })
     }
 
     /// Returns the range of the distribution, i.e. the length of the support.
@@ -165,7 +669,64 @@ impl DiscreteUniformDist {
     }
 }
 
-impl DiscreteDist<i32> for DiscreteUniformDist {
+impl DiscreteUniformDist {
+    /// Creates a new discrete uniform distribution with the given lower and upper bounds.
+    ///
+    /// Returns an `InvalidBounds` error if `lower_bound > upper_bound`, otherwise returns `Ok` containing 
+    /// the created `DiscreteUniformDist`.
+    ///
+    /// ```ignore
+    /// let a = 1;
+    /// let b = 5;
+    /// 
+    /// let dist = DiscreteUniformDist::new(a, b).unwrap();
+    /// ```
+    ///
+    /// ```ignore
+    /// let a = 5;
+    /// let b = 1;
+    /// 
+    /// match DiscreteUniformDist::new(a, b) {
+    ///     Ok(_) => println!("got the distribution!"),
+    ///     Err(error) => println!("creation failed: {}", error),
+    /// } // prints "creation failed: invalid bounds: [5, 1]"
+    /// ```
+    pub fn new(lower_bound: i32, upper_bound: i32) -> Result<DiscreteUniformDist, PensivError> {
+        DiscreteUniformDist::new_in(lower_bound, upper_bound)
+    }
+
+    /// Fits a discrete uniform distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood bounds are the minimum and maximum of the data. Since the likelihood isn't smooth in the 
+    /// bounds, their standard errors are the (approximate) standard deviations of the sample minimum and maximum rather 
+    /// than being derived from the Fisher information.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidInput` error if it contains values that aren't integers representable by an `i32`.
+    ///
+    /// ```ignore
+    /// let data = array![2.0, 5.0, 3.0, 4.0, 2.0];
+    /// let fit = DiscreteUniformDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().lower_bound(), fit.dist().upper_bound()); // prints "2 5"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<DiscreteUniformDist>, PensivError> {
+        fit_mean(data)?;
+
+        if !all_integers_within(data, i32::MIN as f64, i32::MAX as f64) {
+            return Err(PensivError::InvalidInput("data must be integers representable by an i32"));
+        }
+
+        let lower_bound = data.fold(f64::INFINITY, |a, &b| a.min(b));
+        let upper_bound = data.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        let std_error = uniform_bound_std_error(upper_bound - lower_bound, data.len());
+
+        let dist = DiscreteUniformDist::new(lower_bound as i32, upper_bound as i32)?;
+        Ok(Fit { dist, std_errors: array![std_error, std_error] })
+    }
+}
+
+impl<F: Float> DiscreteDist<i32, F> for DiscreteUniformDist<F> {
     /// Returns the PMF of `value` within the distribution.
     ///
     /// This method returns `1.0 / range` (where `range` is the range of the distributrion) uniformly for all elements 
@@ -182,12 +743,12 @@ impl DiscreteDist<i32> for DiscreteUniformDist {
     /// 
     /// println!("{}", dist.pmf(2 * b)); // prints "0"
     /// ```
-    fn pmf(&self, value: i32) -> f64 {
+    fn pmf(&self, value: i32) -> F {
         if value < self.lower_bound || value > self.upper_bound {
-            return 0.0;
+            return F::zero();
         }
 
-        1.0 / self.range() as f64
+        F::one() / cast(self.range() as f64)
     }
 
     /// Returns the CDF of `value` within the distribution.
@@ -208,96 +769,220 @@ impl DiscreteDist<i32> for DiscreteUniformDist {
     /// println!("{}", dist.cdf(a - 1)); // prints "0.0"
     /// println!("{}", dist.cdf(b + 1)); // prints "1.0"
     /// ```
-    fn cdf(&self, value: i32) -> f64 {
+    fn cdf(&self, value: i32) -> F {
         if value < self.lower_bound {
-            return 0.0;
+            return F::zero();
         }
         else if value > self.upper_bound {
-            return 1.0;
+            return F::one();
         }
 
-        (value - self.lower_bound + 1) as f64 / self.range() as f64
+        cast((value - self.lower_bound + 1) as f64 / self.range() as f64)
     }
 
     // TODO: better interval cdf implementation
 
     /// Returns the mean of the uniform distribution, i.e. the average of the upper and lower bounds of the support
-    fn mean(&self) -> f64 {
-        (self.upper_bound + self.lower_bound) as f64 / 2.0
+    fn mean(&self) -> F {
+        cast((self.upper_bound as f64 + self.lower_bound as f64) / 2.0)
     }
 
     /// Returns the variance of the uniform distribution.
     ///
     /// The variance is calculated according to the following formula:
     /// `((upper bound - lower bound + 1)^2 - 1) / 12`
-    fn variance(&self) -> f64 {
-        (((self.upper_bound - self.upper_bound + 1) * (self.upper_bound - self.upper_bound + 1)) as f64 - 1.0) / 12.0
+    fn variance(&self) -> F {
+        cast(((self.range() * self.range()) as f64 - 1.0) / 12.0)
     }
-}
-
-
-/// A distribution describing a Bernoulli ("indicator") random variable.
-///
-/// Bernoulli distributions are parametrized by `p`, the probability that a "success" will occur; or equivalently the probability 
-/// that the random variable will be the value `1`. The support of a Bernoulli random variable, therefore, is `{0, 1}`; the variable
-/// essentially "indicates" a 1 with probability `p`.
-#[derive(Debug, PartialEq)]
-pub struct BernoulliDist {
-    p_success: f64,
-}
 
-impl BernoulliDist {
-    /// Creates and returns a new Bernoulli distribution with parameter `p = p_success`.
-    ///
-    /// Note that `p_success` must be a valid probability, so the function returns None if it's invalid, i.e. if 
-    /// `p_success < 0` or `p_success > 1`. If `p_success` is valid, however, the created distribution is returned.
+    /// Draws a random value from the distribution; every value in the support is equally likely to be drawn.
     ///
     /// ```ignore
-    /// let p = 0.4;
-    /// let dist = BernoulliDist::new(p).unwrap();
-    /// 
-    /// println!("{}", dist.p_success()); // prints "0.4"
+    /// let dist = DiscreteUniformDist::new(1, 6).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let roll = dist.sample(&mut rng); // a fair die roll
     /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        // widen before adding 1 so that an upper bound of i32::MAX doesn't overflow
+        rng.gen_range(self.lower_bound as i64, self.upper_bound as i64 + 1) as i32
+    }
+
+    /// Returns the quantile of `p`, i.e. the smallest value in the support whose CDF is at least `p`.
+    ///
+    /// Because the CDF grows in equal steps of `1 / range`, the quantile is found directly as 
+    /// `lower bound - 1 + ceil(p * range)`.
     ///
     /// ```ignore
-    /// let p1 = -1.0;
-    /// match BernoulliDist::new(p1) {
-    ///     Some(_) => println!("got it!"),
-    ///     None => println!("don't got it!"),
-    /// }
+    /// let dist = DiscreteUniformDist::new(1, 4).unwrap();
     ///
-    /// let p2 = 1.7;
-    /// match BernoulliDist::new(p2) {
-    ///     Some(_) => println!("got it!"),
-    ///     None => println!("don't got it!"),
-    /// }
-    /// 
-    /// // both print "don't got it!"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(2)"
+    /// println!("{:?}", dist.ppf(0.6)); // prints "Some(3)"
+    /// println!("{:?}", dist.ppf(1.5)); // prints "None"
     /// ```
-    pub fn new(p_success: f64) -> Option<Self> {
-        if p_success < 0.0 || p_success > 1.0 {
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) {
             return None;
         }
 
-        Some(BernoulliDist { p_success })
+        let steps = ((to_f64(p) * self.range() as f64).ceil() as i32).max(1);
+        let mut k = self.lower_bound - 1 + steps.min(self.range());
+
+        // guard against p * range rounding up past an exact step
+        if k > self.lower_bound && self.cdf(k - 1) >= p {
+            k -= 1;
+        }
+
+        Some(k)
+    }
+
+    /// Returns the mode of the distribution; every value in the support is equally likely, so the lower bound is returned.
+    fn mode(&self) -> Option<i32> {
+        Some(self.lower_bound)
+    }
+
+    /// Returns the skewness of the distribution, which is `0` by symmetry (or `NaN` if the support has only 1 value).
+    fn skewness(&self) -> F {
+        if self.lower_bound < self.upper_bound { F::zero() } else { F::nan() }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `-6(n^2 + 1) / (5(n^2 - 1))` where `n` is the number 
+    /// of values in the support (or `NaN` if there's only 1).
+    fn kurtosis(&self) -> F {
+        let n = self.range() as f64;
+        if n > 1.0 { cast(-6.0 * (n * n + 1.0) / (5.0 * (n * n - 1.0))) } else { F::nan() }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `ln(n)` where `n` is the number of values in the support.
+    fn entropy(&self) -> F {
+        cast((self.range() as f64).ln())
+    }
+
+    /// Returns the MGF of `t`, equivalent to `e^(at) (e^(nt) - 1) / (n (e^t - 1))` where `a` is the lower bound and `n` is 
+    /// the number of values in the support, or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        if t == F::zero() {
+            return Some(F::one());
+        }
+
+        let n: F = cast(self.range() as f64);
+        let value = (cast::<F>(self.lower_bound as f64) * t).exp() * (n * t).exp_m1() / (n * t.exp_m1());
+
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `e^(iat) (e^(int) - 1) / (n (e^(it) - 1))`.
+    fn cf(&self, t: F) -> Complex<F> {
+        let n: F = cast(self.range() as f64);
+        let lower_bound: F = cast(self.lower_bound as f64);
+        let unit = |x: F| Complex::new(x.cos(), x.sin());
+        let denominator = unit(t) - F::one();
+
+        // every value of the support is a multiple of the period
+        if denominator.norm() == F::zero() {
+            return unit(lower_bound * t);
+        }
+
+        unit(lower_bound * t) * (unit(n * t) - F::one()) / (denominator * n)
+    }
+}
+
+
+/// A distribution describing a Bernoulli ("indicator") random variable.
+///
+/// Bernoulli distributions are parametrized by `p`, the probability that a "success" will occur; or equivalently the probability 
+/// that the random variable will be the value `1`. The support of a Bernoulli random variable, therefore, is `{0, 1}`; the variable
+/// essentially "indicates" a 1 with probability `p`.
+#[derive(Debug, PartialEq)]
+pub struct BernoulliDist<F: Float = f64> {
+    p_success: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(BernoulliDist<F: Float>, |dist| {
+    p_success: F = &dist.p_success,
+} => BernoulliDist::new_in(p_success));
+
+impl<F: Float> BernoulliDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(p_success: F) -> Result<BernoulliDist<F>, PensivError> {
+        check_probability("p_success", p_success)?;
+        Ok(BernoulliDist { p_success })
     }
 
     /// Returns the probability of the Bernoulli random variable indicating success, i.e. the probability of the value `1`.
     ///
     /// Note that this is the same as the value of the parameter.
-    pub fn p_success(&self) -> f64 {
+    pub fn p_success(&self) -> F {
         self.p_success
     }
 
     /// Returns the probability of the Bernoulli random variable indicating failure, i.e. the probability of the value `0`.
     ///
     /// Note that this is the same as `1 - p`, where `p` is the value of the parameter.
-    pub fn p_failure(&self) -> f64 {
-        1.0 - self.p_success
+    pub fn p_failure(&self) -> F {
+        F::one() - self.p_success
+    }
+}
+
+impl BernoulliDist {
+    /// Creates and returns a new Bernoulli distribution with parameter `p = p_success`.
+    ///
+    /// Note that `p_success` must be a valid probability, so the function returns an `InvalidParameter` error if it's 
+    /// invalid, i.e. if `p_success < 0`, `p_success > 1` or `p_success` is `NaN`. If `p_success` is valid, however, the 
+    /// created distribution is returned.
+    ///
+    /// ```ignore
+    /// let p = 0.4;
+    /// let dist = BernoulliDist::new(p).unwrap();
+    /// 
+    /// println!("{}", dist.p_success()); // prints "0.4"
+    /// ```
+    ///
+    /// ```ignore
+    /// let p1 = -1.0;
+    /// match BernoulliDist::new(p1) {
+    ///     Ok(_) => println!("got it!"),
+    ///     Err(_) => println!("don't got it!"),
+    /// }
+    ///
+    /// let p2 = 1.7;
+    /// match BernoulliDist::new(p2) {
+    ///     Ok(_) => println!("got it!"),
+    ///     Err(_) => println!("don't got it!"),
+    /// }
+    /// 
+    /// // both print "don't got it!"
+    /// ```
+    pub fn new(p_success: f64) -> Result<BernoulliDist, PensivError> {
+        BernoulliDist::new_in(p_success)
+    }
+
+    /// Fits a Bernoulli distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimate of `p` is the fraction of successes in the data, and its standard error is 
+    /// `sqrt(p(1 - p) / n)`. Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that 
+    /// aren't finite, or an `InvalidInput` error if it contains values other than `0` and `1`.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 0.0, 1.0, 1.0];
+    /// let fit = BernoulliDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().p_success()); // prints "0.75"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<BernoulliDist>, PensivError> {
+        let p = fit_mean(data)?;
+
+        if !all_integers_within(data, 0.0, 1.0) {
+            return Err(PensivError::InvalidInput("data must be 0 or 1"));
+        }
+
+        let std_error = (p * (1.0 - p) / data.len() as f64).sqrt();
+        Ok(Fit { dist: BernoulliDist::new(p)?, std_errors: array![std_error] })
     }
 }
 
-impl DiscreteDist<i32> for BernoulliDist {
+impl<F: Float> DiscreteDist<i32, F> for BernoulliDist<F> {
     /// Returns the Bernoulli PMF of `value`.
     ///
     /// Returns `p` for `value = 1`, `1 - p` for `value = 0`, and `0.0` otherwise (where `p` is the value of the parameter).
@@ -311,7 +996,7 @@ impl DiscreteDist<i32> for BernoulliDist {
     /// println!("{}", dist.pmf(1)); // prints "0.4"
     /// println!("{}", dist.pmf(2)); // prints "0.0"
     /// ```
-    fn pmf(&self, value: i32) -> f64 {
+    fn pmf(&self, value: i32) -> F {
         if value == 0 {
             return self.p_failure();
         }
@@ -319,7 +1004,18 @@ impl DiscreteDist<i32> for BernoulliDist {
             return self.p_success;
         }
         else {
-            return 0.0;
+            return F::zero();
+        }
+    }
+
+    /// Returns the natural log of the Bernoulli PMF of `value`.
+    ///
+    /// The log of `1 - p` is computed directly (rather than by subtracting from `1`), so it keeps its precision for tiny `p`.
+    fn log_pmf(&self, value: i32) -> F {
+        match value {
+            0 => (-self.p_success).ln_1p(),
+            1 => self.p_success.ln(),
+            _ => F::neg_infinity(),
         }
     }
 
@@ -337,27 +1033,89 @@ impl DiscreteDist<i32> for BernoulliDist {
     /// println!("{}", dist.cdf(1)); // prints "1.0"
     /// println!("{}", dist.cdf(2)); // prints "1.0"
     /// ```
-    fn cdf(&self, value: i32) -> f64 {
+    fn cdf(&self, value: i32) -> F {
         if value < 0 {
-            return 0.0;
+            return F::zero();
         }
         else if value == 0 {
             return self.p_failure();
         }
         else {
-            return 1.0;
+            return F::one();
         }
     }
 
     /// Returns the mean of the Bernoulli random variable, equal to the parameter `p`.
-    fn mean(&self) -> f64 {
+    fn mean(&self) -> F {
         self.p_success
     }
 
     /// Returns the variance of the Bernoulli random variable, equal to `p(1 - p)` where `p` is the value of the parameter.
-    fn variance(&self) -> f64 {
+    fn variance(&self) -> F {
         self.p_success * self.p_failure()
     }
+
+    /// Draws a random value from the distribution, i.e. `1` with probability `p` and `0` otherwise.
+    ///
+    /// ```ignore
+    /// let dist = BernoulliDist::new(0.4).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let flips = dist.sample_n(&mut rng, 10); // ten flips of a biased coin
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        if rng.gen::<f64>() < to_f64(self.p_success) { 1 } else { 0 }
+    }
+
+    /// Returns the quantile of `p`, i.e. `0` if `p <= 1 - p_success` and `1` otherwise.
+    ///
+    /// ```ignore
+    /// let dist = BernoulliDist::new(0.4).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(0)"
+    /// println!("{:?}", dist.ppf(0.7)); // prints "Some(1)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        if quantile_reached(self.p_failure(), p) { Some(0) } else { Some(1) }
+    }
+
+    /// Returns the mode of the distribution, i.e. `1` if `p > 1/2` and `0` otherwise.
+    fn mode(&self) -> Option<i32> {
+        Some(if self.p_success > cast(0.5) { 1 } else { 0 })
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `(1 - 2p) / sqrt(p(1 - p))` (or `NaN` if `p` is `0` or `1`).
+    fn skewness(&self) -> F {
+        let variance = self.variance();
+        if variance > F::zero() { (F::one() - cast::<F>(2.0) * self.p_success) / variance.sqrt() } else { F::nan() }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `(1 - 6p(1 - p)) / (p(1 - p))` (or `NaN` if `p` is 
+    /// `0` or `1`).
+    fn kurtosis(&self) -> F {
+        let variance = self.variance();
+        if variance > F::zero() { (F::one() - cast::<F>(6.0) * variance) / variance } else { F::nan() }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `-p ln(p) - (1 - p) ln(1 - p)`.
+    fn entropy(&self) -> F {
+        -x_ln_x(self.p_success) - x_ln_x(self.p_failure())
+    }
+
+    /// Returns the MGF of `t`, equivalent to `1 - p + p e^t`, or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = self.p_failure() + self.p_success * t.exp();
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `1 - p + p e^(it)`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::new(self.p_failure() + self.p_success * t.cos(), self.p_success * t.sin())
+    }
 }
 
 
@@ -366,15 +1124,54 @@ impl DiscreteDist<i32> for BernoulliDist {
 /// Binomial distributions are parameterized by `p`, the probability that each trial will be a success (i.e. the Bernoulli probability of each trial), 
 /// and by `n`, the number of trials to perform. The support of the binomial distribution is the (infinite) set of non-negative integers.
 #[derive(Debug, PartialEq)]
-pub struct BinomDist {
-    p_success: f64,
+pub struct BinomDist<F: Float = f64> {
+    p_success: F,
     trials: i32,
 }
 
+#[cfg(feature = "serde")]
+serde_via_params!(BinomDist<F: Float>, |dist| {
+    trials: i32 = &dist.trials,
+    p_success: F = &dist.p_success,
+} => BinomDist::new_in(trials, p_success));
+
+impl<F: Float> BinomDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(trials: i32, p_success: F) -> Result<BinomDist<F>, PensivError> {
+        check_probability("p_success", p_success)?;
+
+        if trials < 0 {
+            return Err(PensivError::InvalidParameter {
+                name: "trials",
+                value: trials as f64,
+                expected: "a non-negative integer",
+            });
+        }
+
+        Ok(BinomDist { p_success, trials })
+    }
+
+    /// Returns the probability that each trial will succeed, i.e. the Bernoulli probability of each trial or the value of the parameter `p`.
+    pub fn p_success(&self) -> F {
+        self.p_success
+    }
+
+    /// Returns the probability that each trial will fail.
+    pub fn p_failure(&self) -> F {
+        F::one() - self.p_success
+    }
+
+    /// Returns the number of trials.
+    pub fn trials(&self) -> i32 {
+        self.trials
+    }
+}
+
 impl BinomDist {
     /// Creates and returns a new binomial distribution with parameters `n = trials` and `p = p_success`.
     ///
-    /// Returns `None` if `p_success` is not a valid probability or if `trials < 0`, otherwise returns the created distribution.
+    /// Returns an `InvalidParameter` error if `p_success` is not a valid probability or if `trials < 0`, otherwise returns 
+    /// the created distribution.
     ///
     /// ```ignore
     /// // rolling two fair coins 5 times, counting the number of times both are heads
@@ -392,7 +1189,7 @@ impl BinomDist {
     /// let n = 5;
     ///
     /// let dist = BinomDist::new(n, p);
-    /// println!("{}", dist == None); // prints "true"
+    /// println!("{}", dist.is_err()); // prints "true"
     /// ```
     ///
     /// ```ignore
@@ -400,7 +1197,7 @@ impl BinomDist {
     /// let n = 5;
     ///
     /// let dist = BinomDist::new(n, p);
-    /// println!("{}", dist == None); // prints "true"
+    /// println!("{}", dist.is_err()); // prints "true"
     /// ```
     ///
     /// ```ignore
@@ -408,44 +1205,53 @@ impl BinomDist {
     /// let n = -5;
     ///
     /// let dist = BinomDist::new(n, p);
-    /// println!("{}", dist == None); // prints "true"
+    /// println!("{}", dist.is_err()); // prints "true"
     /// ```
-    pub fn new(trials: i32, p_success: f64) -> Option<Self> {
-        if p_success < 0.0 || p_success > 1.0 {
-            return None;
-        }
-
-        if trials < 0 {
-            return None;
-        }
-
-        Some(BinomDist { p_success, trials })
+    pub fn new(trials: i32, p_success: f64) -> Result<BinomDist, PensivError> {
+        BinomDist::new_in(trials, p_success)
     }
 
-    /// Returns the probability that each trial will succeed, i.e. the Bernoulli probability of each trial or the value of the parameter `p`.
-    pub fn p_success(&self) -> f64 {
-        self.p_success
-    }
+    /// Fits a binomial distribution with a known number of trials to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimate of `p` is the mean of the data divided by the number of trials, and its standard 
+    /// error is `sqrt(p(1 - p) / (n * trials))`. Since the number of trials is known, its standard error is `0`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidParameter` error if `trials` isn't positive, or an `InvalidInput` error if `data` contains values that 
+    /// aren't integers in `[0, trials]`.
+    ///
+    /// ```ignore
+    /// let data = array![3.0, 5.0, 4.0, 4.0];
+    /// let fit = BinomDist::fit(&data, 10).unwrap();
+    ///
+    /// println!("{}", fit.dist().p_success()); // prints "0.4"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>, trials: i32) -> Result<Fit<BinomDist>, PensivError> {
+        let mean = fit_mean(data)?;
 
-    /// Returns the probability that each trial will fail.
-    pub fn p_failure(&self) -> f64 {
-        1.0 - self.p_success
-    }
+        if trials <= 0 {
+            return Err(PensivError::InvalidParameter { name: "trials", value: trials as f64, expected: "a positive integer" });
+        }
+        else if !all_integers_within(data, 0.0, trials as f64) {
+            return Err(PensivError::InvalidInput("data must be integers between 0 & the number of trials"));
+        }
 
-    /// Returns the number of trials.
-    pub fn trials(&self) -> i32 {
-        self.trials
+        let p = mean / trials as f64;
+        let std_error = (p * (1.0 - p) / (data.len() as f64 * trials as f64)).sqrt();
+
+        Ok(Fit { dist: BinomDist::new(trials, p)?, std_errors: array![0.0, std_error] })
     }
 }
 
-impl DiscreteDist<i32> for BinomDist {
+impl<F: Float> DiscreteDist<i32, F> for BinomDist<F> {
     /// Returns the binomial PMF of `value`.
     ///
     /// The binomial PMF is calculated as:
     /// `(n choose k)(p)^k(1 - p)^(n - k)` where `n` is the number of trials and `k = value`
     ///
-    /// Note that because of how `choose()` is implemented, this function will (correctly) return `0.0` for values outside of
-    /// the support of the distribution, i.e. negative values.
+    /// To avoid overflow for large numbers of trials, the PMF is computed in log-space using `ln_choose()` and then 
+    /// exponentiated. `0.0` is returned for values outside of the support of the distribution, i.e. negative values and
+    /// values greater than `n`.
     ///
     /// ```ignore
     /// // flipping a biased coin twice, counting the number of heads
@@ -458,8 +1264,24 @@ impl DiscreteDist<i32> for BinomDist {
     ///     println!("{}", dist.pmf(k));
     /// }
     /// ```
-    fn pmf(&self, value: i32) -> f64 {
-        choose(self.trials, value) as f64 * self.p_success.powi(value) * self.p_failure().powi(self.trials - value)
+    fn pmf(&self, value: i32) -> F {
+        self.log_pmf(value).exp()
+    }
+
+    /// Returns the natural log of the binomial PMF of `value`, or `-inf` for values outside of the support.
+    fn log_pmf(&self, value: i32) -> F {
+        if value < 0 || value > self.trials {
+            return F::neg_infinity();
+        }
+
+        let successes = value as u64;
+        let failures = (self.trials - value) as u64;
+
+        // avoid 0 * ln(0) = NaN when p is 0 or 1
+        let ln_p_successes = if successes == 0 { F::zero() } else { cast::<F>(successes as f64) * self.p_success.ln() };
+        let ln_p_failures = if failures == 0 { F::zero() } else { cast::<F>(failures as f64) * (-self.p_success).ln_1p() };
+
+        cast::<F>(ln_choose(self.trials as u64, successes)) + ln_p_successes + ln_p_failures
     }
 
     /// Returns the binomial CDF of `value`.
@@ -478,11 +1300,8 @@ impl DiscreteDist<i32> for BinomDist {
     ///     println!("{}", dist.cdf(k));
     /// }
     /// ```
-    fn cdf(&self, value: i32) -> f64 {
-        let mut cdf_values = Array::range(0.0, value as f64 + 1.0, 1.0);
-
-        cdf_values.mapv_inplace(|n| { self.pmf(n as i32) }); // avoid using map because that allocates another array
-        cdf_values.sum()
+    fn cdf(&self, value: i32) -> F {
+        self.interval_cdf(0, value)
     }
 
     /// Returns the probability that the binomial random variable falls between `lower_bound` and `upper_bound`, inclusive.
@@ -495,28 +1314,147 @@ impl DiscreteDist<i32> for BinomDist {
     ///
     /// println!("{}", dist.interval_cdf(1, 2)); // prints "0.72"
     /// ```
-    fn interval_cdf(&self, lower_bound: i32, upper_bound: i32) -> f64 {
-        let mut cdf_values = Array::range(lower_bound as f64, upper_bound as f64 + 1.0, 1.0);
+    fn interval_cdf(&self, lower_bound: i32, upper_bound: i32) -> F {
+        // only sum over the part of the interval that's actually in the support
+        let lower = lower_bound.max(0);
+        let upper = upper_bound.min(self.trials);
 
-        cdf_values.mapv_inplace(|n| { self.pmf(n as i32) }); // avoid using map because that allocates another array
-        cdf_values.sum()
+        (lower..=upper).fold(F::zero(), |sum, k| sum + self.pmf(k))
     }
 
     /// Returns the mean of the binomial distribution.
     ///
     /// The mean of a binomial distribution is equivalent to `np`, i.e. the number of trials multiplied by the probability that 
     /// each trial will succeed.
-    fn mean(&self) -> f64 {
-        self.trials as f64 * self.p_success
+    fn mean(&self) -> F {
+        cast::<F>(self.trials as f64) * self.p_success
     }
 
     /// Returns the variance of the binomial distribution.
     ///
     /// The variance of a binomial distribution is equivalent to `np(1 - p)`, i.e. the number of trials multiplied by the variance of the Bernoulli 
     /// random variable for each trial.
-    fn variance(&self) -> f64 {
-        self.trials as f64 * self.p_success * (1.0 - self.p_success)
+    fn variance(&self) -> F {
+        cast::<F>(self.trials as f64) * self.p_success * self.p_failure()
+    }
+
+    /// Draws a random value from the binomial distribution.
+    ///
+    /// Values are drawn exactly by inversion (the BINV algorithm of Kachitvichyanukul & Schmeiser), which walks up the
+    /// PMF from `0` until the drawn uniform value is exhausted. Because `(1 - p)^n` underflows for large numbers of trials,
+    /// large distributions are split into chunks of trials that are sampled independently and summed, which is exact since
+    /// the sum of independent binomials with the same `p` is itself binomial.
+    ///
+    /// ```ignore
+    /// let dist = BinomDist::new(10, 0.25).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let successes = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        // sample the less likely outcome so that the walk up the PMF stays short
+        if self.p_success > cast(0.5) {
+            return self.trials - binom_inversion_sample(self.trials, to_f64(self.p_failure()), rng);
+        }
+
+        binom_inversion_sample(self.trials, to_f64(self.p_success), rng)
+    }
+
+    /// Returns the quantile of `p`, i.e. the smallest number of successes whose CDF is at least `p`.
+    ///
+    /// The quantile is found with a binary search of the CDF over the support `[0, n]`.
+    ///
+    /// ```ignore
+    /// let dist = BinomDist::new(2, 0.4).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.36)); // prints "Some(0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(1)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "Some(2)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        let k = integer_quantile_search(-1, self.trials as i64, |k| quantile_reached(self.cdf(k as i32), p));
+        Some(k as i32)
+    }
+
+    /// Returns the mode of the distribution, equivalent to `floor((n + 1)p)`; if `(n + 1)p` is a positive integer, both it 
+    /// and `(n + 1)p - 1` are modes, and the latter is returned.
+    fn mode(&self) -> Option<i32> {
+        Some(integer_mode((self.trials as f64 + 1.0) * to_f64(self.p_success)))
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `(1 - 2p) / sqrt(np(1 - p))` (or `NaN` if the variance is 
+    /// `0`).
+    fn skewness(&self) -> F {
+        let variance = self.variance();
+        if variance > F::zero() { (F::one() - cast::<F>(2.0) * self.p_success) / variance.sqrt() } else { F::nan() }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `(1 - 6p(1 - p)) / (np(1 - p))` (or `NaN` if the 
+    /// variance is `0`).
+    fn kurtosis(&self) -> F {
+        let variance = self.variance();
+        if variance > F::zero() { (F::one() - cast::<F>(6.0) * self.p_success * self.p_failure()) / variance } else { F::nan() }
+    }
+
+    /// Returns the MGF of `t`, equivalent to `(1 - p + p e^t)^n`, or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = (self.p_failure() + self.p_success * t.exp()).powi(self.trials);
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `(1 - p + p e^(it))^n`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::new(self.p_failure() + self.p_success * t.cos(), self.p_success * t.sin()).powi(self.trials)
+    }
+}
+
+/// Samples a binomial random variable with `trials` trials and success probability `p <= 0.5` by inversion.
+fn binom_inversion_sample(trials: i32, p: f64, rng: &mut dyn RngCore) -> i32 {
+    if p == 0.0 {
+        return 0;
+    }
+
+    let q = 1.0 - p;
+    let s = p / q;
+
+    // largest number of trials for which q^n stays comfortably above the smallest normal f64
+    let chunk_size = ((500.0 / -q.ln()) as i32).max(1);
+    let mut remaining = trials;
+    let mut successes = 0;
+
+    while remaining > 0 {
+        let n = remaining.min(chunk_size);
+        let a = (n as f64 + 1.0) * s;
+        let r0 = q.powi(n);
+
+        'draw: loop {
+            let mut r = r0;
+            let mut u = rng.gen::<f64>();
+            let mut x = 0;
+
+            while u > r {
+                u -= r;
+                x += 1;
+
+                if x > n { // rounding error pushed u past the end of the support, so redraw
+                    continue 'draw;
+                }
+
+                r *= a / x as f64 - s;
+            }
+
+            successes += x;
+            break;
+        }
+
+        remaining -= n;
     }
+
+    successes
 }
 
 
@@ -525,14 +1463,38 @@ impl DiscreteDist<i32> for BinomDist {
 /// Geometric distributions are parameterized solely by `p`, the probability that each trial will be a success. The support 
 /// of the geometric distribution is the (infinite) set of non-negative integers.
 #[derive(Debug, PartialEq)]
-pub struct GeometricDist {
-    p_success: f64,
+pub struct GeometricDist<F: Float = f64> {
+    p_success: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(GeometricDist<F: Float>, |dist| {
+    p_success: F = &dist.p_success,
+} => GeometricDist::new_in(p_success));
+
+impl<F: Float> GeometricDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(p_success: F) -> Result<GeometricDist<F>, PensivError> {
+        check_probability("p_success", p_success)?;
+        Ok(GeometricDist { p_success })
+    }
+
+    /// Returns the probability that each trial will be a success.
+    pub fn p_success(&self) -> F {
+        self.p_success
+    }
+
+    /// Returns the probability that each trial will be a failure.
+    pub fn p_failure(&self) -> F {
+        F::one() - self.p_success
+    }
 }
 
 impl GeometricDist {
     /// Creates and returns a new geometric distribution parameterized by `p_success`.
     ///
-    /// Returns `None` if `p_success` isn't a valid probability, otherwise returns the created distribution.
+    /// Returns an `InvalidParameter` error if `p_success` isn't a valid probability, otherwise returns the created 
+    /// distribution.
     ///
     /// ```ignore
     /// // Flipping a biased coin until it comes up heads
@@ -547,35 +1509,46 @@ impl GeometricDist {
     /// let p = -0.4;
     /// let dist = GeometricDist::new(p);
     /// 
-    /// println!("{}", dist == None); // prints "true"
+    /// println!("{}", dist.is_err()); // prints "true"
     /// ```
     ///
     /// ```ignore
     /// let p = 1.4;
     /// let dist = GeometricDist::new(p);
     /// 
-    /// println!("{}", dist == None); // prints "true"
+    /// println!("{}", dist.is_err()); // prints "true"
     /// ```
-    pub fn new(p_success: f64) -> Option<GeometricDist> {
-        if p_success < 0.0 || p_success > 1.0 {
-            return None;
-        }
-
-        Some(GeometricDist { p_success })
+    pub fn new(p_success: f64) -> Result<GeometricDist, PensivError> {
+        GeometricDist::new_in(p_success)
     }
 
-    /// Returns the probability that each trial will be a success.
-    pub fn p_success(&self) -> f64 {
-        self.p_success
-    }
+    /// Fits a geometric distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimate of `p` is the inverse of the mean of the data, and its standard error is 
+    /// `p * sqrt((1 - p) / n)`. Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values 
+    /// that aren't finite, or an `InvalidInput` error if it contains values that aren't positive integers.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 3.0, 2.0, 2.0];
+    /// let fit = GeometricDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().p_success()); // prints "0.5"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<GeometricDist>, PensivError> {
+        let mean = fit_mean(data)?;
 
-    /// Returns the probability that each trial will be a failure.
-    pub fn p_failure(&self) -> f64 {
-        1.0 - self.p_success
+        if !all_integers_within(data, 1.0, f64::INFINITY) {
+            return Err(PensivError::InvalidInput("data must be positive integers"));
+        }
+
+        let p = 1.0 / mean;
+        let std_error = p * ((1.0 - p) / data.len() as f64).sqrt();
+
+        Ok(Fit { dist: GeometricDist::new(p)?, std_errors: array![std_error] })
     }
 }
 
-impl DiscreteDist<i32> for GeometricDist {
+impl<F: Float> DiscreteDist<i32, F> for GeometricDist<F> {
     /// Returns the geometric PMF of `value`.
     ///
     /// Returns `0.0` for `value <= 0`, otherwise returns:
@@ -591,14 +1564,26 @@ impl DiscreteDist<i32> for GeometricDist {
     ///     println!("{}", dist.pmf(k));
     /// }
     /// ```
-    fn pmf(&self, value: i32) -> f64 {
+    fn pmf(&self, value: i32) -> F {
         if value <= 0 {
-            return 0.0;
+            return F::zero();
         }
 
         self.p_success * self.p_failure().powi(value - 1)
     }
 
+    /// Returns the natural log of the geometric PMF of `value`, or `-inf` for `value <= 0`.
+    fn log_pmf(&self, value: i32) -> F {
+        if value <= 0 {
+            return F::neg_infinity();
+        }
+        else if value == 1 { // avoid 0 * ln(0) = NaN when p is 1
+            return self.p_success.ln();
+        }
+
+        self.p_success.ln() + cast::<F>((value - 1) as f64) * (-self.p_success).ln_1p()
+    }
+
     /// Returns the geometric CDF of `value`.
     ///
     /// Returns `0.0` for `value <= 0`, otherwise returns the probability that the geometric random variable is less than 
@@ -614,1402 +1599,7101 @@ impl DiscreteDist<i32> for GeometricDist {
     ///     println!("{}", dist.cdf(k));
     /// }
     /// ```
-    fn cdf(&self, value: i32) -> f64 {
+    fn cdf(&self, value: i32) -> F {
         if value <= 0 {
-            return 0.0;
+            return F::zero();
         }
         
-        1.0 - self.p_failure().powi(value)
+        F::one() - self.p_failure().powi(value)
     }
 
     /// Returns the mean of the geometric distribution.
     /// 
     /// The mean of a geometric distribution is equivalent to the inverse of the parameter `p`, i.e. `1 / p`.
-    fn mean(&self) -> f64 {
-        1.0 / self.p_success
+    fn mean(&self) -> F {
+        F::one() / self.p_success
     }
 
     /// Returns the variance of the geometric distribution.
     /// 
     /// The variance of a geometric distribution is equivalent to `(1 - p) / p^2`.
-    fn variance(&self) -> f64 {
+    fn variance(&self) -> F {
         self.p_failure() / self.p_success.powi(2)
     }
-}
 
+    /// Draws a random value from the geometric distribution.
+    ///
+    /// Values are drawn exactly by inverting the CDF, i.e. as `ceil(ln(u) / ln(1 - p))` for a uniform `u` in `(0, 1]`.
+    /// Note that if `p = 0`, no trial ever succeeds, so `i32::MAX` is returned.
+    ///
+    /// ```ignore
+    /// let dist = GeometricDist::new(0.4).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let trials = dist.sample(&mut rng); // number of flips until the first heads
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        if self.p_success == F::one() {
+            return 1;
+        }
+        else if self.p_success == F::zero() {
+            return i32::MAX;
+        }
+
+        let u = 1.0 - rng.gen::<f64>(); // in (0, 1] so that the log is finite
+        let k = (u.ln() / (-to_f64(self.p_success)).ln_1p()).ceil();
 
-/// An empirical distribution describing a set of data.
-///
-/// Empirical distributions are "parameterized" by a data set containing real numbers; they describe the distribution of values within that data set. 
-/// Therefore, the support of the empirical distribution is the set of real numbers present in the data.
-#[derive(Debug)]
-pub struct EmpiricalDist {
-    // NOTE: if this dist is immutable, should cache stats directly
-    //       on the other hand, if it's mutable, should add mutating methods
-    counts: BTreeMap<ComparableFloat, i32>,
-    data: Array<f64, Ix1>,
-    data_len: usize,
-}
+        (k as i32).max(1) // the cast saturates for astronomically unlikely values
+    }
 
-impl EmpiricalDist {
-    /// Creates and returns a new empirical distribution describing `data`.
+    /// Returns the quantile of `p`, i.e. the smallest number of trials whose CDF is at least `p`.
     ///
-    /// Note that `data` must be a 1-dimensional instance of `ndarray::Array` containing the dataset. To create the dataset, `data` is borrowed immutably; 
-    /// because it's borrowed rather than moved, it can continue to be used after the distribution is created. If any element 
-    /// in `data` is `NaN` or infinite, `None` is returned. Otherwise, the created empirical distribution instance is returned.
+    /// An initial guess is obtained by inverting the CDF in closed form, i.e. `ceil(ln(1 - p) / ln(1 - p_success))`, and is 
+    /// then corrected by searching the neighbouring values of the CDF to account for rounding error. Because the support 
+    /// is unbounded above, `None` is returned for `p = 1` unless `p_success = 1`.
     ///
     /// ```ignore
-    /// let data = array![1.0, 2.0, 2.0, 3.0];
-    /// let dist = EmpiricalDist::new(&data).unwrap();
-    /// 
-    /// println!("{}", dist.data() == data); // prints "true"
-    /// ```
-    /// 
-    /// ```ignore
-    /// let data = array![1.0, 2.0, 3.0, 1.0 / 0.0, f64::NAN];
-    /// match EmpiricalDist::new(&data) {
-    ///     Some(_) => println!("how'd that happen?"),
-    ///     None => println!("that looks right"),
-    /// } // prints "that looks right"
+    /// let dist = GeometricDist::new(0.4).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(2)"
+    /// println!("{:?}", dist.ppf(0.64)); // prints "Some(2)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "None"
     /// ```
-    pub fn new(dataset: &Array<f64, Ix1>) -> Option<EmpiricalDist> {
-        let mut counts = BTreeMap::<ComparableFloat, i32>::new();
-        let data_len = dataset.len();
-        let mut data = Array::<f64, Ix1>::zeros(data_len);
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) || self.p_success == F::zero() {
+            return None;
+        }
+        else if p == F::zero() || self.p_success == F::one() {
+            return Some(1);
+        }
+        else if p == F::one() {
+            return None;
+        }
 
-        for (i, elem) in dataset.iter().enumerate() {
-            match ComparableFloat::new(*elem) {
-                Some(f) => {
-                    if !counts.contains_key(&f) {
-                        counts.insert(f, 0);
-                    }
+        let guess = ((-to_f64(p)).ln_1p() / (-to_f64(self.p_success)).ln_1p()).ceil();
+        if guess >= i32::MAX as f64 {
+            return None;
+        }
 
-                    counts.insert(f, counts[&f] + 1);
-                    data[[i]] = *elem;
-                },
-                None => return None,
-            }
+        let mut k = (guess as i32).max(1);
+
+        while k > 1 && self.cdf(k - 1) >= p {
+            k -= 1;
+        }
+
+        while self.cdf(k) < p {
+            k = k.checked_add(1)?;
         }
 
-        Some(EmpiricalDist { counts, data, data_len })
+        Some(k)
     }
 
-    /// Returns a reference to the distribution's data.
-    /// 
-    /// Note that this array will be a copy of the array that was originally provided when the distribution was created due 
-    /// to the constructor's implementation.
-    pub fn data(&self) -> &Array<f64, Ix1> {
-        &self.data
+    /// Returns the mode of the distribution, which is `1` since the first trial is always the most likely to succeed.
+    fn mode(&self) -> Option<i32> {
+        Some(1)
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `(2 - p) / sqrt(1 - p)` (or `NaN` if `p` is `0` or `1`).
+    fn skewness(&self) -> F {
+        if self.p_success > F::zero() && self.p_success < F::one() {
+            (cast::<F>(2.0) - self.p_success) / self.p_failure().sqrt()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `6 + p^2 / (1 - p)` (or `NaN` if `p` is `0` or `1`).
+    fn kurtosis(&self) -> F {
+        if self.p_success > F::zero() && self.p_success < F::one() {
+            cast::<F>(6.0) + self.p_success.powi(2) / self.p_failure()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `(-(1 - p) ln(1 - p) - p ln(p)) / p`.
+    fn entropy(&self) -> F {
+        (-x_ln_x(self.p_failure()) - x_ln_x(self.p_success)) / self.p_success
+    }
+
+    /// Returns the MGF of `t`, equivalent to `p e^t / (1 - (1 - p) e^t)`, which exists for `t < -ln(1 - p)`.
+    fn mgf(&self, t: F) -> Option<F> {
+        let denominator = F::one() - self.p_failure() * t.exp();
+        let value = self.p_success * t.exp() / denominator;
+
+        if denominator > F::zero() && value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `p e^(it) / (1 - (1 - p) e^(it))`.
+    fn cf(&self, t: F) -> Complex<F> {
+        let unit = Complex::new(t.cos(), t.sin());
+        unit * self.p_success / (Complex::new(F::one(), F::zero()) - unit * self.p_failure())
     }
 }
 
-impl DiscreteDist<f64> for EmpiricalDist {
-    /// Returns the empirical PMF of `value`.
-    /// 
-    /// The empirical PMF of a value is equivalent to the fraction of the data set that it occupies, i.e. the number of 
-    /// occurrences of `value` in the data set divided by the number of element in the data set. For values that are not 
-    /// present in the data set, the empirical PMF returned is `0.0`.
-    /// 
+
+/// A Poisson distribution, representing the number of events that occur in a fixed interval.
+///
+/// Poisson distributions are parameterized solely by `rate`, the expected number of events in the interval (often called
+/// `lambda`). The support of the Poisson distribution is the (infinite) set of non-negative integers.
+#[derive(Debug, PartialEq)]
+pub struct PoissonDist<F: Float = f64> {
+    rate: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(PoissonDist<F: Float>, |dist| {
+    rate: F = &dist.rate,
+} => PoissonDist::new_in(rate));
+
+impl<F: Float> PoissonDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(rate: F) -> Result<PoissonDist<F>, PensivError> {
+        check_positive("rate", rate)?;
+        Ok(PoissonDist { rate })
+    }
+
+    /// Returns the rate of the distribution, i.e. the expected number of events.
+    pub fn rate(&self) -> F {
+        self.rate
+    }
+}
+
+impl PoissonDist {
+    /// Creates and returns a new Poisson distribution with the given rate.
+    ///
+    /// Returns an `InvalidParameter` error if `rate` isn't a positive, finite number, otherwise returns the created 
+    /// distribution.
+    ///
     /// ```ignore
-    /// let data = array![1.0, 2.0, 2.0, 3.0];
-    /// let dist = EmpiricalDist::new(&data).unwrap();
-    /// 
-    /// println!("{}", dist.pmf(1.0)); // prints "0.25"
-    /// println!("{}", dist.pmf(2.0)); // prints "0.5"
-    /// println!("{}", dist.pmf(3.0)); // prints "0.25"
+    /// // counting the number of emails received in an hour
+    /// let dist = PoissonDist::new(3.5).unwrap();
+    /// println!("{}", dist.rate()); // prints "3.5"
     /// ```
-    fn pmf(&self, value: f64) -> f64 {
-        match ComparableFloat::new(value) {
-            Some(f) => {
-                if self.counts.contains_key(&f) {
-                    self.counts[&f] as f64 / self.data_len as f64
-                }
-                else {
-                    0.0
-                }
-            },
-            None => 0.0,
-        }
+    ///
+    /// ```ignore
+    /// let dist = PoissonDist::new(-3.5);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(rate: f64) -> Result<PoissonDist, PensivError> {
+        PoissonDist::new_in(rate)
     }
 
-    /// Returns the empirical CDF of `value`.
-    /// 
-    /// The empirical CDF of a value is equivalent to the sum of the empirical PMFs of all values in the data set that are 
-    /// less than or equal to `value`.
-    /// 
+    /// Fits a Poisson distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimate of the rate is the mean of the data, and its standard error is `sqrt(rate / n)`. 
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidInput` error if it contains values that aren't non-negative integers or is entirely `0` (since the 
+    /// estimated rate would then be `0`).
+    ///
     /// ```ignore
-    /// let data = array![1.0, 2.0, 2.0, 3.0];
-    /// let dist = EmpiricalDist::new(&data).unwrap();
+    /// let data = array![2.0, 4.0, 3.0, 3.0];
+    /// let fit = PoissonDist::fit(&data).unwrap();
     ///
-    /// println!("{}", dist.cdf(1.0)); // prints "0.25"
-    /// println!("{}", dist.cdf(2.0)); // prints "0.75"
-    /// println!("{}", dist.cdf(3.0)); // prints "1.0"
+    /// println!("{}", fit.dist().rate()); // prints "3.0"
     /// ```
-    fn cdf(&self, value: f64) -> f64 {
-        let mut cdf = 0.0;
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<PoissonDist>, PensivError> {
+        let rate = fit_mean(data)?;
 
-        for key in self.counts.keys() {
-            if key.value() <= value {
-                cdf += self.counts[key] as f64;
-            }
-            else {
-                break;
-            }
+        if !all_integers_within(data, 0.0, f64::INFINITY) {
+            return Err(PensivError::InvalidInput("data must be non-negative integers"));
+        }
+        else if rate == 0.0 {
+            return Err(PensivError::InvalidInput("data must not all be 0"));
         }
 
-        cdf / self.data_len as f64
+        let std_error = (rate / data.len() as f64).sqrt();
+        Ok(Fit { dist: PoissonDist::new(rate)?, std_errors: array![std_error] })
     }
+}
 
-    /// Returns the probability that a randomly chosen element of the data set will fall between `lower_bound` and `upper_bound`, inclusive.
-    /// 
-    /// This is equivalent to the sum of the PMFs of the elements of the data set that are in the interval `lower_bound <= element <= upper_bound`.
-    /// 
+impl<F: Float> DiscreteDist<i32, F> for PoissonDist<F> {
+    /// Returns the Poisson PMF of `value`.
+    ///
+    /// The Poisson PMF is calculated (in log-space, to avoid overflow) as:
+    /// `rate^k * e^(-rate) / k!` where `k = value`
+    ///
+    /// `0.0` is returned for negative values.
+    ///
     /// ```ignore
-    /// let data = array![1.0, 2.0, 2.0, 3.0];
-    /// let dist = EmpiricalDist::new(&data).unwrap();
+    /// let dist = PoissonDist::new(2.0).unwrap();
     ///
-    /// println!("{}", dist.interval_cdf(1.0, 2.0)); // prints "0.75"
-    /// println!("{}", dist.interval_cdf(2.0, 4.0)); // prints "0.75"
+    /// for k in 0..3 {
+    ///     // prints approximately "0.1353", "0.2707", "0.2707" separated by newlines
+    ///     println!("{}", dist.pmf(k));
+    /// }
     /// ```
-    fn interval_cdf(&self, lower_bound: f64, upper_bound: f64) -> f64 {
-        let mut cdf = 0.0;
+    fn pmf(&self, value: i32) -> F {
+        self.log_pmf(value).exp()
+    }
 
-        for key in self.counts.keys() {
-            if key.value() >= lower_bound && key.value() <= upper_bound {
-                cdf += self.counts[key] as f64;
-            }
-            else if key.value() > upper_bound {
-                break;
-            }
+    /// Returns the natural log of the Poisson PMF of `value`, or `-inf` for negative values.
+    fn log_pmf(&self, value: i32) -> F {
+        if value < 0 {
+            return F::neg_infinity();
         }
 
-        cdf / self.data_len as f64
+        cast::<F>(value as f64) * self.rate.ln() - self.rate - cast(ln_factorial(value as u64))
     }
 
-    /// Returns the mean of the empirical distribution.
-    /// 
-    /// The mean of an empirical distribution is equivalent to the mean of the data set.
-    fn mean(&self) -> f64 {
-        self.data.mean().unwrap_or(0.0)
+    /// Returns the Poisson CDF of `value`.
+    ///
+    /// Rather than summing the PMF, the CDF is calculated directly as the regularized upper incomplete gamma function 
+    /// `Q(k + 1, rate)`, which is accurate even far into the tails. `0.0` is returned for negative values.
+    ///
+    /// ```ignore
+    /// let dist = PoissonDist::new(2.0).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints approximately "0.1353", "0.4060", "0.6767" separated by newlines
+    ///     println!("{}", dist.cdf(k));
+    /// }
+    /// ```
+    fn cdf(&self, value: i32) -> F {
+        if value < 0 {
+            return F::zero();
+        }
+
+        special::gamma_q(cast(value as f64 + 1.0), self.rate)
     }
 
-    /// Returns the variance of the empirical distribution.
-    /// 
-    /// The variance of an empirical distribution is equivalent to the difference between the mean of the squared data set 
-    /// and the squared mean of the data set.
-    fn variance(&self) -> f64 {
-        let data_squared = &self.data * &self.data;
+    /// Returns the mean of the Poisson distribution, which is equal to its rate.
+    fn mean(&self) -> F {
+        self.rate
+    }
+
+    /// Returns the variance of the Poisson distribution, which is also equal to its rate.
+    fn variance(&self) -> F {
+        self.rate
+    }
+
+    /// Draws a random value from the Poisson distribution.
+    ///
+    /// Small rates are sampled exactly by inversion; rates of `10` or more use the transformed rejection method of 
+    /// Hörmann (PTRS), whose cost doesn't grow with the rate.
+    ///
+    /// ```ignore
+    /// let dist = PoissonDist::new(3.5).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let events = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        poisson_sample(to_f64(self.rate), rng)
+    }
 
-        data_squared.mean().unwrap_or(0.0) - self.mean().powi(2)
+    /// Returns the mode of the distribution, equivalent to `floor(rate)`; if the rate is an integer, both it and the rate 
+    /// minus 1 are modes, and the latter is returned.
+    fn mode(&self) -> Option<i32> {
+        Some(integer_mode(to_f64(self.rate)))
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `1 / sqrt(rate)`.
+    fn skewness(&self) -> F {
+        F::one() / self.rate.sqrt()
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `1 / rate`.
+    fn kurtosis(&self) -> F {
+        F::one() / self.rate
+    }
+
+    /// Returns the MGF of `t`, equivalent to `e^(rate (e^t - 1))`, or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = (self.rate * t.exp_m1()).exp();
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `e^(rate (e^(it) - 1))`.
+    fn cf(&self, t: F) -> Complex<F> {
+        (Complex::new(t.cos() - F::one(), t.sin()) * self.rate).exp()
     }
 }
 
+/// Draws a value from the Poisson distribution with the given rate, by inversion for small rates and by Hörmann's PTRS 
+/// algorithm otherwise.
+fn poisson_sample(rate: f64, rng: &mut dyn RngCore) -> i32 {
+    if rate == 0.0 {
+        return 0;
+    }
+    else if rate < 10.0 {
+        let mut u = rng.gen::<f64>();
+        let mut p = (-rate).exp();
+        let mut k = 0;
 
-/// Base trait for all continuous distributions.
-/// 
-/// `ContinuousDist` provides a general interface for implementing the functionality of continuous distributions; it differs 
-/// from `DiscreteDist` only in its requirement of the `pdf()` function in place of `pmf()`, although this difference 
-/// underscores a larger conceptual difference between the two traits.
-/// 
-/// Like `DiscreteDist`, `ContinuousDist` is also parameterized by a generic type `N` which conforms to the `Num` trait from 
-/// `num-traits`.
-pub trait ContinuousDist<N: Num> { // NOTE: are generics necessary?
-    /// Returns the probability density function (PDF) of `value` according to the distribution. Convention is to return `0.0` for 
-    /// values outside the distribution's support.
-    fn pdf(&self, value: N) -> f64;
+        while u > p {
+            u -= p;
+            k += 1;
+            p *= rate / k as f64;
 
-    /// Returns the cumulative distribution function (CDF) of `value` according to the distribution.
-    fn cdf(&self, value: N) -> f64;
+            if p == 0.0 { // rounding error pushed u past the (numerically) nonzero part of the support
+                return k;
+            }
+        }
 
-    /// Returns the probability that a value in the distribution will fall between `lower_bound` and `upper_bound`.
-    /// 
-    /// The default implementation simply finds the difference in the CDFs of the bounds.
-    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> f64 {
-        self.cdf(upper_bound) - self.cdf(lower_bound)
+        return k;
     }
 
-    /// Returns the mean (expectation) of the distribution.
-    fn mean(&self) -> f64;
+    let sqrt_rate = rate.sqrt();
+    let ln_rate = rate.ln();
+    let b = 0.931 + 2.53 * sqrt_rate;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
 
-    /// Returns the variance (average square distance from the mean) of the distribution.
-    fn variance(&self) -> f64;
+    loop {
+        let u = rng.gen::<f64>() - 0.5;
+        let v = rng.gen::<f64>();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + rate + 0.43).floor();
 
-    /// Returns the standard deviation of the distribution.
-    /// 
-    /// The default implementation returns the square root of the variance.
-    fn std(&self) -> f64 {
-        self.variance().sqrt()
+        // cheap acceptance test first, then rejection of the tails, then the exact acceptance test
+        if us >= 0.07 && v <= v_r {
+            return k as i32;
+        }
+        else if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+
+        if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln() <= -rate + k * ln_rate - special::ln_gamma(k + 1.0) {
+            return k as i32; // the cast saturates for astronomically large rates
+        }
     }
 }
 
 
-/// A continuous uniform distribution.
-/// 
-/// The continuous uniform distribution is the continuous adaptation of the discrete uniform distribution. Given a lower and 
-/// upper bound of its support, the probabilitu of any real number inside the support is uniform. Any value outside the 
-/// support has probability `0`.
+/// A negative binomial distribution, representing the number of failures that occur before some number of successes.
+///
+/// Negative binomial distributions are parameterized by `p`, the probability that each trial will be a success, and by `r`, 
+/// the number of successes to wait for. `r` isn't required to be an integer; for non-integer values, the distribution is 
+/// the gamma-Poisson mixture commonly used to model overdispersed count data. The support of the negative binomial 
+/// distribution is the (infinite) set of non-negative integers.
 #[derive(Debug, PartialEq)]
-pub struct ContinuousUniformDist {
-    // TODO: bad float values?
-    lower_bound: f64,
-    upper_bound: f64,
+pub struct NegativeBinomialDist<F: Float = f64> {
+    successes: F,
+    p_success: F,
 }
 
-impl ContinuousUniformDist {
-    /// Creates and returns a new continuous uniform distribution from `lower_bound` to `upper_bound`.
-    /// 
-    /// Returns `None` if `lower_bound > upper_bound`, otherwise returns the created distribution.
-    /// 
-    /// ```ignore
-    /// let a = 1.0;
-    /// let b = 2.5;
-    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
-    /// 
-    /// println!("{}", dist.lower_bound()); // prints "1.0"
-    /// println!("{}", dist.upper_bound()); // prints "2.5"
-    /// ```
-    ///
-    /// ```ignore
-    /// let a = 1.0;
-    /// let b = 2.5;
-    /// let dist = ContinuousUniformDist::new(b, a);
-    /// 
-    /// println!("{}", dist == None); // prints "true"
-    /// ```
-    pub fn new(lower_bound: f64, upper_bound: f64) -> Option<ContinuousUniformDist> {
-        if lower_bound > upper_bound {
-            return None;
+#[cfg(feature = "serde")]
+serde_via_params!(NegativeBinomialDist<F: Float>, |dist| {
+    successes: F = &dist.successes,
+    p_success: F = &dist.p_success,
+} => NegativeBinomialDist::new_in(successes, p_success));
+
+impl<F: Float> NegativeBinomialDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(successes: F, p_success: F) -> Result<NegativeBinomialDist<F>, PensivError> {
+        check_positive("successes", successes)?;
+
+        if !(p_success > F::zero() && p_success <= F::one()) {
+            return Err(PensivError::InvalidParameter {
+                name: "p_success",
+                value: to_f64(p_success),
+                expected: "a probability in (0, 1]",
+            });
         }
 
-        Some(ContinuousUniformDist { lower_bound, upper_bound })
+        Ok(NegativeBinomialDist { successes, p_success })
     }
 
-    /// Returns the lower bound of the support of the distribution.
-    pub fn lower_bound(&self) -> f64 {
-        self.lower_bound
+    /// Returns the number of successes to wait for, i.e. the value of the parameter `r`.
+    pub fn successes(&self) -> F {
+        self.successes
     }
 
-    /// Returns the upper bound of the support of the distribution.
-    pub fn upper_bound(&self) -> f64 {
-        self.upper_bound
+    /// Returns the probability that each trial will succeed.
+    pub fn p_success(&self) -> F {
+        self.p_success
     }
 
-    /// Returns the size of the interval of the distribution's values.
-    /// 
-    /// This is equivalent to the difference between the bounds of the support.
-    pub fn range(&self) -> f64 {
-        self.upper_bound - self.lower_bound
+    /// Returns the probability that each trial will fail.
+    pub fn p_failure(&self) -> F {
+        F::one() - self.p_success
     }
 }
 
-impl ContinuousDist<f64> for ContinuousUniformDist {
-    /// Returns the continuous uniform PDF of `value`.
-    /// 
-    /// If `value` is in the support of the distribution, then `1 / range` is returned, where `range` is the range of the 
-    /// distribution. Otherwise, `0.0` is returned.
-    /// 
+impl NegativeBinomialDist {
+    /// Creates and returns a new negative binomial distribution with parameters `r = successes` and `p = p_success`.
+    ///
+    /// Returns an `InvalidParameter` error if `successes` isn't a positive, finite number or if `p_success` isn't a valid, 
+    /// nonzero probability (since no number of failures would be enough if `p = 0`). Otherwise, returns the created 
+    /// distribution.
+    ///
     /// ```ignore
-    /// let a = 1.0;
-    /// let b = 2.5;
-    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
-    /// 
-    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.6667"
-    /// println!("{}", dist.pdf(1.5)); // prints approximately "0.6667"
-    /// println!("{}", dist.pdf(2.0)); // prints approximately "0.6667"
-    /// println!("{}", dist.pdf(2.5)); // prints approximately "0.6667"
-    /// 
-    /// println!("{}", dist.pdf(0.0)); // prints "0.0"
-    /// println!("{}", dist.pdf(3.0)); // prints "0.0"
+    /// // rolling a die until it comes up 6 three times, counting the number of other rolls
+    /// let dist = NegativeBinomialDist::new(3.0, 1.0 / 6.0).unwrap();
+    ///
+    /// println!("{}", dist.successes()); // prints "3.0"
+    /// println!("{}", dist.p_success()); // prints "0.16666666666666666"
     /// ```
-    fn pdf(&self, value: f64) -> f64 {
-        if value >= self.lower_bound && value <= self.upper_bound {
-            return 1.0 / self.range();
+    ///
+    /// ```ignore
+    /// let dist = NegativeBinomialDist::new(3.0, 0.0);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(successes: f64, p_success: f64) -> Result<NegativeBinomialDist, PensivError> {
+        NegativeBinomialDist::new_in(successes, p_success)
+    }
+
+    /// Fits a negative binomial distribution to `data` by maximum likelihood.
+    ///
+    /// For a given `r`, the maximum-likelihood `p` is `r / (r + mean)`. Substituting it leaves a profile likelihood in 
+    /// `r` alone, whose maximum is found with Newton's method as the root of 
+    /// `sum(digamma(x + r)) - n * digamma(r) + n * ln(r / (r + mean)) = 0`, starting from the method-of-moments 
+    /// estimate `mean^2 / (variance - mean)`. Standard errors are derived from the observed information.
+    ///
+    /// The maximum-likelihood `r` is only finite if the data are overdispersed, i.e. their (biased) variance exceeds 
+    /// their mean; otherwise the likelihood keeps increasing as `r` grows, towards that of a Poisson distribution.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidInput` error if it contains values that aren't non-negative integers or isn't overdispersed, or a 
+    /// `NotConverged` error if Newton's method fails.
+    ///
+    /// ```ignore
+    /// let data = array![0.0, 4.0, 1.0, 7.0, 2.0, 0.0, 3.0, 1.0];
+    /// let fit = NegativeBinomialDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().successes(), fit.dist().p_success());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<NegativeBinomialDist>, PensivError> {
+        let mean = fit_mean(data)?;
+
+        if !all_integers_within(data, 0.0, f64::INFINITY) {
+            return Err(PensivError::InvalidInput("data must be non-negative integers"));
         }
-        else {
-            return 0.0;
+
+        let n = data.len() as f64;
+        let variance = data.mapv(|x| (x - mean).powi(2)).sum() / n;
+        if variance <= mean {
+            return Err(PensivError::InvalidInput("data must be overdispersed, i.e. have a variance above its mean"));
         }
+
+        // derivative of the profile log-likelihood, & its own derivative
+        let score = |r: f64| {
+            let value = data.iter().map(|&x| special::digamma(x + r)).sum::<f64>() - n * special::digamma(r)
+                + n * (r / (r + mean)).ln();
+            let derivative = data.iter().map(|&x| special::trigamma(x + r)).sum::<f64>() - n * special::trigamma(r)
+                + n * mean / (r * (r + mean));
+
+            (value, derivative)
+        };
+
+        let guess = mean * mean / (variance - mean);
+        let successes = numeric::positive_newton_root(score, guess).map_err(|_| PensivError::NotConverged)?;
+        let p_success = successes / (successes + mean);
+
+        // inverse of the observed information, i.e. of the negated Hessian of the log-likelihood in (r, p)
+        let info_rr = n * special::trigamma(successes)
+            - data.iter().map(|&x| special::trigamma(x + successes)).sum::<f64>();
+        let info_rp = -n / p_success;
+        let info_pp = n * successes / p_success.powi(2) + n * mean / (1.0 - p_success).powi(2);
+        let det = info_rr * info_pp - info_rp * info_rp;
+        let std_errors = array![(info_pp / det).sqrt(), (info_rr / det).sqrt()];
+
+        Ok(Fit { dist: NegativeBinomialDist::new(successes, p_success)?, std_errors })
     }
+}
 
-    /// Returns the continuous uniform CDF of `value`.
-    /// 
-    /// This is equivalent to the fraction of the support that `value` is greater than.
-    /// 
+impl<F: Float> DiscreteDist<i32, F> for NegativeBinomialDist<F> {
+    /// Returns the negative binomial PMF of `value`.
+    ///
+    /// The negative binomial PMF is calculated (in log-space, to avoid overflow) as:
+    /// `(k + r - 1 choose k)(p)^r(1 - p)^k` where `k = value`
+    ///
+    /// The binomial coefficient is generalized to non-integer `r` via the log-gamma function. `0.0` is returned for 
+    /// negative values.
+    ///
     /// ```ignore
-    /// let a = 1.0;
-    /// let b = 2.5;
-    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
-    /// 
-    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.0"
-    /// println!("{}", dist.cdf(1.5)); // prints approximately "0.3333"
-    /// println!("{}", dist.cdf(2.0)); // prints approximately "0.6667"
-    /// println!("{}", dist.cdf(2.5)); // prints approximately "1.0"
-    /// 
-    /// println!("{}", dist.cdf(0.0)); // prints "0.0"
-    /// println!("{}", dist.cdf(3.0)); // prints "1.0"
+    /// let dist = NegativeBinomialDist::new(2.0, 0.5).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints "0.25", "0.25", "0.1875" separated by newlines
+    ///     println!("{}", dist.pmf(k));
+    /// }
     /// ```
-    fn cdf(&self, value: f64) -> f64 {
-        if value >= self.lower_bound && value <= self.upper_bound {
-            return (value - self.lower_bound) / self.range();
-        }
-        else if value < self.lower_bound {
-            return 0.0;
-        }
-        else {
-            return 1.0;
-        }
+    fn pmf(&self, value: i32) -> F {
+        self.log_pmf(value).exp()
     }
 
-    /// Returns the probability that the continuous uniform random variable falls between `lower_bound` and `upper_bound`.
-    /// 
-    /// This is equivalent to the fraction of the support that the interval occupies.
-    /// 
-    /// ```ignore
-    /// let a = 1.0;
-    /// let b = 2.5;
-    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
-    /// 
-    /// println!("{}", dist.interval_cdf(1.5, 2.0)); // prints approximately "0.3333"
-    /// ```
-    fn interval_cdf(&self, lower_bound: f64, upper_bound: f64) -> f64 {
-        let upper = if upper_bound < self.upper_bound { upper_bound } else { self.upper_bound };
-        let lower = if lower_bound > self.lower_bound { lower_bound } else { self.lower_bound };
-
-        (upper - lower) / self.range()
-    }
+    /// Returns the natural log of the negative binomial PMF of `value`, or `-inf` for negative values.
+    fn log_pmf(&self, value: i32) -> F {
+        if value < 0 {
+            return F::neg_infinity();
+        }
 
-    /// Returns the mean of the uniform distribution.
-    /// 
-    /// This is equivalent to the average of the support's bounds.
-    fn mean(&self) -> f64 {
-        (self.lower_bound + self.upper_bound) / 2.0
-    }
+        let k: F = cast(value as f64);
+        let ln_coefficient = special::ln_gamma(k + self.successes) - special::ln_gamma(self.successes) 
+            - cast(ln_factorial(value as u64));
 
-    /// Returns the variance of the uniform distribution.
-    /// 
-    /// This is equivalent to: `(upper bound - lower bound)^2 / 12`.
-    fn variance(&self) -> f64 {
-        (self.upper_bound - self.lower_bound).powi(2) / 12.0
-    }
+        // avoid 0 * ln(0) = NaN when p is 1
+        let ln_p_failures = if value == 0 { F::zero() } else { k * (-self.p_success).ln_1p() };
 
-    /// Returns the variance of the uniform distribution.
-    /// 
-    /// This is equivalent to the square root of the variance, or `(upper bound - lower bound) / sqrt(12)`.
-    fn std(&self) -> f64 {
-        (self.upper_bound - self.lower_bound) / 12.0_f64.sqrt()
+        ln_coefficient + self.successes * self.p_success.ln() + ln_p_failures
     }
-}
-
-
-/// An exponential distribution.
-/// 
-/// The exponential distribution is parameterized by a rate parameter, which dictates the "stretch/shrink" of the distribution's PDF. 
-/// The support of the exponential distribution is the set of non-negative real numbers.
-#[derive(Debug, PartialEq)]
-pub struct ExponentialDist {
-    rate_param: f64
-}
 
-impl ExponentialDist {
-    /// Creates and returns a new exponential distribution.
-    /// 
-    /// Because the rate parameter must be positive, the function returns `None` if `rate_param <= 0.0`. Otherwise, the 
-    /// distribution is returned.
-    /// 
-    /// ```ignore
-    /// let r = 0.5;
-    /// let dist = ExponentialDist::new(r).unwrap();
-    /// 
-    /// println!("{}", dist.rate_param()); // prints "0.5"
-    /// ```
-    /// 
+    /// Returns the negative binomial CDF of `value`.
+    ///
+    /// The CDF is calculated directly as the regularized incomplete beta function `I_p(r, k + 1)` rather than by summing 
+    /// the PMF. `0.0` is returned for negative values.
+    ///
     /// ```ignore
-    /// let r = -0.5;
-    /// let dist = ExponentialDist::new(r);
-    /// println!("{}", dist == None); // prints "true"
+    /// let dist = NegativeBinomialDist::new(2.0, 0.5).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints "0.25", "0.5", "0.6875" separated by newlines
+    ///     println!("{}", dist.cdf(k));
+    /// }
     /// ```
-    pub fn new(rate_param: f64) -> Option<ExponentialDist> {
-        if rate_param <= 0.0 {
-            return None;
+    fn cdf(&self, value: i32) -> F {
+        if value < 0 {
+            return F::zero();
         }
 
-        Some(ExponentialDist { rate_param })
+        special::beta_inc(self.successes, cast(value as f64 + 1.0), self.p_success)
     }
 
-    /// Returns the rate parameter of the distribution.
-    pub fn rate_param(&self) -> f64 {
-        self.rate_param
+    /// Returns the mean of the negative binomial distribution, equivalent to `r(1 - p) / p`.
+    fn mean(&self) -> F {
+        self.successes * self.p_failure() / self.p_success
     }
-}
 
-impl ContinuousDist<f64> for ExponentialDist {
-    /// Returns the exponential PDF of `value`.
-    /// 
-    /// When `value` is outside the support (i.e. negative), `0.0` is returned. Otherwise, the correct PDF is returned.
-    /// 
+    /// Returns the variance of the negative binomial distribution, equivalent to `r(1 - p) / p^2`.
+    fn variance(&self) -> F {
+        self.successes * self.p_failure() / self.p_success.powi(2)
+    }
+
+    /// Draws a random value from the negative binomial distribution.
+    ///
+    /// Values are drawn as a gamma-Poisson mixture: a rate is drawn from a gamma distribution with shape `r` and rate 
+    /// `p / (1 - p)`, and the value is then drawn from the Poisson distribution with that rate.
+    ///
     /// ```ignore
-    /// let r = 0.5;
-    /// let dist = ExponentialDist::new(r).unwrap();
-    /// 
-    /// println!("{}", dist.pdf(0.0)); // prints "0.0"
-    /// println!("{}", dist.pdf(2.5)); // prints approximately "0.1432"
-    /// println!("{}", dist.pdf(5.0)); // prints approximately "0.041"
+    /// let dist = NegativeBinomialDist::new(3.0, 1.0 / 6.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let failures = dist.sample(&mut rng);
     /// ```
-    fn pdf(&self, value: f64) -> f64 {
-        if value < 0.0 { 
-            return 0.0;
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        if self.p_success == F::one() {
+            return 0;
         }
 
-        self.rate_param * (-self.rate_param * value).exp()
+        let rate = std_gamma_sample(to_f64(self.successes), rng) * to_f64(self.p_failure() / self.p_success);
+        poisson_sample(rate, rng)
     }
 
-    /// Returns the exponential CDF of `value`.
-    /// 
-    /// When `value` is outside the support (i.e. negative), `0.0` is returned. Otherwise, the correct PDF is returned.
-    /// 
-    /// ```ignore
-    /// let r = 0.5;
-    /// let dist = ExponentialDist::new(r).unwrap();
-    /// 
-    /// println!("{}", dist.cdf(0.0)); // prints "0.0"
-    /// println!("{}", dist.cdf(2.5)); // prints approximately "0.7135"
-    /// println!("{}", dist.cdf(5.0)); // prints approximately "0.9179"
-    /// ```
-    fn cdf(&self, value: f64) -> f64 {
-        if value < 0.0 { 
-            return 0.0;
+    /// Returns the mode of the distribution, equivalent to `floor((r - 1)(1 - p) / p)` for `r > 1` and `0` otherwise; if 
+    /// `(r - 1)(1 - p) / p` is a positive integer, both it and it minus 1 are modes, and the latter is returned.
+    fn mode(&self) -> Option<i32> {
+        if self.successes <= F::one() {
+            return Some(0);
         }
 
-        1.0 - (-self.rate_param * value).exp()
+        let mode = integer_mode(to_f64((self.successes - F::one()) * self.p_failure() / self.p_success));
+        if mode >= 0 { Some(mode) } else { None }
     }
 
-    /// Returns the mean of the distribution.
-    /// 
-    /// This is equivalent to the inverse of the rate parameter of the distribution.
-    fn mean(&self) -> f64 {
-        1.0 / self.rate_param
+    /// Returns the skewness of the distribution, equivalent to `(2 - p) / sqrt(r(1 - p))` (or `NaN` if `p = 1`).
+    fn skewness(&self) -> F {
+        let spread = self.successes * self.p_failure();
+        if spread > F::zero() { (cast::<F>(2.0) - self.p_success) / spread.sqrt() } else { F::nan() }
     }
 
-    /// Returns the variance of the distribution.
-    /// 
-    /// This is equivalent to the inverse of the squared rate parameter of the distribution.
-    fn variance(&self) -> f64 {
-        1.0 / self.rate_param.powi(2)
+    /// Returns the excess kurtosis of the distribution, equivalent to `6 / r + p^2 / (r(1 - p))` (or `NaN` if `p = 1`).
+    fn kurtosis(&self) -> F {
+        let spread = self.successes * self.p_failure();
+        if spread > F::zero() { cast::<F>(6.0) / self.successes + self.p_success.powi(2) / spread } else { F::nan() }
     }
 
-    /// Returns the standard of the distribution.
-    /// 
-    /// This is equivalent to the inverse of the rate parameter of the distribution (which, coincidentally, is also the mean).
-    fn std(&self) -> f64 {
-        1.0 / self.rate_param
+    /// Returns the MGF of `t`, equivalent to `(p / (1 - (1 - p) e^t))^r`, which exists for `t < -ln(1 - p)`.
+    fn mgf(&self, t: F) -> Option<F> {
+        let denominator = F::one() - self.p_failure() * t.exp();
+        let value = (self.p_success / denominator).powf(self.successes);
+
+        if denominator > F::zero() && value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `(p / (1 - (1 - p) e^(it)))^r`.
+    fn cf(&self, t: F) -> Complex<F> {
+        let denominator = Complex::new(F::one(), F::zero()) - Complex::new(t.cos(), t.sin()) * self.p_failure();
+        (denominator.inv() * self.p_success).powf(self.successes)
     }
 }
 
 
-/// A normal (Gaussian) distribution.
-/// 
-/// The normal distribution is parameterized by a location and a scale (both real numbers). Those familiar with the normal 
-/// distribution may recognize these parameters as the distribution's mean and standard deviation; `NormalDist` names its 
-/// parameters to reflect their conceptual purpose rather than their relevance to the properties of the distribution.
-/// 
-/// The support of the normal distribution is all real numbers.
+/// A hypergeometric distribution, representing the number of successes in some number of draws without replacement.
+///
+/// Hypergeometric distributions are parameterized by the size of the population being drawn from, the number of 
+/// successes in that population, and the number of draws. The support of the hypergeometric distribution is the set of 
+/// integers from `max(0, draws + successes - population)` to `min(draws, successes)`, inclusive.
 #[derive(Debug, PartialEq)]
-pub struct NormalDist {
-    loc: f64,
-    scale: f64,
+pub struct HypergeometricDist<F: Float = f64> {
+    population: i32,
+    successes: i32,
+    draws: i32,
+    precision: PhantomData<F>,
 }
 
-impl NormalDist {
-    /// Creates and returns a new normal distribution.
-    /// 
-    /// If `scale` is negative, `None` is returned; otherwise, the created distribution with parameters 
-    /// `loc` and `scale` is returned.
-    /// 
-    /// ```ignore
-    /// let loc = 5.0;
-    /// let scale = 2.0;
-    /// let dist = NormalDist::new(loc, scale).unwrap();
-    /// 
-    /// println!("{}", dist.loc()); // prints "5.0"
-    /// println!("{}", dist.scale()); // prints "2.0"
-    /// ```
-    /// 
-    /// ```ignore
-    /// let loc = 5.0;
-    /// let scale = -2.0;
-    /// let dist = NormalDist::new(loc, scale);
-    /// 
-    /// println!("{}", dist == None); // prints "true"
-    /// ```
-    pub fn new(loc: f64, scale: f64) -> Option<NormalDist> {
-        if scale < 0.0 {
-            return None;
+#[cfg(feature = "serde")]
+serde_via_params!(HypergeometricDist<F: Float>, |dist| {
+    population: i32 = &dist.population,
+    successes: i32 = &dist.successes,
+    draws: i32 = &dist.draws,
+} => HypergeometricDist::new_in(population, successes, draws));
+
+impl<F: Float> HypergeometricDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(population: i32, successes: i32, draws: i32) -> Result<HypergeometricDist<F>, PensivError> {
+        if population < 0 {
+            return Err(PensivError::InvalidParameter {
+                name: "population",
+                value: population as f64,
+                expected: "a non-negative integer",
+            });
+        }
+
+        for (name, value) in [("successes", successes), ("draws", draws)].iter() {
+            if !(0..=population).contains(value) {
+                return Err(PensivError::InvalidParameter {
+                    name,
+                    value: *value as f64,
+                    expected: "an integer between 0 and the population",
+                });
+            }
         }
 
-        Some(NormalDist { loc, scale })
+        Ok(HypergeometricDist { population, successes, draws, precision: PhantomData })
     }
 
-    /// Creates and returns a new standard normal distribution.
-    /// 
-    /// The standard normal distribution is a normal distribution with `loc = 0` and `scale = 1`.
-    /// 
-    /// ```ignore
-    /// let dist = NormalDist::std();
-    /// 
-    /// println!("{}", dist.loc()); // prints "0.0"
-    /// println!("{}", dist.scale()); // prints "1.0"
-    /// ```
-    pub fn std() -> NormalDist {
-        NormalDist { loc: 0.0, scale: 1.0 }
+    /// Returns the size of the population being drawn from.
+    pub fn population(&self) -> i32 {
+        self.population
     }
 
-    /// Returns the location of the normal distribution.
-    pub fn loc(&self) -> f64 {
-        self.loc
+    /// Returns the number of successes in the population.
+    pub fn successes(&self) -> i32 {
+        self.successes
     }
 
-    /// Returns the scale of the normal distribution.
-    pub fn scale(&self) -> f64 {
-        self.scale
+    /// Returns the number of draws.
+    pub fn draws(&self) -> i32 {
+        self.draws
     }
 
-    /// Returns the Z-value of `value` within the distribution.
-    /// 
-    /// The Z-value is obtained by finding the distance from `value` to the mean of the distribution in units of its standard 
-    /// deviation.
-    /// 
-    /// ```ignore
-    /// let loc = 5.0;
-    /// let scale = 2.0;
-    /// let dist = NormalDist::new(loc, scale).unwrap();
-    /// 
-    /// println!("{}", dist.z(7.0)); // prints "1.0"
-    /// println!("{}", dist.z(3.0)); // prints "-1.0"
-    /// ```
-    pub fn z(&self, value: f64) -> f64 {
-        (value - self.loc) / self.scale
+    /// Returns the smallest value in the support of the distribution.
+    pub fn min_successes(&self) -> i32 {
+        (self.draws + self.successes - self.population).max(0)
+    }
+
+    /// Returns the largest value in the support of the distribution.
+    pub fn max_successes(&self) -> i32 {
+        self.draws.min(self.successes)
     }
 }
 
-impl ContinuousDist<f64> for NormalDist {
-    /// Returns the normal PDF of `value`.
-    /// 
+impl HypergeometricDist {
+    /// Creates and returns a new hypergeometric distribution.
+    ///
+    /// Returns an `InvalidParameter` error if `population` is negative, or if `successes` or `draws` is negative or greater 
+    /// than `population`; otherwise returns the created distribution.
+    ///
     /// ```ignore
-    /// let loc = 5.0;
-    /// let scale = 2.0;
-    /// let dist = NormalDist::new(loc, scale).unwrap();
-    /// 
-    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.0269"
-    /// println!("{}", dist.pdf(3.0)); // prints approximately "0.1209"
-    /// println!("{}", dist.pdf(5.0)); // prints approximately "0.1995"
-    /// println!("{}", dist.pdf(7.0)); // prints approximately "0.1209"
-    /// println!("{}", dist.pdf(9.0)); // prints approximately "0.0269"
+    /// // drawing 5 cards from a standard deck, counting the number of hearts
+    /// let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+    ///
+    /// println!("{}", dist.population()); // prints "52"
+    /// println!("{}", dist.successes()); // prints "13"
+    /// println!("{}", dist.draws()); // prints "5"
     /// ```
-    fn pdf(&self, value: f64) -> f64 {
-        let t1 = 1.0 / ((2.0 * PI).sqrt() * self.scale);
-        let t2 = -(value - self.loc).powi(2) / (2.0 * self.variance());
-
-        t1 * t2.exp()
+    ///
+    /// ```ignore
+    /// let dist = HypergeometricDist::new(52, 13, 60);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(population: i32, successes: i32, draws: i32) -> Result<HypergeometricDist, PensivError> {
+        HypergeometricDist::new_in(population, successes, draws)
     }
 
-    /// Returns the normal CDF of `value`.
-    /// 
-    /// The normal CDF is approximated using the above approximation of the error function; see `erf()` above for details.
-    /// 
+    /// Fits a hypergeometric distribution with a known population & number of draws to `data` by maximum likelihood.
+    ///
+    /// The number of successes in the population is an integer, so rather than solving for a root, the log-likelihood 
+    /// (which is concave in it) is climbed one success at a time from the method-of-moments estimate 
+    /// `population * mean / draws`, until neither neighbour is more likely. An integer parameter has no Fisher 
+    /// information, so the standard error of the number of successes is that of the method-of-moments estimate, i.e. 
+    /// `population / draws * sqrt(variance / n)` where `variance` is that of the fitted distribution. Since the 
+    /// population & number of draws are known, their standard errors are `0`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidParameter` error if `draws` isn't positive or is greater than `population`, or an `InvalidInput` error 
+    /// if `data` contains values that aren't integers in `[0, draws]`, or values that no single number of successes 
+    /// could produce (e.g. `0` & `draws` when the population is `draws`).
+    ///
     /// ```ignore
-    /// let loc = 5.0;
-    /// let scale = 2.0;
-    /// let dist = NormalDist::new(loc, scale).unwrap();
-    /// 
-    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.025"
-    /// println!("{}", dist.cdf(3.0)); // prints approximately "0.17"
-    /// println!("{}", dist.cdf(5.0)); // prints approximately "0.5"
-    /// println!("{}", dist.cdf(7.0)); // prints approximately "0.83"
-    /// println!("{}", dist.cdf(9.0)); // prints approximately "0.975"
+    /// let data = array![1.0, 2.0, 0.0, 1.0, 3.0];
+    /// let fit = HypergeometricDist::fit(&data, 52, 5).unwrap();
+    ///
+    /// println!("{}", fit.dist().successes()); // prints "15"
     /// ```
-    fn cdf(&self, value: f64) -> f64 {
-        let z = self.z(value);
+    pub fn fit(data: &Array<f64, Ix1>, population: i32, draws: i32) -> Result<Fit<HypergeometricDist>, PensivError> {
+        let mean = fit_mean(data)?;
+
+        if !(1..=population).contains(&draws) {
+            return Err(PensivError::InvalidParameter {
+                name: "draws",
+                value: draws as f64,
+                expected: "a positive integer no greater than the population",
+            });
+        }
+        else if !all_integers_within(data, 0.0, draws as f64) {
+            return Err(PensivError::InvalidInput("data must be integers between 0 & the number of draws"));
+        }
 
-        (1.0 + erf(z / 2_f64.sqrt())) / 2.0
-    }
+        // each value x needs at least x successes & draws - x failures in the population
+        let lower = data.fold(0.0_f64, |a, &b| a.max(b)) as i32;
+        let upper = population - draws + data.fold(f64::INFINITY, |a, &b| a.min(b)) as i32;
 
-    /// Returns the mean of the distribution, equivalent to the location.
-    fn mean(&self) -> f64 {
-        self.loc
-    }
+        if lower > upper {
+            return Err(PensivError::InvalidInput("data must be consistent with a single number of successes"));
+        }
 
-    /// Returns the variance of the distribution, equivalent to the scale squared.
-    fn variance(&self) -> f64 {
-        self.scale.powi(2)
-    }
+        // log of the likelihood ratio between k + 1 & k successes
+        let (big_n, small_n) = (population as f64, draws as f64);
+        let ln_ratio = |k: i32| {
+            let k = k as f64;
+            data.iter()
+                .map(|&x| ((k + 1.0) / (k + 1.0 - x)).ln() + ((big_n - k - small_n + x) / (big_n - k)).ln())
+                .sum::<f64>()
+        };
 
-    /// Returns the standard deviation of the distribution, equivalent to the scale.
-    fn std(&self) -> f64 {
-        self.scale
-    }
-}
+        let mut successes = ((big_n * mean / small_n).round() as i32).clamp(lower, upper);
 
+        while successes < upper && ln_ratio(successes) > 0.0 {
+            successes += 1;
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        while successes > lower && ln_ratio(successes - 1) < 0.0 {
+            successes -= 1;
+        }
 
-    #[test]
-    fn discrete_uniform_dist_created_correctly() {
-        let a = 0;
-        let b = 4;
-        let dist = DiscreteUniformDist::new(0, 4).unwrap(); // panics if creation fails
-        
-        assert_eq!(dist.lower_bound(), a);
-        assert_eq!(dist.upper_bound(), b);
-        assert_eq!(dist.range(), b - a + 1);
+        let dist = HypergeometricDist::new(population, successes, draws)?;
+        let std_error = big_n / small_n * (dist.variance() / data.len() as f64).sqrt();
+
+        Ok(Fit { dist, std_errors: array![0.0, std_error, 0.0] })
+    }
+}
+
+impl<F: Float> DiscreteDist<i32, F> for HypergeometricDist<F> {
+    /// Returns the hypergeometric PMF of `value`.
+    ///
+    /// The hypergeometric PMF is calculated (in log-space, using `ln_choose()`) as:
+    /// `(K choose k)(N - K choose n - k) / (N choose n)` where `N` is the population, `K` is the number of successes in the 
+    /// population, `n` is the number of draws, and `k = value`
+    ///
+    /// `0.0` is returned for values outside of the support of the distribution.
+    ///
+    /// ```ignore
+    /// // drawing 2 balls from an urn with 3 red balls & 2 blue balls, counting the number of red balls
+    /// let dist = HypergeometricDist::new(5, 3, 2).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints "0.1", "0.6", "0.3" separated by newlines
+    ///     println!("{}", dist.pmf(k));
+    /// }
+    /// ```
+    fn pmf(&self, value: i32) -> F {
+        self.log_pmf(value).exp()
+    }
+
+    /// Returns the natural log of the hypergeometric PMF of `value`, or `-inf` for values outside of the support.
+    fn log_pmf(&self, value: i32) -> F {
+        if value < self.min_successes() || value > self.max_successes() {
+            return F::neg_infinity();
+        }
+
+        let (population, successes, draws) = (self.population as u64, self.successes as u64, self.draws as u64);
+        let k = value as u64;
+
+        cast(ln_choose(successes, k) + ln_choose(population - successes, draws - k) - ln_choose(population, draws))
+    }
+
+    /// Returns the hypergeometric CDF of `value`.
+    ///
+    /// The hypergeometric CDF is the sum of the hypergeometric PMF of values in the support up to `value` (inclusive).
+    ///
+    /// ```ignore
+    /// let dist = HypergeometricDist::new(5, 3, 2).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints "0.1", "0.7", "1.0" separated by newlines
+    ///     println!("{}", dist.cdf(k));
+    /// }
+    /// ```
+    fn cdf(&self, value: i32) -> F {
+        self.interval_cdf(self.min_successes(), value)
+    }
+
+    /// Returns the probability that the hypergeometric random variable falls between `lower_bound` and `upper_bound`, 
+    /// inclusive.
+    fn interval_cdf(&self, lower_bound: i32, upper_bound: i32) -> F {
+        // only sum over the part of the interval that's actually in the support
+        let lower = lower_bound.max(self.min_successes());
+        let upper = upper_bound.min(self.max_successes());
+
+        (lower..=upper).fold(F::zero(), |sum, k| sum + self.pmf(k))
+    }
+
+    /// Returns the mean of the hypergeometric distribution, equivalent to `nK / N`.
+    fn mean(&self) -> F {
+        if self.population == 0 {
+            return F::zero();
+        }
+
+        cast(self.draws as f64 * self.successes as f64 / self.population as f64)
+    }
+
+    /// Returns the variance of the hypergeometric distribution, equivalent to `n(K / N)((N - K) / N)((N - n) / (N - 1))`.
+    fn variance(&self) -> F {
+        if self.population <= 1 {
+            return F::zero();
+        }
+
+        let population = self.population as f64;
+        let p = self.successes as f64 / population;
+
+        cast(self.draws as f64 * p * (1.0 - p) * (population - self.draws as f64) / (population - 1.0))
+    }
+
+    /// Draws a random value from the hypergeometric distribution.
+    ///
+    /// Values are drawn exactly by inversion, walking up the PMF from the bottom of the support.
+    ///
+    /// ```ignore
+    /// let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let hearts = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        let mut u = rng.gen::<f64>();
+        let mut k = self.min_successes();
+
+        while k < self.max_successes() {
+            let p = to_f64(self.pmf(k));
+
+            if u < p {
+                break;
+            }
+
+            u -= p;
+            k += 1;
+        }
+
+        k
+    }
+
+    /// Returns the quantile of `p`, i.e. the smallest number of successes whose CDF is at least `p`.
+    ///
+    /// The quantile is found with a binary search of the CDF over the support.
+    ///
+    /// ```ignore
+    /// let dist = HypergeometricDist::new(5, 3, 2).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.1)); // prints "Some(0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(1)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "Some(2)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        let lower = self.min_successes() as i64 - 1;
+        let k = integer_quantile_search(lower, self.max_successes() as i64, |k| quantile_reached(self.cdf(k as i32), p));
+
+        Some(k as i32)
+    }
+}
+
+
+/// The largest number of categories that `CategoricalDist::fit()` will fit.
+///
+/// The number of categories fitted is the largest index in the data plus 1, so a single huge index would otherwise
+/// allocate a probability for every category below it.
+pub const MAX_FIT_CATEGORIES: usize = 1_000_000;
+
+
+/// A categorical distribution over a finite set of categories.
+///
+/// Categorical distributions are parameterized by a weight for each category; the probability of each category is its 
+/// weight divided by the sum of all weights. Categories are identified by their index, so the support of the categorical 
+/// distribution is the set of integers from `0` to the number of categories minus 1 (inclusive).
+#[derive(Debug, PartialEq)]
+pub struct CategoricalDist<F: Float = f64> {
+    probabilities: Array<F, Ix1>,
+    cumulative: Array<F, Ix1>,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(CategoricalDist<F: Float>, |dist| {
+    probabilities: Vec<F> = &dist.probabilities.to_vec(),
+} => CategoricalDist::new_in(&Array::from(probabilities)));
+
+impl<F: Float> CategoricalDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(weights: &Array<F, Ix1>) -> Result<CategoricalDist<F>, PensivError> {
+        if weights.is_empty() {
+            return Err(PensivError::EmptyData);
+        }
+        else if weights.iter().any(|w| !(*w >= F::zero() && w.is_finite())) {
+            return Err(PensivError::InvalidInput("weights must be non-negative & finite"));
+        }
+
+        let total = weights.sum();
+        if total <= F::zero() || !total.is_finite() {
+            return Err(PensivError::InvalidInput("weights must have a positive, finite sum"));
+        }
+
+        let probabilities = weights.mapv(|w| w / total);
+        let mut cumulative = Array::<F, Ix1>::zeros(probabilities.len());
+        let mut running_total = F::zero();
+
+        for (i, p) in probabilities.iter().enumerate() {
+            running_total = running_total + *p;
+            cumulative[[i]] = running_total;
+        }
+
+        // make sure the last category absorbs any rounding error
+        let last = cumulative.len() - 1;
+        cumulative[[last]] = F::one();
+
+        Ok(CategoricalDist { probabilities, cumulative })
+    }
+
+    /// Returns the number of categories.
+    pub fn categories(&self) -> usize {
+        self.probabilities.len()
+    }
+
+    /// Returns the (normalized) probability of each category.
+    pub fn probabilities(&self) -> &Array<F, Ix1> {
+        &self.probabilities
+    }
+
+    /// Returns the index of the first category whose cumulative probability reaches `p`.
+    fn category_reaching(&self, p: F) -> i32 {
+        let lower = -1;
+        let upper = self.categories() as i64 - 1;
+
+        integer_quantile_search(lower, upper, |k| quantile_reached(self.cumulative[[k as usize]], p)) as i32
+    }
+}
+
+impl CategoricalDist {
+    /// Creates and returns a new categorical distribution, with category `i` having weight `weights[i]`.
+    ///
+    /// The weights don't need to sum to `1`; they're normalized when the distribution is created. Returns an `EmptyData` 
+    /// error if `weights` is empty, or an `InvalidInput` error if any weight is negative or not finite, or if all weights 
+    /// are `0`. Otherwise, returns the created distribution.
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+    ///
+    /// println!("{}", dist.categories()); // prints "3"
+    /// println!("{}", dist.probabilities()); // prints "[0.25, 0.5, 0.25]"
+    /// ```
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, -2.0, 1.0]);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(weights: &Array<f64, Ix1>) -> Result<CategoricalDist, PensivError> {
+        CategoricalDist::new_in(weights)
+    }
+
+    /// Fits a categorical distribution to `data` by maximum likelihood, where each value in `data` is a category index.
+    ///
+    /// The maximum-likelihood estimate of the probability of each category is the fraction of the data that it occupies, 
+    /// and its standard error is `sqrt(p(1 - p) / n)`. The number of categories is taken to be the largest index in the 
+    /// data plus 1, so categories above it that never occur are left out of the fitted distribution.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidInput` error if it contains values that aren't non-negative integers, or indices of `MAX_FIT_CATEGORIES` 
+    /// or more.
+    ///
+    /// ```ignore
+    /// let data = array![0.0, 2.0, 2.0, 1.0];
+    /// let fit = CategoricalDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().probabilities()); // prints "[0.25, 0.25, 0.5]"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<CategoricalDist>, PensivError> {
+        fit_mean(data)?;
+
+        if !all_integers_within(data, 0.0, i32::MAX as f64) {
+            return Err(PensivError::InvalidInput("data must be non-negative integers representable by an i32"));
+        }
+
+        let categories = data.fold(0.0_f64, |a, &b| a.max(b)) as usize + 1;
+        if categories > MAX_FIT_CATEGORIES {
+            return Err(PensivError::InvalidInput("data must have category indices less than MAX_FIT_CATEGORIES"));
+        }
+
+        let mut counts = Array::<f64, Ix1>::zeros(categories);
+
+        for x in data.iter() {
+            counts[[*x as usize]] += 1.0;
+        }
+
+        let n = data.len() as f64;
+        let std_errors = counts.mapv(|count| {
+            let p = count / n;
+            (p * (1.0 - p) / n).sqrt()
+        });
+
+        Ok(Fit { dist: CategoricalDist::new(&counts)?, std_errors })
+    }
+}
+
+impl<F: Float> DiscreteDist<i32, F> for CategoricalDist<F> {
+    /// Returns the probability of category `value`, or `0.0` if `value` isn't a category.
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+    ///
+    /// println!("{}", dist.pmf(1)); // prints "0.5"
+    /// println!("{}", dist.pmf(3)); // prints "0.0"
+    /// ```
+    fn pmf(&self, value: i32) -> F {
+        if value < 0 || value as usize >= self.categories() {
+            return F::zero();
+        }
+
+        self.probabilities[[value as usize]]
+    }
+
+    /// Returns the probability that the category is at most `value`.
+    ///
+    /// The cumulative probabilities are computed when the distribution is created, so this is a constant-time lookup.
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+    ///
+    /// for k in 0..3 {
+    ///     // prints "0.25", "0.75", "1.0" separated by newlines
+    ///     println!("{}", dist.cdf(k));
+    /// }
+    /// ```
+    fn cdf(&self, value: i32) -> F {
+        if value < 0 {
+            return F::zero();
+        }
+        else if value as usize >= self.categories() {
+            return F::one();
+        }
+
+        self.cumulative[[value as usize]]
+    }
+
+    /// Returns the mean category index.
+    fn mean(&self) -> F {
+        self.probabilities.iter().enumerate().fold(F::zero(), |sum, (k, p)| sum + cast::<F>(k as f64) * *p)
+    }
+
+    /// Returns the variance of the category index.
+    fn variance(&self) -> F {
+        let mean = self.mean();
+        self.probabilities.iter().enumerate().fold(F::zero(), |sum, (k, p)| sum + *p * (cast::<F>(k as f64) - mean).powi(2))
+    }
+
+    /// Draws a random category from the distribution.
+    ///
+    /// Categories are drawn by inversion, i.e. with a binary search of the cumulative probabilities.
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let category = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> i32 {
+        let u = 1.0 - rng.gen::<f64>(); // in (0, 1] so that categories with 0 probability are never drawn
+        self.category_reaching(cast(u))
+    }
+
+    /// Returns the quantile of `p`, i.e. the first category whose cumulative probability is at least `p`.
+    ///
+    /// ```ignore
+    /// let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.25)); // prints "Some(0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(1)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "Some(2)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<i32> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        Some(self.category_reaching(p))
+    }
+}
+
+
+/// An empirical distribution describing a set of data.
+///
+/// Empirical distributions are "parameterized" by a data set containing real numbers; they describe the distribution of values within that data set. 
+/// Therefore, the support of the empirical distribution is the set of real numbers present in the data.
+///
+/// The data set can be updated after the distribution is created with `insert()`, `remove()`, & `merge()`, which keep 
+/// the distribution's mean & variance up to date without recomputing them from the whole data set; each update takes 
+/// logarithmic time in the number of distinct values. The CDF & quantile queries use a tree of cumulative counts that's 
+/// updated along with the data set, so they take logarithmic time too, however queries & updates are interleaved.
+#[derive(Debug)]
+pub struct EmpiricalDist<F: Float = f64> {
+    counts: BTreeMap<ComparableFloat<F>, i32>,
+    // the same counts as `counts`, indexed for queries about the number of elements <= a value
+    cumulative_counts: CountTree<F>,
+    data_len: usize,
+    mean: F,
+    sum_squared_deviations: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(EmpiricalDist<F: Float>, |dist| {
+    data: Vec<F> = &dist.data().to_vec(),
+} => EmpiricalDist::new_in(&Array::from(data)));
+
+impl<F: Float> EmpiricalDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(dataset: &Array<F, Ix1>) -> Result<EmpiricalDist<F>, PensivError> {
+        let mut counts = BTreeMap::<ComparableFloat<F>, i32>::new();
+        let data_len = dataset.len();
+
+        for elem in dataset.iter() {
+            match ComparableFloat::new(*elem) {
+                Ok(f) => {
+                    if !counts.contains_key(&f) {
+                        counts.insert(f, 0);
+                    }
+
+                    counts.insert(f, counts[&f] + 1);
+                },
+                Err(_) => return Err(PensivError::NonFiniteData),
+            }
+        }
+
+        let mut cumulative_counts = CountTree::new();
+        for (key, n) in counts.iter() {
+            cumulative_counts.add(*key, *n as isize);
+        }
+
+        let values = dataset.mapv(to_f64);
+        let mean = cast(describe::mean(&values).unwrap_or(0.0));
+        let sum_squared_deviations = cast(describe::variance(&values, 0).unwrap_or(0.0) * data_len as f64);
+
+        Ok(EmpiricalDist { counts, cumulative_counts, data_len, mean, sum_squared_deviations })
+    }
+
+    /// Returns the distribution's data set in ascending order.
+    /// 
+    /// The distribution only stores the number of occurrences of each distinct value, so the data set is rebuilt from those 
+    /// counts on each call; it reflects any calls to `insert()`, `remove()`, or `merge()`, but not the order in which the 
+    /// values were added.
+    pub fn data(&self) -> Array<F, Ix1> {
+        self.counts.iter()
+            .flat_map(|(key, n)| vec![key.value(); *n as usize])
+            .collect()
+    }
+
+    /// Adds `value` to the data set, updating the distribution's mean & variance incrementally (via Welford's algorithm).
+    ///
+    /// Returns `false`, leaving the distribution unchanged, if `value` is `NaN` or infinite; otherwise returns `true`.
+    ///
+    /// ```ignore
+    /// let mut dist = EmpiricalDist::new(&array![1.0, 2.0]).unwrap();
+    /// dist.insert(3.0);
+    ///
+    /// println!("{}", dist.mean()); // prints "2.0"
+    /// println!("{}", dist.insert(f64::NAN)); // prints "false"
+    /// ```
+    pub fn insert(&mut self, value: F) -> bool {
+        let key = match ComparableFloat::new(value) {
+            Ok(key) => key,
+            Err(_) => return false,
+        };
+
+        *self.counts.entry(key).or_insert(0) += 1;
+        self.cumulative_counts.add(key, 1);
+        self.data_len += 1;
+
+        let delta = value - self.mean;
+        self.mean = self.mean + delta / cast(self.data_len as f64);
+        self.sum_squared_deviations = self.sum_squared_deviations + delta * (value - self.mean);
+
+        true
+    }
+
+    /// Removes one occurrence of `value` from the data set, updating the distribution's mean & 
+    /// variance incrementally by reversing Welford's algorithm.
+    ///
+    /// This makes it possible to maintain the distribution of a sliding window over a stream of values. Returns `false`, 
+    /// leaving the distribution unchanged, if `value` isn't in the data set; otherwise returns `true`.
+    ///
+    /// ```ignore
+    /// let mut dist = EmpiricalDist::new(&array![1.0, 2.0, 3.0]).unwrap();
+    /// dist.remove(1.0);
+    ///
+    /// println!("{}", dist.mean()); // prints "2.5"
+    /// println!("{}", dist.remove(1.0)); // prints "false"
+    /// ```
+    pub fn remove(&mut self, value: F) -> bool {
+        let key = match ComparableFloat::new(value) {
+            Ok(key) => key,
+            Err(_) => return false,
+        };
+
+        match self.counts.get_mut(&key) {
+            Some(count) if *count > 1 => *count -= 1,
+            Some(_) => { self.counts.remove(&key); },
+            None => return false,
+        }
+
+        self.cumulative_counts.add(key, -1);
+        self.data_len -= 1;
+
+        if self.data_len == 0 {
+            // start over exactly rather than carrying rounding error into the next value
+            self.mean = F::zero();
+            self.sum_squared_deviations = F::zero();
+        }
+        else {
+            let delta = value - self.mean;
+            self.mean = self.mean - delta / cast(self.data_len as f64);
+            self.sum_squared_deviations = (self.sum_squared_deviations - delta * (value - self.mean)).max(F::zero());
+        }
+
+        true
+    }
+
+    /// Adds the data set of `other` to this distribution's data set, e.g. to combine distributions built on different shards
+    /// of a data set.
+    ///
+    /// The mean & variance are combined directly from those of the two distributions (via Chan et al.'s parallel 
+    /// algorithm), and `other` is left unchanged.
+    ///
+    /// ```ignore
+    /// let mut dist = EmpiricalDist::new(&array![1.0, 2.0]).unwrap();
+    /// let other = EmpiricalDist::new(&array![3.0, 4.0]).unwrap();
+    ///
+    /// dist.merge(&other);
+    /// println!("{}", dist.variance()); // prints "1.25"
+    /// ```
+    pub fn merge(&mut self, other: &EmpiricalDist<F>) {
+        if other.data_len == 0 {
+            return;
+        }
+
+        for (key, n) in other.counts.iter() {
+            *self.counts.entry(*key).or_insert(0) += *n;
+            self.cumulative_counts.add(*key, *n as isize);
+        }
+
+        let n_self: F = cast(self.data_len as f64);
+        let n_other: F = cast(other.data_len as f64);
+        let n = n_self + n_other;
+        let delta = other.mean - self.mean;
+
+        self.mean = self.mean + delta * n_other / n;
+        self.sum_squared_deviations = self.sum_squared_deviations + other.sum_squared_deviations 
+            + delta * delta * n_self * n_other / n;
+        self.data_len += other.data_len;
+    }
+
+    /// Returns the `q`-th quantile of the data set, linearly interpolated between adjacent order statistics.
+    ///
+    /// This is the "type 7" definition of a sample quantile (the default in R and NumPy): with the data sorted as
+    /// `x[0] <= ... <= x[n - 1]`, the quantile is `x[j] + (h - j)(x[j + 1] - x[j])` where `h = (n - 1)q` and `j = floor(h)`.
+    /// Unlike `ppf()`, which always returns an element of the data set, this gives a continuous estimate of the quantile
+    /// of the underlying population. Returns `None` if `q` isn't a valid probability or if the data set is empty.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 3.0, 4.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    ///
+    /// println!("{:?}", dist.quantile(0.5)); // prints "Some(2.5)"
+    /// println!("{:?}", dist.quantile(0.25)); // prints "Some(1.75)"
+    /// ```
+    pub fn quantile(&self, q: F) -> Option<F> {
+        if !(F::zero()..=F::one()).contains(&q) || self.data_len == 0 {
+            return None;
+        }
+
+        let h = cast::<F>((self.data_len - 1) as f64) * q;
+        let j = to_f64(h.floor()) as usize;
+
+        let lower = self.order_statistic(j);
+        if j + 1 >= self.data_len {
+            return Some(lower);
+        }
+
+        let upper = self.order_statistic(j + 1);
+        Some(lower + (h - cast(j as f64)) * (upper - lower))
+    }
+
+    /// Returns the empirical CDF of each element of `values`.
+    ///
+    /// This is equivalent to calling `cdf()` on each element, but `values` is sorted and then evaluated in a single merge 
+    /// pass over the distinct values of the data set, which is much faster for large batches; if `values` is already 
+    /// sorted, the sort is very cheap.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    ///
+    /// println!("{}", dist.cdf_many(&array![2.0, 0.0, 3.0])); // prints "[0.75, 0, 1]"
+    /// ```
+    pub fn cdf_many(&self, values: &Array<F, Ix1>) -> Array<F, Ix1> {
+        let mut order = (0..values.len()).filter(|i| !values[*i].is_nan()).collect::<Vec<usize>>();
+        order.sort_by(|i, j| values[*i].partial_cmp(&values[*j]).unwrap());
+
+        let mut counts = self.counts.iter().peekable();
+        let mut cdfs = Array::<F, Ix1>::zeros(values.len());
+        let mut count = 0;
+
+        for i in order {
+            while let Some((_, n)) = counts.next_if(|(key, _)| key.value() <= values[i]) {
+                count += *n as usize;
+            }
+
+            cdfs[i] = cast(count as f64 / self.data_len as f64);
+        }
+
+        cdfs
+    }
+
+    /// Returns the `i`-th smallest element of the data set (starting from 0), assuming that `i < data_len`.
+    fn order_statistic(&self, i: usize) -> F {
+        self.cumulative_counts.first_reaching(|count| count > i).unwrap_or_else(F::nan)
+    }
+
+    /// Returns the number of elements of the data set that are less than or equal to `value`.
+    fn count_at_most(&self, value: F) -> usize {
+        self.cumulative_counts.count_while(|key| key <= value)
+    }
+
+    /// Returns the number of elements of the data set that are less than `value`.
+    fn count_below(&self, value: F) -> usize {
+        self.cumulative_counts.count_while(|key| key < value)
+    }
+}
+
+impl EmpiricalDist {
+    /// Creates and returns a new empirical distribution describing `data`.
+    ///
+    /// Note that `data` must be a 1-dimensional instance of `ndarray::Array` containing the dataset. To create the dataset, `data` is borrowed immutably; 
+    /// because it's borrowed rather than moved, it can continue to be used after the distribution is created. If any element 
+    /// in `data` is `NaN` or infinite, a `NonFiniteData` error is returned. Otherwise, the created empirical distribution 
+    /// instance is returned.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    /// 
+    /// println!("{}", dist.data() == data); // prints "true", since `data` is sorted
+    /// ```
+    /// 
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 3.0, 1.0 / 0.0, f64::NAN];
+    /// match EmpiricalDist::new(&data) {
+    ///     Ok(_) => println!("how'd that happen?"),
+    ///     Err(_) => println!("that looks right"),
+    /// } // prints "that looks right"
+    /// ```
+    pub fn new(dataset: &Array<f64, Ix1>) -> Result<EmpiricalDist, PensivError> {
+        EmpiricalDist::new_in(dataset)
+    }
+}
+
+impl<F: Float> DiscreteDist<F, F> for EmpiricalDist<F> {
+    /// Returns the empirical PMF of `value`.
+    /// 
+    /// The empirical PMF of a value is equivalent to the fraction of the data set that it occupies, i.e. the number of 
+    /// occurrences of `value` in the data set divided by the number of element in the data set. For values that are not 
+    /// present in the data set, the empirical PMF returned is `0.0`.
+    /// 
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    /// 
+    /// println!("{}", dist.pmf(1.0)); // prints "0.25"
+    /// println!("{}", dist.pmf(2.0)); // prints "0.5"
+    /// println!("{}", dist.pmf(3.0)); // prints "0.25"
+    /// ```
+    fn pmf(&self, value: F) -> F {
+        match ComparableFloat::new(value) {
+            Ok(f) => {
+                if self.counts.contains_key(&f) {
+                    cast(self.counts[&f] as f64 / self.data_len as f64)
+                }
+                else {
+                    F::zero()
+                }
+            },
+            Err(_) => F::zero(),
+        }
+    }
+
+    /// Returns the empirical CDF of `value`.
+    /// 
+    /// The empirical CDF of a value is equivalent to the sum of the empirical PMFs of all values in the data set that are 
+    /// less than or equal to `value`.
+    /// 
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    ///
+    /// println!("{}", dist.cdf(1.0)); // prints "0.25"
+    /// println!("{}", dist.cdf(2.0)); // prints "0.75"
+    /// println!("{}", dist.cdf(3.0)); // prints "1.0"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        cast(self.count_at_most(value) as f64 / self.data_len as f64)
+    }
+
+    /// Returns the probability that a randomly chosen element of the data set will fall between `lower_bound` and `upper_bound`, inclusive.
+    /// 
+    /// This is equivalent to the sum of the PMFs of the elements of the data set that are in the interval `lower_bound <= element <= upper_bound`.
+    /// 
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    ///
+    /// println!("{}", dist.interval_cdf(1.0, 2.0)); // prints "0.75"
+    /// println!("{}", dist.interval_cdf(2.0, 4.0)); // prints "0.75"
+    /// ```
+    fn interval_cdf(&self, lower_bound: F, upper_bound: F) -> F {
+        let count = self.count_at_most(upper_bound).saturating_sub(self.count_below(lower_bound));
+        cast(count as f64 / self.data_len as f64)
+    }
+
+    /// Returns the mean of the empirical distribution.
+    /// 
+    /// The mean of an empirical distribution is equivalent to the mean of the data set.
+    fn mean(&self) -> F {
+        self.mean
+    }
+
+    /// Returns the variance of the empirical distribution.
+    /// 
+    /// The variance of an empirical distribution is equivalent to the population variance of the data set (see 
+    /// `describe::variance()`). It's maintained from the sum of squared deviations from the mean rather than from the mean 
+    /// of the squared data, so that it stays accurate when the mean is large relative to the spread.
+    fn variance(&self) -> F {
+        if self.data_len == 0 {
+            return F::zero();
+        }
+
+        self.sum_squared_deviations / cast(self.data_len as f64)
+    }
+
+    /// Draws a random element of the data set, with each element equally likely to be drawn; this is the resampling step
+    /// used by bootstrapping.
+    ///
+    /// If the data set is empty, `NaN` is returned.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let resampled = dist.sample_n(&mut rng, data.len());
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        if self.data_len == 0 {
+            return F::nan();
+        }
+
+        self.order_statistic(rng.gen_range(0, self.data_len))
+    }
+
+    /// Returns the quantile of `p`, i.e. the smallest element of the data set whose empirical CDF is at least `p`.
+    /// 
+    /// This is the inverse of the empirical CDF, so the quantile is always an element of the data set; see `quantile()` 
+    /// for an interpolated estimate. Returns `None` if `p` isn't a valid probability or the data set is empty.
+    /// 
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.0, 3.0];
+    /// let dist = EmpiricalDist::new(&data).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.25)); // prints "Some(1.0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(2.0)"
+    /// println!("{:?}", dist.ppf(0.9)); // prints "Some(3.0)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<F> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        let n: F = cast(self.data_len as f64);
+        self.cumulative_counts.first_reaching(|count| quantile_reached(cast::<F>(count as f64) / n, p))
+    }
+
+    /// Returns the mode of the data set, i.e. its most frequent value (the smallest such value, if there are several), or 
+    /// `None` if the data set is empty.
+    fn mode(&self) -> Option<F> {
+        self.counts.iter()
+            .fold(None, |best: Option<(F, i32)>, (value, count)| match best {
+                Some((_, max)) if max >= *count => best,
+                _ => Some((value.value(), *count)),
+            })
+            .map(|(value, _)| value)
+    }
+
+    /// Returns the skewness of the data set, i.e. its third standardized moment, or `NaN` if it's empty or has no spread.
+    fn skewness(&self) -> F {
+        cast(describe::skewness(&self.data().mapv(to_f64)).unwrap_or(f64::NAN))
+    }
+
+    /// Returns the excess kurtosis of the data set, i.e. its fourth standardized moment minus `3`, or `NaN` if it's empty 
+    /// or has no spread.
+    fn kurtosis(&self) -> F {
+        cast(describe::kurtosis(&self.data().mapv(to_f64)).unwrap_or(f64::NAN))
+    }
+
+    /// Returns the entropy of the empirical distribution, i.e. `-sum(p ln(p))` over the fraction `p` of the data set 
+    /// occupied by each distinct value, or `NaN` if the data set is empty.
+    fn entropy(&self) -> F {
+        if self.data_len == 0 {
+            return F::nan();
+        }
+
+        cast(-self.counts.values().map(|count| x_ln_x(*count as f64 / self.data_len as f64)).sum::<f64>())
+    }
+
+    /// Returns the MGF of `t`, i.e. the mean of `e^(tx)` over the data set, or `None` if the data set is empty or the 
+    /// mean overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let sum = self.counts.iter().fold(F::zero(), |sum, (x, n)| sum + cast::<F>(*n as f64) * (t * x.value()).exp());
+        let value = sum / cast(self.data_len as f64);
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, i.e. the mean of `e^(itx)` over the data set (or `NaN` if the data set 
+    /// is empty).
+    fn cf(&self, t: F) -> Complex<F> {
+        let sum = self.counts.iter().fold(Complex::new(F::zero(), F::zero()), |sum, (x, n)| {
+            sum + Complex::new((t * x.value()).cos(), (t * x.value()).sin()) * cast::<F>(*n as f64)
+        });
+        sum / cast::<F>(self.data_len as f64)
+    }
+}
+
+
+/// Base trait for all continuous distributions.
+/// 
+/// `ContinuousDist` provides a general interface for implementing the functionality of continuous distributions; it differs 
+/// from `DiscreteDist` only in its requirement of the `pdf()` function in place of `pmf()`, although this difference 
+/// underscores a larger conceptual difference between the two traits.
+/// 
+/// `ContinuousDist` is parameterized by a single float type `N` conforming to the `Float` trait from `num-traits`, 
+/// which (unlike the two type parameters of `DiscreteDist`) is the type of both the values in its support and the 
+/// probabilities, densities & moments it returns; e.g. a `NormalDist<f32>` is a `ContinuousDist<f32>`, which works in 
+/// single precision throughout. The default implementations that integrate or solve numerically do so in double 
+/// precision, and round the result to `N`.
+pub trait ContinuousDist<N: Float> {
+    /// Returns the probability density function (PDF) of `value` according to the distribution. Convention is to return `0.0` for 
+    /// values outside the distribution's support.
+    fn pdf(&self, value: N) -> N;
+
+    /// Returns the natural log of the PDF of `value`. Convention is to return `-inf` for values outside the distribution's 
+    /// support.
+    ///
+    /// As with `DiscreteDist::log_pmf()`, the default implementation takes the log of `pdf()`, and distributions override 
+    /// it to compute the log directly.
+    fn log_pdf(&self, value: N) -> N {
+        self.pdf(value).ln()
+    }
+
+    /// Returns the log-likelihood of `data` under the distribution, i.e. the sum of the log PDF of each value in `data`.
+    ///
+    /// If any value in `data` is outside the distribution's support, `-inf` is returned.
+    fn log_likelihood(&self, data: &Array<N, Ix1>) -> N {
+        data.iter().fold(N::zero(), |sum, value| sum + self.log_pdf(*value))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of `value` according to the distribution.
+    ///
+    /// The default implementation integrates `pdf()` numerically up to `value`, split at several multiples of the 
+    /// standard deviation either side of the mean (or of `1` either side of `0`, if they aren't finite) so that the 
+    /// scale & location of the distribution are taken into account. It's accurate to about 12 significant digits for 
+    /// smooth densities, but each call takes many evaluations of `pdf()`, so distributions whose CDF has a closed form 
+    /// (or a special function) should override it. Returns `NaN` if `value` or the PDF is `NaN`.
+    ///
+    /// ```ignore
+    /// struct Laplace; // centered at 0, implementing only pdf(), mean(), variance() & sample()
+    ///
+    /// println!("{}", Laplace.cdf(1.0)); // prints approximately "0.8161", i.e. 1 - e^(-1) / 2
+    /// ```
+    fn cdf(&self, value: N) -> N {
+        let x = to_f64(value);
+        if x.is_nan() {
+            return N::nan();
+        }
+
+        let (center, scale) = match (to_f64(self.mean()), to_f64(self.std())) {
+            (mean, std) if mean.is_finite() && std.is_finite() && std > 0.0 => (mean, std),
+            _ => (0.0, 1.0),
+        };
+
+        let mut breakpoints = vec![f64::NEG_INFINITY];
+        breakpoints.extend(CDF_BREAKPOINT_DEVIATIONS.iter().map(|k| center + k * scale).filter(|t| *t < x));
+        // so that a tail far below the center is still integrated on the scale of the distribution
+        if breakpoints.len() == 1 && x.is_finite() {
+            breakpoints.push(x - scale);
+        }
+        breakpoints.push(x);
+
+        cast(integrate_piecewise(|t| density_at(self, t), &breakpoints).clamp(0.0, 1.0))
+    }
+
+    /// Returns the probability that a value in the distribution will fall between `lower_bound` and `upper_bound`.
+    /// 
+    /// The default implementation simply finds the difference in the CDFs of the bounds.
+    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> N {
+        self.cdf(upper_bound) - self.cdf(lower_bound)
+    }
+
+    /// Returns the mean (expectation) of the distribution.
+    fn mean(&self) -> N;
+
+    /// Returns the variance (average square distance from the mean) of the distribution.
+    fn variance(&self) -> N;
+
+    /// Returns the standard deviation of the distribution.
+    /// 
+    /// The default implementation returns the square root of the variance.
+    fn std(&self) -> N {
+        self.variance().sqrt()
+    }
+
+    /// Draws a single random value from the distribution, using `rng` as the source of randomness.
+    /// 
+    /// As with `DiscreteDist::sample()`, seeding `rng` makes the drawn values reproducible.
+    fn sample(&self, rng: &mut dyn RngCore) -> N;
+
+    /// Draws `n` independent random values from the distribution and returns them as a 1-dimensional array.
+    /// 
+    /// The default implementation simply calls `sample()` `n` times.
+    fn sample_n(&self, rng: &mut dyn RngCore, n: usize) -> Array<N, Ix1> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+
+    /// Returns the quantile function (inverse CDF) of `p`, i.e. the value `x` for which `cdf(x) = p`. For `p = 0` and 
+    /// `p = 1`, the bounds of the support are returned, which may be infinite.
+    /// 
+    /// Returns `None` if `p` isn't a valid probability.
+    /// 
+    /// The default implementation is a generic numeric fallback: it brackets the quantile with 
+    /// `numeric::bracket_root()`, searching outward from `[-1, 1]`, then finds it with `numeric::brent_root()` to 
+    /// floating-point resolution using `cdf()`. If the bracket overflows, the quantile is taken to be infinite. 
+    /// Distributions with a closed-form or otherwise more accurate inverse CDF should override it.
+    fn ppf(&self, p: N) -> Option<N> {
+        if !(p >= N::zero() && p <= N::one()) {
+            return None;
+        }
+
+        let p = to_f64(p);
+
+        // positive exactly where the quantile has been reached & never 0, so that the root found is the smallest value 
+        // that reaches it, even where the CDF is flat (e.g. at the bounds of the support for p = 0 or 1)
+        let excess = |x: f64| {
+            let cdf = N::from(x).map_or(f64::NAN, |value| to_f64(self.cdf(value)));
+
+            if quantile_reached(cdf, p) { (cdf - p).max(f64::MIN_POSITIVE) } else { (cdf - p).min(-f64::MIN_POSITIVE) }
+        };
+
+        match numeric::bracket_root(excess, -1.0, 1.0) {
+            Ok((lower, upper)) => N::from(numeric::brent_root(excess, lower, upper).ok()?),
+            Err(numeric::NumericError::NoSignChange) => {
+                N::from(if excess(0.0) > 0.0 { f64::NEG_INFINITY } else { f64::INFINITY })
+            },
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the median of the distribution, i.e. the quantile of `1/2`, or `None` if it can't be found.
+    ///
+    /// The default implementation returns `ppf(0.5)`.
+    fn median(&self) -> Option<N> {
+        self.ppf(cast(0.5))
+    }
+
+    /// Returns the mode of the distribution, i.e. the value at which the PDF is largest (the smallest such value, if 
+    /// there are several), or `None` if it can't be found.
+    ///
+    /// The default implementation evaluates the PDF over a grid of quantiles, then refines the best of them by 
+    /// golden-section search between its neighbors in the grid. It finds the global maximum as long as the PDF doesn't 
+    /// have another, narrower peak between two adjacent grid points.
+    fn mode(&self) -> Option<N> {
+        let mut grid = (0..=MODE_GRID_POINTS)
+            .filter_map(|i| self.ppf(cast(i as f64 / MODE_GRID_POINTS as f64))?.to_f64())
+            .filter(|x| x.is_finite())
+            .collect::<Vec<f64>>();
+        grid.dedup();
+
+        let densities = grid.iter().map(|x| density_at(self, *x)).collect::<Vec<f64>>();
+        let best = (0..grid.len()).fold(None, |best: Option<usize>, i| match best {
+            Some(j) if densities[j] >= densities[i] => best,
+            _ => Some(i),
+        })?;
+
+        let ratio = (5_f64.sqrt() - 1.0) / 2.0;
+        let mut a = grid[best.saturating_sub(1)];
+        let mut b = grid[(best + 1).min(grid.len() - 1)];
+
+        for _ in 0..MODE_REFINEMENTS {
+            let c = b - ratio * (b - a);
+            let d = a + ratio * (b - a);
+
+            if density_at(self, c) >= density_at(self, d) {
+                b = d;
+            }
+            else {
+                a = c;
+            }
+        }
+
+        // the grid point itself is kept if the PDF peaks at the edge of the support
+        let refined = (a + b) / 2.0;
+        N::from(if density_at(self, refined) > densities[best] { refined } else { grid[best] })
+    }
+
+    /// Returns the skewness of the distribution, i.e. its third standardized moment `E[(X - mean)^3] / std^3`.
+    ///
+    /// Returns `NaN` if the skewness is undefined, e.g. if the variance is `0` or infinite. The default implementation 
+    /// integrates numerically over the support, split at several of its quantiles.
+    fn skewness(&self) -> N {
+        cast(standardized_moment(to_f64(self.mean()), to_f64(self.std()), 3, |f| continuous_expectation(self, f)))
+    }
+
+    /// Returns the excess kurtosis of the distribution, i.e. its fourth standardized moment `E[(X - mean)^4] / std^4` 
+    /// minus `3`, which is `0` for a normal distribution.
+    ///
+    /// Returns `NaN` if the kurtosis is undefined, e.g. if the variance is `0` or infinite. The default implementation 
+    /// integrates numerically like that of `skewness()`.
+    fn kurtosis(&self) -> N {
+        cast(standardized_moment(to_f64(self.mean()), to_f64(self.std()), 4, |f| continuous_expectation(self, f)) - 3.0)
+    }
+
+    /// Returns the differential entropy of the distribution in nats, i.e. `-E[ln(pdf(X))]`.
+    ///
+    /// The default implementation integrates numerically like that of `skewness()`.
+    fn entropy(&self) -> N {
+        cast(-integrate_over(self, |x| {
+            let density = density_at(self, x);
+            if density > 0.0 { density * density.ln() } else { 0.0 }
+        }))
+    }
+
+    /// Returns the moment-generating function (MGF) of `t`, i.e. `E[e^(tX)]`, or `None` if it doesn't exist at `t`.
+    ///
+    /// The default implementation integrates numerically like that of `skewness()`, so it only detects that the MGF 
+    /// doesn't exist if the integral overflows; distributions whose MGF is known override it.
+    fn mgf(&self, t: N) -> Option<N> {
+        let t = to_f64(t);
+        let value: N = cast(continuous_expectation(self, |x| (t * x).exp()));
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, i.e. `E[e^(itX)]`, which (unlike the MGF) exists for every distribution.
+    ///
+    /// The default implementation integrates numerically like that of `skewness()`; the integrand oscillates for large 
+    /// `|t|`, so it's less accurate there.
+    ///
+    /// ```ignore
+    /// let dist = NormalDist::std();
+    /// println!("{}", dist.cf(1.0)); // prints approximately "0.6065+0i"
+    /// ```
+    fn cf(&self, t: N) -> Complex<N> {
+        let t = to_f64(t);
+        let re = continuous_expectation(self, |x| (t * x).cos());
+        let im = continuous_expectation(self, |x| (t * x).sin());
+
+        Complex::new(cast(re), cast(im))
+    }
+}
+
+
+/// A continuous uniform distribution.
+/// 
+/// The continuous uniform distribution is the continuous adaptation of the discrete uniform distribution. Given a lower and 
+/// upper bound of its support, the probabilitu of any real number inside the support is uniform. Any value outside the 
+/// support has probability `0`.
+#[derive(Debug, PartialEq)]
+pub struct ContinuousUniformDist<F: Float = f64> {
+    lower_bound: F,
+    upper_bound: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(ContinuousUniformDist<F: Float>, |dist| {
+    lower_bound: F = &dist.lower_bound,
+    upper_bound: F = &dist.upper_bound,
+} => ContinuousUniformDist::new_in(lower_bound, upper_bound));
+
+impl<F: Float> ContinuousUniformDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(lower_bound: F, upper_bound: F) -> Result<ContinuousUniformDist<F>, PensivError> {
+        if !lower_bound.is_finite() || !upper_bound.is_finite() || lower_bound > upper_bound {
+            return Err(PensivError::InvalidBounds { lower: to_f64(lower_bound), upper: to_f64(upper_bound) });
+        }
+
+        Ok(ContinuousUniformDist { lower_bound, upper_bound })
+    }
+
+    /// Returns the lower bound of the support of the distribution.
+    pub fn lower_bound(&self) -> F {
+        self.lower_bound
+    }
+
+    /// Returns the upper bound of the support of the distribution.
+    pub fn upper_bound(&self) -> F {
+        self.upper_bound
+    }
+
+    /// Returns the size of the interval of the distribution's values.
+    /// 
+    /// This is equivalent to the difference between the bounds of the support.
+    pub fn range(&self) -> F {
+        self.upper_bound - self.lower_bound
+    }
+}
+
+impl ContinuousUniformDist {
+    /// Creates and returns a new continuous uniform distribution from `lower_bound` to `upper_bound`.
+    /// 
+    /// Returns an `InvalidBounds` error if either bound isn't finite or `lower_bound > upper_bound`, otherwise returns the 
+    /// created distribution.
+    /// 
+    /// ```ignore
+    /// let a = 1.0;
+    /// let b = 2.5;
+    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
+    /// 
+    /// println!("{}", dist.lower_bound()); // prints "1.0"
+    /// println!("{}", dist.upper_bound()); // prints "2.5"
+    /// ```
+    ///
+    /// ```ignore
+    /// let a = 1.0;
+    /// let b = 2.5;
+    /// let dist = ContinuousUniformDist::new(b, a);
+    /// 
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(lower_bound: f64, upper_bound: f64) -> Result<ContinuousUniformDist, PensivError> {
+        ContinuousUniformDist::new_in(lower_bound, upper_bound)
+    }
+
+    /// Fits a continuous uniform distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood bounds are the minimum and maximum of the data. Since the likelihood isn't smooth in the 
+    /// bounds, their standard errors are the standard deviations of the sample minimum and maximum, i.e. 
+    /// `range * sqrt(n) / ((n + 1) * sqrt(n + 2))`, rather than being derived from the Fisher information.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidBounds` error if all of its values are equal.
+    ///
+    /// ```ignore
+    /// let data = array![1.5, 2.0, 1.0, 2.5];
+    /// let fit = ContinuousUniformDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().lower_bound(), fit.dist().upper_bound()); // prints "1 2.5"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<ContinuousUniformDist>, PensivError> {
+        fit_mean(data)?;
+
+        let lower_bound = data.fold(f64::INFINITY, |a, &b| a.min(b));
+        let upper_bound = data.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        let std_error = uniform_bound_std_error(upper_bound - lower_bound, data.len());
+
+        let dist = ContinuousUniformDist::new(lower_bound, upper_bound)?;
+        Ok(Fit { dist, std_errors: array![std_error, std_error] })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for ContinuousUniformDist<F> {
+    /// Returns the continuous uniform PDF of `value`.
+    /// 
+    /// If `value` is in the support of the distribution, then `1 / range` is returned, where `range` is the range of the 
+    /// distribution. Otherwise, `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let a = 1.0;
+    /// let b = 2.5;
+    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.6667"
+    /// println!("{}", dist.pdf(1.5)); // prints approximately "0.6667"
+    /// println!("{}", dist.pdf(2.0)); // prints approximately "0.6667"
+    /// println!("{}", dist.pdf(2.5)); // prints approximately "0.6667"
+    /// 
+    /// println!("{}", dist.pdf(0.0)); // prints "0.0"
+    /// println!("{}", dist.pdf(3.0)); // prints "0.0"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        if value >= self.lower_bound && value <= self.upper_bound {
+            return F::one() / self.range();
+        }
+        else {
+            return F::zero();
+        }
+    }
+
+    /// Returns the continuous uniform CDF of `value`.
+    /// 
+    /// This is equivalent to the fraction of the support that `value` is greater than.
+    /// 
+    /// ```ignore
+    /// let a = 1.0;
+    /// let b = 2.5;
+    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.0"
+    /// println!("{}", dist.cdf(1.5)); // prints approximately "0.3333"
+    /// println!("{}", dist.cdf(2.0)); // prints approximately "0.6667"
+    /// println!("{}", dist.cdf(2.5)); // prints approximately "1.0"
+    /// 
+    /// println!("{}", dist.cdf(0.0)); // prints "0.0"
+    /// println!("{}", dist.cdf(3.0)); // prints "1.0"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value >= self.lower_bound && value <= self.upper_bound {
+            return (value - self.lower_bound) / self.range();
+        }
+        else if value < self.lower_bound {
+            return F::zero();
+        }
+        else {
+            return F::one();
+        }
+    }
+
+    /// Returns the probability that the continuous uniform random variable falls between `lower_bound` and `upper_bound`.
+    /// 
+    /// This is equivalent to the fraction of the support that the interval occupies.
+    /// 
+    /// ```ignore
+    /// let a = 1.0;
+    /// let b = 2.5;
+    /// let dist = ContinuousUniformDist::new(a, b).unwrap();
+    /// 
+    /// println!("{}", dist.interval_cdf(1.5, 2.0)); // prints approximately "0.3333"
+    /// ```
+    fn interval_cdf(&self, lower_bound: F, upper_bound: F) -> F {
+        let upper = if upper_bound < self.upper_bound { upper_bound } else { self.upper_bound };
+        let lower = if lower_bound > self.lower_bound { lower_bound } else { self.lower_bound };
+
+        (upper - lower) / self.range()
+    }
+
+    /// Returns the mean of the uniform distribution.
+    /// 
+    /// This is equivalent to the average of the support's bounds.
+    fn mean(&self) -> F {
+        (self.lower_bound + self.upper_bound) / cast(2.0)
+    }
+
+    /// Returns the variance of the uniform distribution.
+    /// 
+    /// This is equivalent to: `(upper bound - lower bound)^2 / 12`.
+    fn variance(&self) -> F {
+        (self.upper_bound - self.lower_bound).powi(2) / cast(12.0)
+    }
+
+    /// Returns the variance of the uniform distribution.
+    /// 
+    /// This is equivalent to the square root of the variance, or `(upper bound - lower bound) / sqrt(12)`.
+    fn std(&self) -> F {
+        (self.upper_bound - self.lower_bound) / cast::<F>(12.0).sqrt()
+    }
+
+    /// Draws a random value from the distribution by scaling a uniform value in `[0, 1)` onto the support.
+    ///
+    /// ```ignore
+    /// let dist = ContinuousUniformDist::new(1.0, 2.5).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let x = dist.sample(&mut rng); // somewhere in [1.0, 2.5)
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        self.lower_bound + cast::<F>(rng.gen::<f64>()) * self.range()
+    }
+
+    /// Returns the quantile of `p`, i.e. `lower bound + p * range`.
+    ///
+    /// ```ignore
+    /// let dist = ContinuousUniformDist::new(1.0, 2.5).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.0)); // prints "Some(1.0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(1.75)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "Some(2.5)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<F> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        Some(self.lower_bound + p * self.range())
+    }
+
+    /// Returns the median of the distribution, i.e. the midpoint of the support.
+    fn median(&self) -> Option<F> {
+        Some((self.lower_bound + self.upper_bound) / cast(2.0))
+    }
+
+    /// Returns the mode of the distribution; every value in the support is equally likely, so the lower bound is returned.
+    fn mode(&self) -> Option<F> {
+        Some(self.lower_bound)
+    }
+
+    /// Returns the skewness of the distribution, which is `0` by symmetry (or `NaN` if the support is a single point).
+    fn skewness(&self) -> F {
+        if self.range() > F::zero() { F::zero() } else { F::nan() }
+    }
+
+    /// Returns the excess kurtosis of the distribution, which is `-6/5` (or `NaN` if the support is a single point).
+    fn kurtosis(&self) -> F {
+        if self.range() > F::zero() { cast(-1.2) } else { F::nan() }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `ln(upper bound - lower bound)`.
+    fn entropy(&self) -> F {
+        self.range().ln()
+    }
+
+    /// Returns the MGF of `t`, equivalent to `(e^(tb) - e^(ta)) / (t(b - a))` where `a` & `b` are the bounds of the support, 
+    /// or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let spread = t * self.range();
+        if spread == F::zero() {
+            return Some((t * self.lower_bound).exp());
+        }
+
+        let value = (t * self.lower_bound).exp() * spread.exp_m1() / spread;
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `(e^(itb) - e^(ita)) / (it(b - a))` where `a` & `b` are 
+    /// the bounds of the support.
+    fn cf(&self, t: F) -> Complex<F> {
+        let spread = t * self.range();
+        let start = Complex::new((t * self.lower_bound).cos(), (t * self.lower_bound).sin());
+
+        if spread == F::zero() {
+            return start;
+        }
+
+        // (e^(is) - 1) / (is) = (sin(s) + i(1 - cos(s))) / s, with 1 - cos(s) = 2 sin^2(s / 2) to avoid cancellation
+        let two = cast::<F>(2.0);
+        start * Complex::new(spread.sin(), two * (spread / two).sin().powi(2)) / spread
+    }
+}
+
+
+/// An exponential distribution.
+/// 
+/// The exponential distribution is parameterized by a rate parameter, which dictates the "stretch/shrink" of the distribution's PDF. 
+/// The support of the exponential distribution is the set of non-negative real numbers.
+#[derive(Debug, PartialEq)]
+pub struct ExponentialDist<F: Float = f64> {
+    rate_param: F
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(ExponentialDist<F: Float>, |dist| {
+    rate_param: F = &dist.rate_param,
+} => ExponentialDist::new_in(rate_param));
+
+impl<F: Float> ExponentialDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(rate_param: F) -> Result<ExponentialDist<F>, PensivError> {
+        check_positive("rate_param", rate_param)?;
+        Ok(ExponentialDist { rate_param })
+    }
+
+    /// Returns the rate parameter of the distribution.
+    pub fn rate_param(&self) -> F {
+        self.rate_param
+    }
+}
+
+impl ExponentialDist {
+    /// Creates and returns a new exponential distribution.
+    /// 
+    /// Because the rate parameter must be positive, the function returns an `InvalidParameter` error if `rate_param` 
+    /// isn't a positive, finite number. Otherwise, the distribution is returned.
+    /// 
+    /// ```ignore
+    /// let r = 0.5;
+    /// let dist = ExponentialDist::new(r).unwrap();
+    /// 
+    /// println!("{}", dist.rate_param()); // prints "0.5"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let r = -0.5;
+    /// let dist = ExponentialDist::new(r);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(rate_param: f64) -> Result<ExponentialDist, PensivError> {
+        ExponentialDist::new_in(rate_param)
+    }
+
+    /// Fits an exponential distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimate of the rate is the inverse of the mean of the data, and its standard error is 
+    /// `rate / sqrt(n)`. Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that 
+    /// aren't finite, or an `InvalidInput` error if it contains negative values or is entirely `0`.
+    ///
+    /// ```ignore
+    /// let data = array![0.5, 2.5, 1.0, 2.0];
+    /// let fit = ExponentialDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().rate_param()); // prints "0.6666666666666666"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<ExponentialDist>, PensivError> {
+        let mean = fit_mean(data)?;
+
+        if data.iter().any(|&x| x < 0.0) {
+            return Err(PensivError::InvalidInput("data must be non-negative"));
+        }
+        else if mean == 0.0 {
+            return Err(PensivError::InvalidInput("data must not all be 0"));
+        }
+
+        let rate_param = 1.0 / mean;
+        let std_error = rate_param / (data.len() as f64).sqrt();
+
+        Ok(Fit { dist: ExponentialDist::new(rate_param)?, std_errors: array![std_error] })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for ExponentialDist<F> {
+    /// Returns the exponential PDF of `value`.
+    /// 
+    /// When `value` is outside the support (i.e. negative), `0.0` is returned. Otherwise, the correct PDF is returned.
+    /// 
+    /// ```ignore
+    /// let r = 0.5;
+    /// let dist = ExponentialDist::new(r).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(0.0)); // prints "0.0"
+    /// println!("{}", dist.pdf(2.5)); // prints approximately "0.1432"
+    /// println!("{}", dist.pdf(5.0)); // prints approximately "0.041"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        if value < F::zero() { 
+            return F::zero();
+        }
+
+        self.rate_param * (-self.rate_param * value).exp()
+    }
+
+    /// Returns the natural log of the exponential PDF of `value`, i.e. `ln(rate) - rate * value`, or `-inf` for negative 
+    /// values.
+    fn log_pdf(&self, value: F) -> F {
+        if value < F::zero() {
+            return F::neg_infinity();
+        }
+
+        self.rate_param.ln() - self.rate_param * value
+    }
+
+    /// Returns the exponential CDF of `value`.
+    /// 
+    /// When `value` is outside the support (i.e. negative), `0.0` is returned. Otherwise, the correct PDF is returned.
+    /// 
+    /// ```ignore
+    /// let r = 0.5;
+    /// let dist = ExponentialDist::new(r).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(0.0)); // prints "0.0"
+    /// println!("{}", dist.cdf(2.5)); // prints approximately "0.7135"
+    /// println!("{}", dist.cdf(5.0)); // prints approximately "0.9179"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value < F::zero() { 
+            return F::zero();
+        }
+
+        F::one() - (-self.rate_param * value).exp()
+    }
+
+    /// Returns the mean of the distribution.
+    /// 
+    /// This is equivalent to the inverse of the rate parameter of the distribution.
+    fn mean(&self) -> F {
+        F::one() / self.rate_param
+    }
+
+    /// Returns the variance of the distribution.
+    /// 
+    /// This is equivalent to the inverse of the squared rate parameter of the distribution.
+    fn variance(&self) -> F {
+        F::one() / self.rate_param.powi(2)
+    }
+
+    /// Returns the standard of the distribution.
+    /// 
+    /// This is equivalent to the inverse of the rate parameter of the distribution (which, coincidentally, is also the mean).
+    fn std(&self) -> F {
+        F::one() / self.rate_param
+    }
+
+    /// Draws a random value from the distribution.
+    ///
+    /// Values are drawn by inverting the CDF, i.e. as `-ln(1 - u) / rate` for a uniform `u` in `[0, 1)`.
+    ///
+    /// ```ignore
+    /// let dist = ExponentialDist::new(0.5).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let wait = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        let u = cast::<F>(rng.gen::<f64>());
+
+        -(-u).ln_1p() / self.rate_param
+    }
+
+    /// Returns the quantile of `p`, i.e. `-ln(1 - p) / rate`.
+    ///
+    /// ```ignore
+    /// let dist = ExponentialDist::new(0.5).unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.0)); // prints "Some(0.0)"
+    /// println!("{:?}", dist.ppf(0.5)); // prints approximately "Some(1.3863)"
+    /// println!("{:?}", dist.ppf(1.0)); // prints "Some(inf)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<F> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        Some(-(-p).ln_1p() / self.rate_param)
+    }
+
+    /// Returns the median of the distribution, equivalent to `ln(2) / rate`.
+    fn median(&self) -> Option<F> {
+        Some(cast::<F>(2.0).ln() / self.rate_param)
+    }
+
+    /// Returns the mode of the distribution, which is `0`.
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    /// Returns the skewness of the distribution, which is always `2`.
+    fn skewness(&self) -> F {
+        cast::<F>(2.0)
+    }
+
+    /// Returns the excess kurtosis of the distribution, which is always `6`.
+    fn kurtosis(&self) -> F {
+        cast::<F>(6.0)
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `1 - ln(rate)`.
+    fn entropy(&self) -> F {
+        F::one() - self.rate_param.ln()
+    }
+
+    /// Returns the MGF of `t`, equivalent to `rate / (rate - t)`, which exists for `t < rate`.
+    fn mgf(&self, t: F) -> Option<F> {
+        if t < self.rate_param { Some(self.rate_param / (self.rate_param - t)) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `rate / (rate - it)`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::new(self.rate_param, -t).inv() * self.rate_param
+    }
+}
+
+
+/// A normal (Gaussian) distribution.
+/// 
+/// The normal distribution is parameterized by a location and a scale (both real numbers). Those familiar with the normal 
+/// distribution may recognize these parameters as the distribution's mean and standard deviation; `NormalDist` names its 
+/// parameters to reflect their conceptual purpose rather than their relevance to the properties of the distribution.
+/// 
+/// The support of the normal distribution is all real numbers.
+#[derive(Debug, PartialEq)]
+pub struct NormalDist<F: Float = f64> {
+    loc: F,
+    scale: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(NormalDist<F: Float>, |dist| {
+    loc: F = &dist.loc,
+    scale: F = &dist.scale,
+} => NormalDist::new_in(loc, scale));
+
+impl<F: Float> NormalDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(loc: F, scale: F) -> Result<NormalDist<F>, PensivError> {
+        if !loc.is_finite() {
+            return Err(PensivError::InvalidParameter { name: "loc", value: to_f64(loc), expected: "a finite number" });
+        }
+
+        check_positive("scale", scale)?;
+        Ok(NormalDist { loc, scale })
+    }
+
+    /// Like `std()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn std_in() -> NormalDist<F> {
+        NormalDist { loc: F::zero(), scale: F::one() }
+    }
+
+    /// Returns the location of the normal distribution.
+    pub fn loc(&self) -> F {
+        self.loc
+    }
+
+    /// Returns the scale of the normal distribution.
+    pub fn scale(&self) -> F {
+        self.scale
+    }
+
+    /// Returns the Z-value of `value` within the distribution.
+    /// 
+    /// The Z-value is obtained by finding the distance from `value` to the mean of the distribution in units of its standard 
+    /// deviation.
+    /// 
+    /// ```ignore
+    /// let loc = 5.0;
+    /// let scale = 2.0;
+    /// let dist = NormalDist::new(loc, scale).unwrap();
+    /// 
+    /// println!("{}", dist.z(7.0)); // prints "1.0"
+    /// println!("{}", dist.z(3.0)); // prints "-1.0"
+    /// ```
+    pub fn z(&self, value: F) -> F {
+        (value - self.loc) / self.scale
+    }
+}
+
+impl NormalDist {
+    /// Creates and returns a new normal distribution.
+    /// 
+    /// If `loc` isn't finite, or `scale` isn't a positive, finite number (so a scale of `0` or `NaN` is rejected), an 
+    /// `InvalidParameter` error is returned; otherwise, the created distribution with parameters `loc` and `scale` is 
+    /// returned.
+    /// 
+    /// ```ignore
+    /// let loc = 5.0;
+    /// let scale = 2.0;
+    /// let dist = NormalDist::new(loc, scale).unwrap();
+    /// 
+    /// println!("{}", dist.loc()); // prints "5.0"
+    /// println!("{}", dist.scale()); // prints "2.0"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let loc = 5.0;
+    /// let scale = -2.0;
+    /// let dist = NormalDist::new(loc, scale);
+    /// 
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(loc: f64, scale: f64) -> Result<NormalDist, PensivError> {
+        NormalDist::new_in(loc, scale)
+    }
+
+    /// Creates and returns a new standard normal distribution.
+    /// 
+    /// The standard normal distribution is a normal distribution with `loc = 0` and `scale = 1`.
+    /// 
+    /// ```ignore
+    /// let dist = NormalDist::std();
+    /// 
+    /// println!("{}", dist.loc()); // prints "0.0"
+    /// println!("{}", dist.scale()); // prints "1.0"
+    /// ```
+    pub fn std() -> NormalDist {
+        NormalDist::std_in()
+    }
+
+    /// Fits a normal distribution to `data` by maximum likelihood.
+    ///
+    /// The maximum-likelihood estimates are the mean of the data and its (biased, i.e. divided by `n`) standard 
+    /// deviation. Their standard errors are `scale / sqrt(n)` and `scale / sqrt(2n)` respectively. Returns an 
+    /// `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidInput` error if it has no spread.
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 3.0, 4.0];
+    /// let fit = NormalDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().loc()); // prints "2.5"
+    /// println!("{}", fit.dist().scale()); // prints approximately "1.118"
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<NormalDist>, PensivError> {
+        let loc = fit_mean(data)?;
+        let scale = (data.mapv(|x| (x - loc).powi(2)).sum() / data.len() as f64).sqrt();
+
+        if scale <= 0.0 {
+            return Err(PensivError::InvalidInput("data must have some spread"));
+        }
+
+        let n = data.len() as f64;
+        let std_errors = array![scale / n.sqrt(), scale / (2.0 * n).sqrt()];
+
+        Ok(Fit { dist: NormalDist::new(loc, scale)?, std_errors })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for NormalDist<F> {
+    /// Returns the normal PDF of `value`.
+    /// 
+    /// ```ignore
+    /// let loc = 5.0;
+    /// let scale = 2.0;
+    /// let dist = NormalDist::new(loc, scale).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.0269"
+    /// println!("{}", dist.pdf(3.0)); // prints approximately "0.1209"
+    /// println!("{}", dist.pdf(5.0)); // prints approximately "0.1995"
+    /// println!("{}", dist.pdf(7.0)); // prints approximately "0.1209"
+    /// println!("{}", dist.pdf(9.0)); // prints approximately "0.0269"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        let t1 = F::one() / (cast::<F>(2.0 * PI).sqrt() * self.scale);
+        let t2 = -(value - self.loc).powi(2) / (cast::<F>(2.0) * self.variance());
+
+        t1 * t2.exp()
+    }
+
+    /// Returns the natural log of the normal PDF of `value`.
+    ///
+    /// Unlike the PDF, which underflows to `0.0` once `value` is about 38 standard deviations from the mean, the log PDF 
+    /// is a quadratic in `value` and is finite everywhere.
+    fn log_pdf(&self, value: F) -> F {
+        let z = (value - self.loc) / self.scale;
+        -cast::<F>(0.5) * z * z - self.scale.ln() - cast::<F>(0.5) * cast::<F>(2.0 * PI).ln()
+    }
+
+    /// Returns the normal CDF of `value`.
+    /// 
+    /// The normal CDF is computed using the complementary error function (see `special::erfc()`), which keeps its full 
+    /// relative precision far into the lower tail, e.g. for p-values well below `1e-10`.
+    /// 
+    /// ```ignore
+    /// let loc = 5.0;
+    /// let scale = 2.0;
+    /// let dist = NormalDist::new(loc, scale).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.025"
+    /// println!("{}", dist.cdf(3.0)); // prints approximately "0.17"
+    /// println!("{}", dist.cdf(5.0)); // prints approximately "0.5"
+    /// println!("{}", dist.cdf(7.0)); // prints approximately "0.83"
+    /// println!("{}", dist.cdf(9.0)); // prints approximately "0.975"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        let z = self.z(value);
+
+        special::erfc(-z / cast::<F>(2.0).sqrt()) / cast(2.0)
+    }
+
+    /// Returns the mean of the distribution, equivalent to the location.
+    fn mean(&self) -> F {
+        self.loc
+    }
+
+    /// Returns the variance of the distribution, equivalent to the scale squared.
+    fn variance(&self) -> F {
+        self.scale.powi(2)
+    }
+
+    /// Returns the standard deviation of the distribution, equivalent to the scale.
+    fn std(&self) -> F {
+        self.scale
+    }
+
+    /// Draws a random value from the distribution.
+    ///
+    /// Values are drawn using the Box-Muller transform, which maps two independent uniform values onto a standard normal
+    /// value that's then shifted and scaled by the distribution's location and scale.
+    ///
+    /// ```ignore
+    /// let dist = NormalDist::new(5.0, 2.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let samples = dist.sample_n(&mut rng, 100);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        self.loc + self.scale * cast::<F>(std_normal_sample(rng))
+    }
+
+    /// Returns the quantile of `p`.
+    ///
+    /// The standard normal quantile is computed using Wichura's algorithm AS 241 (`PPND16`), a set of rational 
+    /// approximations that are accurate to about 1 part in `10^16`, and is then shifted and scaled by the distribution's 
+    /// location and scale. For `p = 0` and `p = 1`, `-inf` and `inf` are returned, respectively.
+    ///
+    /// ```ignore
+    /// let dist = NormalDist::std();
+    ///
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(0.0)"
+    /// println!("{:?}", dist.ppf(0.975)); // prints approximately "Some(1.96)"
+    /// ```
+    fn ppf(&self, p: F) -> Option<F> {
+        if !(F::zero()..=F::one()).contains(&p) {
+            return None;
+        }
+
+        Some(self.loc + self.scale * std_normal_ppf(p))
+    }
+
+    /// Returns the median of the distribution, which is equal to its mean.
+    fn median(&self) -> Option<F> {
+        Some(self.loc)
+    }
+
+    /// Returns the mode of the distribution, which is equal to its mean.
+    fn mode(&self) -> Option<F> {
+        Some(self.loc)
+    }
+
+    /// Returns the skewness of the distribution, which is `0` by symmetry.
+    fn skewness(&self) -> F {
+        F::zero()
+    }
+
+    /// Returns the excess kurtosis of the distribution, which is `0` by definition.
+    fn kurtosis(&self) -> F {
+        F::zero()
+    }
+
+    /// Returns the entropy of the distribution, equivalent to `ln(2 pi e scale^2) / 2`.
+    fn entropy(&self) -> F {
+        (cast::<F>(2.0 * PI * E) * self.scale * self.scale).ln() / cast(2.0)
+    }
+
+    /// Returns the MGF of `t`, equivalent to `e^(loc t + scale^2 t^2 / 2)`, or `None` if it overflows.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = (self.loc * t + (self.scale * t).powi(2) / cast(2.0)).exp();
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `e^(i loc t - scale^2 t^2 / 2)`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::from_polar(&(-(self.scale * t).powi(2) / cast(2.0)).exp(), &(self.loc * t))
+    }
+}
+
+/// Draws a value from the standard normal distribution using the Box-Muller transform.
+fn std_normal_sample(rng: &mut dyn RngCore) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>(); // in (0, 1] so that the log is finite
+    let u2 = rng.gen::<f64>();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+
+/// Returns the quantile of `p` in the standard normal distribution, using algorithm AS 241 (Wichura, 1988).
+#[allow(clippy::excessive_precision)] // coefficients are kept exactly as published
+fn std_normal_ppf<F: Float>(p: F) -> F {
+    if p == F::zero() {
+        return F::neg_infinity();
+    }
+    else if p == F::one() {
+        return F::infinity();
+    }
+
+    let q = p - cast(0.5);
+
+    if q.abs() <= cast(0.425) {
+        let r = cast::<F>(0.180625) - q * q;
+
+        let num = horner(r, &[
+            2509.0809287301226727, 33430.575583588128105, 67265.770927008700853, 45921.953931549871457, 
+            13731.693765509461125, 1971.5909503065514427, 133.14166789178437745, 3.387132872796366608,
+        ]);
+        let den = horner(r, &[
+            5226.495278852545925, 28729.085735721942674, 39307.89580009271061, 21213.794301586595867, 
+            5394.1960214247511077, 687.1870074920579083, 42.313330701600911252, 1.0,
+        ]);
+
+        return q * num / den;
+    }
+
+    let mut r = (-(if q < F::zero() { p } else { F::one() - p }).ln()).sqrt();
+
+    let value = if r <= cast(5.0) {
+        r = r - cast(1.6);
+
+        let num = horner(r, &[
+            7.7454501427834140764e-4, 0.0227238449892691845833, 0.24178072517745061177, 1.27045825245236838258, 
+            3.64784832476320460504, 5.7694972214606914055, 4.6303378461565452959, 1.42343711074968357734,
+        ]);
+        let den = horner(r, &[
+            1.05075007164441684324e-9, 5.475938084995344946e-4, 0.0151986665636164571966, 0.14810397642748007459, 
+            0.68976733498510000455, 1.6763848301838038494, 2.05319162663775882187, 1.0,
+        ]);
+
+        num / den
+    }
+    else {
+        r = r - cast(5.0);
+
+        let num = horner(r, &[
+            2.01033439929228813265e-7, 2.71155556874348757815e-5, 0.0012426609473880784386, 0.026532189526576123093, 
+            0.29656057182850489123, 1.7848265399172913358, 5.4637849111641143699, 6.6579046435011037772,
+        ]);
+        let den = horner(r, &[
+            2.04426310338993978564e-15, 1.4215117583164458887e-7, 1.8463183175100546818e-5, 7.868691311456132591e-4, 
+            0.0148753612908506148525, 0.13692988092273580531, 0.59983220655588793769, 1.0,
+        ]);
+
+        num / den
+    };
+
+    if q < F::zero() { -value } else { value }
+}
+
+
+/// Evaluates the polynomial with coefficients `coeffs` (from the highest degree down) at `x`, using Horner's method.
+fn horner<F: Float>(x: F, coeffs: &[f64]) -> F {
+    coeffs[1..].iter().fold(cast(coeffs[0]), |sum, c| sum * x + cast(*c))
+}
+
+
+/// A gamma distribution.
+/// 
+/// The gamma distribution is parameterized by a shape parameter and a rate parameter, both of which must be positive; it 
+/// generalizes the exponential distribution, which is a gamma distribution with shape `1`. The support of the gamma 
+/// distribution is the set of non-negative real numbers.
+#[derive(Debug, PartialEq)]
+pub struct GammaDist<F: Float = f64> {
+    shape: F,
+    rate_param: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(GammaDist<F: Float>, |dist| {
+    shape: F = &dist.shape,
+    rate_param: F = &dist.rate_param,
+} => GammaDist::new_in(shape, rate_param));
+
+impl<F: Float> GammaDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(shape: F, rate_param: F) -> Result<GammaDist<F>, PensivError> {
+        check_positive("shape", shape)?;
+        check_positive("rate_param", rate_param)?;
+
+        Ok(GammaDist { shape, rate_param })
+    }
+
+    /// Returns the shape parameter of the distribution.
+    pub fn shape(&self) -> F {
+        self.shape
+    }
+
+    /// Returns the rate parameter of the distribution.
+    pub fn rate_param(&self) -> F {
+        self.rate_param
+    }
+
+    /// Returns the scale parameter of the distribution, i.e. the inverse of the rate parameter.
+    pub fn scale(&self) -> F {
+        F::one() / self.rate_param
+    }
+}
+
+impl GammaDist {
+    /// Creates and returns a new gamma distribution with the given shape and rate parameters.
+    /// 
+    /// Because both parameters must be positive, the function returns an `InvalidParameter` error if either of them is 
+    /// not a positive, finite number. Otherwise, the distribution is returned.
+    /// 
+    /// ```ignore
+    /// let dist = GammaDist::new(2.5, 1.5).unwrap();
+    /// 
+    /// println!("{}", dist.shape()); // prints "2.5"
+    /// println!("{}", dist.rate_param()); // prints "1.5"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let dist = GammaDist::new(-2.5, 1.5);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(shape: f64, rate_param: f64) -> Result<GammaDist, PensivError> {
+        GammaDist::new_in(shape, rate_param)
+    }
+
+    /// Fits a gamma distribution to `data` by maximum likelihood.
+    ///
+    /// There's no closed form for the maximum-likelihood shape, so it's found with Newton's method as the root of 
+    /// `ln(shape) - digamma(shape) = ln(mean) - mean(ln(x))`, starting from Minka's approximation; the rate is then 
+    /// `shape / mean`. Standard errors are derived from the Fisher information, which involves `special::trigamma()`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidInput` error if it contains values that aren't positive or has no spread, or a `NotConverged` error if 
+    /// Newton's method fails.
+    ///
+    /// ```ignore
+    /// let data = array![1.2, 0.4, 2.5, 1.9, 0.8];
+    /// let fit = GammaDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().shape(), fit.dist().rate_param());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<GammaDist>, PensivError> {
+        let mean = fit_mean(data)?;
+
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(PensivError::InvalidInput("data must be positive"));
+        }
+
+        let s = mean.ln() - data.mapv(f64::ln).sum() / data.len() as f64;
+        if s <= 0.0 { // all values are equal, so the shape would be infinite
+            return Err(PensivError::InvalidInput("data must have some spread"));
+        }
+
+        let guess = (3.0 - s + ((s - 3.0).powi(2) + 24.0 * s).sqrt()) / (12.0 * s);
+        let shape = numeric::positive_newton_root(|k| {
+            (k.ln() - special::digamma(k) - s, 1.0 / k - special::trigamma(k))
+        }, guess).map_err(|_| PensivError::NotConverged)?;
+        let rate_param = shape / mean;
+
+        // inverse of the Fisher information [[trigamma(k), -1 / rate], [-1 / rate, k / rate^2]]
+        let n = data.len() as f64;
+        let trigamma = special::trigamma(shape);
+        let det = shape * trigamma - 1.0;
+        let std_errors = array![(shape / (n * det)).sqrt(), rate_param * (trigamma / (n * det)).sqrt()];
+
+        Ok(Fit { dist: GammaDist::new(shape, rate_param)?, std_errors })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for GammaDist<F> {
+    /// Returns the gamma PDF of `value`.
+    /// 
+    /// The gamma PDF is calculated (in log-space, to avoid overflow) as:
+    /// `rate^shape * x^(shape - 1) * e^(-rate * x) / gamma(shape)` where `x = value`
+    /// 
+    /// When `value` is outside the support (i.e. negative), `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = GammaDist::new(2.5, 1.5).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(-1.0)); // prints "0.0"
+    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.4625"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        if value == F::zero() {
+            return gamma_pdf_at_zero(self.shape, self.rate_param);
+        }
+
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the gamma PDF of `value`, or `-inf` for negative values.
+    fn log_pdf(&self, value: F) -> F {
+        if value < F::zero() {
+            return F::neg_infinity();
+        }
+        else if value == F::zero() {
+            return gamma_pdf_at_zero(self.shape, self.rate_param).ln();
+        }
+
+        self.shape * self.rate_param.ln() + (self.shape - F::one()) * value.ln() - self.rate_param * value 
+            - special::ln_gamma(self.shape)
+    }
+
+    /// Returns the gamma CDF of `value`.
+    /// 
+    /// The gamma CDF is the regularized lower incomplete gamma function of the shape and `rate * value`; see 
+    /// `special::gamma_p()` for details. When `value` is negative, `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = GammaDist::new(2.5, 1.5).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(-1.0)); // prints "0.0"
+    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.3"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value <= F::zero() {
+            return F::zero();
+        }
+
+        special::gamma_p(self.shape, self.rate_param * value)
+    }
+
+    /// Returns the mean of the distribution, equivalent to `shape / rate`.
+    fn mean(&self) -> F {
+        self.shape / self.rate_param
+    }
+
+    /// Returns the variance of the distribution, equivalent to `shape / rate^2`.
+    fn variance(&self) -> F {
+        self.shape / self.rate_param.powi(2)
+    }
+
+    /// Draws a random value from the distribution.
+    /// 
+    /// Values are drawn using the method of Marsaglia & Tsang, a rejection sampler over transformed normal values; for 
+    /// shapes below `1`, a value is drawn with the shape increased by `1` and scaled back down by `u^(1 / shape)` for a 
+    /// uniform `u`.
+    /// 
+    /// ```ignore
+    /// let dist = GammaDist::new(2.5, 1.5).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// 
+    /// let x = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        cast::<F>(std_gamma_sample(to_f64(self.shape), rng)) / self.rate_param
+    }
+
+    /// Returns the mode of the distribution, equivalent to `(shape - 1) / rate` for `shape >= 1` and `0` otherwise.
+    fn mode(&self) -> Option<F> {
+        Some(if self.shape >= F::one() { (self.shape - F::one()) / self.rate_param } else { F::zero() })
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `2 / sqrt(shape)`.
+    fn skewness(&self) -> F {
+        cast::<F>(2.0) / self.shape.sqrt()
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `6 / shape`.
+    fn kurtosis(&self) -> F {
+        cast::<F>(6.0) / self.shape
+    }
+
+    /// Returns the entropy of the distribution, equivalent to 
+    /// `shape - ln(rate) + ln_gamma(shape) + (1 - shape) digamma(shape)`.
+    fn entropy(&self) -> F {
+        let shape = self.shape;
+        shape - self.rate_param.ln() + special::ln_gamma(shape) + (F::one() - shape) * special::digamma(shape)
+    }
+
+    /// Returns the MGF of `t`, equivalent to `(1 - t / rate)^(-shape)`, which exists for `t < rate`.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = (F::one() - t / self.rate_param).powf(-self.shape);
+        if t < self.rate_param && value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `(1 - it / rate)^(-shape)`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::new(F::one(), -t / self.rate_param).powf(-self.shape)
+    }
+}
+
+
+/// Returns the PDF of a gamma distribution at `0`, which depends on whether the shape is below, at, or above `1`.
+fn gamma_pdf_at_zero<F: Float>(shape: F, rate_param: F) -> F {
+    if shape < F::one() {
+        F::infinity()
+    }
+    else if shape == F::one() {
+        rate_param
+    }
+    else {
+        F::zero()
+    }
+}
+
+
+/// Draws a value from the gamma distribution with the given shape and a rate of `1`, using the method of Marsaglia & Tsang.
+fn std_gamma_sample(shape: f64, rng: &mut dyn RngCore) -> f64 {
+    if shape < 1.0 {
+        let u = 1.0 - rng.gen::<f64>(); // in (0, 1] so that the result is never 0 because of u alone
+        return std_gamma_sample(shape + 1.0, rng) * u.powf(1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+
+    loop {
+        let x = std_normal_sample(rng);
+        let v = 1.0 + c * x;
+
+        if v <= 0.0 {
+            continue;
+        }
+
+        let v = v * v * v;
+        let u = 1.0 - rng.gen::<f64>();
+
+        // cheap squeeze test first, then the exact acceptance test
+        if u < 1.0 - 0.0331 * x.powi(4) || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
+
+
+/// A beta distribution.
+/// 
+/// The beta distribution is parameterized by two positive shape parameters, `alpha` and `beta`, and is commonly used to 
+/// model probabilities and proportions. The support of the beta distribution is the interval `[0, 1]`.
+#[derive(Debug, PartialEq)]
+pub struct BetaDist<F: Float = f64> {
+    alpha: F,
+    beta: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(BetaDist<F: Float>, |dist| {
+    alpha: F = &dist.alpha,
+    beta: F = &dist.beta,
+} => BetaDist::new_in(alpha, beta));
+
+impl<F: Float> BetaDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(alpha: F, beta: F) -> Result<BetaDist<F>, PensivError> {
+        check_positive("alpha", alpha)?;
+        check_positive("beta", beta)?;
+
+        Ok(BetaDist { alpha, beta })
+    }
+
+    /// Returns the first shape parameter, `alpha`.
+    pub fn alpha(&self) -> F {
+        self.alpha
+    }
+
+    /// Returns the second shape parameter, `beta`.
+    pub fn beta(&self) -> F {
+        self.beta
+    }
+}
+
+impl BetaDist {
+    /// Creates and returns a new beta distribution with shape parameters `alpha` and `beta`.
+    /// 
+    /// Returns an `InvalidParameter` error if either parameter is not a positive, finite number; otherwise returns the 
+    /// distribution.
+    /// 
+    /// ```ignore
+    /// let dist = BetaDist::new(2.0, 5.0).unwrap();
+    /// 
+    /// println!("{}", dist.alpha()); // prints "2.0"
+    /// println!("{}", dist.beta()); // prints "5.0"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let dist = BetaDist::new(0.0, 5.0);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(alpha: f64, beta: f64) -> Result<BetaDist, PensivError> {
+        BetaDist::new_in(alpha, beta)
+    }
+
+    /// Fits a beta distribution to `data` by maximum likelihood.
+    ///
+    /// There's no closed form for the maximum-likelihood parameters, so they're found with Newton's method as the root of 
+    /// `digamma(alpha) - digamma(alpha + beta) = mean(ln(x))` and `digamma(beta) - digamma(alpha + beta) = mean(ln(1 - x))`, 
+    /// starting from the method-of-moments estimates. Standard errors are derived from the Fisher information.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidInput` error if it contains values outside of the open interval `(0, 1)` or has no spread, or a 
+    /// `NotConverged` error if Newton's method fails.
+    ///
+    /// ```ignore
+    /// let data = array![0.2, 0.35, 0.1, 0.5, 0.25];
+    /// let fit = BetaDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().alpha(), fit.dist().beta());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<BetaDist>, PensivError> {
+        let mean = fit_mean(data)?;
+
+        if data.iter().any(|&x| x <= 0.0 || x >= 1.0) {
+            return Err(PensivError::InvalidInput("data must be within (0, 1)"));
+        }
+
+        let n = data.len() as f64;
+        let variance = data.mapv(|x| (x - mean).powi(2)).sum() / n;
+        if variance <= 0.0 {
+            return Err(PensivError::InvalidInput("data must have some spread"));
+        }
+
+        let ln_mean = data.mapv(f64::ln).sum() / n;
+        let ln_1m_mean = data.mapv(|x| (-x).ln_1p()).sum() / n;
+
+        // method-of-moments estimates (which are only valid when the variance is small enough) as the starting point
+        let common = mean * (1.0 - mean) / variance - 1.0;
+        let guess = if common > 0.0 { [mean * common, (1.0 - mean) * common] } else { [1.0, 1.0] };
+
+        let [alpha, beta] = numeric::positive_newton_root_2d(|[alpha, beta]| {
+            let digamma_sum = special::digamma(alpha + beta);
+            let trigamma_sum = special::trigamma(alpha + beta);
+
+            let values = [
+                special::digamma(alpha) - digamma_sum - ln_mean,
+                special::digamma(beta) - digamma_sum - ln_1m_mean,
+            ];
+            let jacobian = [
+                [special::trigamma(alpha) - trigamma_sum, -trigamma_sum],
+                [-trigamma_sum, special::trigamma(beta) - trigamma_sum],
+            ];
+
+            (values, jacobian)
+        }, guess).map_err(|_| PensivError::NotConverged)?;
+
+        // inverse of the Fisher information, which is the same matrix as the Jacobian above
+        let trigamma_sum = special::trigamma(alpha + beta);
+        let a = special::trigamma(alpha) - trigamma_sum;
+        let b = special::trigamma(beta) - trigamma_sum;
+        let det = a * b - trigamma_sum * trigamma_sum;
+        let std_errors = array![(b / (n * det)).sqrt(), (a / (n * det)).sqrt()];
+
+        Ok(Fit { dist: BetaDist::new(alpha, beta)?, std_errors })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for BetaDist<F> {
+    /// Returns the beta PDF of `value`.
+    /// 
+    /// The beta PDF is calculated (in log-space, to avoid overflow) as:
+    /// `x^(alpha - 1) * (1 - x)^(beta - 1) / B(alpha, beta)` where `x = value` and `B` is the beta function
+    /// 
+    /// When `value` is outside the support (i.e. outside of `[0, 1]`), `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = BetaDist::new(2.0, 5.0).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(0.3)); // prints approximately "2.1609"
+    /// println!("{}", dist.pdf(1.5)); // prints "0.0"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        if value == F::zero() {
+            return beta_pdf_at_bound(self.alpha, self.beta);
+        }
+        else if value == F::one() {
+            return beta_pdf_at_bound(self.beta, self.alpha);
+        }
+
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the beta PDF of `value`, or `-inf` for values outside of `[0, 1]`.
+    fn log_pdf(&self, value: F) -> F {
+        if !(F::zero()..=F::one()).contains(&value) {
+            return F::neg_infinity();
+        }
+        else if value == F::zero() {
+            return beta_pdf_at_bound(self.alpha, self.beta).ln();
+        }
+        else if value == F::one() {
+            return beta_pdf_at_bound(self.beta, self.alpha).ln();
+        }
+
+        let (alpha, beta) = (self.alpha, self.beta);
+        (alpha - F::one()) * value.ln() + (beta - F::one()) * (-value).ln_1p() - special::ln_beta(alpha, beta)
+    }
+
+    /// Returns the beta CDF of `value`.
+    /// 
+    /// The beta CDF is the regularized incomplete beta function of `value`; see `special::beta_inc()` for details. For 
+    /// values below the support, `0.0` is returned, and for values above it, `1.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = BetaDist::new(2.0, 5.0).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(0.5)); // prints approximately "0.8906"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value <= F::zero() {
+            return F::zero();
+        }
+        else if value >= F::one() {
+            return F::one();
+        }
+
+        special::beta_inc(self.alpha, self.beta, value)
+    }
+
+    /// Returns the mean of the distribution, equivalent to `alpha / (alpha + beta)`.
+    fn mean(&self) -> F {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    /// Returns the variance of the distribution.
+    /// 
+    /// This is equivalent to `alpha * beta / ((alpha + beta)^2 * (alpha + beta + 1))`.
+    fn variance(&self) -> F {
+        let sum = self.alpha + self.beta;
+
+        self.alpha * self.beta / (sum * sum * (sum + F::one()))
+    }
+
+    /// Draws a random value from the distribution.
+    /// 
+    /// Values are drawn as `x / (x + y)`, where `x` and `y` are drawn from gamma distributions with shapes `alpha` and `beta`.
+    /// 
+    /// ```ignore
+    /// let dist = BetaDist::new(2.0, 5.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// 
+    /// let p = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        let x = cast::<F>(std_gamma_sample(to_f64(self.alpha), rng));
+        let y = cast::<F>(std_gamma_sample(to_f64(self.beta), rng));
+
+        x / (x + y)
+    }
+
+    /// Returns the mode of the distribution, equivalent to `(alpha - 1) / (alpha + beta - 2)` if both parameters are 
+    /// greater than `1`. Otherwise the density is largest at (or tends to infinity at) one or both ends of the support; the 
+    /// smaller end is returned if the density is equally large at both, or constant.
+    fn mode(&self) -> Option<F> {
+        if self.alpha > F::one() && self.beta > F::one() {
+            Some((self.alpha - F::one()) / (self.alpha + self.beta - cast(2.0)))
+        }
+        else if self.alpha < F::one() || (self.alpha == F::one() && self.beta >= F::one()) {
+            Some(F::zero())
+        }
+        else {
+            Some(F::one())
+        }
+    }
+
+    /// Returns the skewness of the distribution, equivalent to 
+    /// `2 (beta - alpha) sqrt(alpha + beta + 1) / ((alpha + beta + 2) sqrt(alpha beta))`.
+    fn skewness(&self) -> F {
+        let sum = self.alpha + self.beta;
+        let two = cast::<F>(2.0);
+
+        two * (self.beta - self.alpha) * (sum + F::one()).sqrt() / ((sum + two) * (self.alpha * self.beta).sqrt())
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to 
+    /// `6 ((alpha - beta)^2 (alpha + beta + 1) - alpha beta (alpha + beta + 2)) / (alpha beta (alpha + beta + 2) (alpha + beta + 3))`.
+    fn kurtosis(&self) -> F {
+        let sum = self.alpha + self.beta;
+        let product = self.alpha * self.beta;
+        let two = cast::<F>(2.0);
+
+        cast::<F>(6.0) * ((self.alpha - self.beta).powi(2) * (sum + F::one()) - product * (sum + two))
+            / (product * (sum + two) * (sum + cast(3.0)))
+    }
+
+    /// Returns the entropy of the distribution, equivalent to 
+    /// `ln_beta(alpha, beta) - (alpha - 1) digamma(alpha) - (beta - 1) digamma(beta) + (alpha + beta - 2) digamma(alpha + beta)`.
+    fn entropy(&self) -> F {
+        special::ln_beta(self.alpha, self.beta)
+            - (self.alpha - F::one()) * special::digamma(self.alpha)
+            - (self.beta - F::one()) * special::digamma(self.beta)
+            + (self.alpha + self.beta - cast(2.0)) * special::digamma(self.alpha + self.beta)
+    }
+}
+
+
+/// Returns the PDF of a beta distribution at the bound of its support whose exponent is `shape - 1`, where `other` is the 
+/// remaining shape parameter.
+fn beta_pdf_at_bound<F: Float>(shape: F, other: F) -> F {
+    if shape < F::one() {
+        F::infinity()
+    }
+    else if shape == F::one() {
+        F::one() / special::beta(shape, other)
+    }
+    else {
+        F::zero()
+    }
+}
+
+
+/// A chi-squared distribution.
+/// 
+/// The chi-squared distribution is parameterized by its (positive) degrees of freedom `k`, and describes the sum of the 
+/// squares of `k` independent standard normal random variables; it's a gamma distribution with shape `k / 2` and rate `1/2`. 
+/// The support of the chi-squared distribution is the set of non-negative real numbers.
+#[derive(Debug, PartialEq)]
+pub struct ChiSquaredDist<F: Float = f64> {
+    dof: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(ChiSquaredDist<F: Float>, |dist| {
+    dof: F = &dist.dof,
+} => ChiSquaredDist::new_in(dof));
+
+impl<F: Float> ChiSquaredDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(dof: F) -> Result<ChiSquaredDist<F>, PensivError> {
+        check_positive("dof", dof)?;
+        Ok(ChiSquaredDist { dof })
+    }
+
+    /// Returns the degrees of freedom of the distribution.
+    pub fn dof(&self) -> F {
+        self.dof
+    }
+}
+
+impl ChiSquaredDist {
+    /// Creates and returns a new chi-squared distribution with `dof` degrees of freedom.
+    /// 
+    /// Returns an `InvalidParameter` error if `dof` is not a positive, finite number; otherwise returns the 
+    /// distribution.
+    /// 
+    /// ```ignore
+    /// let dist = ChiSquaredDist::new(4.0).unwrap();
+    /// println!("{}", dist.dof()); // prints "4.0"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let dist = ChiSquaredDist::new(-4.0);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(dof: f64) -> Result<ChiSquaredDist, PensivError> {
+        ChiSquaredDist::new_in(dof)
+    }
+
+    /// Fits a chi-squared distribution to `data` by maximum likelihood.
+    ///
+    /// There's no closed form for the maximum-likelihood degrees of freedom `k`, so `k / 2` is found with Newton's method 
+    /// as the root of `digamma(k / 2) = mean(ln(x)) - ln(2)`. Its standard error is `2 / sqrt(n * trigamma(k / 2))`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidInput` error if it contains values that aren't positive, or a `NotConverged` error if Newton's method 
+    /// fails.
+    ///
+    /// ```ignore
+    /// let data = array![2.5, 4.1, 1.3, 6.2, 3.8];
+    /// let fit = ChiSquaredDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().dof());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<ChiSquaredDist>, PensivError> {
+        fit_mean(data)?;
+
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(PensivError::InvalidInput("data must be positive"));
+        }
+
+        let c = data.mapv(f64::ln).sum() / data.len() as f64 - 2.0_f64.ln();
+
+        // Minka's approximation of the inverse digamma function as the starting point
+        let guess = if c >= -2.22 { c.exp() + 0.5 } else { -1.0 / (c + 0.5772156649015329) };
+        let half_dof = numeric::positive_newton_root(|a| (special::digamma(a) - c, special::trigamma(a)), guess)
+            .map_err(|_| PensivError::NotConverged)?;
+
+        let std_error = 2.0 / (data.len() as f64 * special::trigamma(half_dof)).sqrt();
+        Ok(Fit { dist: ChiSquaredDist::new(2.0 * half_dof)?, std_errors: array![std_error] })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for ChiSquaredDist<F> {
+    /// Returns the chi-squared PDF of `value`.
+    /// 
+    /// The chi-squared PDF is calculated (in log-space, to avoid overflow) as:
+    /// `x^(k/2 - 1) * e^(-x/2) / (2^(k/2) * gamma(k/2))` where `x = value` and `k` is the degrees of freedom
+    /// 
+    /// When `value` is outside the support (i.e. negative), `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = ChiSquaredDist::new(4.0).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(2.0)); // prints approximately "0.1839"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        if value == F::zero() {
+            return gamma_pdf_at_zero(self.dof / cast(2.0), cast(0.5));
+        }
+
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the chi-squared PDF of `value`, or `-inf` for negative values.
+    fn log_pdf(&self, value: F) -> F {
+        let shape = self.dof / cast(2.0);
+
+        if value < F::zero() {
+            return F::neg_infinity();
+        }
+        else if value == F::zero() {
+            return gamma_pdf_at_zero(shape, cast(0.5)).ln();
+        }
+
+        (shape - F::one()) * value.ln() - value / cast(2.0) - shape * cast::<F>(2.0).ln() - special::ln_gamma(shape)
+    }
+
+    /// Returns the chi-squared CDF of `value`.
+    /// 
+    /// The chi-squared CDF is the regularized lower incomplete gamma function `P(k / 2, value / 2)`; see 
+    /// `special::gamma_p()` for details. When `value` is negative, `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = ChiSquaredDist::new(4.0).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(4.0)); // prints approximately "0.594"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value <= F::zero() {
+            return F::zero();
+        }
+
+        special::gamma_p(self.dof / cast(2.0), value / cast(2.0))
+    }
+
+    /// Returns the mean of the distribution, equivalent to the degrees of freedom.
+    fn mean(&self) -> F {
+        self.dof
+    }
+
+    /// Returns the variance of the distribution, equivalent to twice the degrees of freedom.
+    fn variance(&self) -> F {
+        cast::<F>(2.0) * self.dof
+    }
+
+    /// Draws a random value from the distribution, as a gamma value with shape `k / 2` and rate `1/2`.
+    /// 
+    /// ```ignore
+    /// let dist = ChiSquaredDist::new(4.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// 
+    /// let x = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        cast::<F>(2.0 * std_gamma_sample(to_f64(self.dof) / 2.0, rng))
+    }
+
+    /// Returns the mode of the distribution, equivalent to `max(dof - 2, 0)`.
+    fn mode(&self) -> Option<F> {
+        Some((self.dof - cast(2.0)).max(F::zero()))
+    }
+
+    /// Returns the skewness of the distribution, equivalent to `sqrt(8 / dof)`.
+    fn skewness(&self) -> F {
+        (cast::<F>(8.0) / self.dof).sqrt()
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `12 / dof`.
+    fn kurtosis(&self) -> F {
+        cast::<F>(12.0) / self.dof
+    }
+
+    /// Returns the entropy of the distribution, equivalent to 
+    /// `dof / 2 + ln(2) + ln_gamma(dof / 2) + (1 - dof / 2) digamma(dof / 2)`.
+    fn entropy(&self) -> F {
+        let half = self.dof / cast(2.0);
+        half + cast::<F>(2.0).ln() + special::ln_gamma(half) + (F::one() - half) * special::digamma(half)
+    }
+
+    /// Returns the MGF of `t`, equivalent to `(1 - 2t)^(-dof / 2)`, which exists for `t < 1/2`.
+    fn mgf(&self, t: F) -> Option<F> {
+        let value = (F::one() - cast::<F>(2.0) * t).powf(-self.dof / cast(2.0));
+        if t < cast(0.5) && value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, equivalent to `(1 - 2it)^(-dof / 2)`.
+    fn cf(&self, t: F) -> Complex<F> {
+        Complex::new(F::one(), -cast::<F>(2.0) * t).powf(-self.dof / cast(2.0))
+    }
+}
+
+
+/// A (standard) Student's t-distribution.
+/// 
+/// The t-distribution is parameterized by its (positive) degrees of freedom `v`; it describes the standardized mean of a 
+/// sample from a normal population whose variance is estimated from the sample itself, and approaches the standard normal 
+/// distribution as `v` grows. The support of the t-distribution is all real numbers.
+#[derive(Debug, PartialEq)]
+pub struct StudentTDist<F: Float = f64> {
+    dof: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(StudentTDist<F: Float>, |dist| {
+    dof: F = &dist.dof,
+} => StudentTDist::new_in(dof));
+
+impl<F: Float> StudentTDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(dof: F) -> Result<StudentTDist<F>, PensivError> {
+        check_positive("dof", dof)?;
+        Ok(StudentTDist { dof })
+    }
+
+    /// Returns the degrees of freedom of the distribution.
+    pub fn dof(&self) -> F {
+        self.dof
+    }
+}
+
+impl StudentTDist {
+    /// Creates and returns a new t-distribution with `dof` degrees of freedom.
+    /// 
+    /// Returns an `InvalidParameter` error if `dof` is not a positive, finite number; otherwise returns the 
+    /// distribution.
+    /// 
+    /// ```ignore
+    /// let dist = StudentTDist::new(5.0).unwrap();
+    /// println!("{}", dist.dof()); // prints "5.0"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let dist = StudentTDist::new(0.0);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(dof: f64) -> Result<StudentTDist, PensivError> {
+        StudentTDist::new_in(dof)
+    }
+
+    /// Fits a t-distribution to `data` by maximum likelihood.
+    ///
+    /// There's no closed form for the maximum-likelihood degrees of freedom `v`, so it's found as the root of the 
+    /// derivative of the log-likelihood in `v`, which is half of 
+    /// `n * (digamma((v + 1) / 2) - digamma(v / 2) - 1/v) + sum((v + 1) x^2 / (v(v + x^2)) - ln(1 + x^2 / v))`. The 
+    /// log-likelihood isn't concave in `v`, so Newton's method can run away towards infinite `v`; instead, the root is 
+    /// found with Brent's method in terms of `ln(v)`, searching `v` between `1e-3` & `1e6`. Its standard error is 
+    /// derived from the Fisher information 
+    /// `(trigamma(v / 2) - trigamma((v + 1) / 2)) / 4 - (v + 5) / (2v(v + 1)(v + 3))`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, or an 
+    /// `InvalidInput` error if the likelihood is still increasing at `v = 1e6`, i.e. the data are no more heavy-tailed 
+    /// than a standard normal sample (which fits best as `v` grows without bound).
+    ///
+    /// ```ignore
+    /// let data = array![0.3, -1.2, 4.5, 0.1, -0.7, 2.2, -3.1, 0.4];
+    /// let fit = StudentTDist::fit(&data).unwrap();
+    ///
+    /// println!("{}", fit.dist().dof());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<StudentTDist>, PensivError> {
+        fit_mean(data)?;
+
+        let n = data.len() as f64;
+        let squares = data.mapv(|x| x * x);
+
+        let score = |ln_v: f64| {
+            let v = ln_v.exp();
+            let sum: f64 = squares.iter().map(|&x2| (v + 1.0) * x2 / (v * (v + x2)) - (x2 / v).ln_1p()).sum();
+
+            n * (special::digamma((v + 1.0) / 2.0) - special::digamma(v / 2.0) - 1.0 / v) + sum
+        };
+
+        let (min_dof, max_dof) = FIT_DOF_RANGE;
+
+        let dof = match numeric::brent_root(score, min_dof.ln(), max_dof.ln()) {
+            Ok(ln_dof) => ln_dof.exp(),
+            Err(numeric::NumericError::NoSignChange) => {
+                return Err(PensivError::InvalidInput("data must be heavier-tailed than a standard normal sample"));
+            },
+            Err(_) => return Err(PensivError::NotConverged),
+        };
+
+        let information = (special::trigamma(dof / 2.0) - special::trigamma((dof + 1.0) / 2.0)) / 4.0
+            - (dof + 5.0) / (2.0 * dof * (dof + 1.0) * (dof + 3.0));
+        let std_error = 1.0 / (n * information).sqrt();
+
+        Ok(Fit { dist: StudentTDist::new(dof)?, std_errors: array![std_error] })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for StudentTDist<F> {
+    /// Returns the t PDF of `value`.
+    /// 
+    /// The t PDF is calculated (in log-space, to avoid overflow) as:
+    /// `gamma((v + 1) / 2) / (sqrt(v * pi) * gamma(v / 2)) * (1 + t^2 / v)^(-(v + 1) / 2)` where `t = value` and `v` is the 
+    /// degrees of freedom
+    /// 
+    /// ```ignore
+    /// let dist = StudentTDist::new(5.0).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(0.0)); // prints approximately "0.3796"
+    /// println!("{}", dist.pdf(2.0)); // prints approximately "0.0651"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the t PDF of `value`.
+    fn log_pdf(&self, value: F) -> F {
+        let v = self.dof;
+        let r = value / v.sqrt();
+        let two = cast::<F>(2.0);
+
+        // ln(1 + r^2), without overflowing r^2 far in the tails
+        let ln_kernel = if r.abs() > F::max_value().sqrt() { two * r.abs().ln() } else { (r * r).ln_1p() };
+
+        special::ln_gamma((v + F::one()) / two) - special::ln_gamma(v / two) - cast::<F>(0.5) * (v * cast(PI)).ln()
+            - (v + F::one()) / two * ln_kernel
+    }
+
+    /// Returns the t CDF of `value`.
+    /// 
+    /// The t CDF is computed from the regularized incomplete beta function: the probability of the tail beyond `|t|` is 
+    /// `I_x(v / 2, 1 / 2) / 2` where `x = v / (v + t^2)`, which keeps its full relative precision far into the tails.
+    /// 
+    /// ```ignore
+    /// let dist = StudentTDist::new(5.0).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(0.0)); // prints "0.5"
+    /// println!("{}", dist.cdf(2.0)); // prints approximately "0.949"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value.is_nan() {
+            return F::nan();
+        }
+        else if value.is_infinite() {
+            return if value > F::zero() { F::one() } else { F::zero() };
+        }
+
+        let tail = special::beta_inc(self.dof / cast(2.0), cast(0.5), self.dof / (self.dof + value * value)) / cast(2.0);
+
+        if value < F::zero() { tail } else { F::one() - tail }
+    }
+
+    /// Returns the mean of the distribution.
+    /// 
+    /// The mean is `0` for more than 1 degree of freedom, and is undefined (so `NaN` is returned) otherwise.
+    fn mean(&self) -> F {
+        if self.dof > F::one() { F::zero() } else { F::nan() }
+    }
+
+    /// Returns the variance of the distribution.
+    /// 
+    /// The variance is `v / (v - 2)` for more than 2 degrees of freedom, infinite for between 1 and 2 degrees of freedom, 
+    /// and undefined (so `NaN` is returned) otherwise.
+    fn variance(&self) -> F {
+        if self.dof > cast(2.0) {
+            self.dof / (self.dof - cast(2.0))
+        }
+        else if self.dof > F::one() {
+            F::infinity()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Draws a random value from the distribution, as `z / sqrt(x / v)` where `z` is drawn from the standard normal 
+    /// distribution and `x` from the chi-squared distribution with `v` degrees of freedom.
+    /// 
+    /// ```ignore
+    /// let dist = StudentTDist::new(5.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// 
+    /// let t = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        let z = cast::<F>(std_normal_sample(rng));
+        let x = cast::<F>(2.0 * std_gamma_sample(to_f64(self.dof) / 2.0, rng));
+
+        z / (x / self.dof).sqrt()
+    }
+
+    /// Returns the median of the distribution, which is `0` by symmetry.
+    fn median(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    /// Returns the mode of the distribution, which is `0`.
+    fn mode(&self) -> Option<F> {
+        Some(F::zero())
+    }
+
+    /// Returns the skewness of the distribution, which is `0` by symmetry for `dof > 3` and undefined (`NaN`) otherwise.
+    fn skewness(&self) -> F {
+        if self.dof > cast(3.0) { F::zero() } else { F::nan() }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to `6 / (dof - 4)` for `dof > 4`; it's infinite for 
+    /// `2 < dof <= 4` and undefined (`NaN`) otherwise.
+    fn kurtosis(&self) -> F {
+        if self.dof > cast(4.0) {
+            cast::<F>(6.0) / (self.dof - cast(4.0))
+        }
+        else if self.dof > cast(2.0) {
+            F::infinity()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to 
+    /// `(dof + 1) / 2 (digamma((dof + 1) / 2) - digamma(dof / 2)) + ln(sqrt(dof) beta(dof / 2, 1 / 2))`.
+    fn entropy(&self) -> F {
+        let half = (self.dof + F::one()) / cast(2.0);
+        half * (special::digamma(half) - special::digamma(self.dof / cast(2.0)))
+            + self.dof.sqrt().ln() + special::ln_beta(self.dof / cast(2.0), cast(0.5))
+    }
+
+    /// Returns the MGF of `t`, which only exists at `t = 0` since the tails of the distribution are too heavy.
+    fn mgf(&self, t: F) -> Option<F> {
+        if t == F::zero() { Some(F::one()) } else { None }
+    }
+}
+
+
+/// An F-distribution (also known as the Fisher-Snedecor distribution).
+/// 
+/// The F-distribution is parameterized by two (positive) degrees of freedom, `d1` and `d2`, and describes the ratio of two 
+/// independent chi-squared random variables, each divided by its degrees of freedom; it's most commonly used in analysis 
+/// of variance. The support of the F-distribution is the set of non-negative real numbers.
+#[derive(Debug, PartialEq)]
+pub struct FDist<F: Float = f64> {
+    dof1: F,
+    dof2: F,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(FDist<F: Float>, |dist| {
+    dof1: F = &dist.dof1,
+    dof2: F = &dist.dof2,
+} => FDist::new_in(dof1, dof2));
+
+impl<F: Float> FDist<F> {
+    /// Like `new()`, but creates a distribution whose values are of the float type `F` rather than `f64`.
+    pub fn new_in(dof1: F, dof2: F) -> Result<FDist<F>, PensivError> {
+        check_positive("dof1", dof1)?;
+        check_positive("dof2", dof2)?;
+
+        Ok(FDist { dof1, dof2 })
+    }
+
+    /// Returns the numerator degrees of freedom of the distribution.
+    pub fn dof1(&self) -> F {
+        self.dof1
+    }
+
+    /// Returns the denominator degrees of freedom of the distribution.
+    pub fn dof2(&self) -> F {
+        self.dof2
+    }
+}
+
+impl FDist {
+    /// Creates and returns a new F-distribution with `dof1` and `dof2` degrees of freedom (in the numerator and denominator, 
+    /// respectively).
+    /// 
+    /// Returns an `InvalidParameter` error if either parameter is not a positive, finite number; otherwise returns the 
+    /// distribution.
+    /// 
+    /// ```ignore
+    /// let dist = FDist::new(3.0, 8.0).unwrap();
+    /// 
+    /// println!("{}", dist.dof1()); // prints "3.0"
+    /// println!("{}", dist.dof2()); // prints "8.0"
+    /// ```
+    /// 
+    /// ```ignore
+    /// let dist = FDist::new(3.0, -8.0);
+    /// println!("{}", dist.is_err()); // prints "true"
+    /// ```
+    pub fn new(dof1: f64, dof2: f64) -> Result<FDist, PensivError> {
+        FDist::new_in(dof1, dof2)
+    }
+
+    /// Fits an F-distribution to `data` by maximum likelihood.
+    ///
+    /// There's no closed form for the maximum-likelihood degrees of freedom, and the log-likelihood isn't concave in 
+    /// them, so they're found by profile likelihood: for a given `d1`, the maximum-likelihood `d2` is the root of the 
+    /// derivative of the log-likelihood in `d2`, and the maximum-likelihood `d1` is then the root of the derivative in 
+    /// `d1` at that `d2`. Both roots are found with Brent's method in terms of the logs of the degrees of freedom, 
+    /// searching between `1e-3` & `1e6`. Standard errors are derived from the observed information, i.e. the negated 
+    /// Hessian of the log-likelihood at the estimates.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains values that aren't finite, an 
+    /// `InvalidInput` error if it contains values that aren't positive, or a `NotConverged` error if no maximum is 
+    /// found within the range searched.
+    ///
+    /// ```ignore
+    /// let data = array![0.8, 1.3, 0.4, 2.1, 0.9, 1.1, 0.6, 3.2];
+    /// let fit = FDist::fit(&data).unwrap();
+    ///
+    /// println!("{} {}", fit.dist().dof1(), fit.dist().dof2());
+    /// ```
+    pub fn fit(data: &Array<f64, Ix1>) -> Result<Fit<FDist>, PensivError> {
+        fit_mean(data)?;
+
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(PensivError::InvalidInput("data must be positive"));
+        }
+
+        let n = data.len() as f64;
+        let (min_dof, max_dof) = FIT_DOF_RANGE;
+
+        // the gradient of the log-likelihood, doubled
+        let gradient = |d1: f64, d2: f64| {
+            let (mut g1, mut g2) = (0.0, 0.0);
+
+            for &x in data.iter() {
+                let d = d1 * x + d2;
+
+                g1 += (d1 * x / d).ln() + d2 * (1.0 - x) / d;
+                g2 += (d2 / d).ln() + d1 * (x - 1.0) / d;
+            }
+
+            let digamma_sum = special::digamma((d1 + d2) / 2.0);
+            [g1 + n * (digamma_sum - special::digamma(d1 / 2.0)), g2 + n * (digamma_sum - special::digamma(d2 / 2.0))]
+        };
+
+        // the maximum-likelihood d2 for a given d1, or whichever end of the range searched is more likely if the 
+        // likelihood is monotonic over it
+        let profile_dof2 = |d1: f64| {
+            let g2 = |ln_d2: f64| gradient(d1, ln_d2.exp())[1];
+
+            match numeric::brent_root(g2, min_dof.ln(), max_dof.ln()) {
+                Ok(ln_d2) => Ok(ln_d2.exp()),
+                Err(numeric::NumericError::NoSignChange) => Ok(if g2(max_dof.ln()) > 0.0 { max_dof } else { min_dof }),
+                Err(error) => Err(error),
+            }
+        };
+
+        let profile_score = |ln_d1: f64| {
+            let d1 = ln_d1.exp();
+            profile_dof2(d1).map_or(f64::NAN, |d2| gradient(d1, d2)[0])
+        };
+
+        let dof1 = numeric::brent_root(profile_score, min_dof.ln(), max_dof.ln())
+            .map_err(|_| PensivError::NotConverged)?
+            .exp();
+        let dof2 = profile_dof2(dof1).map_err(|_| PensivError::NotConverged)?;
+
+        // inverse of the observed information, i.e. of the negated Hessian of the log-likelihood
+        let (mut info_11, mut info_12, mut info_22) = (0.0, 0.0, 0.0);
+
+        for &x in data.iter() {
+            let d = dof1 * x + dof2;
+
+            info_11 -= 1.0 / dof1 - x / d - dof2 * x * (1.0 - x) / (d * d);
+            info_12 += (dof2 + dof1 * x * x) / (d * d);
+            info_22 -= 1.0 / dof2 - 1.0 / d - dof1 * (x - 1.0) / (d * d);
+        }
+
+        let trigamma_sum = special::trigamma((dof1 + dof2) / 2.0);
+
+        info_11 = (info_11 - n * (trigamma_sum - special::trigamma(dof1 / 2.0)) / 2.0) / 2.0;
+        info_12 = (info_12 - n * trigamma_sum / 2.0) / 2.0;
+        info_22 = (info_22 - n * (trigamma_sum - special::trigamma(dof2 / 2.0)) / 2.0) / 2.0;
+
+        let det = info_11 * info_22 - info_12 * info_12;
+        let std_errors = array![(info_22 / det).sqrt(), (info_11 / det).sqrt()];
+
+        Ok(Fit { dist: FDist::new(dof1, dof2)?, std_errors })
+    }
+}
+
+impl<F: Float> ContinuousDist<F> for FDist<F> {
+    /// Returns the F PDF of `value`.
+    /// 
+    /// The F PDF is calculated (in log-space, to avoid overflow) as:
+    /// `sqrt((d1 x)^d1 * d2^d2 / (d1 x + d2)^(d1 + d2)) / (x * B(d1 / 2, d2 / 2))` where `x = value` and `B` is the beta 
+    /// function
+    /// 
+    /// When `value` is outside the support (i.e. negative), `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = FDist::new(3.0, 8.0).unwrap();
+    /// 
+    /// println!("{}", dist.pdf(1.0)); // prints approximately "0.3922"
+    /// ```
+    fn pdf(&self, value: F) -> F {
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the F PDF of `value`, or `-inf` for negative values.
+    fn log_pdf(&self, value: F) -> F {
+        let (d1, d2) = (self.dof1, self.dof2);
+
+        if value < F::zero() {
+            return F::neg_infinity();
+        }
+        else if value == F::zero() {
+            // behaves like x^(d1/2 - 1) near 0
+            return if d1 < cast(2.0) { F::infinity() } else if d1 == cast(2.0) { F::zero() } else { F::neg_infinity() };
+        }
+
+        cast::<F>(0.5) * (d1 * (d1 * value).ln() + d2 * d2.ln() - (d1 + d2) * (d1 * value + d2).ln()) 
+            - value.ln() - special::ln_beta(d1 / cast(2.0), d2 / cast(2.0))
+    }
+
+    /// Returns the F CDF of `value`.
+    /// 
+    /// The F CDF is the regularized incomplete beta function `I_x(d1 / 2, d2 / 2)` where `x = d1 * value / (d1 * value + d2)`; 
+    /// see `special::beta_inc()` for details. When `value` is negative, `0.0` is returned.
+    /// 
+    /// ```ignore
+    /// let dist = FDist::new(3.0, 8.0).unwrap();
+    /// 
+    /// println!("{}", dist.cdf(1.0)); // prints approximately "0.5589"
+    /// ```
+    fn cdf(&self, value: F) -> F {
+        if value <= F::zero() {
+            return F::zero();
+        }
+        else if value.is_infinite() {
+            return F::one();
+        }
+
+        let x = self.dof1 * value / (self.dof1 * value + self.dof2);
+        special::beta_inc(self.dof1 / cast(2.0), self.dof2 / cast(2.0), x)
+    }
+
+    /// Returns the mean of the distribution.
+    /// 
+    /// The mean is `d2 / (d2 - 2)` for `d2 > 2`, and is undefined (so `NaN` is returned) otherwise.
+    fn mean(&self) -> F {
+        if self.dof2 > cast(2.0) { self.dof2 / (self.dof2 - cast(2.0)) } else { F::nan() }
+    }
+
+    /// Returns the variance of the distribution.
+    /// 
+    /// The variance is `2 d2^2 (d1 + d2 - 2) / (d1 (d2 - 2)^2 (d2 - 4))` for `d2 > 4`, infinite for `2 < d2 <= 4`, and 
+    /// undefined (so `NaN` is returned) otherwise.
+    fn variance(&self) -> F {
+        let (d1, d2) = (self.dof1, self.dof2);
+
+        if d2 > cast(4.0) {
+            cast::<F>(2.0) * d2 * d2 * (d1 + d2 - cast(2.0)) / (d1 * (d2 - cast(2.0)).powi(2) * (d2 - cast(4.0)))
+        }
+        else if d2 > cast(2.0) {
+            F::infinity()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Draws a random value from the distribution, as the ratio of two chi-squared values, each divided by its degrees of 
+    /// freedom.
+    /// 
+    /// ```ignore
+    /// let dist = FDist::new(3.0, 8.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// 
+    /// let f = dist.sample(&mut rng);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> F {
+        let x1 = cast::<F>(2.0 * std_gamma_sample(to_f64(self.dof1) / 2.0, rng));
+        let x2 = cast::<F>(2.0 * std_gamma_sample(to_f64(self.dof2) / 2.0, rng));
+
+        (x1 / self.dof1) / (x2 / self.dof2)
+    }
+
+    /// Returns the mode of the distribution, equivalent to `(dof1 - 2) / dof1 * dof2 / (dof2 + 2)` for `dof1 > 2` and `0` 
+    /// otherwise.
+    fn mode(&self) -> Option<F> {
+        if self.dof1 > cast(2.0) {
+            Some((self.dof1 - cast(2.0)) / self.dof1 * self.dof2 / (self.dof2 + cast(2.0)))
+        }
+        else {
+            Some(F::zero())
+        }
+    }
+
+    /// Returns the skewness of the distribution, equivalent to 
+    /// `(2 dof1 + dof2 - 2) sqrt(8 (dof2 - 4)) / ((dof2 - 6) sqrt(dof1 (dof1 + dof2 - 2)))` for `dof2 > 6`; it's infinite for 
+    /// `4 < dof2 <= 6` and undefined (`NaN`) otherwise.
+    fn skewness(&self) -> F {
+        let (d1, d2) = (self.dof1, self.dof2);
+        let two = cast::<F>(2.0);
+
+        if d2 > cast(6.0) {
+            (two * d1 + d2 - two) * (cast::<F>(8.0) * (d2 - cast(4.0))).sqrt() / ((d2 - cast(6.0)) * (d1 * (d1 + d2 - two)).sqrt())
+        }
+        else if d2 > cast(4.0) {
+            F::infinity()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Returns the excess kurtosis of the distribution, equivalent to 
+    /// `12 (dof1 (5 dof2 - 22) (dof1 + dof2 - 2) + (dof2 - 4) (dof2 - 2)^2) / (dof1 (dof2 - 6) (dof2 - 8) (dof1 + dof2 - 2))` 
+    /// for `dof2 > 8`; it's infinite for `4 < dof2 <= 8` and undefined (`NaN`) otherwise.
+    fn kurtosis(&self) -> F {
+        let (d1, d2) = (self.dof1, self.dof2);
+        let two = cast::<F>(2.0);
+
+        if d2 > cast(8.0) {
+            cast::<F>(12.0) * (d1 * (cast::<F>(5.0) * d2 - cast(22.0)) * (d1 + d2 - two) + (d2 - cast(4.0)) * (d2 - two).powi(2))
+                / (d1 * (d2 - cast(6.0)) * (d2 - cast(8.0)) * (d1 + d2 - two))
+        }
+        else if d2 > cast(4.0) {
+            F::infinity()
+        }
+        else {
+            F::nan()
+        }
+    }
+
+    /// Returns the entropy of the distribution, equivalent to 
+    /// `ln(dof2 / dof1) + ln_beta(dof1 / 2, dof2 / 2) + (1 - dof1 / 2) digamma(dof1 / 2) - (1 + dof2 / 2) digamma(dof2 / 2) 
+    /// + (dof1 + dof2) / 2 digamma((dof1 + dof2) / 2)`.
+    fn entropy(&self) -> F {
+        let (h1, h2) = (self.dof1 / cast(2.0), self.dof2 / cast(2.0));
+
+        (self.dof2 / self.dof1).ln() + special::ln_beta(h1, h2)
+            + (F::one() - h1) * special::digamma(h1) - (F::one() + h2) * special::digamma(h2)
+            + (h1 + h2) * special::digamma(h1 + h2)
+    }
+
+    /// Returns the MGF of `t`, which only exists at `t = 0` since the right tail of the distribution is too heavy.
+    fn mgf(&self, t: F) -> Option<F> {
+        if t == F::zero() { Some(F::one()) } else { None }
+    }
+}
+
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn factorial_calculated_correctly() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(5), Some(120));
+        assert_eq!(factorial(13), Some(6227020800));
+        assert_eq!(factorial(20), Some(2432902008176640000));
+        assert_eq!(factorial(21), None);
+
+        assert_eq!(factorial_u128(34), Some(295232799039604140847618609643520000000));
+        assert_eq!(factorial_u128(35), None);
+    }
+
+    #[test]
+    fn ln_factorial_calculated_correctly() {
+        assert_eq!(ln_factorial(0), 0.0);
+        assert!((ln_factorial(13) - 6227020800_f64.ln()).abs() < 1e-12);
+        assert!((ln_factorial(100) - 363.73937555556347).abs() < 1e-10);
+    }
+
+    #[test]
+    fn permutations_calculated_correctly() {
+        assert_eq!(permutations(5, 2), Some(20));
+        assert_eq!(permutations(5, 0), Some(1));
+        assert_eq!(permutations(5, 7), Some(0));
+        assert_eq!(permutations(30, 20), None);
+        assert_eq!(permutations_u128(30, 20), Some(73096577329197271449600000));
+
+        assert!((ln_permutations(5, 2) - 20_f64.ln()).abs() < 1e-12);
+        assert_eq!(ln_permutations(5, 7), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn choose_calculated_correctly() {
+        assert_eq!(choose(5, 2), Some(10));
+        assert_eq!(choose(5, 0), Some(1));
+        assert_eq!(choose(5, 5), Some(1));
+        assert_eq!(choose(2, 5), Some(0));
+        assert_eq!(choose(50, 25), Some(126410606437752));
+        assert_eq!(choose(100, 50), None);
+        assert_eq!(choose_u128(100, 50), Some(100891344545564193334812497256));
+    }
+
+    #[test]
+    fn ln_choose_calculated_correctly() {
+        assert!((ln_choose(50, 25) - 126410606437752_f64.ln()).abs() < 1e-12);
+        assert!((ln_choose(1000000, 300000) - 610857.2556846419).abs() < 1e-6);
+        assert_eq!(ln_choose(2, 5), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn ln_gamma_calculated_correctly() {
+        // reference values computed with mpmath
+        let x = array![0.1, 0.5, 1.5, 7.3, 100.0, 10000.0, -0.5];
+        let expected = array![
+            2.252712651734206, 
+            0.5723649429247001, 
+            -0.12078223763524522, 
+            7.147892523022248, 
+            359.1342053695754, 
+            82099.71749644238, 
+            1.2655121234846454
+        ];
+
+        for (x, y) in x.iter().zip(expected.iter()) {
+            assert!((ln_gamma(*x) - y).abs() < 1e-13 * y.abs().max(1.0));
+        }
+
+        assert_eq!(ln_gamma(1.0), 0.0);
+        assert_eq!(ln_gamma(-2.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_created_correctly() {
+        let a = 0;
+        let b = 4;
+        let dist = DiscreteUniformDist::new(0, 4).unwrap(); // panics if creation fails
+        
+        assert_eq!(dist.lower_bound(), a);
+        assert_eq!(dist.upper_bound(), b);
+        assert_eq!(dist.range(), b - a + 1);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_invalid_creation_fails() {
+        let dist = DiscreteUniformDist::new(4, 0);
+        assert!(dist.is_err());
+    }
+            
+    #[test]
+    fn discrete_uniform_dist_gives_correct_pmf_inrange() {
+        let lower_bound = 0;
+        let upper_bound = 4;
+
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        let mut values = Array::range(lower_bound as f64, upper_bound as f64 + 1.0, 1.0);
+        values.mapv_inplace(|n| { dist.pmf(n as i32) });
+
+        assert!(values.iter().all(|&p| p == 1.0 / dist.range() as f64));
+    }
+
+    #[test]
+    fn discrete_uniform_dist_gives_correct_pmf_outofrange() {
+        let lower_bound = 0;
+        let upper_bound = 4;
+
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        assert_eq!(dist.pmf(lower_bound - 1), 0.0);
+        assert_eq!(dist.pmf(upper_bound + 1), 0.0);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_correct_cdf_outofrange() {
+        let lower_bound = 0;
+        let upper_bound = 4;
+
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        assert_eq!(dist.cdf(lower_bound - 1), 0.0);
+        assert_eq!(dist.cdf(upper_bound + 1), 1.0);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_correct_cdf_withinrange() {
+        let lower_bound = 1;
+        let upper_bound = 5;
+
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        let values = Array::range(lower_bound as f64, upper_bound as f64 + 1.0, 1.0);
+        let correct_cdf = values.mapv(|n| n as f64 / dist.range() as f64);
+        let cdf = values.mapv(|n| dist.cdf(n as i32));
+
+        assert_eq!(cdf, correct_cdf);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_correct_inverval_cdf() {
+        let lower_bound = 1;
+        let upper_bound = 5;
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        
+        let diff = dist.interval_cdf(lower_bound + 1, upper_bound - 1) - (dist.range() - 2) as f64 / dist.range() as f64;
+        assert!(diff < 1e-10);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_mean_calculated_correctly() {
+        let lower_bound = 1;
+        let upper_bound = 5;
+
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        assert_eq!(dist.mean(), (upper_bound + lower_bound) as f64 / 2.0);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_samples_within_support() {
+        let lower_bound = 1;
+        let upper_bound = 6;
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let samples = dist.sample_n(&mut rng, 1000);
+
+        assert!(samples.iter().all(|&k| k >= lower_bound && k <= upper_bound));
+
+        // every value should show up at least once in 1000 rolls
+        for k in lower_bound..(upper_bound + 1) {
+            assert!(samples.iter().any(|&s| s == k));
+        }
+    }
+
+    #[test]
+    fn discrete_uniform_dist_sample_mean_close() {
+        let dist = DiscreteUniformDist::new(1, 6).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let samples = dist.sample_n(&mut rng, 10000).mapv(|k| k as f64);
+
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn discrete_uniform_dist_ppf_inverts_cdf() {
+        let lower_bound = 1;
+        let upper_bound = 5;
+        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+
+        for k in lower_bound..(upper_bound + 1) {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(lower_bound));
+        assert_eq!(dist.ppf(0.5), Some(3));
+        assert_eq!(dist.ppf(-0.5), None);
+    }
+
+
+    #[test]
+    fn discrete_uniform_dist_fit_correct() {
+        let fit = DiscreteUniformDist::fit(&array![2.0, 5.0, 3.0, 4.0, 2.0]).unwrap();
+
+        assert_eq!(fit.dist(), &DiscreteUniformDist::new(2, 5).unwrap());
+        assert_eq!(fit.std_errors().len(), 2);
+
+        assert_eq!(DiscreteUniformDist::fit(&array![]), Err(PensivError::EmptyData));
+        assert_eq!(DiscreteUniformDist::fit(&array![2.0, 2.5]), Err(PensivError::InvalidInput("data must be integers representable by an i32")));
+    }
+
+    #[test]
+    fn discrete_uniform_dist_higher_moments_correct() {
+        let dist = DiscreteUniformDist::new(1, 6).unwrap();
+
+        assert_eq!(dist.median(), Some(3));
+        assert_eq!(dist.mode(), Some(1));
+        assert!((dist.variance() - 35.0 / 12.0).abs() < 1e-15);
+        assert!((dist.std() - (35.0_f64 / 12.0).sqrt()).abs() < 1e-15);
+        assert_eq!(dist.skewness(), 0.0);
+        assert!((dist.kurtosis() + 1.2685714285714286).abs() < 1e-15);
+        assert!((dist.entropy() - 6_f64.ln()).abs() < 1e-15);
+
+        assert_eq!(dist.mgf(0.0), Some(1.0));
+        assert!((dist.mgf(0.5).unwrap() - 8.084_296_525_386_504).abs() < 1e-13);
+        assert!((dist.cf(0.5) - Complex::new(-0.11977681321138437, 0.661_213_514_834_276_6)).norm() < 1e-15);
+        assert_eq!(dist.mgf(1000.0), None);
+
+        let point = DiscreteUniformDist::new(3, 3).unwrap();
+        assert!(point.skewness().is_nan());
+        assert!(point.kurtosis().is_nan());
+        assert_eq!(point.entropy(), 0.0);
+        assert!((point.cf(2.0 * PI) - Complex::new(1.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn bernoulli_dist_created_correctly() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.p_success(), p);
+        assert_eq!(dist.p_failure(), 1.0 - p);
+    }
+
+    #[test]
+    fn bernoulli_dist_invalid_creation_fails() {
+        let p = -0.5;
+        let dist = BernoulliDist::new(p);
+
+        let expected = PensivError::InvalidParameter { name: "p_success", value: -0.5, expected: "a probability in [0, 1]" };
+        assert_eq!(dist, Err(expected));
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_pmf_inrange() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.pmf(0), dist.p_failure());
+        assert_eq!(dist.pmf(1), dist.p_success());
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_pmf_outofrange() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.pmf(-1), 0.0);
+        assert_eq!(dist.pmf(2), 0.0);
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_cdf_inrange() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.cdf(0), dist.p_failure());
+        assert_eq!(dist.cdf(1), 1.0);
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_cdf_outofrange() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.cdf(-1), 0.0);
+        assert_eq!(dist.cdf(2), 1.0);
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_interval_cdf() {
+        let p = 0.5;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.interval_cdf(0, 1), 1.0);
+    }
+
+    #[test]
+    fn bernoulli_dist_mean_calculated_correctly() {
+        let p = 0.4;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.mean(), p);
+    }
+
+    #[test]
+    fn bernoulli_dist_variance_calculated_correctly() {
+        let p = 0.4;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.variance(), p * (1.0 - p));
+    }
+
+    #[test]
+    fn bernoulli_dist_std_dev_calculated_correctly() {
+        let p = 0.4;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        assert_eq!(dist.std(), (p * (1.0 - p)).sqrt());
+    }
+
+    #[test]
+    fn bernoulli_dist_sample_mean_close() {
+        let p = 0.3;
+        let dist = BernoulliDist::new(p).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(2);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|&k| k == 0 || k == 1));
+        assert!((samples.mapv(|k| k as f64).mean().unwrap() - p).abs() < 0.02);
+    }
+
+    #[test]
+    fn bernoulli_dist_correct_ppf() {
+        let dist = BernoulliDist::new(0.4).unwrap();
+
+        assert_eq!(dist.ppf(0.0), Some(0));
+        assert_eq!(dist.ppf(0.6), Some(0));
+        assert_eq!(dist.ppf(0.7), Some(1));
+        assert_eq!(dist.ppf(1.0), Some(1));
+        assert_eq!(dist.ppf(f64::NAN), None);
+    }
+
+
+    #[test]
+    fn bernoulli_dist_correct_log_pmf() {
+        let dist = BernoulliDist::new(1e-20).unwrap();
+
+        // 1 - p rounds to 1.0, but its log shouldn't round to 0.0
+        assert_eq!(dist.log_pmf(0), -1e-20);
+        assert_eq!(dist.log_pmf(1), 1e-20_f64.ln());
+        assert_eq!(dist.log_pmf(2), f64::NEG_INFINITY);
+    }
+
+
+    #[test]
+    fn bernoulli_dist_fit_correct() {
+        let fit = BernoulliDist::fit(&array![1.0, 0.0, 1.0, 1.0]).unwrap();
+
+        assert_eq!(fit.dist().p_success(), 0.75);
+        assert_eq!(fit.std_errors(), &array![(0.75 * 0.25 / 4.0_f64).sqrt()]);
+
+        assert_eq!(BernoulliDist::fit(&array![1.0, 2.0]), Err(PensivError::InvalidInput("data must be 0 or 1")));
+        assert_eq!(BernoulliDist::fit(&array![]), Err(PensivError::EmptyData));
+    }
+
+    #[test]
+    fn bernoulli_dist_higher_moments_correct() {
+        let dist = BernoulliDist::new(0.3).unwrap();
+
+        assert_eq!(dist.median(), Some(0));
+        assert_eq!(dist.mode(), Some(0));
+        assert_eq!(BernoulliDist::new(0.7).unwrap().mode(), Some(1));
+        assert!((dist.skewness() - 0.4 / 0.21_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - (1.0 - 6.0 * 0.21) / 0.21).abs() < 1e-14);
+        assert!((dist.entropy() - 0.610_864_302_054_893_5).abs() < 1e-15);
+
+        assert!((dist.mgf(1.0).unwrap() - (0.7 + 0.3 * 1_f64.exp())).abs() < 1e-15);
+        assert!((dist.cf(1.0) - Complex::new(0.7 + 0.3 * 1_f64.cos(), 0.3 * 1_f64.sin())).norm() < 1e-15);
+
+        let certain = BernoulliDist::new(1.0).unwrap();
+        assert!(certain.skewness().is_nan());
+        assert!(certain.kurtosis().is_nan());
+        assert_eq!(certain.entropy(), 0.0);
+    }
+
+    #[test]
+    fn binom_dist_created_correctly() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        assert_eq!(dist.trials(), n);
+        assert_eq!(dist.p_success(), p);
+        assert_eq!(dist.p_failure(), 1.0 - p);
+    }
+
+    #[test]
+    fn binom_dist_invalid_p_creation_fails() {
+        let n = 4;
+        let p = -0.5;
+
+        let dist = BinomDist::new(n, p);
+
+        assert!(dist.is_err());
+    }
+
+    #[test]
+    fn binom_dist_invalid_trials_creation_fails() {
+        let n = -1;
+        let p = 0.5;
+
+        let dist = BinomDist::new(n, p);
+
+        assert!(dist.is_err());
+    }
+
+    #[test]
+    fn binom_dist_correct_pmf_inrange() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+        let pmf = Array::range(0.0, n as f64 + 1.0, 1.0).mapv(|k| dist.pmf(k as i32));
+
+        assert!(pmf.all_close(&array![0.1296, 0.3456, 0.3456, 0.1536, 0.0256], 1e-10));
+    }
+
+    #[test]
+    fn binom_dist_correct_pmf_outofrange() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        assert_eq!(dist.pmf(-1), 0.0);
+        assert_eq!(dist.pmf(5), 0.0);
+    }
+
+    #[test]
+    fn binom_dist_correct_cdf_inrange() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        let v = 4.0;
+        let cdf = Array::range(0.0, v, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert_eq!(dist.cdf(v as i32 - 1), cdf);
+    }
+
+    #[test]
+    fn binom_dist_correct_cdf_outofrange() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+        
+        assert!((dist.cdf(-1) - 0.0).abs() < 1e-10);
+        assert!((dist.cdf(n + 1) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn binom_dist_correct_interval_cdf() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        let a = 2.0;
+        let b = 3.0;
+        let cdf = Array::range(a, b + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert_eq!(dist.interval_cdf(a as i32, b as i32), cdf);
+    }
+
+    #[test]
+    fn binom_dist_mean_calculated_correctly() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        assert_eq!(dist.mean(), n as f64 * p);
+    }
+
+    #[test]
+    fn binom_dist_variance_calculated_correctly() {
+        let n = 4;
+        let p = 0.4;
+
+        let dist = BinomDist::new(n, p).unwrap();
+
+        assert_eq!(dist.variance(), n as f64 * p * (1.0 - p));
+    }
+
+    #[test]
+    fn binom_dist_large_trials_correct_pmf() {
+        // used to overflow once trials > 12
+        let dist = BinomDist::new(5000, 0.3).unwrap();
+
+        // reference value computed with mpmath
+        assert!((dist.pmf(1500) - 0.012310854166288873).abs() < 1e-13);
+        assert!((dist.cdf(5000) - 1.0).abs() < 1e-10);
+        assert_eq!(dist.pmf(5001), 0.0);
+    }
+
+    #[test]
+    fn binom_dist_degenerate_p_correct_pmf() {
+        let never = BinomDist::new(10, 0.0).unwrap();
+        let always = BinomDist::new(10, 1.0).unwrap();
+
+        assert_eq!(never.pmf(0), 1.0);
+        assert_eq!(never.pmf(1), 0.0);
+        assert_eq!(always.pmf(10), 1.0);
+        assert_eq!(always.pmf(9), 0.0);
+    }
+
+    #[test]
+    fn binom_dist_samples_within_support() {
+        let n = 10;
+        let dist = BinomDist::new(n, 0.7).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let samples = dist.sample_n(&mut rng, 1000);
+
+        assert!(samples.iter().all(|&k| k >= 0 && k <= n));
+    }
+
+    #[test]
+    fn binom_dist_sample_mean_close() {
+        let n = 10;
+        let p = 0.7;
+        let dist = BinomDist::new(n, p).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(4);
+        let samples = dist.sample_n(&mut rng, 10000).mapv(|k| k as f64);
+
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.06);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.1);
+    }
+
+    #[test]
+    fn binom_dist_large_trials_sample_mean_close() {
+        // large enough that (1 - p)^n underflows, so sampling has to be split into chunks
+        let n = 100000;
+        let p = 0.5;
+        let dist = BinomDist::new(n, p).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let samples = dist.sample_n(&mut rng, 200).mapv(|k| k as f64);
+
+        // standard error of the sample mean is sqrt(np(1 - p) / 200) ~= 11.2
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 50.0);
+    }
+
+    #[test]
+    fn binom_dist_ppf_inverts_cdf() {
+        let n = 10;
+        let dist = BinomDist::new(n, 0.3).unwrap();
+
+        for k in 0..(n + 1) {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(0));
+        assert_eq!(dist.ppf(1.0), Some(n));
+        assert_eq!(dist.ppf(1.5), None);
+    }
+
+
+    #[test]
+    fn binom_dist_log_pmf_finite_where_pmf_underflows() {
+        let dist = BinomDist::new(5000, 0.3).unwrap();
+
+        assert_eq!(dist.pmf(0), 0.0);
+        assert!((dist.log_pmf(0) - 5000.0 * 0.7_f64.ln()).abs() < 1e-9);
+        assert!((dist.log_pmf(1500) - dist.pmf(1500).ln()).abs() < 1e-12);
+        assert_eq!(dist.log_pmf(-1), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn binom_dist_correct_log_likelihood() {
+        let dist = BinomDist::new(4, 0.4).unwrap();
+        let data = array![0, 1, 1, 2, 4];
+
+        let likelihood: f64 = data.iter().map(|&k| dist.pmf(k)).product();
+        assert!((dist.log_likelihood(&data) - likelihood.ln()).abs() < 1e-12);
+
+        // one value outside of the support makes the whole data set impossible
+        assert_eq!(dist.log_likelihood(&array![0, 1, 5]), f64::NEG_INFINITY);
+        assert_eq!(dist.log_likelihood(&array![]), 0.0);
+    }
+
+
+    #[test]
+    fn binom_dist_fit_correct() {
+        let fit = BinomDist::fit(&array![3.0, 5.0, 4.0, 4.0], 10).unwrap();
+
+        assert_eq!(fit.dist().trials(), 10);
+        assert!((fit.dist().p_success() - 0.4).abs() < 1e-15);
+        assert!((fit.std_errors()[1] - (0.4 * 0.6 / 40.0_f64).sqrt()).abs() < 1e-15);
+        assert_eq!(fit.std_errors()[0], 0.0);
+
+        assert_eq!(BinomDist::fit(&array![3.0, 11.0], 10), Err(PensivError::InvalidInput("data must be integers between 0 & the number of trials")));
+        let invalid_trials = PensivError::InvalidParameter { name: "trials", value: 0.0, expected: "a positive integer" };
+        assert_eq!(BinomDist::fit(&array![3.0, 5.0], 0), Err(invalid_trials));
+    }
+
+    #[test]
+    fn binom_dist_fit_recovers_parameters() {
+        let dist = BinomDist::new(20, 0.3).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(26);
+        let data = dist.sample_n(&mut rng, 5000).mapv(|k| k as f64);
+        let fit = BinomDist::fit(&data, 20).unwrap();
+
+        assert!((fit.dist().p_success() - 0.3).abs() < 4.0 * fit.std_errors()[1]);
+    }
+
+    #[test]
+    fn binom_dist_higher_moments_correct() {
+        let dist = BinomDist::new(10, 0.3).unwrap();
+
+        assert_eq!(dist.median(), Some(3));
+        assert_eq!(dist.mode(), Some(3));
+        assert!((dist.skewness() - 0.4 / 2.1_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - (1.0 - 6.0 * 0.21) / 2.1).abs() < 1e-15);
+        assert!((dist.entropy() - 1.779_078_784_090_063).abs() < 1e-14);
+
+        assert!((dist.mgf(0.5).unwrap() - 5.919_494_989_185_635).abs() < 1e-13);
+        assert!((dist.cf(0.5) - Complex::new(0.067_985_199_970_317_63, 0.765_019_245_056_412_7)).norm() < 1e-14);
+
+        // (n + 1)p = 3 is an integer, so 2 & 3 are both modes
+        let tied = BinomDist::new(5, 0.5).unwrap();
+        assert_eq!(tied.mode(), Some(2));
+        assert!((tied.pmf(2) - tied.pmf(3)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn geometric_dist_valid_created_correctly() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        assert_eq!(dist.p_success(), p);
+        assert_eq!(dist.p_failure(), 1.0 - p);
+    }
+
+    #[test]
+    fn geometric_dist_invalid_p_creation_fails() {
+        let p = -0.4;
+        let dist = GeometricDist::new(p);
+
+        assert!(dist.is_err());
+    }
+
+    #[test]
+    fn geometric_dist_correct_pmf_inrange() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        let pmfs = Array::range(1.0, 4.0, 1.0).mapv(|k| dist.pmf(k as i32));
+        assert!(pmfs.all_close(&array![0.4, 0.24, 0.144], 1e-10));
+    }
+
+    #[test]
+    fn geometric_dist_correct_pmf_outofrange() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        assert_eq!(dist.pmf(0), 0.0);
+    }
+
+    #[test]
+    fn geometric_dist_correct_cdf_inrange() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        let n = 3.0;
+        let cdf = Array::range(1.0, n + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert_eq!(dist.cdf(n as i32), cdf);
+    }
+
+    #[test]
+    fn geometric_dist_correct_cdf_outofrange() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        assert_eq!(dist.cdf(-1), 0.0);
+    }
+
+    #[test]
+    fn geometric_dist_correct_interval_cdf() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        let a = 3.0;
+        let b = 5.0;
+        let cdf = Array::range(a, b + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        let diff = dist.interval_cdf(a as i32, b as i32) - cdf;
+
+        assert!(diff.abs() < 1e-10);
+    }
+
+    #[test]
+    fn geometric_dist_mean_calculated_correctly() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        assert_eq!(dist.mean(), 1.0 / p);
+    }
+
+    #[test]
+    fn geometric_dist_variance_calculated_correctly() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        let diff = dist.variance() - (1.0 - p) / p.powi(2);
+        assert!(diff < 1e-10);
+    }
+
+    #[test]
+    fn geometric_dist_sample_mean_close() {
+        let p = 0.4;
+        let dist = GeometricDist::new(p).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(6);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|&k| k >= 1));
+        assert!((samples.mapv(|k| k as f64).mean().unwrap() - dist.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn geometric_dist_certain_success_samples_one() {
+        let dist = GeometricDist::new(1.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        assert!(dist.sample_n(&mut rng, 100).iter().all(|&k| k == 1));
+    }
+
+    #[test]
+    fn geometric_dist_ppf_inverts_cdf() {
+        let dist = GeometricDist::new(0.4).unwrap();
+
+        for k in 1..30 {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.5), Some(2));
+        assert_eq!(dist.ppf(0.0), Some(1));
+        assert_eq!(dist.ppf(1.0), None);
+    }
+
+
+    #[test]
+    fn geometric_dist_correct_log_pmf() {
+        let dist = GeometricDist::new(0.4).unwrap();
+
+        for k in 1..10 {
+            assert!((dist.log_pmf(k) - dist.pmf(k).ln()).abs() < 1e-12);
+        }
+
+        assert_eq!(dist.log_pmf(0), f64::NEG_INFINITY);
+        assert_eq!(GeometricDist::new(1.0).unwrap().log_pmf(1), 0.0);
+    }
+
+
+    #[test]
+    fn geometric_dist_fit_correct() {
+        let fit = GeometricDist::fit(&array![1.0, 3.0, 2.0, 2.0]).unwrap();
+
+        assert_eq!(fit.dist().p_success(), 0.5);
+        assert_eq!(fit.std_errors(), &array![0.5 * (0.5 / 4.0_f64).sqrt()]);
+
+        assert_eq!(GeometricDist::fit(&array![0.0, 1.0]), Err(PensivError::InvalidInput("data must be positive integers")));
+    }
+
+    #[test]
+    fn geometric_dist_higher_moments_correct() {
+        let dist = GeometricDist::new(0.4).unwrap();
+
+        assert_eq!(dist.mode(), Some(1));
+        assert!((dist.skewness() - 1.6 / 0.6_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - (6.0 + 0.16 / 0.6)).abs() < 1e-15);
+        assert!((dist.entropy() - 1.682_529_167_523_141).abs() < 1e-14);
+
+        assert!((dist.mgf(0.2).unwrap() - 1.828_732_331_284_902).abs() < 1e-14);
+        assert_eq!(dist.mgf(1.0), None);
+        assert!((dist.cf(0.2) - Complex::new(0.826_590_599_335_488_6, 0.432_077_459_018_419_7)).norm() < 1e-14);
+    }
+
+
+    #[test]
+    fn poisson_dist_valid_created_correctly() {
+        let rate = 3.5;
+        let dist = PoissonDist::new(rate).unwrap();
+
+        assert_eq!(dist.rate(), rate);
+    }
+
+    #[test]
+    fn poisson_dist_invalid_rate_creation_fails() {
+        assert!(PoissonDist::new(-3.5).is_err());
+        assert!(PoissonDist::new(0.0).is_err());
+        assert!(PoissonDist::new(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn poisson_dist_correct_pmf_inrange() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        // reference values computed with mpmath
+        let pmfs = array![0.0, 1.0, 3.0, 7.0].mapv(|k| dist.pmf(k as i32));
+        let expected = array![0.0301973834223185, 0.10569084197811475, 0.21578546903865095, 0.038549174937634];
+
+        assert_all_close(&pmfs, &expected, 1e-15);
+    }
+
+    #[test]
+    fn poisson_dist_correct_pmf_outofrange() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        assert_eq!(dist.pmf(-1), 0.0);
+    }
+
+    #[test]
+    fn poisson_dist_correct_cdf_inrange() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        let cdfs = array![0.0, 1.0, 3.0, 7.0].mapv(|k| dist.cdf(k as i32));
+        let expected = array![0.0301973834223185, 0.13588822540043324, 0.536632667900785, 0.9732610779086803];
+
+        assert_all_close(&cdfs, &expected, 1e-14);
+
+        let n = 6.0;
+        let cdf = Array::range(0.0, n + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert!((dist.cdf(n as i32) - cdf).abs() < 1e-14);
+    }
+
+    #[test]
+    fn poisson_dist_correct_cdf_outofrange() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        assert_eq!(dist.cdf(-1), 0.0);
+    }
+
+    #[test]
+    fn poisson_dist_large_rate_correct_pmf_cdf() {
+        // rate^k / k! overflows long before this if computed directly
+        let dist = PoissonDist::new(1000.0).unwrap();
+
+        assert!((dist.pmf(1000) - 0.012614611348721499).abs() < 1e-14);
+        assert!((dist.cdf(900) - 0.0006977673277963068).abs() < 1e-15);
+    }
+
+    #[test]
+    fn poisson_dist_mean_variance_calculated_correctly() {
+        let rate = 3.5;
+        let dist = PoissonDist::new(rate).unwrap();
+
+        assert_eq!(dist.mean(), rate);
+        assert_eq!(dist.variance(), rate);
+    }
+
+    #[test]
+    fn poisson_dist_sample_mean_close() {
+        let mut rng = StdRng::seed_from_u64(20);
+
+        // rates on either side of 10 take different sampling paths
+        for &rate in [3.5, 250.0].iter() {
+            let dist = PoissonDist::new(rate).unwrap();
+            let samples = dist.sample_n(&mut rng, 10000).mapv(|k| k as f64);
+
+            assert!(samples.iter().all(|&k| k >= 0.0));
+            assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05 * rate.sqrt());
+            assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.1 * rate);
+        }
+    }
+
+    #[test]
+    fn poisson_dist_ppf_inverts_cdf() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        for k in 0..20 {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(0));
+        assert_eq!(dist.ppf(1.5), None);
+    }
+
+
+    #[test]
+    fn poisson_dist_log_pmf_finite_where_pmf_underflows() {
+        let dist = PoissonDist::new(1000.0).unwrap();
+
+        assert_eq!(dist.pmf(0), 0.0);
+        assert_eq!(dist.log_pmf(0), -1000.0);
+        assert!((dist.log_pmf(1000) - dist.pmf(1000).ln()).abs() < 1e-12);
+        assert_eq!(dist.log_pmf(-1), f64::NEG_INFINITY);
+    }
+
+
+    #[test]
+    fn poisson_dist_fit_correct() {
+        let fit = PoissonDist::fit(&array![2.0, 4.0, 3.0, 3.0]).unwrap();
+
+        assert_eq!(fit.dist().rate(), 3.0);
+        assert_eq!(fit.std_errors(), &array![(3.0 / 4.0_f64).sqrt()]);
+
+        assert_eq!(PoissonDist::fit(&array![0.0, 0.0]), Err(PensivError::InvalidInput("data must not all be 0")));
+        assert_eq!(PoissonDist::fit(&array![-1.0, 2.0]), Err(PensivError::InvalidInput("data must be non-negative integers")));
+    }
+
+    #[test]
+    fn poisson_dist_higher_moments_correct() {
+        let dist = PoissonDist::new(3.5).unwrap();
+
+        assert_eq!(dist.mode(), Some(3));
+        assert_eq!(PoissonDist::new(4.0).unwrap().mode(), Some(3));
+        assert_eq!(PoissonDist::new(0.5).unwrap().mode(), Some(0));
+        assert!((dist.skewness() - 1.0 / 3.5_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - 1.0 / 3.5).abs() < 1e-15);
+        assert!((dist.entropy() - 2.015_172_522_512_972).abs() < 1e-13);
+
+        assert!((dist.mgf(0.2).unwrap() - 2.1703960307717894).abs() < 1e-14);
+        assert!((dist.cf(0.2) - Complex::new(0.716_090_730_617_020_7, 0.597_475_987_225_653_9)).norm() < 1e-14);
+    }
+
+    #[test]
+    fn negative_binomial_dist_valid_created_correctly() {
+        let r = 2.5;
+        let p = 0.4;
+        let dist = NegativeBinomialDist::new(r, p).unwrap();
+
+        assert_eq!(dist.successes(), r);
+        assert_eq!(dist.p_success(), p);
+        assert_eq!(dist.p_failure(), 1.0 - p);
+    }
+
+    #[test]
+    fn negative_binomial_dist_invalid_creation_fails() {
+        assert!(NegativeBinomialDist::new(2.5, 0.0).is_err());
+        assert!(NegativeBinomialDist::new(2.5, 1.4).is_err());
+        assert!(NegativeBinomialDist::new(0.0, 0.4).is_err());
+        assert!(NegativeBinomialDist::new(f64::NAN, 0.4).is_err());
+    }
+
+    #[test]
+    fn negative_binomial_dist_correct_pmf_inrange() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        // reference values computed with mpmath
+        let pmfs = array![0.0, 1.0, 4.0, 10.0].mapv(|k| dist.pmf(k as i32));
+        let expected = array![0.10119288512538814, 0.1517893276880822, 0.1183387545988211, 0.01735749289001471];
+
+        assert_all_close(&pmfs, &expected, 1e-15);
+    }
+
+    #[test]
+    fn negative_binomial_dist_correct_pmf_outofrange() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        assert_eq!(dist.pmf(-1), 0.0);
+    }
+
+    #[test]
+    fn negative_binomial_dist_integer_successes_matches_ln_choose() {
+        let r = 3;
+        let p = 0.3;
+        let dist = NegativeBinomialDist::new(r as f64, p).unwrap();
+
+        for k in 0..20 {
+            let expected = choose(k + r - 1, k).unwrap() as f64 * p.powi(r as i32) * (1.0 - p).powi(k as i32);
+            assert!((dist.pmf(k as i32) - expected).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn negative_binomial_dist_correct_cdf_inrange() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        let cdfs = array![0.0, 1.0, 4.0, 10.0].mapv(|k| dist.cdf(k as i32));
+        let expected = array![0.10119288512538814, 0.25298221281347033, 0.6741406761500155, 0.9645608619098024];
+
+        assert_all_close(&cdfs, &expected, 1e-14);
+    }
+
+    #[test]
+    fn negative_binomial_dist_correct_cdf_outofrange() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        assert_eq!(dist.cdf(-1), 0.0);
+    }
+
+    #[test]
+    fn negative_binomial_dist_mean_variance_calculated_correctly() {
+        let r = 2.5;
+        let p = 0.4;
+        let dist = NegativeBinomialDist::new(r, p).unwrap();
+
+        assert!((dist.mean() - r * (1.0 - p) / p).abs() < 1e-14);
+        assert!((dist.variance() - r * (1.0 - p) / p.powi(2)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn negative_binomial_dist_certain_success_samples_zero() {
+        let dist = NegativeBinomialDist::new(2.5, 1.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(21);
+
+        assert_eq!(dist.pmf(0), 1.0);
+        assert!(dist.sample_n(&mut rng, 100).iter().all(|&k| k == 0));
+    }
+
+    #[test]
+    fn negative_binomial_dist_sample_mean_close() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(22);
+        let samples = dist.sample_n(&mut rng, 10000).mapv(|k| k as f64);
+
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.1);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.5);
+    }
+
+    #[test]
+    fn negative_binomial_dist_ppf_inverts_cdf() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        for k in 0..30 {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(0));
+    }
+
+
+    #[test]
+    fn negative_binomial_dist_correct_log_pmf() {
+        let dist = NegativeBinomialDist::new(2.5, 0.4).unwrap();
+
+        for k in 0..20 {
+            assert!((dist.log_pmf(k) - dist.pmf(k).ln()).abs() < 1e-12);
+        }
+
+        assert_eq!(dist.log_pmf(-1), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn negative_binomial_dist_higher_moments_correct() {
+        let dist = NegativeBinomialDist::new(3.0, 0.4).unwrap();
+
+        // (r - 1)(1 - p) / p = 3 is an integer, so 2 & 3 are both modes
+        assert_eq!(dist.mode(), Some(2));
+        assert!((dist.pmf(2) - dist.pmf(3)).abs() < 1e-15);
+        assert_eq!(NegativeBinomialDist::new(0.5, 0.4).unwrap().mode(), Some(0));
+
+        assert!((dist.skewness() - 1.1925695879998878).abs() < 1e-14);
+        assert!((dist.kurtosis() - (2.0 + 0.16 / 1.8)).abs() < 1e-14);
+
+        assert!((dist.mgf(0.2).unwrap() - 3.3564002148016905).abs() < 1e-13);
+        assert_eq!(dist.mgf(1.0), None);
+        assert!((dist.cf(0.2) - Complex::new(0.538_565_341_657_449, 0.606_894_419_725_088_6)).norm() < 1e-14);
+    }
+
+    #[test]
+    fn negative_binomial_dist_fit_correct() {
+        let fit = NegativeBinomialDist::fit(&array![0.0, 4.0, 1.0, 7.0, 2.0, 0.0, 3.0, 1.0]).unwrap();
+
+        assert!((fit.dist().successes() - 1.5849369533611821).abs() < 1e-10);
+        assert!((fit.dist().p_success() - 0.4132889204272427).abs() < 1e-10);
+        assert_all_close(fit.std_errors(), &array![1.5307233705211323, 0.25049398701434605], 1e-10);
+
+        let underdispersed =
+            PensivError::InvalidInput("data must be overdispersed, i.e. have a variance above its mean");
+        assert_eq!(NegativeBinomialDist::fit(&array![2.0, 3.0, 2.0]), Err(underdispersed));
+
+        let not_counts = PensivError::InvalidInput("data must be non-negative integers");
+        assert_eq!(NegativeBinomialDist::fit(&array![2.0, 3.5]), Err(not_counts));
+    }
+
+    #[test]
+    fn negative_binomial_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(28);
+
+        for &(successes, p_success) in [(0.5, 0.2), (2.5, 0.4), (20.0, 0.7)].iter() {
+            let dist = NegativeBinomialDist::new(successes, p_success).unwrap();
+            let fit = NegativeBinomialDist::fit(&dist.sample_n(&mut rng, 5000).mapv(|k| k as f64)).unwrap();
+
+            assert!((fit.dist().successes() - successes).abs() < 4.0 * fit.std_errors()[0]);
+            assert!((fit.dist().p_success() - p_success).abs() < 4.0 * fit.std_errors()[1]);
+        }
+    }
+
+    #[test]
+    fn hypergeometric_dist_valid_created_correctly() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        assert_eq!(dist.population(), 52);
+        assert_eq!(dist.successes(), 13);
+        assert_eq!(dist.draws(), 5);
+        assert_eq!(dist.min_successes(), 0);
+        assert_eq!(dist.max_successes(), 5);
+
+        // drawing more than the number of failures forces some successes
+        let dist = HypergeometricDist::new(10, 7, 5).unwrap();
+
+        assert_eq!(dist.min_successes(), 2);
+        assert_eq!(dist.max_successes(), 5);
+    }
+
+    #[test]
+    fn hypergeometric_dist_invalid_creation_fails() {
+        assert!(HypergeometricDist::new(-1, 0, 0).is_err());
+        assert!(HypergeometricDist::new(52, 53, 5).is_err());
+        assert!(HypergeometricDist::new(52, 13, 60).is_err());
+        assert!(HypergeometricDist::new(52, -13, 5).is_err());
+    }
+
+    #[test]
+    fn hypergeometric_dist_correct_pmf_inrange() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        // reference values computed with mpmath
+        let pmfs = Array::range(0.0, 6.0, 1.0).mapv(|k| dist.pmf(k as i32));
+        let expected = array![
+            0.22153361344537814, 
+            0.41141956782713085, 
+            0.2742797118847539, 
+            0.08154261704681873, 
+            0.010729291716686675, 
+            0.0004951980792316927
+        ];
+
+        assert_all_close(&pmfs, &expected, 1e-14);
+    }
+
+    #[test]
+    fn hypergeometric_dist_correct_pmf_outofrange() {
+        let dist = HypergeometricDist::new(10, 7, 5).unwrap();
+
+        assert_eq!(dist.pmf(-1), 0.0);
+        assert_eq!(dist.pmf(1), 0.0);
+        assert_eq!(dist.pmf(6), 0.0);
+    }
+
+    #[test]
+    fn hypergeometric_dist_large_population_correct_pmf() {
+        let dist = HypergeometricDist::new(10000, 4000, 3000).unwrap();
+
+        // the log-space binomial coefficients are in the thousands, so some relative precision is lost when they cancel
+        assert!((dist.pmf(1200) / 0.01776853753748202 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hypergeometric_dist_correct_cdf() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        let v = 3.0;
+        let cdf = Array::range(0.0, v + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert_eq!(dist.cdf(v as i32), cdf);
+        assert_eq!(dist.cdf(-1), 0.0);
+        assert!((dist.cdf(6) - 1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn hypergeometric_dist_correct_interval_cdf() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        let a = 1.0;
+        let b = 3.0;
+        let cdf = Array::range(a, b + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+
+        assert_eq!(dist.interval_cdf(a as i32, b as i32), cdf);
+    }
+
+    #[test]
+    fn hypergeometric_dist_mean_variance_calculated_correctly() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        assert_eq!(dist.mean(), 5.0 * 13.0 / 52.0);
+        assert!((dist.variance() - 5.0 * 0.25 * 0.75 * 47.0 / 51.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn hypergeometric_dist_sample_mean_close() {
+        let dist = HypergeometricDist::new(52, 13, 5).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(23);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|k| (0..=5).contains(k)));
+
+        let samples = samples.mapv(|k| k as f64);
+
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.03);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.05);
+    }
+
+    #[test]
+    fn hypergeometric_dist_ppf_inverts_cdf() {
+        let dist = HypergeometricDist::new(10, 7, 5).unwrap();
+
+        for k in 2..6 {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(2));
+        assert_eq!(dist.ppf(1.0), Some(5));
+    }
+
+
+    #[test]
+    fn hypergeometric_dist_correct_log_pmf() {
+        let dist = HypergeometricDist::new(10, 7, 5).unwrap();
+
+        for k in 2..6 {
+            assert!((dist.log_pmf(k) - dist.pmf(k).ln()).abs() < 1e-12);
+        }
+
+        assert_eq!(dist.log_pmf(1), f64::NEG_INFINITY);
+        assert_eq!(dist.log_pmf(6), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn hypergeometric_dist_default_higher_moments_correct() {
+        let dist = HypergeometricDist::new(50, 10, 5).unwrap();
+
+        assert_eq!(dist.median(), Some(1));
+        assert_eq!(dist.mode(), Some(1));
+        assert!((dist.skewness() - 0.583_333_333_333_333_4).abs() < 1e-13);
+        assert!((dist.kurtosis() + 0.075_059_101_654_846_33).abs() < 1e-13);
+        assert!((dist.entropy() - 1.2143173878789477).abs() < 1e-13);
+        assert!((dist.mgf(0.5).unwrap() - 1.820_851_170_929_457).abs() < 1e-13);
+        assert!((dist.mgf(0.0).unwrap() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn hypergeometric_dist_fit_correct() {
+        let data = array![1.0, 2.0, 0.0, 1.0, 3.0];
+        let fit = HypergeometricDist::fit(&data, 52, 5).unwrap();
+
+        assert_eq!(fit.dist(), &HypergeometricDist::new(52, 15, 5).unwrap());
+        assert_all_close(fit.std_errors(), &array![0.0, 4.523143102911045, 0.0], 1e-12);
+
+        // neither neighbouring number of successes is more likely
+        let log_likelihood = |k| HypergeometricDist::new(52, k, 5).unwrap().log_likelihood(&data.mapv(|x| x as i32));
+        assert!(log_likelihood(15) > log_likelihood(14) && log_likelihood(15) > log_likelihood(16));
+
+        let invalid_draws = PensivError::InvalidParameter {
+            name: "draws",
+            value: 60.0,
+            expected: "a positive integer no greater than the population",
+        };
+        assert_eq!(HypergeometricDist::fit(&data, 52, 60), Err(invalid_draws));
+        assert_eq!(
+            HypergeometricDist::fit(&array![1.0, 6.0], 52, 5),
+            Err(PensivError::InvalidInput("data must be integers between 0 & the number of draws"))
+        );
+        assert_eq!(
+            HypergeometricDist::fit(&array![0.0, 5.0], 5, 5),
+            Err(PensivError::InvalidInput("data must be consistent with a single number of successes"))
+        );
+    }
+
+    #[test]
+    fn hypergeometric_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(29);
+
+        for &(successes, draws) in [(13, 5), (30, 20), (2, 40)].iter() {
+            let dist = HypergeometricDist::new(52, successes, draws).unwrap();
+            let fit = HypergeometricDist::fit(&dist.sample_n(&mut rng, 500).mapv(|k| k as f64), 52, draws).unwrap();
+
+            assert!(((fit.dist().successes() - successes) as f64).abs() <= (4.0 * fit.std_errors()[1]).max(1.0));
+        }
+    }
+
+    #[test]
+    fn categorical_dist_valid_created_correctly() {
+        let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+
+        assert_eq!(dist.categories(), 3);
+        assert_eq!(dist.probabilities(), &array![0.25, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn categorical_dist_invalid_creation_fails() {
+        assert_eq!(CategoricalDist::new(&array![]), Err(PensivError::EmptyData));
+        assert!(CategoricalDist::new(&array![1.0, -2.0, 1.0]).is_err());
+        assert!(CategoricalDist::new(&array![1.0, f64::NAN]).is_err());
+        assert!(CategoricalDist::new(&array![0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn categorical_dist_correct_pmf() {
+        let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+
+        let pmfs = Array::range(-1.0, 4.0, 1.0).mapv(|k| dist.pmf(k as i32));
+        assert_eq!(pmfs, array![0.0, 0.25, 0.5, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn categorical_dist_correct_cdf() {
+        let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+
+        let cdfs = Array::range(-1.0, 4.0, 1.0).mapv(|k| dist.cdf(k as i32));
+        assert_eq!(cdfs, array![0.0, 0.25, 0.75, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn categorical_dist_mean_variance_calculated_correctly() {
+        let dist = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+
+        assert_eq!(dist.mean(), 1.0);
+        assert_eq!(dist.variance(), 0.5);
+    }
+
+    #[test]
+    fn categorical_dist_sample_frequencies_close() {
+        let dist = CategoricalDist::new(&array![1.0, 0.0, 2.0, 1.0]).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(24);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        for k in 0..4 {
+            let frequency = samples.iter().filter(|&&s| s == k).count() as f64 / 10000.0;
+            assert!((frequency - dist.pmf(k)).abs() < 0.02);
+        }
+
+        // categories with no weight are never drawn
+        assert!(samples.iter().all(|&k| k != 1));
+    }
+
+    #[test]
+    fn categorical_dist_ppf_inverts_cdf() {
+        let dist = CategoricalDist::new(&array![0.0, 1.0, 2.0, 1.0]).unwrap();
+
+        for k in 1..4 {
+            assert_eq!(dist.ppf(dist.cdf(k)), Some(k));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(1));
+        assert_eq!(dist.ppf(0.5), Some(2));
+        assert_eq!(dist.ppf(-0.5), None);
+    }
+
+
+    #[test]
+    fn categorical_dist_fit_correct() {
+        let fit = CategoricalDist::fit(&array![0.0, 2.0, 2.0, 0.0]).unwrap();
+
+        assert_eq!(fit.dist().probabilities(), &array![0.5, 0.0, 0.5]);
+        assert_eq!(fit.std_errors(), &array![0.25, 0.0, 0.25]);
+
+        let not_categories = Err(PensivError::InvalidInput("data must be non-negative integers representable by an i32"));
+        assert_eq!(CategoricalDist::fit(&array![0.0, -1.0]), not_categories);
+        assert_eq!(CategoricalDist::fit(&array![0.0, 1.5]), not_categories);
+
+        let too_many = Err(PensivError::InvalidInput("data must have category indices less than MAX_FIT_CATEGORIES"));
+        assert_eq!(CategoricalDist::fit(&array![0.0, 2147483647.0]), too_many);
+        assert_eq!(CategoricalDist::fit(&array![0.0, MAX_FIT_CATEGORIES as f64]), too_many);
+        assert!(CategoricalDist::fit(&array![0.0, (MAX_FIT_CATEGORIES - 1) as f64]).is_ok());
+    }
+
+    #[test]
+    fn empirical_dist_valid_created_correctly() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.data(), data);
+        assert_eq!(EmpiricalDist::new(&array![3.0, 1.0, 2.0, 1.0]).unwrap().data(), array![1.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn empirical_dist_invalid_creation_fails() {
+        let data = array![1.0, 2.0, f64::NAN, 3.0];
+
+        match EmpiricalDist::new(&data) {
+            Ok(_) => panic!("found empirical dist instead of an error"),
+            Err(error) => assert_eq!(error, PensivError::NonFiniteData),
+        }
+    }
+
+    #[test]
+    fn empirical_dist_correct_pmf_indata() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        let mut counts = BTreeMap::new();
+        counts.insert(0, 1.0);
+        counts.insert(1, 2.0);
+        counts.insert(3, 3.0);
+
+        for (i, n) in counts.iter() {
+            let v = data[[*i]];
+
+            assert_eq!(dist.pmf(v), *n / data.len() as f64);
+        }
+    }
+
+    #[test]
+    fn empirical_dist_correct_pmf_notindata() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.pmf(4.0), 0.0);
+    }
+
+    #[test]
+    fn empirical_dist_correct_pmf_invalid_value() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.pmf(f64::NAN), 0.0);
+    }
+
+    #[test]
+    fn empirical_dist_correct_cdf_inrange() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        let mut counts = BTreeMap::new();
+        counts.insert(0, 1.0);
+        counts.insert(1, 3.0);
+        counts.insert(3, 6.0);
+
+        for (i, n) in counts.iter() {
+            let v = data[[*i]];
+
+            assert_eq!(dist.cdf(v), *n / data.len() as f64);
+        }
+    }
+
+    #[test]
+    fn empirical_dist_correct_cdf_outofrange() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.cdf(0.0), 0.0);
+        assert_eq!(dist.cdf(5.0), 1.0);
+    }
+
+    #[test]
+    fn empirical_dist_correct_interval_cdf() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.interval_cdf(2.0, 4.0), 5.0 / 6.0);
+    }
+
+    #[test]
+    fn empirical_dist_mean_calculated_correctly() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.mean(), 2.5);
+    }
+
+    #[test]
+    fn empirical_dist_variance_calculated_correctly() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        let diff = dist.variance() - 0.917;
+
+        assert!(diff < 1e-10);
+    }
+
+
+    #[test]
+    fn empirical_dist_variance_stable_with_large_offset() {
+        let data = array![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.variance(), 22.5);
+    }
+
+
+    #[test]
+    fn empirical_dist_insert_updates_stats() {
+        let mut dist = EmpiricalDist::new(&array![1.0, 2.0]).unwrap();
+
+        assert!(dist.insert(2.0));
+        assert!(dist.insert(5.0));
+        assert!(!dist.insert(f64::NAN));
+        assert!(!dist.insert(f64::INFINITY));
+
+        assert_eq!(dist.data(), array![1.0, 2.0, 2.0, 5.0]);
+        assert_eq!(dist.pmf(2.0), 0.5);
+        assert_eq!(dist.cdf(2.0), 0.75);
+        assert_eq!(dist.mean(), 2.5);
+        assert!((dist.variance() - 2.25).abs() < 1e-15);
+    }
+
+    #[test]
+    fn empirical_dist_remove_updates_stats() {
+        let mut dist = EmpiricalDist::new(&array![1.0, 2.0, 4.0, 2.0]).unwrap();
+
+        assert!(dist.remove(2.0));
+        assert!(!dist.remove(3.0));
+        assert!(!dist.remove(f64::NAN));
+
+        assert_eq!(dist.data(), array![1.0, 2.0, 4.0]);
+        assert_eq!(dist.pmf(2.0), 1.0 / 3.0);
+        assert!((dist.mean() - 7.0 / 3.0).abs() < 1e-15);
+        assert!((dist.variance() - 14.0 / 9.0).abs() < 1e-15);
+
+        assert!(dist.remove(1.0));
+        assert!(dist.remove(4.0));
+        assert!(dist.remove(2.0));
+        assert!(!dist.remove(2.0));
+
+        assert_eq!(dist.data().len(), 0);
+        assert_eq!(dist.mean(), 0.0);
+        assert_eq!(dist.variance(), 0.0);
+    }
+
+    #[test]
+    fn empirical_dist_queries_reflect_each_update() {
+        let mut dist = EmpiricalDist::new(&array![1.0, 3.0]).unwrap();
+
+        assert_eq!(dist.cdf(2.0), 0.5);
+        dist.insert(2.0);
+        assert_eq!(dist.cdf(2.0), 2.0 / 3.0);
+        assert_eq!(dist.ppf(0.5), Some(2.0));
+
+        dist.remove(1.0);
+        assert_eq!(dist.cdf(2.0), 0.5);
+        assert_eq!(dist.quantile(0.0), Some(2.0));
+
+        dist.merge(&EmpiricalDist::new(&array![0.0, 0.0]).unwrap());
+        assert_eq!(dist.cdf(2.0), 0.75);
+        assert_eq!(dist.cdf_many(&array![3.0, 0.0]), array![1.0, 0.5]);
+    }
+
+    #[test]
+    fn empirical_dist_sliding_window_matches_recomputation() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let stream = NormalDist::new(1e6, 3.0).unwrap().sample_n(&mut rng, 1000);
+        let window = 50;
+
+        let mut dist = EmpiricalDist::new(&stream.slice(s![..window]).to_owned()).unwrap();
+
+        for i in window..stream.len() {
+            dist.remove(stream[i - window]);
+            dist.insert(stream[i]);
+        }
+
+        let expected = EmpiricalDist::new(&stream.slice(s![(stream.len() - window)..]).to_owned()).unwrap();
+
+        assert_eq!(dist.data(), expected.data());
+        assert!((dist.mean() - expected.mean()).abs() < 1e-8);
+        assert!((dist.variance() / expected.variance() - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn empirical_dist_merge_combines_stats() {
+        let mut dist = EmpiricalDist::new(&array![1.0, 2.0, 2.0]).unwrap();
+        let other = EmpiricalDist::new(&array![2.0, 7.0]).unwrap();
+
+        dist.merge(&other);
+        dist.merge(&EmpiricalDist::new(&array![]).unwrap());
+
+        let expected = EmpiricalDist::new(&array![1.0, 2.0, 2.0, 2.0, 7.0]).unwrap();
+
+        assert_eq!(dist.data(), expected.data());
+        assert_eq!(dist.pmf(2.0), 0.6);
+        assert!((dist.mean() - expected.mean()).abs() < 1e-15);
+        assert!((dist.variance() - expected.variance()).abs() < 1e-14);
+        assert_eq!(other.data(), array![2.0, 7.0]);
+
+        let mut empty = EmpiricalDist::new(&array![]).unwrap();
+        empty.merge(&other);
+
+        assert_eq!(empty.mean(), 4.5);
+        assert_eq!(empty.variance(), 6.25);
+    }
+
+
+    #[test]
+    fn empirical_dist_cdf_many_matches_cdf() {
+        let mut rng = StdRng::seed_from_u64(12);
+        let data = PoissonDist::new(5.0).unwrap().sample_n(&mut rng, 200).mapv(|k| k as f64);
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        let mut values = NormalDist::new(5.0, 4.0).unwrap().sample_n(&mut rng, 100).to_vec();
+        values.extend_from_slice(&[2.0, 2.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY]);
+        let values = Array::from(values);
+
+        let cdfs = dist.cdf_many(&values);
+
+        for (value, cdf) in values.iter().zip(cdfs.iter()) {
+            assert_eq!(*cdf, dist.cdf(*value));
+        }
+    }
+
+    #[test]
+    fn empirical_dist_index_consistent_after_updates() {
+        let mut dist = EmpiricalDist::new(&array![3.0, 1.0, 2.0]).unwrap();
+
+        dist.insert(2.0);
+        dist.insert(0.5);
+        dist.insert(4.0);
+        dist.remove(3.0);
+        dist.remove(0.5);
+        dist.merge(&EmpiricalDist::new(&array![1.0, 5.0]).unwrap());
+
+        let expected = EmpiricalDist::new(&array![1.0, 1.0, 2.0, 2.0, 4.0, 5.0]).unwrap();
+        let values = Array::range(0.0, 6.0, 0.5);
+
+        assert_eq!(dist.cdf_many(&values), expected.cdf_many(&values));
+        assert_eq!(dist.interval_cdf(1.0, 2.0), 4.0 / 6.0);
+        assert_eq!(dist.interval_cdf(1.5, 4.5), 0.5);
+        assert_eq!(dist.interval_cdf(3.0, 1.0), 0.0);
+        assert_eq!(dist.ppf(0.5), Some(2.0));
+        assert_eq!(dist.quantile(0.5), Some(2.0));
+    }
+
+    #[test]
+    fn empirical_dist_samples_from_data() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(8);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|x| data.iter().any(|y| x == y)));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05);
+    }
+
+    #[test]
+    fn empirical_dist_empty_sample_is_nan() {
+        let data = Array::<f64, Ix1>::zeros(0);
+        let dist = EmpiricalDist::new(&data).unwrap();
+        let mut rng = StdRng::seed_from_u64(9);
+
+        assert!(dist.sample(&mut rng).is_nan());
+    }
+
+    #[test]
+    fn empirical_dist_ppf_inverts_cdf() {
+        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        for x in data.iter() {
+            assert_eq!(dist.ppf(dist.cdf(*x)), Some(*x));
+        }
+
+        assert_eq!(dist.ppf(0.0), Some(1.0));
+        assert_eq!(dist.ppf(0.4), Some(2.0));
+        assert_eq!(dist.ppf(2.0), None);
+    }
+
+    #[test]
+    fn empirical_dist_correct_quantile() {
+        let data = array![3.0, 1.0, 4.0, 2.0, 2.0, 3.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+
+        assert_eq!(dist.quantile(0.0), Some(1.0));
+        assert_eq!(dist.quantile(0.25), Some(2.0));
+        assert_eq!(dist.quantile(0.5), Some(2.5));
+        assert_eq!(dist.quantile(0.9), Some(3.5));
+        assert_eq!(dist.quantile(1.0), Some(4.0));
+        assert_eq!(dist.quantile(-0.1), None);
+    }
+
+    #[test]
+    fn empirical_dist_higher_moments_correct() {
+        let dist = EmpiricalDist::new(&array![5.0, 2.0, 1.0, 3.0, 2.0]).unwrap();
+
+        assert_eq!(DiscreteDist::median(&dist), Some(2.0));
+        assert_eq!(dist.mode(), Some(2.0));
+        assert!((dist.skewness() - 0.750_030_820_440_128_9).abs() < 1e-14);
+        assert!((dist.kurtosis() + 0.636_105_860_113_421_5).abs() < 1e-14);
+        assert!((dist.entropy() - 1.3321790402101224).abs() < 1e-14);
+        assert!((dist.mgf(0.5).unwrap() - 4.749_893_591_731_952).abs() < 1e-14);
+        assert!((dist.cf(0.0) - Complex::new(1.0, 0.0)).norm() < 1e-15);
+
+        // ties between the most frequent values go to the smallest
+        assert_eq!(EmpiricalDist::new(&array![3.0, 1.0, 3.0, 1.0]).unwrap().mode(), Some(1.0));
+
+        let empty = EmpiricalDist::new(&array![]).unwrap();
+        assert_eq!(empty.mode(), None);
+        assert!(empty.skewness().is_nan());
+        assert!(empty.entropy().is_nan());
+        assert_eq!(empty.mgf(1.0), None);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_valid_created_correctly() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.lower_bound(), a);
+        assert_eq!(dist.upper_bound(), b);
+        assert_eq!(dist.range(), b - a);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_invalid_bounds_creation_fails() {
+        let a = 2.0;
+        let b = 1.5;
+        let dist = ContinuousUniformDist::new(a, b);
+
+        assert_eq!(dist, Err(PensivError::InvalidBounds { lower: 2.0, upper: 1.5 }));
+    }
+
+    #[test]
+    fn continuous_uniform_dist_non_finite_bounds_creation_fails() {
+        assert!(ContinuousUniformDist::new(f64::NAN, 1.0).is_err());
+        assert!(ContinuousUniformDist::new(0.0, f64::NAN).is_err());
+        assert!(ContinuousUniformDist::new(f64::NEG_INFINITY, 1.0).is_err());
+    }
+            
+    #[test]
+    fn continuous_uniform_dist_correct_pdf_inrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        let pdfs = Array::range(a, b, 0.5).mapv(|x| dist.pdf(x));
+        let expected = Array::from_elem(pdfs.shape(), 1.0 / (b - a));
+
+        assert!(pdfs.all_close(&expected, 1e-10));
+    }
+
+    #[test]
+    fn continuous_uniform_dist_correct_pdf_outofrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.pdf(0.0), 0.0);
+        assert_eq!(dist.pdf(5.0), 0.0);
+    }
+
+        // cdf
+    #[test]
+    fn continuous_uniform_dist_correct_cdf_inrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        let cdfs = Array::range(a, b, 0.5).mapv(|x| dist.cdf(x));
+        let expected = Array::range(a, b, 0.5).mapv(|x| (x - a) / (b - a));
+
+        assert!(cdfs.all_close(&expected, 1e-10));
+    }
+
+    #[test]
+    fn continuous_uniform_dist_correct_cdf_outofrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.cdf(0.0), 0.0);
+        assert_eq!(dist.cdf(5.0), 1.0);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_correct_interval_cdf_inrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        let x = a + 0.5;
+        let y = b - 0.5;
+
+        assert_eq!(dist.interval_cdf(x, y), dist.cdf(y) - dist.cdf(x));
+    }
+
+    #[test]
+    fn continuous_uniform_dist_correct_interval_cdf_outofrange() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.interval_cdf(a - 0.5, b + 0.5), 1.0);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_mean_calculated_correctly() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.mean(), (a + b) / 2.0);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_variance_calculated_correctly() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.variance(), (b - a).powi(2) / 12.0);
+    }
+
+    #[test]
+    fn continuous_uniform_dist_sample_moments_close() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(10);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|&x| x >= a && x < b));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.02);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.01);
     }
 
     #[test]
-    fn discrete_uniform_dist_invalid_creation_fails() {
-        let dist = DiscreteUniformDist::new(4, 0);
-        assert_eq!(dist, None);
+    fn continuous_uniform_dist_correct_ppf() {
+        let a = 1.0;
+        let b = 2.5;
+        let dist = ContinuousUniformDist::new(a, b).unwrap();
+
+        assert_eq!(dist.ppf(0.0), Some(a));
+        assert_eq!(dist.ppf(0.5), Some(1.75));
+        assert_eq!(dist.ppf(1.0), Some(b));
+        assert_eq!(dist.ppf(1.1), None);
     }
-            
+
+
     #[test]
-    fn discrete_uniform_dist_gives_correct_pmf_inrange() {
-        let lower_bound = 0;
-        let upper_bound = 4;
+    fn continuous_uniform_dist_fit_correct() {
+        let fit = ContinuousUniformDist::fit(&array![1.5, 2.0, 1.0, 2.5]).unwrap();
 
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        assert_eq!(fit.dist(), &ContinuousUniformDist::new(1.0, 2.5).unwrap());
 
-        let mut values = Array::range(lower_bound as f64, upper_bound as f64 + 1.0, 1.0);
-        values.mapv_inplace(|n| { dist.pmf(n as i32) });
+        // standard deviation of the minimum of 4 uniform values on an interval of width 1.5
+        let std_error = 1.5 * 2.0 / (5.0 * 6.0_f64.sqrt());
+        assert!(fit.std_errors().iter().all(|&e| (e - std_error).abs() < 1e-15));
 
-        assert!(values.iter().all(|&p| p == 1.0 / dist.range() as f64));
+        assert_eq!(ContinuousUniformDist::fit(&array![1.0, f64::NAN]), Err(PensivError::NonFiniteData));
     }
 
     #[test]
-    fn discrete_uniform_dist_gives_correct_pmf_outofrange() {
-        let lower_bound = 0;
-        let upper_bound = 4;
+    fn continuous_uniform_dist_higher_moments_correct() {
+        let dist = ContinuousUniformDist::new(-1.0, 2.0).unwrap();
 
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        assert_eq!(dist.median(), Some(0.5));
+        assert_eq!(dist.mode(), Some(-1.0));
+        assert_eq!(dist.skewness(), 0.0);
+        assert_eq!(dist.kurtosis(), -1.2);
+        assert!((dist.entropy() - 3_f64.ln()).abs() < 1e-15);
 
-        assert_eq!(dist.pmf(lower_bound - 1), 0.0);
-        assert_eq!(dist.pmf(upper_bound + 1), 0.0);
+        assert_eq!(dist.mgf(0.0), Some(1.0));
+        assert!((dist.mgf(0.5).unwrap() - 1.407_834_112_497_608).abs() < 1e-15);
+        assert!((dist.cf(0.5) - Complex::new(0.880_597_682_274_733, 0.224_853_504_014_822)).norm() < 1e-15);
+        assert!((dist.cf(0.0) - Complex::new(1.0, 0.0)).norm() < 1e-15);
     }
 
     #[test]
-    fn discrete_uniform_dist_correct_cdf_outofrange() {
-        let lower_bound = 0;
-        let upper_bound = 4;
+    fn exp_dist_valid_created_correctly() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
 
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        assert_eq!(dist.rate_param(), r);
+    }
+    
+    #[test]
+    fn exp_dist_invalid_rateparam_creation_fails() {
+        let r = -0.5;
+        let dist = ExponentialDist::new(r);
 
-        assert_eq!(dist.cdf(lower_bound - 1), 0.0);
-        assert_eq!(dist.cdf(upper_bound + 1), 1.0);
+        assert!(dist.is_err());
+    }
+        
+    #[test]
+    fn exp_dist_correct_pdf_inrange() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        let pdfs = Array::range(0.0, 2.0, 0.5).mapv(|k| dist.pdf(k));
+        let expected = array![0.5, 0.3894, 0.3033, 0.2362];
+
+        assert!(pdfs.all_close(&expected, 1e-4)); // error is relatively low because I rounded to 4 decimal places
     }
 
     #[test]
-    fn discrete_uniform_dist_correct_cdf_withinrange() {
-        let lower_bound = 1;
-        let upper_bound = 5;
+    fn exp_dist_correct_pdf_outofrange() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
 
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        assert_eq!(dist.pdf(-1.0), 0.0);
+    }
+    
+    #[test]
+    fn exp_dist_correct_cdf_inrange() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
 
-        let values = Array::range(lower_bound as f64, upper_bound as f64 + 1.0, 1.0);
-        let correct_cdf = values.mapv(|n| n as f64 / dist.range() as f64);
-        let cdf = values.mapv(|n| dist.cdf(n as i32));
+        let cdfs = Array::range(0.0, 4.0, 0.5).mapv(|x| dist.cdf(x));
+        let expected = Array::range(0.0, 4.0, 0.5).mapv(|x| { 1.0 - (-r * x).exp() });
 
-        assert_eq!(cdf, correct_cdf);
+        assert!(cdfs.all_close(&expected, 1e-10));
     }
 
     #[test]
-    fn discrete_uniform_dist_correct_inverval_cdf() {
-        let lower_bound = 1;
-        let upper_bound = 5;
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+    fn exp_dist_correct_cdf_outofrange() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        assert_eq!(dist.cdf(-1.0), 0.0);
+    }
         
-        let diff = dist.interval_cdf(lower_bound + 1, upper_bound - 1) - (dist.range() - 2) as f64 / dist.range() as f64;
-        assert!(diff < 1e-10);
+    #[test]
+    fn exp_dist_correct_interval_cdf() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        let a = 1.0;
+        let b = 4.0;
+
+        assert_eq!(dist.interval_cdf(a, b), dist.cdf(b) - dist.cdf(a));
+    }
+        
+    #[test]
+    fn exp_dist_mean_calculated_correctly() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        assert_eq!(dist.mean(), 1.0 / r);
     }
+        
+    #[test]
+    fn exp_dist_variance_calculated_correctly() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
 
+        assert_eq!(dist.variance(), 1.0 / r.powi(2));
+    }
+        
     #[test]
-    fn discrete_uniform_dist_mean_calculated_correctly() {
-        let lower_bound = 1;
-        let upper_bound = 5;
+    fn exp_dist_std_calculated_correctly() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
 
-        let dist = DiscreteUniformDist::new(lower_bound, upper_bound).unwrap();
+        assert_eq!(dist.std(), 1.0 / r);
+    }
+
+    #[test]
+    fn exp_dist_sample_moments_close() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.08);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.4);
+    }
+
+    #[test]
+    fn exp_dist_ppf_inverts_cdf() {
+        let dist = ExponentialDist::new(0.5).unwrap();
+
+        for x in Array::range(0.0, 10.0, 0.5).iter() {
+            assert!((dist.ppf(dist.cdf(*x)).unwrap() - x).abs() < 1e-10);
+        }
+
+        assert_eq!(dist.ppf(1.0), Some(f64::INFINITY));
+        assert_eq!(dist.ppf(-1.0), None);
+    }
+
+
+    #[test]
+    fn exp_dist_correct_log_pdf() {
+        let r = 0.5;
+        let dist = ExponentialDist::new(r).unwrap();
+
+        assert_eq!(dist.log_pdf(2.0), r.ln() - 1.0);
+        assert!((dist.log_pdf(2.5) - dist.pdf(2.5).ln()).abs() < 1e-14);
+        assert_eq!(dist.log_pdf(-1.0), f64::NEG_INFINITY);
+
+        // e^(-1000) underflows, but its log doesn't
+        assert_eq!(dist.log_pdf(2000.0), r.ln() - 1000.0);
+    }
+
+
+    #[test]
+    fn exp_dist_fit_correct() {
+        let fit = ExponentialDist::fit(&array![0.5, 2.5, 1.0, 2.0]).unwrap();
+
+        assert_eq!(fit.dist().rate_param(), 1.0 / 1.5);
+        assert_eq!(fit.std_errors(), &array![1.0 / 1.5 / 2.0]);
+
+        assert_eq!(ExponentialDist::fit(&array![0.5, -2.5]), Err(PensivError::InvalidInput("data must be non-negative")));
+        assert_eq!(ExponentialDist::fit(&array![0.0, 0.0]), Err(PensivError::InvalidInput("data must not all be 0")));
+    }
+
+    #[test]
+    fn exp_dist_higher_moments_correct() {
+        let dist = ExponentialDist::new(2.0).unwrap();
+
+        assert!((dist.median().unwrap() - 2_f64.ln() / 2.0).abs() < 1e-15);
+        assert_eq!(dist.mode(), Some(0.0));
+        assert_eq!(dist.skewness(), 2.0);
+        assert_eq!(dist.kurtosis(), 6.0);
+        assert!((dist.entropy() - (1.0 - 2_f64.ln())).abs() < 1e-15);
+
+        assert_eq!(dist.mgf(1.0), Some(2.0));
+        assert_eq!(dist.mgf(2.0), None);
+        assert!((dist.cf(1.0) - Complex::new(0.8, 0.4)).norm() < 1e-15);
+    }
+    
+    #[test]
+    fn normal_dist_valid_created_correctly() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        assert_eq!(dist.loc(), loc);
+        assert_eq!(dist.scale(), scale);
+    }
+    
+    #[test]
+    fn normal_dist_invalid_scale_creation_fails() {
+        let loc = 5.0;
+        let scale = -2.0;
+        let dist = NormalDist::new(loc, scale);
+
+        assert!(dist.is_err());
+    }
+
+    #[test]
+    fn normal_dist_degenerate_parameters_creation_fails() {
+        let expected = PensivError::InvalidParameter { name: "scale", value: 0.0, expected: "a positive, finite number" };
+        assert_eq!(NormalDist::new(0.0, 0.0), Err(expected));
+
+        assert!(NormalDist::new(0.0, f64::NAN).is_err());
+        assert!(NormalDist::new(0.0, f64::INFINITY).is_err());
+        assert!(NormalDist::new(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn normal_dist_std_created_correctly() {
+        let dist = NormalDist::std();
+
+        assert_eq!(dist.loc(), 0.0);
+        assert_eq!(dist.scale(), 1.0);
+    }
+
+    #[test]
+    fn normal_dist_zvalue_calculated_correctly() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        assert_eq!(dist.z(7.0), 1.0);
+        assert_eq!(dist.z(5.0), 0.0);
+        assert_eq!(dist.z(3.0), -1.0);
+    }
+
+    #[test]
+    fn normal_dist_pdf_correct() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        let pdfs = Array::range(-5.0, 20.0, 5.0).mapv(|x| dist.pdf(x));
+        let expected = array![0.00000074, 0.00876415, 0.19947114, 0.00876415, 0.00000074];
+
+        assert!(pdfs.all_close(&expected, 1e-8));
+    }
+
+    #[test]
+    fn normal_dist_cdf_correct() {
+        let loc = 5.0;
+        let scale = 2.5;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        let cdfs = Array::range(0.0, 12.5, 2.5).mapv(|x| dist.cdf(x));
+        let expected = array![
+            0.02275013194817921, 
+            0.15865525393145705, 
+            0.5, 
+            0.8413447460685429, 
+            0.9772498680518208
+        ];
+
+        assert!(cdfs.all_close(&expected, 1e-8));
+    }
+
+    #[test]
+    fn normal_dist_cdf_accurate_in_tails() {
+        let dist = NormalDist::std();
+
+        // reference values computed with mpmath; these used to round to 0
+        let x = array![-6.5, -7.0, -9.0];
+        let expected = array![4.016000583859118e-11, 1.279812543885835e-12, 1.1285884059538405e-19];
+
+        for (x, p) in x.iter().zip(expected.iter()) {
+            assert!((dist.cdf(*x) - p).abs() < 1e-13 * p);
+        }
+    }
+        
+    #[test]
+    fn normal_dist_mean_calculated_correctly() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        assert_eq!(dist.mean(), loc);
+    }
+    
+    #[test]
+    fn normal_dist_variance_calculated_correctly() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        assert_eq!(dist.variance(), scale.powi(2));
+    }
+    
+    #[test]
+    fn normal_dist_std_calculated_correctly() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        assert_eq!(dist.std(), scale);
+    }
+
+    #[test]
+    fn normal_dist_sample_moments_close() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(12);
+        let samples = dist.sample_n(&mut rng, 10000);
 
-        assert_eq!(dist.mean(), (upper_bound + lower_bound) as f64 / 2.0);
+        assert!((samples.mean().unwrap() - loc).abs() < 0.08);
+        assert!((samples.std_axis(Axis(0), 0.0).into_scalar() - scale).abs() < 0.06);
     }
 
     #[test]
-    fn bernoulli_dist_created_correctly() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn sampling_reproducible_with_same_seed() {
+        let normal = NormalDist::new(5.0, 2.0).unwrap();
+        let binom = BinomDist::new(20, 0.3).unwrap();
 
-        assert_eq!(dist.p_success(), p);
-        assert_eq!(dist.p_failure(), 1.0 - p);
+        let first = normal.sample_n(&mut StdRng::seed_from_u64(13), 100);
+        let second = normal.sample_n(&mut StdRng::seed_from_u64(13), 100);
+        assert_eq!(first, second);
+
+        let first = binom.sample_n(&mut StdRng::seed_from_u64(14), 100);
+        let second = binom.sample_n(&mut StdRng::seed_from_u64(14), 100);
+        assert_eq!(first, second);
     }
 
     #[test]
-    fn bernoulli_dist_invalid_creation_fails() {
-        let p = -0.5;
-        let dist = BernoulliDist::new(p);
+    fn normal_dist_correct_ppf() {
+        let dist = NormalDist::std();
 
-        assert_eq!(dist, None);
-    }
+        // reference values computed with mpmath
+        let p = array![1e-20, 1e-10, 0.001, 0.025, 0.3, 0.5, 0.9, 0.975, 0.999];
+        let expected = array![
+            -9.262340089798407,
+            -6.361340902404057,
+            -3.0902323061678136,
+            -1.9599639845400543,
+            -0.5244005127080408,
+            0.0,
+            1.2815515655446006,
+            1.9599639845400538,
+            3.090232306167813,
+        ];
 
-    #[test]
-    fn bernoulli_dist_correct_pmf_inrange() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+        for (p, x) in p.iter().zip(expected.iter()) {
+            assert!((dist.ppf(*p).unwrap() - x).abs() < 1e-14 * x.abs().max(1.0));
+        }
 
-        assert_eq!(dist.pmf(0), dist.p_failure());
-        assert_eq!(dist.pmf(1), dist.p_success());
+        assert_eq!(dist.ppf(0.0), Some(f64::NEG_INFINITY));
+        assert_eq!(dist.ppf(1.0), Some(f64::INFINITY));
+        assert_eq!(dist.ppf(2.0), None);
     }
 
     #[test]
-    fn bernoulli_dist_correct_pmf_outofrange() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn normal_dist_ppf_shifted_and_scaled() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
 
-        assert_eq!(dist.pmf(-1), 0.0);
-        assert_eq!(dist.pmf(2), 0.0);
+        assert!((dist.ppf(0.975).unwrap() - (loc + scale * 1.9599639845400538)).abs() < 1e-12);
     }
 
     #[test]
-    fn bernoulli_dist_correct_cdf_inrange() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn normal_dist_single_precision_matches_double() {
+        let single = NormalDist::new_in(5.0_f32, 2.0).unwrap();
+        let double = NormalDist::new(5.0, 2.0).unwrap();
 
-        assert_eq!(dist.cdf(0), dist.p_failure());
-        assert_eq!(dist.cdf(1), 1.0);
+        for x in Array::range(-3.0_f32, 13.0, 0.5).iter() {
+            let x64 = to_f64(*x);
+
+            assert!((to_f64(single.pdf(*x)) - double.pdf(x64)).abs() < 1e-5 * double.pdf(x64));
+            assert!((to_f64(single.cdf(*x)) - double.cdf(x64)).abs() < 1e-5 * double.cdf(x64));
+            assert!((to_f64(single.log_pdf(*x)) - double.log_pdf(x64)).abs() < 1e-5);
+        }
+
+        for p in [0.001_f32, 0.3, 0.5, 0.975].iter() {
+            assert!((to_f64(single.ppf(*p).unwrap()) - double.ppf(to_f64(*p)).unwrap()).abs() < 1e-5);
+        }
+
+        let mut rng = StdRng::seed_from_u64(12);
+        let samples = single.sample_n(&mut rng, 10000);
+        assert!((samples.mean().unwrap() - 5.0).abs() < 0.08);
+
+        assert!(NormalDist::new_in(0.0_f32, f32::INFINITY).is_err());
+        assert!(NormalDist::new_in(f32::NAN, 1.0).is_err());
     }
 
     #[test]
-    fn bernoulli_dist_correct_cdf_outofrange() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn continuous_dists_single_precision_match_double() {
+        fn assert_matches<S: ContinuousDist<f32>, D: ContinuousDist<f64>>(single: &S, double: &D, x: &[f32]) {
+            for x in x.iter() {
+                let x64 = to_f64(*x);
 
-        assert_eq!(dist.cdf(-1), 0.0);
-        assert_eq!(dist.cdf(2), 1.0);
+                assert!((to_f64(single.pdf(*x)) - double.pdf(x64)).abs() < 1e-5 * double.pdf(x64).max(1.0));
+                assert!((to_f64(single.cdf(*x)) - double.cdf(x64)).abs() < 1e-5);
+            }
+
+            assert!((to_f64(single.mean()) - double.mean()).abs() < 1e-5 * double.mean().abs().max(1.0));
+            assert!((to_f64(single.ppf(0.9).unwrap()) - double.ppf(0.9).unwrap()).abs() < 1e-4);
+        }
+
+        let x = [0.1, 0.5, 1.0, 2.5, 4.0];
+        assert_matches(&ContinuousUniformDist::new_in(0.0_f32, 5.0).unwrap(), &ContinuousUniformDist::new(0.0, 5.0).unwrap(), &x);
+        assert_matches(&ExponentialDist::new_in(0.5_f32).unwrap(), &ExponentialDist::new(0.5).unwrap(), &x);
+        assert_matches(&GammaDist::new_in(2.5_f32, 1.5).unwrap(), &GammaDist::new(2.5, 1.5).unwrap(), &x);
+        assert_matches(&ChiSquaredDist::new_in(3.0_f32).unwrap(), &ChiSquaredDist::new(3.0).unwrap(), &x);
+        assert_matches(&StudentTDist::new_in(4.0_f32).unwrap(), &StudentTDist::new(4.0).unwrap(), &x);
+        assert_matches(&FDist::new_in(5.0_f32, 10.0).unwrap(), &FDist::new(5.0, 10.0).unwrap(), &x);
+
+        let x = [0.05, 0.25, 0.5, 0.75, 0.95];
+        assert_matches(&BetaDist::new_in(2.0_f32, 3.5).unwrap(), &BetaDist::new(2.0, 3.5).unwrap(), &x);
     }
 
     #[test]
-    fn bernoulli_dist_correct_interval_cdf() {
-        let p = 0.5;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn discrete_dists_single_precision_match_double() {
+        fn assert_matches<S: DiscreteDist<i32, f32>, D: DiscreteDist<i32, f64>>(single: &S, double: &D, k: &[i32]) {
+            for k in k.iter() {
+                assert!((to_f64(single.pmf(*k)) - double.pmf(*k)).abs() < 1e-5);
+                assert!((to_f64(single.cdf(*k)) - double.cdf(*k)).abs() < 1e-5);
+            }
 
-        assert_eq!(dist.interval_cdf(0, 1), 1.0);
+            assert!((to_f64(single.mean()) - double.mean()).abs() < 1e-5 * double.mean().abs().max(1.0));
+            assert_eq!(single.ppf(0.9), double.ppf(0.9));
+        }
+
+        let k = [0, 1, 2, 4, 7];
+        assert_matches(&BernoulliDist::new_in(0.3_f32).unwrap(), &BernoulliDist::new(0.3).unwrap(), &k);
+        assert_matches(&BinomDist::new_in(10, 0.35_f32).unwrap(), &BinomDist::new(10, 0.35).unwrap(), &k);
+        assert_matches(&GeometricDist::new_in(0.25_f32).unwrap(), &GeometricDist::new(0.25).unwrap(), &k);
+        assert_matches(&PoissonDist::new_in(3.5_f32).unwrap(), &PoissonDist::new(3.5).unwrap(), &k);
+        assert_matches(&NegativeBinomialDist::new_in(4.0_f32, 0.6).unwrap(), &NegativeBinomialDist::new(4.0, 0.6).unwrap(), &k);
+        assert_matches(&DiscreteUniformDist::<f32>::new_in(1, 6).unwrap(), &DiscreteUniformDist::new(1, 6).unwrap(), &k);
+        assert_matches(
+            &HypergeometricDist::<f32>::new_in(50, 12, 10).unwrap(), &HypergeometricDist::new(50, 12, 10).unwrap(), &k
+        );
+        assert_matches(
+            &CategoricalDist::new_in(&array![0.1_f32, 0.2, 0.3, 0.4]).unwrap(),
+            &CategoricalDist::new(&array![0.1, 0.2, 0.3, 0.4]).unwrap(),
+            &k
+        );
+
+        let single = EmpiricalDist::new_in(&array![1.5_f32, 2.0, 2.0, 3.25, 7.0]).unwrap();
+        let double = EmpiricalDist::new(&array![1.5, 2.0, 2.0, 3.25, 7.0]).unwrap();
+
+        for x in [1.5, 2.0, 3.0, 7.0].iter() {
+            assert!((to_f64(single.pmf(*x as f32)) - double.pmf(*x)).abs() < 1e-6);
+            assert!((to_f64(single.cdf(*x as f32)) - double.cdf(*x)).abs() < 1e-6);
+        }
+
+        assert!((to_f64(single.mean()) - double.mean()).abs() < 1e-6);
+        assert_eq!(single.ppf(0.6).map(to_f64), double.ppf(0.6));
     }
 
+
     #[test]
-    fn bernoulli_dist_mean_calculated_correctly() {
-        let p = 0.4;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn normal_dist_log_pdf_finite_in_tails() {
+        let loc = 5.0;
+        let scale = 2.0;
+        let dist = NormalDist::new(loc, scale).unwrap();
 
-        assert_eq!(dist.mean(), p);
+        for x in Array::range(-5.0, 15.0, 0.5).iter() {
+            assert!((dist.log_pdf(*x) - dist.pdf(*x).ln()).abs() < 1e-12);
+        }
+
+        let x = loc + 100.0 * scale;
+
+        assert_eq!(dist.pdf(x), 0.0);
+        assert!((dist.log_pdf(x) - (-5000.0 - scale.ln() - 0.5 * (2.0 * PI).ln())).abs() < 1e-9);
     }
 
     #[test]
-    fn bernoulli_dist_variance_calculated_correctly() {
-        let p = 0.4;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn normal_dist_correct_log_likelihood() {
+        let dist = NormalDist::new(0.0, 1.0).unwrap();
+        let data = array![-1.0, 0.0, 0.5, 2.0];
 
-        assert_eq!(dist.variance(), p * (1.0 - p));
+        let expected = -(1.0 + 0.0 + 0.25 + 4.0) / 2.0 - 2.0 * (2.0 * PI).ln();
+        assert!((dist.log_likelihood(&data) - expected).abs() < 1e-12);
+
+        // the likelihood itself underflows for a data set this large, but the log-likelihood doesn't
+        let data = Array::from_elem(1000, 3.0);
+        assert!((dist.log_likelihood(&data) - 1000.0 * (-4.5 - 0.5 * (2.0 * PI).ln())).abs() < 1e-9);
     }
 
+
     #[test]
-    fn bernoulli_dist_std_dev_calculated_correctly() {
-        let p = 0.4;
-        let dist = BernoulliDist::new(p).unwrap();
+    fn normal_dist_fit_correct() {
+        let fit = NormalDist::fit(&array![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let scale = 1.25_f64.sqrt();
 
-        assert_eq!(dist.std(), (p * (1.0 - p)).sqrt());
+        assert_eq!(fit.dist().loc(), 2.5);
+        assert_eq!(fit.dist().scale(), scale);
+        assert_eq!(fit.std_errors(), &array![scale / 2.0, scale / 8.0_f64.sqrt()]);
+
+        assert_eq!(NormalDist::fit(&array![]), Err(PensivError::EmptyData));
+        assert_eq!(NormalDist::fit(&array![1.0, 1.0]), Err(PensivError::InvalidInput("data must have some spread")));
+        assert_eq!(NormalDist::fit(&array![1.0, f64::INFINITY]), Err(PensivError::NonFiniteData));
     }
 
     #[test]
-    fn binom_dist_created_correctly() {
-        let n = 4;
-        let p = 0.4;
+    fn normal_dist_fit_maximizes_likelihood() {
+        let data = array![1.2, 0.8, 1.9, 1.4, 0.6];
+        let fit = NormalDist::fit(&data).unwrap();
+        let best = fit.dist().log_likelihood(&data);
 
-        let dist = BinomDist::new(n, p).unwrap();
+        let (loc, scale) = (fit.dist().loc(), fit.dist().scale());
 
-        assert_eq!(dist.trials(), n);
-        assert_eq!(dist.p_success(), p);
-        assert_eq!(dist.p_failure(), 1.0 - p);
+        for &(dl, ds) in [(0.01, 0.0), (-0.01, 0.0), (0.0, 0.01), (0.0, -0.01)].iter() {
+            let other = NormalDist::new(loc + dl, scale + ds).unwrap();
+            assert!(other.log_likelihood(&data) < best);
+        }
     }
 
     #[test]
-    fn binom_dist_invalid_p_creation_fails() {
-        let n = 4;
-        let p = -0.5;
+    fn normal_dist_higher_moments_correct() {
+        let dist = NormalDist::new(1.0, 2.0).unwrap();
 
-        let dist = BinomDist::new(n, p);
+        assert_eq!(dist.median(), Some(1.0));
+        assert_eq!(dist.mode(), Some(1.0));
+        assert_eq!(dist.skewness(), 0.0);
+        assert_eq!(dist.kurtosis(), 0.0);
+        assert!((dist.entropy() - 2.112_085_713_764_618).abs() < 1e-15);
 
-        assert_eq!(dist, None);
+        assert!((dist.mgf(0.5).unwrap() - 1_f64.exp()).abs() < 1e-15);
+        assert_eq!(dist.mgf(1e3), None);
+        assert!((dist.cf(0.5) - Complex::from_polar(&(-0.5_f64).exp(), &0.5)).norm() < 1e-15);
     }
 
-    #[test]
-    fn binom_dist_invalid_trials_creation_fails() {
-        let n = -1;
-        let p = 0.5;
+    /// A distribution that relies on the default `ppf()`, log density and higher moment implementations, for testing them.
+    struct Triangular;
 
-        let dist = BinomDist::new(n, p);
+    impl ContinuousDist<f64> for Triangular {
+        fn pdf(&self, value: f64) -> f64 {
+            if (0.0..=2.0).contains(&value) { 1.0 - (value - 1.0).abs() } else { 0.0 }
+        }
+
+        fn cdf(&self, value: f64) -> f64 {
+            if value <= 0.0 { 0.0 }
+            else if value <= 1.0 { value * value / 2.0 }
+            else if value <= 2.0 { 1.0 - (2.0 - value).powi(2) / 2.0 }
+            else { 1.0 }
+        }
 
-        assert_eq!(dist, None);
+        fn mean(&self) -> f64 { 1.0 }
+        fn variance(&self) -> f64 { 1.0 / 6.0 }
+        fn sample(&self, _rng: &mut dyn RngCore) -> f64 { 1.0 }
     }
 
-    #[test]
-    fn binom_dist_correct_pmf_inrange() {
-        let n = 4;
-        let p = 0.4;
+    impl DiscreteDist<i32> for Triangular {
+        fn pmf(&self, value: i32) -> f64 {
+            match value { -1 | 1 => 0.25, 0 => 0.5, _ => 0.0 }
+        }
 
-        let dist = BinomDist::new(n, p).unwrap();
-        let pmf = Array::range(0.0, n as f64 + 1.0, 1.0).mapv(|k| dist.pmf(k as i32));
+        fn cdf(&self, value: i32) -> f64 {
+            if value < -1 { 0.0 } else if value == -1 { 0.25 } else if value == 0 { 0.75 } else { 1.0 }
+        }
 
-        assert!(pmf.all_close(&array![0.1296, 0.3456, 0.3456, 0.1536, 0.0256], 1e-10));
+        fn mean(&self) -> f64 { 0.0 }
+        fn variance(&self) -> f64 { 0.5 }
+        fn sample(&self, _rng: &mut dyn RngCore) -> i32 { 0 }
     }
 
     #[test]
-    fn binom_dist_correct_pmf_outofrange() {
-        let n = 4;
-        let p = 0.4;
+    fn default_continuous_ppf_inverts_cdf() {
+        let dist = Triangular;
 
-        let dist = BinomDist::new(n, p).unwrap();
+        for p in Array::range(0.05, 1.0, 0.05).iter() {
+            let x = ContinuousDist::ppf(&dist, *p).unwrap();
+            assert!((ContinuousDist::cdf(&dist, x) - p).abs() < 1e-12);
+        }
 
-        assert_eq!(dist.pmf(-1), 0.0);
-        assert_eq!(dist.pmf(5), 0.0);
+        assert!(ContinuousDist::ppf(&dist, 0.0).unwrap().abs() < 1e-12);
+        // the CDF rounds to 1.0 slightly before the top of the support
+        assert!((ContinuousDist::ppf(&dist, 1.0).unwrap() - 2.0).abs() < 1e-7);
+        assert_eq!(ContinuousDist::ppf(&dist, 1.5), None);
     }
 
     #[test]
-    fn binom_dist_correct_cdf_inrange() {
-        let n = 4;
-        let p = 0.4;
+    fn default_discrete_ppf_inverts_cdf() {
+        let dist = Triangular;
 
-        let dist = BinomDist::new(n, p).unwrap();
+        assert_eq!(DiscreteDist::ppf(&dist, 0.0), Some(-1));
+        assert_eq!(DiscreteDist::ppf(&dist, 0.25), Some(-1));
+        assert_eq!(DiscreteDist::ppf(&dist, 0.5), Some(0));
+        assert_eq!(DiscreteDist::ppf(&dist, 0.9), Some(1));
+        assert_eq!(DiscreteDist::ppf(&dist, 1.0), Some(1));
+        assert_eq!(DiscreteDist::ppf(&dist, -0.5), None);
+    }
 
-        let v = 4.0;
-        let cdf = Array::range(0.0, v, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
 
-        assert_eq!(dist.cdf(v as i32 - 1), cdf);
-    }
+    /// A Laplace distribution centered at `3`, which relies on the default `cdf()` as well as `ppf()`.
+    struct Laplace;
 
-    #[test]
-    fn binom_dist_correct_cdf_outofrange() {
-        let n = 4;
-        let p = 0.4;
+    impl ContinuousDist<f64> for Laplace {
+        fn pdf(&self, value: f64) -> f64 {
+            (-(value - 3.0).abs()).exp() / 2.0
+        }
 
-        let dist = BinomDist::new(n, p).unwrap();
-        
-        assert!((dist.cdf(-1) - 0.0).abs() < 1e-10);
-        assert!((dist.cdf(n + 1) - 1.0).abs() < 1e-10);
+        fn mean(&self) -> f64 { 3.0 }
+        fn variance(&self) -> f64 { 2.0 }
+        fn sample(&self, _rng: &mut dyn RngCore) -> f64 { 3.0 }
     }
 
     #[test]
-    fn binom_dist_correct_interval_cdf() {
-        let n = 4;
-        let p = 0.4;
+    fn default_continuous_cdf_integrates_pdf() {
+        let dist = Laplace;
+        let cdf = |x: f64| if x < 3.0 { (x - 3.0).exp() / 2.0 } else { 1.0 - (3.0 - x).exp() / 2.0 };
 
-        let dist = BinomDist::new(n, p).unwrap();
+        for x in Array::range(-5.0, 12.0, 0.5).iter() {
+            assert!((dist.cdf(*x) - cdf(*x)).abs() < 1e-13, "{}", x);
+        }
 
-        let a = 2.0;
-        let b = 3.0;
-        let cdf = Array::range(a, b + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+        assert!(dist.cdf(-40.0) < 1e-15);
+        assert!((dist.interval_cdf(2.0, 4.0) - (1.0 - (-1_f64).exp())).abs() < 1e-13);
 
-        assert_eq!(dist.interval_cdf(a as i32, b as i32), cdf);
+        assert_eq!(dist.cdf(f64::NEG_INFINITY), 0.0);
+        assert!((dist.cdf(f64::INFINITY) - 1.0).abs() < 1e-13);
+        assert!(dist.cdf(f64::NAN).is_nan());
     }
 
     #[test]
-    fn binom_dist_mean_calculated_correctly() {
-        let n = 4;
-        let p = 0.4;
+    fn default_continuous_ppf_inverts_default_cdf() {
+        let dist = Laplace;
+        let ppf = |p: f64| if p < 0.5 { 3.0 + (2.0 * p).ln() } else { 3.0 - (2.0 * (1.0 - p)).ln() };
 
-        let dist = BinomDist::new(n, p).unwrap();
+        for p in [1e-6, 0.01, 0.2, 0.5, 0.75, 0.99].iter() {
+            assert!((dist.ppf(*p).unwrap() - ppf(*p)).abs() < 1e-11, "{}", p);
+        }
 
-        assert_eq!(dist.mean(), n as f64 * p);
+        assert!((dist.median().unwrap() - 3.0).abs() < 1e-12);
+        assert_eq!(dist.ppf(-0.1), None);
     }
 
     #[test]
-    fn binom_dist_variance_calculated_correctly() {
-        let n = 4;
-        let p = 0.4;
+    fn default_log_pdf_pmf_take_log() {
+        let dist = Triangular;
 
-        let dist = BinomDist::new(n, p).unwrap();
+        assert_eq!(ContinuousDist::log_pdf(&dist, 0.5), 0.5_f64.ln());
+        assert_eq!(ContinuousDist::log_pdf(&dist, 3.0), f64::NEG_INFINITY);
+        assert_eq!(ContinuousDist::log_likelihood(&dist, &array![0.5, 1.0]), 0.5_f64.ln());
 
-        assert_eq!(dist.variance(), n as f64 * p * (1.0 - p));
+        assert_eq!(DiscreteDist::log_pmf(&dist, 0), 0.5_f64.ln());
+        assert_eq!(DiscreteDist::log_pmf(&dist, 2), f64::NEG_INFINITY);
+        assert_eq!(DiscreteDist::log_likelihood(&dist, &array![-1, 0]), 0.125_f64.ln());
     }
 
     #[test]
-    fn geometric_dist_valid_created_correctly() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn default_continuous_higher_moments_correct() {
+        let dist = Triangular;
 
-        assert_eq!(dist.p_success(), p);
-        assert_eq!(dist.p_failure(), 1.0 - p);
+        assert!((ContinuousDist::median(&dist).unwrap() - 1.0).abs() < 1e-12);
+        assert!((ContinuousDist::mode(&dist).unwrap() - 1.0).abs() < 1e-8);
+        assert!(ContinuousDist::skewness(&dist).abs() < 1e-12);
+        assert!((ContinuousDist::kurtosis(&dist) + 0.6).abs() < 1e-12);
+        assert!((ContinuousDist::entropy(&dist) - 0.5).abs() < 1e-12);
+
+        assert!((ContinuousDist::mgf(&dist, 1.0).unwrap() - 2.9524924420125593).abs() < 1e-12);
+        let cf = ContinuousDist::cf(&dist, 1.0);
+        assert!((cf - Complex::new(0.496_751_448_283_421_9, 0.773_644_542_790_111_2)).norm() < 1e-12);
     }
 
     #[test]
-    fn geometric_dist_invalid_p_creation_fails() {
-        let p = -0.4;
-        let dist = GeometricDist::new(p);
+    fn default_discrete_higher_moments_correct() {
+        let dist = Triangular;
 
-        assert_eq!(dist, None);
+        assert_eq!(DiscreteDist::median(&dist), Some(0));
+        assert_eq!(DiscreteDist::mode(&dist), Some(0));
+        assert_eq!(DiscreteDist::skewness(&dist), 0.0);
+        assert!((DiscreteDist::kurtosis(&dist) + 1.0).abs() < 1e-15);
+        assert!((DiscreteDist::entropy(&dist) - 1.0397207708399179).abs() < 1e-15);
+
+        assert!((DiscreteDist::mgf(&dist, 1.0).unwrap() - 1.2715403174076219).abs() < 1e-15);
+        assert!((DiscreteDist::cf(&dist, 1.0) - Complex::new(0.770_151_152_934_069_9, 0.0)).norm() < 1e-15);
+    }
+
+    /// Asserts that each of `values` is within an absolute tolerance of `tol` of the corresponding element of `expected`.
+    fn assert_all_close(values: &Array1<f64>, expected: &Array1<f64>, tol: f64) {
+        for (v, e) in values.iter().zip(expected.iter()) {
+            assert!((v - e).abs() <= tol, "{} != {}", v, e);
+        }
     }
 
+    // reference values for the distributions below were computed with mpmath
+
     #[test]
-    fn geometric_dist_correct_pmf_inrange() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn gamma_dist_valid_created_correctly() {
+        let dist = GammaDist::new(2.5, 1.5).unwrap();
 
-        let pmfs = Array::range(1.0, 4.0, 1.0).mapv(|k| dist.pmf(k as i32));
-        assert!(pmfs.all_close(&array![0.4, 0.24, 0.144], 1e-10));
+        assert_eq!(dist.shape(), 2.5);
+        assert_eq!(dist.rate_param(), 1.5);
+        assert_eq!(dist.scale(), 1.0 / 1.5);
     }
 
     #[test]
-    fn geometric_dist_correct_pmf_outofrange() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
-
-        assert_eq!(dist.pmf(0), 0.0);
+    fn gamma_dist_invalid_creation_fails() {
+        assert!(GammaDist::new(-2.5, 1.5).is_err());
+        assert!(GammaDist::new(2.5, 0.0).is_err());
+        assert!(GammaDist::new(f64::NAN, 1.5).is_err());
     }
 
     #[test]
-    fn geometric_dist_correct_cdf_inrange() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn gamma_dist_correct_pdf() {
+        let dist = GammaDist::new(2.5, 1.5).unwrap();
 
-        let n = 3.0;
-        let cdf = Array::range(1.0, n + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+        let pdfs = array![0.5, 1.0, 2.0, 5.0].mapv(|x| dist.pdf(x));
+        let expected = array![0.34619922631227434, 0.46254098941130783, 0.29191303997784873, 0.01281853342382117];
 
-        assert_eq!(dist.cdf(n as i32), cdf);
+        assert_all_close(&pdfs, &expected, 1e-14);
+        assert_eq!(dist.pdf(-1.0), 0.0);
+        assert_eq!(dist.pdf(0.0), 0.0);
     }
 
     #[test]
-    fn geometric_dist_correct_cdf_outofrange() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn gamma_dist_shape_one_matches_exponential() {
+        let gamma = GammaDist::new(1.0, 0.5).unwrap();
+        let exp = ExponentialDist::new(0.5).unwrap();
 
-        assert_eq!(dist.cdf(-1), 0.0);
+        for x in Array::range(0.0, 10.0, 0.5).iter() {
+            assert!((gamma.pdf(*x) - exp.pdf(*x)).abs() < 1e-14);
+            assert!((gamma.cdf(*x) - exp.cdf(*x)).abs() < 1e-14);
+        }
     }
 
     #[test]
-    fn geometric_dist_correct_interval_cdf() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
-
-        let a = 3.0;
-        let b = 5.0;
-        let cdf = Array::range(a, b + 1.0, 1.0).mapv(|k| dist.pmf(k as i32)).sum();
+    fn gamma_dist_correct_cdf() {
+        let dist = GammaDist::new(2.5, 1.5).unwrap();
 
-        let diff = dist.interval_cdf(a as i32, b as i32) - cdf;
+        let cdfs = array![0.5, 1.0, 2.0, 5.0].mapv(|x| dist.cdf(x));
+        let expected = array![0.08693018545560453, 0.3000141641213725, 0.6937810815867216, 0.9896376620842136];
 
-        assert!(diff.abs() < 1e-10);
+        assert_all_close(&cdfs, &expected, 1e-14);
+        assert_eq!(dist.cdf(-1.0), 0.0);
     }
 
     #[test]
-    fn geometric_dist_mean_calculated_correctly() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn gamma_dist_mean_variance_calculated_correctly() {
+        let dist = GammaDist::new(2.5, 1.5).unwrap();
 
-        assert_eq!(dist.mean(), 1.0 / p);
+        assert_eq!(dist.mean(), 2.5 / 1.5);
+        assert_eq!(dist.variance(), 2.5 / 1.5_f64.powi(2));
     }
 
     #[test]
-    fn geometric_dist_variance_calculated_correctly() {
-        let p = 0.4;
-        let dist = GeometricDist::new(p).unwrap();
+    fn gamma_dist_sample_moments_close() {
+        let mut rng = StdRng::seed_from_u64(15);
 
-        let diff = dist.variance() - (1.0 - p) / p.powi(2);
-        assert!(diff < 1e-10);
+        // shapes on either side of 1 take different sampling paths
+        for &shape in [0.5, 2.5].iter() {
+            let dist = GammaDist::new(shape, 1.5).unwrap();
+            let samples = dist.sample_n(&mut rng, 10000);
+
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05);
+            assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.1);
+        }
     }
 
+
     #[test]
-    fn empirical_dist_valid_created_correctly() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn gamma_dist_fit_correct() {
+        let data = array![1.2, 0.4, 2.5, 1.9, 0.8, 3.1, 0.6];
+        let fit = GammaDist::fit(&data).unwrap();
 
-        for (i, n) in data.iter().enumerate() {
-            assert_eq!(dist.data[i], *n);
-        }
+        assert!((fit.dist().shape() - 2.3179618114275375).abs() < 1e-12);
+        assert!((fit.dist().rate_param() - 1.545307874285025).abs() < 1e-12);
+        assert_all_close(fit.std_errors(), &array![1.1609271646782449, 0.8638130670515786], 1e-12);
+
+        assert_eq!(GammaDist::fit(&array![1.0, 0.0]), Err(PensivError::InvalidInput("data must be positive")));
+        assert_eq!(GammaDist::fit(&array![2.0, 2.0]), Err(PensivError::InvalidInput("data must have some spread")));
     }
 
     #[test]
-    fn empirical_dist_invalid_creation_fails() {
-        let data = array![1.0, 2.0, f64::NAN, 3.0];
+    fn gamma_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(27);
 
-        match EmpiricalDist::new(&data) {
-            Some(_) => panic!("found empirical dist instead of None"),
-            None => {},
+        for &shape in [0.3, 2.5, 40.0].iter() {
+            let dist = GammaDist::new(shape, 1.5).unwrap();
+            let fit = GammaDist::fit(&dist.sample_n(&mut rng, 5000)).unwrap();
+
+            assert!((fit.dist().shape() - shape).abs() < 4.0 * fit.std_errors()[0]);
+            assert!((fit.dist().rate_param() - 1.5).abs() < 4.0 * fit.std_errors()[1]);
         }
     }
 
     #[test]
-    fn empirical_dist_correct_pmf_indata() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
-
-        let mut counts = BTreeMap::new();
-        counts.insert(0, 1.0);
-        counts.insert(1, 2.0);
-        counts.insert(3, 3.0);
+    fn gamma_dist_higher_moments_correct() {
+        let dist = GammaDist::new(2.5, 1.5).unwrap();
 
-        for (i, n) in counts.iter() {
-            let v = data[[*i]];
+        assert_eq!(dist.mode(), Some(1.0));
+        assert_eq!(GammaDist::new(0.5, 1.5).unwrap().mode(), Some(0.0));
+        assert!((dist.skewness() - 2.0 / 2.5_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - 2.4).abs() < 1e-15);
+        assert!((dist.entropy() - 1.324_482_801_396_89).abs() < 1e-13);
 
-            assert_eq!(dist.pmf(v), *n / data.len() as f64);
-        }
+        assert!((dist.mgf(0.5).unwrap() - 1.5_f64.powf(2.5)).abs() < 1e-14);
+        assert_eq!(dist.mgf(1.5), None);
+        assert!((dist.cf(1.0) - Complex::new(0.063_541_147_689_686_26, 0.628_295_996_238_808_9)).norm() < 1e-14);
     }
 
     #[test]
-    fn empirical_dist_correct_pmf_notindata() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_valid_created_correctly() {
+        let dist = BetaDist::new(2.0, 5.0).unwrap();
 
-        assert_eq!(dist.pmf(4.0), 0.0);
+        assert_eq!(dist.alpha(), 2.0);
+        assert_eq!(dist.beta(), 5.0);
     }
 
     #[test]
-    fn empirical_dist_correct_pmf_invalid_value() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_invalid_creation_fails() {
+        assert!(BetaDist::new(0.0, 5.0).is_err());
+        assert!(BetaDist::new(2.0, f64::INFINITY).is_err());
+    }
 
-        assert_eq!(dist.pmf(f64::NAN), 0.0);
+    #[test]
+    fn beta_dist_correct_pdf() {
+        let dist = BetaDist::new(2.0, 5.0).unwrap();
+
+        let pdfs = array![0.1, 0.3, 0.5, 0.9].mapv(|x| dist.pdf(x));
+        let expected = array![1.9683, 2.1609, 0.9375, 0.0027];
+
+        assert_all_close(&pdfs, &expected, 1e-13);
+        assert_eq!(dist.pdf(-0.5), 0.0);
+        assert_eq!(dist.pdf(1.5), 0.0);
+        assert_eq!(dist.pdf(0.0), 0.0);
+
+        // the uniform distribution is a beta distribution, including at the bounds of the support
+        let uniform = BetaDist::new(1.0, 1.0).unwrap();
+        assert!((uniform.pdf(0.0) - 1.0).abs() < 1e-14);
+        assert!((uniform.pdf(1.0) - 1.0).abs() < 1e-14);
     }
 
     #[test]
-    fn empirical_dist_correct_cdf_inrange() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_correct_cdf() {
+        let dist = BetaDist::new(2.0, 5.0).unwrap();
 
-        let mut counts = BTreeMap::new();
-        counts.insert(0, 1.0);
-        counts.insert(1, 3.0);
-        counts.insert(3, 6.0);
+        let cdfs = array![0.1, 0.3, 0.5, 0.9].mapv(|x| dist.cdf(x));
+        let expected = array![0.114265, 0.579825, 0.890625, 0.999945];
 
-        for (i, n) in counts.iter() {
-            let v = data[[*i]];
+        assert_all_close(&cdfs, &expected, 1e-14);
+        assert_eq!(dist.cdf(-0.5), 0.0);
+        assert_eq!(dist.cdf(1.5), 1.0);
+    }
 
-            assert_eq!(dist.cdf(v), *n / data.len() as f64);
-        }
+    #[test]
+    fn beta_dist_mean_variance_calculated_correctly() {
+        let dist = BetaDist::new(2.0, 5.0).unwrap();
+
+        assert_eq!(dist.mean(), 2.0 / 7.0);
+        assert!((dist.variance() - 10.0 / (49.0 * 8.0)).abs() < 1e-15);
     }
 
     #[test]
-    fn empirical_dist_correct_cdf_outofrange() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 3.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_sample_moments_close() {
+        let dist = BetaDist::new(2.0, 5.0).unwrap();
 
-        assert_eq!(dist.cdf(0.0), 0.0);
-        assert_eq!(dist.cdf(5.0), 1.0);
+        let mut rng = StdRng::seed_from_u64(16);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|x| (0.0..=1.0).contains(x)));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.01);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.002);
     }
 
+
     #[test]
-    fn empirical_dist_correct_interval_cdf() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_fit_correct() {
+        let data = array![0.2, 0.35, 0.1, 0.5, 0.25, 0.42, 0.15];
+        let fit = BetaDist::fit(&data).unwrap();
 
-        assert_eq!(dist.interval_cdf(2.0, 4.0), 5.0 / 6.0);
+        assert!((fit.dist().alpha() - 2.9211674818452185).abs() < 1e-11);
+        assert!((fit.dist().beta() - 7.469390719203455).abs() < 1e-11);
+        assert_all_close(fit.std_errors(), &array![1.485633634556963, 4.002494715043299], 1e-11);
+
+        assert_eq!(BetaDist::fit(&array![0.5, 1.0]), Err(PensivError::InvalidInput("data must be within (0, 1)")));
+        assert_eq!(BetaDist::fit(&array![0.5, 0.5]), Err(PensivError::InvalidInput("data must have some spread")));
     }
 
     #[test]
-    fn empirical_dist_mean_calculated_correctly() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(28);
 
-        assert_eq!(dist.mean(), 2.5);
+        for &(alpha, beta) in [(0.5, 0.5), (2.0, 5.0), (30.0, 3.0)].iter() {
+            let dist = BetaDist::new(alpha, beta).unwrap();
+            let fit = BetaDist::fit(&dist.sample_n(&mut rng, 5000)).unwrap();
+
+            assert!((fit.dist().alpha() - alpha).abs() < 4.0 * fit.std_errors()[0]);
+            assert!((fit.dist().beta() - beta).abs() < 4.0 * fit.std_errors()[1]);
+        }
     }
 
     #[test]
-    fn empirical_dist_variance_calculated_correctly() {
-        let data = array![1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
-        let dist = EmpiricalDist::new(&data).unwrap();
+    fn beta_dist_higher_moments_correct() {
+        let dist = BetaDist::new(2.0, 3.0).unwrap();
 
-        let diff = dist.variance() - 0.917;
+        assert!((dist.mode().unwrap() - 1.0 / 3.0).abs() < 1e-15);
+        assert_eq!(BetaDist::new(0.5, 2.0).unwrap().mode(), Some(0.0));
+        assert_eq!(BetaDist::new(2.0, 0.5).unwrap().mode(), Some(1.0));
+        assert_eq!(BetaDist::new(1.0, 1.0).unwrap().mode(), Some(0.0));
+        assert!((dist.skewness() - 2.0 / 7.0).abs() < 1e-15);
+        assert!((dist.kurtosis() + 9.0 / 14.0).abs() < 1e-15);
+        assert!((dist.entropy() + 0.234_906_649_788_000_3).abs() < 1e-13);
 
-        assert!(diff < 1e-10);
+        // the MGF falls back on numerical integration
+        assert!((dist.mgf(1.0).unwrap() - 1.5224722339658287).abs() < 1e-10);
     }
 
     #[test]
-    fn continuous_uniform_dist_valid_created_correctly() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_valid_created_correctly() {
+        let dist = ChiSquaredDist::new(4.0).unwrap();
+        assert_eq!(dist.dof(), 4.0);
+    }
 
-        assert_eq!(dist.lower_bound(), a);
-        assert_eq!(dist.upper_bound(), b);
-        assert_eq!(dist.range(), b - a);
+    #[test]
+    fn chi_squared_dist_invalid_creation_fails() {
+        assert!(ChiSquaredDist::new(-4.0).is_err());
+        assert!(ChiSquaredDist::new(f64::NAN).is_err());
     }
 
     #[test]
-    fn continuous_uniform_dist_invalid_bounds_creation_fails() {
-        let a = 2.0;
-        let b = 1.5;
-        let dist = ContinuousUniformDist::new(a, b);
+    fn chi_squared_dist_correct_pdf() {
+        let dist = ChiSquaredDist::new(4.0).unwrap();
+
+        let pdfs = array![0.5, 2.0, 4.0, 10.0].mapv(|x| dist.pdf(x));
+        let expected = array![0.09735009788392561, 0.18393972058572117, 0.1353352832366127, 0.01684486749771367];
 
-        assert_eq!(dist, None);
+        assert_all_close(&pdfs, &expected, 1e-14);
+        assert_eq!(dist.pdf(-1.0), 0.0);
+        assert_eq!(dist.pdf(0.0), 0.0);
     }
-            
+
     #[test]
-    fn continuous_uniform_dist_correct_pdf_inrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_correct_cdf() {
+        let dist = ChiSquaredDist::new(4.0).unwrap();
 
-        let pdfs = Array::range(a, b, 0.5).mapv(|x| dist.pdf(x));
-        let expected = Array::from_elem(pdfs.shape(), 1.0 / (b - a));
+        let cdfs = array![0.5, 2.0, 4.0, 10.0].mapv(|x| dist.cdf(x));
+        let expected = array![0.026499021160743916, 0.26424111765711533, 0.5939941502901619, 0.9595723180054871];
 
-        assert!(pdfs.all_close(&expected, 1e-10));
+        assert_all_close(&cdfs, &expected, 1e-14);
+        assert_eq!(dist.cdf(-1.0), 0.0);
     }
 
     #[test]
-    fn continuous_uniform_dist_correct_pdf_outofrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_mean_variance_calculated_correctly() {
+        let dist = ChiSquaredDist::new(4.0).unwrap();
 
-        assert_eq!(dist.pdf(0.0), 0.0);
-        assert_eq!(dist.pdf(5.0), 0.0);
+        assert_eq!(dist.mean(), 4.0);
+        assert_eq!(dist.variance(), 8.0);
     }
 
-        // cdf
     #[test]
-    fn continuous_uniform_dist_correct_cdf_inrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_sample_moments_close() {
+        let dist = ChiSquaredDist::new(4.0).unwrap();
 
-        let cdfs = Array::range(a, b, 0.5).mapv(|x| dist.cdf(x));
-        let expected = Array::range(a, b, 0.5).mapv(|x| (x - a) / (b - a));
+        let mut rng = StdRng::seed_from_u64(17);
+        let samples = dist.sample_n(&mut rng, 10000);
 
-        assert!(cdfs.all_close(&expected, 1e-10));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.15);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.6);
     }
 
+
     #[test]
-    fn continuous_uniform_dist_correct_cdf_outofrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_fit_correct() {
+        let fit = ChiSquaredDist::fit(&array![2.5, 4.1, 1.3, 6.2, 3.8]).unwrap();
 
-        assert_eq!(dist.cdf(0.0), 0.0);
-        assert_eq!(dist.cdf(5.0), 1.0);
+        assert!((fit.dist().dof() - 4.1069006546456714).abs() < 1e-12);
+        assert!((fit.std_errors()[0] - 1.1322609221384885).abs() < 1e-12);
+
+        assert_eq!(ChiSquaredDist::fit(&array![2.5, -4.1]), Err(PensivError::InvalidInput("data must be positive")));
     }
 
     #[test]
-    fn continuous_uniform_dist_correct_interval_cdf_inrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn chi_squared_dist_higher_moments_correct() {
+        let dist = ChiSquaredDist::new(5.0).unwrap();
 
-        let x = a + 0.5;
-        let y = b - 0.5;
+        assert_eq!(dist.mode(), Some(3.0));
+        assert_eq!(ChiSquaredDist::new(1.0).unwrap().mode(), Some(0.0));
+        assert!((dist.skewness() - 1.6_f64.sqrt()).abs() < 1e-15);
+        assert!((dist.kurtosis() - 2.4).abs() < 1e-15);
+        assert!((dist.entropy() - 2.423_095_090_065).abs() < 1e-13);
 
-        assert_eq!(dist.interval_cdf(x, y), dist.cdf(y) - dist.cdf(x));
+        assert!((dist.mgf(0.25).unwrap() - 2_f64.powf(2.5)).abs() < 1e-14);
+        assert_eq!(dist.mgf(0.5), None);
+        assert!((dist.cf(1.0) - Complex::new(-0.12451615120135681, 0.048829006850428174)).norm() < 1e-14);
     }
 
     #[test]
-    fn continuous_uniform_dist_correct_interval_cdf_outofrange() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn student_t_dist_valid_created_correctly() {
+        let dist = StudentTDist::new(5.0).unwrap();
+        assert_eq!(dist.dof(), 5.0);
+    }
 
-        assert_eq!(dist.interval_cdf(a - 0.5, b + 0.5), 1.0);
+    #[test]
+    fn student_t_dist_invalid_creation_fails() {
+        assert!(StudentTDist::new(0.0).is_err());
+        assert!(StudentTDist::new(f64::NAN).is_err());
     }
 
     #[test]
-    fn continuous_uniform_dist_mean_calculated_correctly() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn student_t_dist_correct_pdf() {
+        let dist = StudentTDist::new(5.0).unwrap();
 
-        assert_eq!(dist.mean(), (a + b) / 2.0);
+        let pdfs = array![-3.0, -1.0, 0.0, 0.5, 2.0].mapv(|x| dist.pdf(x));
+        let expected = array![
+            0.01729257880022296, 
+            0.21967979735098056, 
+            0.37960668982249446, 
+            0.3279185313227465, 
+            0.06509031032621647
+        ];
+
+        assert_all_close(&pdfs, &expected, 1e-14);
     }
 
     #[test]
-    fn continuous_uniform_dist_variance_calculated_correctly() {
-        let a = 1.0;
-        let b = 2.5;
-        let dist = ContinuousUniformDist::new(a, b).unwrap();
+    fn student_t_dist_correct_cdf() {
+        let dist = StudentTDist::new(5.0).unwrap();
 
-        assert_eq!(dist.variance(), (b - a).powi(2) / 12.0);
+        let cdfs = array![-3.0, -1.0, 0.0, 0.5, 2.0].mapv(|x| dist.cdf(x));
+        let expected = array![0.015049623948731286, 0.1816087338245613, 0.5, 0.6808505641795355, 0.9490302605850708];
+
+        assert_all_close(&cdfs, &expected, 1e-14);
+
+        // lower tail keeps its relative precision
+        assert!((dist.cdf(-50.0) - 3.0238788133006125e-08).abs() < 1e-20);
     }
 
     #[test]
-    fn exp_dist_valid_created_correctly() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn student_t_dist_correct_ppf() {
+        let dist = StudentTDist::new(10.0).unwrap();
 
-        assert_eq!(dist.rate_param(), r);
+        assert!((dist.ppf(0.975).unwrap() - 2.228138851986275).abs() < 1e-12);
+        assert!((dist.ppf(0.025).unwrap() + 2.228138851986275).abs() < 1e-12);
     }
-    
+
     #[test]
-    fn exp_dist_invalid_rateparam_creation_fails() {
-        let r = -0.5;
-        let dist = ExponentialDist::new(r);
+    fn student_t_dist_mean_variance_calculated_correctly() {
+        let dist = StudentTDist::new(5.0).unwrap();
 
-        assert_eq!(dist, None);
+        assert_eq!(dist.mean(), 0.0);
+        assert_eq!(dist.variance(), 5.0 / 3.0);
+
+        assert!(StudentTDist::new(1.0).unwrap().mean().is_nan());
+        assert_eq!(StudentTDist::new(1.5).unwrap().variance(), f64::INFINITY);
     }
-        
+
     #[test]
-    fn exp_dist_correct_pdf_inrange() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn student_t_dist_sample_moments_close() {
+        let dist = StudentTDist::new(5.0).unwrap();
 
-        let pdfs = Array::range(0.0, 2.0, 0.5).mapv(|k| dist.pdf(k));
-        let expected = array![0.5, 0.3894, 0.3033, 0.2362];
+        let mut rng = StdRng::seed_from_u64(18);
+        let samples = dist.sample_n(&mut rng, 10000);
 
-        assert!(pdfs.all_close(&expected, 1e-4)); // error is relatively low because I rounded to 4 decimal places
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05);
+        assert!((samples.var_axis(Axis(0), 0.0).into_scalar() - dist.variance()).abs() < 0.2);
     }
 
     #[test]
-    fn exp_dist_correct_pdf_outofrange() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn student_t_dist_higher_moments_correct() {
+        let dist = StudentTDist::new(5.0).unwrap();
 
-        assert_eq!(dist.pdf(-1.0), 0.0);
+        assert_eq!(dist.median(), Some(0.0));
+        assert_eq!(dist.mode(), Some(0.0));
+        assert_eq!(dist.skewness(), 0.0);
+        assert_eq!(dist.kurtosis(), 6.0);
+        assert!((dist.entropy() - 1.627_502_672_414_396).abs() < 1e-13);
+
+        assert_eq!(dist.mgf(0.0), Some(1.0));
+        assert_eq!(dist.mgf(0.1), None);
+        // the characteristic function falls back on numerical integration
+        assert!((dist.cf(1.0) - Complex::new(0.523_994_108_831_820_3, 0.0)).norm() < 1e-12);
+
+        let heavy = StudentTDist::new(3.0).unwrap();
+        assert!(heavy.skewness().is_nan());
+        assert_eq!(heavy.kurtosis(), f64::INFINITY);
+        assert!(StudentTDist::new(2.0).unwrap().kurtosis().is_nan());
     }
-    
+
     #[test]
-    fn exp_dist_correct_cdf_inrange() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn student_t_dist_fit_correct() {
+        let fit = StudentTDist::fit(&array![0.3, -1.2, 4.5, 0.1, -0.7, 2.2, -3.1, 0.4]).unwrap();
 
-        let cdfs = Array::range(0.0, 4.0, 0.5).mapv(|x| dist.cdf(x));
-        let expected = Array::range(0.0, 4.0, 0.5).mapv(|x| { 1.0 - (-r * x).exp() });
+        assert!((fit.dist().dof() - 1.525673692320981).abs() < 1e-10);
+        assert!((fit.std_errors()[0] - 0.9565170882767512).abs() < 1e-10);
 
-        assert!(cdfs.all_close(&expected, 1e-10));
+        let light_tailed = Err(PensivError::InvalidInput("data must be heavier-tailed than a standard normal sample"));
+        assert_eq!(StudentTDist::fit(&array![-1.0, -0.5, 0.0, 0.5, 1.0]), light_tailed);
+        assert_eq!(StudentTDist::fit(&array![]), Err(PensivError::EmptyData));
     }
 
     #[test]
-    fn exp_dist_correct_cdf_outofrange() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn student_t_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(31);
 
-        assert_eq!(dist.cdf(-1.0), 0.0);
+        for &dof in [0.7, 3.0, 10.0].iter() {
+            let dist = StudentTDist::new(dof).unwrap();
+            let fit = StudentTDist::fit(&dist.sample_n(&mut rng, 5000)).unwrap();
+
+            assert!((fit.dist().dof() - dof).abs() < 4.0 * fit.std_errors()[0]);
+        }
     }
-        
+
     #[test]
-    fn exp_dist_correct_interval_cdf() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn f_dist_valid_created_correctly() {
+        let dist = FDist::new(3.0, 8.0).unwrap();
 
-        let a = 1.0;
-        let b = 4.0;
+        assert_eq!(dist.dof1(), 3.0);
+        assert_eq!(dist.dof2(), 8.0);
+    }
 
-        assert_eq!(dist.interval_cdf(a, b), dist.cdf(b) - dist.cdf(a));
+    #[test]
+    fn f_dist_invalid_creation_fails() {
+        assert!(FDist::new(3.0, -8.0).is_err());
+        assert!(FDist::new(0.0, 8.0).is_err());
     }
-        
+
     #[test]
-    fn exp_dist_mean_calculated_correctly() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn f_dist_correct_pdf() {
+        let dist = FDist::new(3.0, 8.0).unwrap();
 
-        assert_eq!(dist.mean(), 1.0 / r);
+        let pdfs = array![0.5, 1.0, 2.0, 4.0].mapv(|x| dist.pdf(x));
+        let expected = array![0.621165860307541, 0.3922316520431642, 0.14723572768942425, 0.02927975409732807];
+
+        assert_all_close(&pdfs, &expected, 1e-14);
+        assert_eq!(dist.pdf(-1.0), 0.0);
+        assert_eq!(dist.pdf(0.0), 0.0);
     }
-        
+
     #[test]
-    fn exp_dist_variance_calculated_correctly() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn f_dist_correct_cdf() {
+        let dist = FDist::new(3.0, 8.0).unwrap();
 
-        assert_eq!(dist.variance(), 1.0 / r.powi(2));
+        let cdfs = array![0.5, 1.0, 2.0, 4.0].mapv(|x| dist.cdf(x));
+        let expected = array![0.30737513305615366, 0.5589009202588271, 0.8073425734970097, 0.9481063231515756];
+
+        assert_all_close(&cdfs, &expected, 1e-14);
+        assert_eq!(dist.cdf(-1.0), 0.0);
     }
-        
+
     #[test]
-    fn exp_dist_std_calculated_correctly() {
-        let r = 0.5;
-        let dist = ExponentialDist::new(r).unwrap();
+    fn f_dist_mean_variance_calculated_correctly() {
+        let dist = FDist::new(3.0, 8.0).unwrap();
 
-        assert_eq!(dist.std(), 1.0 / r);
+        assert_eq!(dist.mean(), 8.0 / 6.0);
+        assert!((dist.variance() - 2.0 * 64.0 * 9.0 / (3.0 * 36.0 * 4.0)).abs() < 1e-14);
+        assert!(FDist::new(3.0, 2.0).unwrap().mean().is_nan());
     }
-    
+
     #[test]
-    fn normal_dist_valid_created_correctly() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    fn f_dist_sample_mean_close() {
+        let dist = FDist::new(3.0, 8.0).unwrap();
 
-        assert_eq!(dist.loc(), loc);
-        assert_eq!(dist.scale(), scale);
+        let mut rng = StdRng::seed_from_u64(19);
+        let samples = dist.sample_n(&mut rng, 10000);
+
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert!((samples.mean().unwrap() - dist.mean()).abs() < 0.05);
     }
-    
+
     #[test]
-    fn normal_dist_invalid_scale_creation_fails() {
-        let loc = 5.0;
-        let scale = -2.0;
-        let dist = NormalDist::new(loc, scale);
+    fn f_dist_higher_moments_correct() {
+        let dist = FDist::new(5.0, 10.0).unwrap();
+
+        assert!((dist.mode().unwrap() - 0.5).abs() < 1e-15);
+        assert_eq!(FDist::new(2.0, 10.0).unwrap().mode(), Some(0.0));
+        assert!((dist.skewness() - 3.8670203198129376).abs() < 1e-14);
+        assert!((dist.kurtosis() - 50.861_538_461_538_46).abs() < 1e-12);
+        assert!((dist.entropy() - 1.1307598049090604).abs() < 1e-13);
+        assert_eq!(dist.mgf(0.1), None);
 
-        assert_eq!(dist, None);
+        let heavy = FDist::new(5.0, 6.0).unwrap();
+        assert_eq!(heavy.skewness(), f64::INFINITY);
+        assert_eq!(heavy.kurtosis(), f64::INFINITY);
+        assert!(FDist::new(5.0, 4.0).unwrap().skewness().is_nan());
     }
 
     #[test]
-    fn normal_dist_std_created_correctly() {
-        let dist = NormalDist::std();
+    fn f_dist_fit_correct() {
+        let fit = FDist::fit(&array![0.8, 1.3, 0.4, 2.1, 0.9, 1.1, 0.6, 3.2]).unwrap();
 
-        assert_eq!(dist.loc(), 0.0);
-        assert_eq!(dist.scale(), 1.0);
+        assert!((fit.dist().dof1() - 22.11112031763421).abs() < 1e-8);
+        assert!((fit.dist().dof2() - 7.659275313375778).abs() < 1e-8);
+        assert_all_close(fit.std_errors(), &array![47.07305309650653, 6.383297436446216], 1e-8);
+
+        assert_eq!(FDist::fit(&array![0.8, -1.3]), Err(PensivError::InvalidInput("data must be positive")));
     }
 
     #[test]
-    fn normal_dist_zvalue_calculated_correctly() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    fn f_dist_fit_recovers_parameters() {
+        let mut rng = StdRng::seed_from_u64(32);
 
-        assert_eq!(dist.z(7.0), 1.0);
-        assert_eq!(dist.z(5.0), 0.0);
-        assert_eq!(dist.z(3.0), -1.0);
+        for &(dof1, dof2) in [(1.0, 1.0), (5.0, 10.0), (30.0, 8.0)].iter() {
+            let dist = FDist::new(dof1, dof2).unwrap();
+            let fit = FDist::fit(&dist.sample_n(&mut rng, 2000)).unwrap();
+
+            assert!((fit.dist().dof1() - dof1).abs() < 4.0 * fit.std_errors()[0]);
+            assert!((fit.dist().dof2() - dof2).abs() < 4.0 * fit.std_errors()[1]);
+        }
     }
 
+
     #[test]
-    fn normal_dist_pdf_correct() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    fn continuous_dists_log_pdf_matches_pdf() {
+        let gamma = GammaDist::new(2.5, 1.5).unwrap();
+        let beta = BetaDist::new(2.0, 5.0).unwrap();
+        let chi_squared = ChiSquaredDist::new(4.0).unwrap();
+        let t = StudentTDist::new(5.0).unwrap();
+        let f = FDist::new(3.0, 8.0).unwrap();
+
+        for x in array![0.1, 0.3, 0.5, 0.9].iter() {
+            assert!((gamma.log_pdf(*x) - gamma.pdf(*x).ln()).abs() < 1e-12);
+            assert!((beta.log_pdf(*x) - beta.pdf(*x).ln()).abs() < 1e-12);
+            assert!((chi_squared.log_pdf(*x) - chi_squared.pdf(*x).ln()).abs() < 1e-12);
+            assert!((t.log_pdf(*x) - t.pdf(*x).ln()).abs() < 1e-12);
+            assert!((f.log_pdf(*x) - f.pdf(*x).ln()).abs() < 1e-12);
+        }
 
-        let pdfs = Array::range(-5.0, 20.0, 5.0).mapv(|x| dist.pdf(x));
-        let expected = array![0.00000074, 0.00876415, 0.19947114, 0.00876415, 0.00000074];
+        assert_eq!(gamma.log_pdf(-1.0), f64::NEG_INFINITY);
+        assert_eq!(beta.log_pdf(1.5), f64::NEG_INFINITY);
+        assert_eq!(chi_squared.log_pdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(f.log_pdf(-1.0), f64::NEG_INFINITY);
 
-        assert!(pdfs.all_close(&expected, 1e-8));
+        // well beyond the point where the PDFs underflow
+        assert!(gamma.log_pdf(1000.0).is_finite());
+        assert!(t.log_pdf(1e200).is_finite());
     }
 
     #[test]
-    fn normal_dist_cdf_correct() {
-        let loc = 5.0;
-        let scale = 2.5;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    #[cfg(feature = "serde")]
+    fn discrete_dists_serde_round_trip() {
+        use crate::utils::json_round_trip;
 
-        let cdfs = Array::range(0.0, 12.5, 2.5).mapv(|x| dist.cdf(x));
-        let expected = array![
-            0.022750062, 
-            0.1586552596, 
-            0.5, 
-            0.8413447404, 
-            0.977249938
-        ];
+        let binom = BinomDist::new(20, 0.3).unwrap();
+        assert_eq!(serde_json::to_string(&binom).unwrap(), r#"{"trials":20,"p_success":0.3}"#);
 
-        assert!(cdfs.all_close(&expected, 1e-8));
+        assert_eq!(json_round_trip(&binom).unwrap(), binom);
+        assert_eq!(json_round_trip(&DiscreteUniformDist::new(-2, 5).unwrap()).unwrap(), DiscreteUniformDist::new(-2, 5).unwrap());
+        assert_eq!(json_round_trip(&BernoulliDist::new(0.4).unwrap()).unwrap(), BernoulliDist::new(0.4).unwrap());
+        assert_eq!(json_round_trip(&GeometricDist::new(0.2).unwrap()).unwrap(), GeometricDist::new(0.2).unwrap());
+        assert_eq!(json_round_trip(&PoissonDist::new(3.5).unwrap()).unwrap(), PoissonDist::new(3.5).unwrap());
+
+        let negative_binomial = NegativeBinomialDist::new(2.5, 0.6).unwrap();
+        assert_eq!(json_round_trip(&negative_binomial).unwrap(), negative_binomial);
+
+        let hypergeometric = HypergeometricDist::new(50, 10, 5).unwrap();
+        assert_eq!(json_round_trip(&hypergeometric).unwrap(), hypergeometric);
+
+        let categorical = CategoricalDist::new(&array![0.2, 0.5, 0.3]).unwrap();
+        assert_eq!(json_round_trip(&categorical).unwrap(), categorical);
+
+        let empirical = EmpiricalDist::new(&array![1.0, 3.0, 2.0, 3.0]).unwrap();
+        let deserialized = json_round_trip(&empirical).unwrap();
+
+        assert_eq!(deserialized.data(), empirical.data());
+        assert_eq!(deserialized.pmf(3.0), 0.5);
     }
-        
+
     #[test]
-    fn normal_dist_mean_calculated_correctly() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    #[cfg(feature = "serde")]
+    fn continuous_dists_serde_round_trip() {
+        use crate::utils::json_round_trip;
 
-        assert_eq!(dist.mean(), loc);
+        let normal = NormalDist::new(5.0, 2.0).unwrap();
+        assert_eq!(serde_json::to_string(&normal).unwrap(), r#"{"loc":5.0,"scale":2.0}"#);
+
+        assert_eq!(json_round_trip(&normal).unwrap(), normal);
+        assert_eq!(json_round_trip(&NormalDist::new_in(5.0_f32, 2.0).unwrap()).unwrap(), NormalDist::new_in(5.0_f32, 2.0).unwrap());
+
+        let uniform = ContinuousUniformDist::new(-1.0, 2.5).unwrap();
+        assert_eq!(json_round_trip(&uniform).unwrap(), uniform);
+        assert_eq!(json_round_trip(&ExponentialDist::new(0.5).unwrap()).unwrap(), ExponentialDist::new(0.5).unwrap());
+        assert_eq!(json_round_trip(&GammaDist::new(2.5, 1.5).unwrap()).unwrap(), GammaDist::new(2.5, 1.5).unwrap());
+        assert_eq!(json_round_trip(&BetaDist::new(2.0, 3.5).unwrap()).unwrap(), BetaDist::new(2.0, 3.5).unwrap());
+        assert_eq!(json_round_trip(&ChiSquaredDist::new(3.0).unwrap()).unwrap(), ChiSquaredDist::new(3.0).unwrap());
+        assert_eq!(json_round_trip(&StudentTDist::new(4.0).unwrap()).unwrap(), StudentTDist::new(4.0).unwrap());
+        assert_eq!(json_round_trip(&FDist::new(5.0, 10.0).unwrap()).unwrap(), FDist::new(5.0, 10.0).unwrap());
     }
-    
+
     #[test]
-    fn normal_dist_variance_calculated_correctly() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
+    #[cfg(feature = "serde")]
+    fn fit_serde_round_trip() {
+        let fit = NormalDist::fit(&array![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let deserialized = crate::utils::json_round_trip(&fit).unwrap();
 
-        assert_eq!(dist.variance(), scale.powi(2));
+        assert_eq!(deserialized, fit);
     }
-    
-    #[test]
-    fn normal_dist_std_calculated_correctly() {
-        let loc = 5.0;
-        let scale = 2.0;
-        let dist = NormalDist::new(loc, scale).unwrap();
 
-        assert_eq!(dist.std(), scale);
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_invalid_parameters() {
+        assert!(serde_json::from_str::<BinomDist>(r#"{"trials":20,"p_success":1.5}"#).is_err());
+        assert!(serde_json::from_str::<BinomDist>(r#"{"trials":-1,"p_success":0.5}"#).is_err());
+        assert!(serde_json::from_str::<HypergeometricDist>(r#"{"population":5,"successes":10,"draws":2}"#).is_err());
+        assert!(serde_json::from_str::<CategoricalDist>(r#"{"probabilities":[0.0,0.0]}"#).is_err());
+        assert!(serde_json::from_str::<NormalDist>(r#"{"loc":0.0,"scale":-1.0}"#).is_err());
+        assert!(serde_json::from_str::<ContinuousUniformDist>(r#"{"lower_bound":2.0,"upper_bound":1.0}"#).is_err());
+        assert!(serde_json::from_str::<GammaDist<f32>>(r#"{"shape":1e39,"rate_param":1.0}"#).is_err());
+
+        // missing parameters are rejected too
+        assert!(serde_json::from_str::<BetaDist>(r#"{"alpha":2.0}"#).is_err());
+
+        let error = serde_json::from_str::<BinomDist>(r#"{"trials":20,"p_success":1.5}"#).unwrap_err();
+        assert!(error.to_string().contains("invalid p_success of 1.5"));
     }
-}
\ No newline at end of file
+}
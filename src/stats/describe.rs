@@ -0,0 +1,496 @@
+//! Descriptive statistics of data sets stored in `ndarray` arrays.
+//!
+//! Unlike `EmpiricalDist`, which describes a data set as a distribution, the functions here compute summary statistics
+//! directly from a 1-dimensional array: location (mean, median, mode, trimmed & weighted means), spread (variance, standard
+//! deviation, IQR, MAD), shape (skewness & kurtosis), and quantiles. Statistics of each row or column of a 2-dimensional
+//! array can be computed with `along_axis()`, and `covariance()` & `correlation()` compute the covariance and correlation
+//! matrices of the variables in a 2-dimensional array.
+//!
+//! Moments are computed with two passes over the data (the mean first, then deviations from it) rather than from sums of
+//! powers, which avoids catastrophic cancellation when the mean is large relative to the spread. All functions return
+//! `None` when the statistic is undefined for their input, e.g. for empty data sets.
+
+use ndarray::prelude::*;
+
+use std::cmp::Ordering;
+
+/// Returns the mean of `data`, or `None` if `data` is empty.
+///
+/// The mean is computed in two passes: after the naive mean, the mean of the deviations from it is added as a correction
+/// for rounding error.
+///
+/// ```ignore
+/// println!("{:?}", mean(&array![1.0, 2.0, 3.0, 4.0])); // prints "Some(2.5)"
+/// ```
+pub fn mean(data: &Array<f64, Ix1>) -> Option<f64> {
+    let naive = data.mean()?;
+    let correction = data.iter().map(|x| x - naive).sum::<f64>() / data.len() as f64;
+
+    Some(naive + correction)
+}
+
+/// Returns the variance of `data` with `ddof` "delta degrees of freedom", i.e. the sum of squared deviations from the
+/// mean divided by `n - ddof`.
+///
+/// Use `ddof = 0` for the population variance (the maximum-likelihood estimate) and `ddof = 1` for the unbiased sample
+/// variance. The variance is computed with the corrected two-pass algorithm, which stays accurate even when the mean is
+/// many orders of magnitude larger than the spread. Returns `None` if `data` has no more than `ddof` elements.
+///
+/// ```ignore
+/// let data = array![1.0, 2.0, 3.0, 4.0];
+///
+/// println!("{:?}", variance(&data, 0)); // prints "Some(1.25)"
+/// println!("{:?}", variance(&data, 1)); // prints "Some(1.6666666666666667)"
+/// ```
+pub fn variance(data: &Array<f64, Ix1>, ddof: usize) -> Option<f64> {
+    let n = data.len();
+    if n <= ddof {
+        return None;
+    }
+
+    let mean = mean(data)?;
+    let (sum_squares, sum) = data.iter().fold((0.0, 0.0), |(sq, s), x| (sq + (x - mean).powi(2), s + (x - mean)));
+
+    Some((sum_squares - sum * sum / n as f64) / (n - ddof) as f64)
+}
+
+/// Returns the standard deviation of `data` with `ddof` delta degrees of freedom, i.e. the square root of
+/// `variance(data, ddof)`.
+///
+/// ```ignore
+/// println!("{:?}", std(&array![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0], 0)); // prints "Some(2.0)"
+/// ```
+pub fn std(data: &Array<f64, Ix1>, ddof: usize) -> Option<f64> {
+    variance(data, ddof).map(f64::sqrt)
+}
+
+/// Returns the `q`-th quantile of `data`, linearly interpolated between adjacent order statistics.
+///
+/// This is the same "type 7" definition used by `EmpiricalDist::quantile()`: with the data sorted as
+/// `x[0] <= ... <= x[n - 1]`, the quantile is `x[j] + (h - j)(x[j + 1] - x[j])` where `h = (n - 1)q` and `j = floor(h)`.
+/// Returns `None` if `q` isn't a valid probability, if `data` is empty, or if `data` contains `NaN`.
+///
+/// ```ignore
+/// let data = array![4.0, 1.0, 3.0, 2.0];
+///
+/// println!("{:?}", quantile(&data, 0.5)); // prints "Some(2.5)"
+/// println!("{:?}", quantile(&data, 0.25)); // prints "Some(1.75)"
+/// ```
+pub fn quantile(data: &Array<f64, Ix1>, q: f64) -> Option<f64> {
+    quantiles(data, &array![q]).map(|q| q[0])
+}
+
+/// Returns the quantile of `data` for each value in `qs`; see `quantile()` for details.
+///
+/// The data is only sorted once, so this is more efficient than calling `quantile()` repeatedly. Returns `None` if any
+/// value in `qs` isn't a valid probability, if `data` is empty, or if `data` contains `NaN`.
+///
+/// ```ignore
+/// let data = array![4.0, 1.0, 3.0, 2.0];
+/// println!("{:?}", quantiles(&data, &array![0.0, 0.5, 1.0])); // prints "Some([1.0, 2.5, 4.0])"
+/// ```
+pub fn quantiles(data: &Array<f64, Ix1>, qs: &Array<f64, Ix1>) -> Option<Array<f64, Ix1>> {
+    if qs.iter().any(|q| !(0.0..=1.0).contains(q)) {
+        return None;
+    }
+
+    let sorted = sorted(data)?;
+    Some(qs.mapv(|q| sorted_quantile(&sorted, q)))
+}
+
+/// Returns the median of `data`, i.e. its `0.5`-th quantile.
+///
+/// Returns `None` if `data` is empty or contains `NaN`.
+///
+/// ```ignore
+/// println!("{:?}", median(&array![3.0, 1.0, 2.0])); // prints "Some(2.0)"
+/// println!("{:?}", median(&array![4.0, 3.0, 1.0, 2.0])); // prints "Some(2.5)"
+/// ```
+pub fn median(data: &Array<f64, Ix1>) -> Option<f64> {
+    quantile(data, 0.5)
+}
+
+/// Returns the interquartile range of `data`, i.e. the difference between its `0.75`-th and `0.25`-th quantiles.
+///
+/// Returns `None` if `data` is empty or contains `NaN`.
+///
+/// ```ignore
+/// println!("{:?}", iqr(&array![1.0, 2.0, 3.0, 4.0, 5.0])); // prints "Some(2.0)"
+/// ```
+pub fn iqr(data: &Array<f64, Ix1>) -> Option<f64> {
+    let quartiles = quantiles(data, &array![0.25, 0.75])?;
+    Some(quartiles[1] - quartiles[0])
+}
+
+/// Returns the mode of `data`, i.e. its most frequent value.
+///
+/// If several values are equally frequent, the smallest of them is returned. Returns `None` if `data` is empty or
+/// contains `NaN`.
+///
+/// ```ignore
+/// println!("{:?}", mode(&array![1.0, 3.0, 3.0, 2.0, 2.0])); // prints "Some(2.0)"
+/// ```
+pub fn mode(data: &Array<f64, Ix1>) -> Option<f64> {
+    let sorted = sorted(data)?;
+
+    let mut mode = sorted[0];
+    let mut mode_count = 0;
+    let mut run_start = 0;
+
+    for i in 1..=sorted.len() {
+        if i == sorted.len() || sorted[i] != sorted[run_start] {
+            if i - run_start > mode_count {
+                mode = sorted[run_start];
+                mode_count = i - run_start;
+            }
+
+            run_start = i;
+        }
+    }
+
+    Some(mode)
+}
+
+/// Returns the median absolute deviation of `data`, i.e. the median of the absolute deviations from the median.
+///
+/// The result isn't scaled; multiply it by about `1.4826` for a consistent estimate of the standard deviation of normally
+/// distributed data. Returns `None` if `data` is empty or contains `NaN`.
+///
+/// ```ignore
+/// println!("{:?}", mad(&array![1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0])); // prints "Some(1.0)"
+/// ```
+pub fn mad(data: &Array<f64, Ix1>) -> Option<f64> {
+    let median = median(data)?;
+    self::median(&data.mapv(|x| (x - median).abs()))
+}
+
+/// Returns the mean of `data` after discarding the `proportion` smallest and `proportion` largest values.
+///
+/// The number of values discarded from each end is `floor(proportion * n)`. Returns `None` if `data` is empty or contains
+/// `NaN`, or if `proportion` isn't in `[0, 0.5)`.
+///
+/// ```ignore
+/// let data = array![1.0, 2.0, 3.0, 4.0, 100.0];
+/// println!("{:?}", trimmed_mean(&data, 0.2)); // prints "Some(3.0)"
+/// ```
+pub fn trimmed_mean(data: &Array<f64, Ix1>, proportion: f64) -> Option<f64> {
+    if !(0.0..0.5).contains(&proportion) {
+        return None;
+    }
+
+    let sorted = sorted(data)?;
+    let trimmed = (proportion * sorted.len() as f64).floor() as usize;
+
+    mean(&sorted.slice(s![trimmed..(sorted.len() - trimmed)]).to_owned())
+}
+
+/// Returns the mean of `data` where each value is weighted by the corresponding element of `weights`.
+///
+/// Weights don't need to sum to `1`. Returns `None` if `data` and `weights` have different lengths, if any weight is
+/// negative or not finite, or if all weights are `0`.
+///
+/// ```ignore
+/// let data = array![1.0, 2.0, 3.0];
+/// println!("{:?}", weighted_mean(&data, &array![3.0, 1.0, 0.0])); // prints "Some(1.25)"
+/// ```
+pub fn weighted_mean(data: &Array<f64, Ix1>, weights: &Array<f64, Ix1>) -> Option<f64> {
+    let total = total_weight(data, weights)?;
+    let naive = data.dot(weights) / total;
+    let correction = data.iter().zip(weights.iter()).map(|(x, w)| w * (x - naive)).sum::<f64>() / total;
+
+    Some(naive + correction)
+}
+
+/// Returns the variance of `data` where each value is weighted by the corresponding element of `weights`, i.e. the
+/// weighted mean of the squared deviations from the weighted mean.
+///
+/// This is the weighted analogue of the population variance (`ddof = 0`); with all weights equal, it's equivalent to
+/// `variance(data, 0)`. Returns `None` under the same conditions as `weighted_mean()`.
+///
+/// ```ignore
+/// let data = array![1.0, 2.0, 3.0];
+/// println!("{:?}", weighted_variance(&data, &array![1.0, 0.0, 1.0])); // prints "Some(1.0)"
+/// ```
+pub fn weighted_variance(data: &Array<f64, Ix1>, weights: &Array<f64, Ix1>) -> Option<f64> {
+    let mean = weighted_mean(data, weights)?;
+    let deviations = data.mapv(|x| (x - mean).powi(2));
+
+    Some(deviations.dot(weights) / weights.sum())
+}
+
+/// Returns the skewness of `data`, i.e. its third central moment divided by the 1.5th power of its second central moment.
+///
+/// This is the (biased) moment estimate `g1`; multiply it by `sqrt(n(n - 1)) / (n - 2)` for the adjusted estimate that's
+/// unbiased for normally distributed data. Returns `None` if `data` is empty or all of its values are equal.
+///
+/// ```ignore
+/// println!("{:?}", skewness(&array![1.0, 2.0, 3.0])); // prints "Some(0.0)"
+/// println!("{:?}", skewness(&array![1.0, 1.0, 4.0])); // prints approximately "Some(0.7071)"
+/// ```
+pub fn skewness(data: &Array<f64, Ix1>) -> Option<f64> {
+    let (m2, m3, _) = central_moments(data)?;
+    Some(m3 / m2.powf(1.5))
+}
+
+/// Returns the excess kurtosis of `data`, i.e. its fourth central moment divided by the square of its second central
+/// moment, minus `3` (the kurtosis of a normal distribution).
+///
+/// As with `skewness()`, this is the (biased) moment estimate `g2`. Returns `None` if `data` is empty or all of its values
+/// are equal.
+///
+/// ```ignore
+/// println!("{:?}", kurtosis(&array![1.0, 2.0, 3.0, 4.0])); // prints approximately "Some(-1.36)"
+/// ```
+pub fn kurtosis(data: &Array<f64, Ix1>) -> Option<f64> {
+    let (m2, _, m4) = central_moments(data)?;
+    Some(m4 / (m2 * m2) - 3.0)
+}
+
+/// Returns the result of applying `statistic` to each lane of `data` along `axis`.
+///
+/// With `axis = Axis(0)`, `statistic` is applied to each column of `data`, and with `axis = Axis(1)`, it's applied to each
+/// row. Returns `None` if `statistic` returns `None` for any lane.
+///
+/// ```ignore
+/// let data = array![[1.0, 10.0], [2.0, 20.0], [3.0, 60.0]];
+///
+/// println!("{:?}", along_axis(&data, Axis(0), median)); // prints "Some([2.0, 20.0])"
+/// println!("{:?}", along_axis(&data, Axis(0), |col| variance(col, 1))); // prints "Some([1.0, 700.0])"
+/// ```
+pub fn along_axis<F>(data: &Array<f64, Ix2>, axis: Axis, statistic: F) -> Option<Array<f64, Ix1>>
+    where F: Fn(&Array<f64, Ix1>) -> Option<f64>
+{
+    data.lanes(axis).into_iter().map(|lane| statistic(&lane.to_owned())).collect::<Option<Vec<f64>>>().map(Array::from)
+}
+
+/// Returns the covariance matrix of the variables in `data` with `ddof` delta degrees of freedom.
+///
+/// `axis` is the axis along which observations lie: with `axis = Axis(0)`, each row of `data` is an observation and each
+/// column is a variable, and with `axis = Axis(1)`, it's the other way around. Entry `(i, j)` of the result is the
+/// covariance of variables `i` and `j`, i.e. the sum of the products of their deviations from their means divided by
+/// `n - ddof`. Returns `None` if there are no more than `ddof` observations or no variables.
+///
+/// ```ignore
+/// let data = array![[1.0, 2.0], [2.0, 4.0], [3.0, 7.0]];
+/// println!("{:?}", covariance(&data, Axis(0), 1)); // prints "Some([[1.0, 2.5], [2.5, 6.333333333333333]])"
+/// ```
+pub fn covariance(data: &Array<f64, Ix2>, axis: Axis, ddof: usize) -> Option<Array<f64, Ix2>> {
+    let observations = if axis == Axis(0) { data.view() } else { data.t() };
+    let (n, variables) = observations.dim();
+
+    if n <= ddof || variables == 0 {
+        return None;
+    }
+
+    let means = along_axis(data, axis, mean)?;
+    let centered = &observations - &means;
+
+    Some(centered.t().dot(&centered) / (n - ddof) as f64)
+}
+
+/// Returns the (Pearson) correlation matrix of the variables in `data`.
+///
+/// As with `covariance()`, `axis` is the axis along which observations lie. Entry `(i, j)` of the result is the covariance
+/// of variables `i` and `j` divided by the product of their standard deviations, so the diagonal is all `1`. Returns `None`
+/// if there are fewer than 2 observations, no variables, or if any variable has no spread.
+///
+/// ```ignore
+/// let data = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// println!("{:?}", correlation(&data, Axis(0))); // prints "Some([[1.0, 1.0], [1.0, 1.0]])"
+/// ```
+pub fn correlation(data: &Array<f64, Ix2>, axis: Axis) -> Option<Array<f64, Ix2>> {
+    let covariance = covariance(data, axis, 1)?;
+    let std = covariance.diag().mapv(f64::sqrt);
+
+    if std.iter().any(|&s| s <= 0.0 || !s.is_finite()) {
+        return None;
+    }
+
+    let mut correlation = covariance / &std / &std.insert_axis(Axis(1));
+
+    // the diagonal is exactly 1 by definition, and the off-diagonal can only exceed 1 in magnitude due to rounding error
+    correlation.mapv_inplace(|r| r.clamp(-1.0, 1.0));
+    correlation.diag_mut().fill(1.0);
+
+    Some(correlation)
+}
+
+
+/// Returns a sorted copy of `data`, or `None` if `data` is empty or contains `NaN`.
+fn sorted(data: &Array<f64, Ix1>) -> Option<Array<f64, Ix1>> {
+    if data.is_empty() || data.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+
+    let mut values = data.to_vec();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    Some(Array::from(values))
+}
+
+/// Returns the type 7 `q`-th quantile of `sorted`, which must be sorted and nonempty.
+fn sorted_quantile(sorted: &Array<f64, Ix1>, q: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * q;
+    let j = h.floor() as usize;
+
+    if j + 1 >= sorted.len() {
+        return sorted[j];
+    }
+
+    sorted[j] + (h - j as f64) * (sorted[j + 1] - sorted[j])
+}
+
+/// Returns the sum of `weights`, or `None` if they aren't valid weights for `data`.
+fn total_weight(data: &Array<f64, Ix1>, weights: &Array<f64, Ix1>) -> Option<f64> {
+    if data.len() != weights.len() || weights.iter().any(|w| !(*w >= 0.0 && w.is_finite())) {
+        return None;
+    }
+
+    let total = weights.sum();
+    if total > 0.0 { Some(total) } else { None }
+}
+
+/// Returns the second, third, and fourth central moments of `data` (dividing by `n`), or `None` if `data` is empty or the
+/// second moment is `0`.
+fn central_moments(data: &Array<f64, Ix1>) -> Option<(f64, f64, f64)> {
+    let mean = mean(data)?;
+    let n = data.len() as f64;
+
+    let (m2, m3, m4) = data.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), x| {
+        let d = x - mean;
+        let d2 = d * d;
+
+        (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+    });
+
+    if m2 <= 0.0 {
+        return None;
+    }
+
+    Some((m2 / n, m3 / n, m4 / n))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_correct() {
+        assert_eq!(mean(&array![1.0, 2.0, 3.0, 4.0]), Some(2.5));
+        assert_eq!(mean(&array![]), None);
+    }
+
+    #[test]
+    fn variance_correct() {
+        let data = array![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        assert_eq!(variance(&data, 0), Some(4.0));
+        assert_eq!(variance(&data, 1), Some(32.0 / 7.0));
+        assert_eq!(std(&data, 0), Some(2.0));
+
+        assert_eq!(variance(&array![1.0], 1), None);
+        assert_eq!(variance(&array![1.0], 0), Some(0.0));
+    }
+
+    #[test]
+    fn variance_stable_with_large_offset() {
+        // E[x^2] - E[x]^2 loses every significant digit here
+        let data = array![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        assert_eq!(variance(&data, 1), Some(30.0));
+    }
+
+    #[test]
+    fn quantiles_correct() {
+        let data = array![4.0, 1.0, 3.0, 2.0];
+
+        assert_eq!(quantile(&data, 0.25), Some(1.75));
+        assert_eq!(quantiles(&data, &array![0.0, 0.5, 1.0]), Some(array![1.0, 2.5, 4.0]));
+        assert_eq!(median(&array![3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(iqr(&array![1.0, 2.0, 3.0, 4.0, 5.0]), Some(2.0));
+
+        assert_eq!(quantile(&data, 1.5), None);
+        assert_eq!(median(&array![]), None);
+        assert_eq!(median(&array![1.0, f64::NAN]), None);
+    }
+
+    #[test]
+    fn mode_correct() {
+        assert_eq!(mode(&array![1.0, 3.0, 3.0, 2.0, 2.0]), Some(2.0));
+        assert_eq!(mode(&array![5.0, 1.0, 5.0]), Some(5.0));
+        assert_eq!(mode(&array![]), None);
+    }
+
+    #[test]
+    fn mad_correct() {
+        assert_eq!(mad(&array![1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]), Some(1.0));
+    }
+
+    #[test]
+    fn trimmed_mean_correct() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 100.0];
+
+        assert_eq!(trimmed_mean(&data, 0.2), Some(3.0));
+        assert_eq!(trimmed_mean(&data, 0.0), Some(22.0));
+        assert_eq!(trimmed_mean(&data, 0.5), None);
+    }
+
+    #[test]
+    fn weighted_mean_variance_correct() {
+        let data = array![1.0, 2.0, 3.0];
+
+        assert_eq!(weighted_mean(&data, &array![3.0, 1.0, 0.0]), Some(1.25));
+        assert_eq!(weighted_variance(&data, &array![1.0, 0.0, 1.0]), Some(1.0));
+        assert_eq!(weighted_variance(&data, &array![2.0, 2.0, 2.0]), variance(&data, 0));
+
+        assert_eq!(weighted_mean(&data, &array![1.0, 1.0]), None);
+        assert_eq!(weighted_mean(&data, &array![1.0, -1.0, 1.0]), None);
+        assert_eq!(weighted_mean(&data, &array![0.0, 0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn skewness_kurtosis_correct() {
+        assert_eq!(skewness(&array![1.0, 2.0, 3.0]), Some(0.0));
+        assert!((skewness(&array![1.0, 1.0, 4.0]).unwrap() - 0.5_f64.sqrt()).abs() < 1e-15);
+        assert!((kurtosis(&array![1.0, 2.0, 3.0, 4.0]).unwrap() + 1.36).abs() < 1e-14);
+
+        assert_eq!(skewness(&array![2.0, 2.0]), None);
+        assert_eq!(kurtosis(&array![]), None);
+    }
+
+    #[test]
+    fn along_axis_correct() {
+        let data = array![[1.0, 10.0], [2.0, 20.0], [3.0, 60.0]];
+
+        assert_eq!(along_axis(&data, Axis(0), median), Some(array![2.0, 20.0]));
+        assert_eq!(along_axis(&data, Axis(0), |col| variance(col, 1)), Some(array![1.0, 700.0]));
+        assert_eq!(along_axis(&data, Axis(1), mean), Some(array![5.5, 11.0, 31.5]));
+        assert_eq!(along_axis(&data, Axis(1), |row| variance(row, 2)), None);
+    }
+
+    #[test]
+    fn covariance_correct() {
+        let data = array![[1.0, 2.0], [2.0, 4.0], [3.0, 7.0]];
+        let expected = array![[1.0, 2.5], [2.5, 19.0 / 3.0]];
+
+        let covariance_rows = covariance(&data, Axis(0), 1).unwrap();
+        let covariance_columns = covariance(&data.t().to_owned(), Axis(1), 1).unwrap();
+
+        assert!(covariance_rows.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-14));
+        assert_eq!(covariance_rows, covariance_columns);
+        assert_eq!(covariance(&data, Axis(1), 2), None);
+    }
+
+    #[test]
+    fn correlation_correct() {
+        let data = array![[1.0, 2.0, -1.0], [2.0, 4.0, -3.0], [3.0, 6.0, -2.0]];
+        let correlation = correlation(&data, Axis(0)).unwrap();
+
+        assert_eq!(correlation.diag(), array![1.0, 1.0, 1.0]);
+        assert!((correlation[[0, 1]] - 1.0).abs() < 1e-15);
+        assert!((correlation[[0, 2]] + 0.5).abs() < 1e-15);
+        assert_eq!(correlation, correlation.t());
+
+        assert_eq!(super::correlation(&array![[1.0, 2.0], [1.0, 3.0]], Axis(0)), None);
+    }
+}
@@ -0,0 +1,655 @@
+//! Histograms of data sets, and the piecewise-constant distributions they describe.
+//!
+//! A `Histogram` counts the elements of a data set falling into each of a sequence of adjacent bins, whose edges are
+//! chosen by one of several `Binning` strategies. Histograms built with the same edges can be merged, and any nonempty
+//! histogram can be converted into a `HistogramDist`, the continuous distribution whose density is constant within each
+//! bin.
+
+use ndarray::prelude::*;
+
+use rand::Rng;
+use rand::RngCore;
+
+use crate::utils::ComparableFloat;
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+
+use super::{describe, quantile_reached, ContinuousDist};
+#[cfg(feature = "serde")]
+use super::CategoricalDist;
+
+
+/// The largest number of bins a `Histogram` can have.
+///
+/// An explicit `FixedWidth` or `Quantile` binning that would need more bins is rejected, while the rule-based binnings
+/// (`Scott` & `FreedmanDiaconis`) are clamped to this many bins, e.g. when a tiny IQR would otherwise give billions of
+/// them.
+pub const MAX_BINS: usize = 1_000_000;
+
+
+/// A strategy for choosing the bin edges of a `Histogram`.
+///
+/// Except for `Edges`, every strategy spans the range of the data set; the strategies other than `Edges` & `Quantile`
+/// produce bins of equal width. If every element of the data set is equal, the equal-width strategies produce a single
+/// bin of width `1` centered on that value.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Binning {
+    /// Bins of the given width, starting at the smallest element of the data set; the width must be positive & finite,
+    /// and give at most `MAX_BINS` bins.
+    FixedWidth(f64),
+    /// Bins with the given edges, which must be finite & strictly increasing, with at least 2 of them. Elements of the
+    /// data set outside of the edges aren't counted.
+    Edges(Array<f64, Ix1>),
+    /// `ceil(log2(n)) + 1` bins, i.e. Sturges' rule, which works well for small, roughly normal data sets.
+    Sturges,
+    /// Bins of width `3.49 sd n^(-1/3)`, i.e. Scott's rule, which is optimal for normally distributed data; the number of
+    /// bins is clamped to `MAX_BINS`.
+    Scott,
+    /// Bins of width `2 IQR n^(-1/3)`, i.e. the Freedman-Diaconis rule, which is robust to outliers; the number of bins is
+    /// clamped to `MAX_BINS`.
+    FreedmanDiaconis,
+    /// The given number of bins (between `1` & `MAX_BINS`), each containing (as nearly as possible) the same number of
+    /// elements; bins whose edges coincide due to repeated values are merged.
+    Quantile(usize),
+}
+
+
+/// A histogram of a data set, i.e. the number of its elements in each of a sequence of adjacent bins.
+///
+/// Every bin includes its lower edge & excludes its upper edge, except for the last bin, which includes both.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Histogram {
+    edges: Array<f64, Ix1>,
+    counts: Array<usize, Ix1>,
+    total: usize,
+}
+
+impl Histogram {
+    /// Creates and returns a histogram of `data`, with bin edges chosen by `binning`.
+    ///
+    /// Returns a `NonFiniteData` error if any element of `data` is `NaN` or infinite, an `InvalidParameter` or
+    /// `InvalidInput` error if the parameters of `binning` are invalid, or an `EmptyData` error if `data` is empty and
+    /// `binning` isn't `Edges` (since the other strategies need data to choose edges from).
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.5, 4.0];
+    /// let histogram = Histogram::new(&data, Binning::FixedWidth(1.0)).unwrap();
+    ///
+    /// println!("{}", histogram.edges()); // prints "[1, 2, 3, 4]"
+    /// println!("{}", histogram.counts()); // prints "[1, 2, 1]"
+    /// ```
+    ///
+    /// ```ignore
+    /// let data = array![1.0, f64::NAN];
+    /// println!("{:?}", Histogram::new(&data, Binning::Sturges)); // prints "Err(NonFiniteData)"
+    /// ```
+    pub fn new(data: &Array<f64, Ix1>, binning: Binning) -> Result<Histogram, PensivError> {
+        if data.iter().any(|x| ComparableFloat::new(*x).is_err()) {
+            return Err(PensivError::NonFiniteData);
+        }
+
+        let edges = match binning {
+            Binning::Edges(edges) => {
+                check_edges(&edges)?;
+                edges
+            },
+            Binning::FixedWidth(width) => {
+                if !(width > 0.0 && width.is_finite()) {
+                    return Err(PensivError::InvalidParameter {
+                        name: "width",
+                        value: width,
+                        expected: "a positive, finite number",
+                    });
+                }
+
+                let (min, max) = data_range(data).ok_or(PensivError::EmptyData)?;
+                let mut bins = ((max - min) / width).ceil().max(1.0) as usize;
+
+                // make sure that rounding error doesn't leave the maximum outside of the last bin
+                while min + bins as f64 * width < max {
+                    bins += 1;
+                }
+
+                if bins > MAX_BINS {
+                    return Err(PensivError::InvalidParameter {
+                        name: "width",
+                        value: width,
+                        expected: "a width giving at most MAX_BINS bins",
+                    });
+                }
+
+                (0..=bins).map(|i| min + i as f64 * width).collect()
+            },
+            Binning::Sturges => {
+                let bins = (data.len() as f64).log2().ceil() as usize + 1;
+                equal_width_edges(data, bins).ok_or(PensivError::EmptyData)?
+            },
+            Binning::Scott => {
+                let width = 3.49 * describe::std(data, 1).unwrap_or(0.0) * (data.len() as f64).powf(-1.0 / 3.0);
+                equal_width_edges(data, bins_of_width(data, width)).ok_or(PensivError::EmptyData)?
+            },
+            Binning::FreedmanDiaconis => {
+                let iqr = describe::iqr(data).ok_or(PensivError::EmptyData)?;
+                let width = 2.0 * iqr * (data.len() as f64).powf(-1.0 / 3.0);
+                equal_width_edges(data, bins_of_width(data, width)).ok_or(PensivError::EmptyData)?
+            },
+            Binning::Quantile(bins) => {
+                if bins == 0 || bins > MAX_BINS {
+                    return Err(PensivError::InvalidParameter {
+                        name: "bins",
+                        value: bins as f64,
+                        expected: "a positive number of bins, at most MAX_BINS",
+                    });
+                }
+
+                let levels = (0..=bins).map(|i| i as f64 / bins as f64).collect();
+                let mut edges = describe::quantiles(data, &levels).ok_or(PensivError::EmptyData)?.to_vec();
+                edges.dedup();
+
+                if edges.len() < 2 {
+                    equal_width_edges(data, 1).ok_or(PensivError::EmptyData)?
+                }
+                else {
+                    Array::from(edges)
+                }
+            },
+        };
+
+        let mut histogram = Histogram { counts: Array::zeros(edges.len() - 1), edges, total: 0 };
+
+        for x in data.iter() {
+            if let Some(bin) = histogram.bin_of(*x) {
+                histogram.counts[bin] += 1;
+                histogram.total += 1;
+            }
+        }
+
+        Ok(histogram)
+    }
+
+    /// Returns the edges of the histogram's bins, in ascending order; there's one more edge than there are bins.
+    pub fn edges(&self) -> &Array<f64, Ix1> {
+        &self.edges
+    }
+
+    /// Returns the number of elements of the data set in each bin.
+    pub fn counts(&self) -> &Array<usize, Ix1> {
+        &self.counts
+    }
+
+    /// Returns the number of elements of the data set counted by the histogram, i.e. the sum of its counts.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the density of each bin, i.e. its count divided by its width and by the total count, so that the area of
+    /// the histogram is `1`.
+    ///
+    /// If the histogram is empty, every density is `NaN`.
+    ///
+    /// ```ignore
+    /// let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+    /// println!("{}", histogram.densities()); // prints "[0.25, 0.5, 0.25]"
+    /// ```
+    pub fn densities(&self) -> Array<f64, Ix1> {
+        let widths = &self.edges.slice(s![1..]) - &self.edges.slice(s![..-1]);
+        self.counts.mapv(|c| c as f64 / self.total as f64) / widths
+    }
+
+    /// Adds the counts of `other` to this histogram's counts, e.g. to combine histograms built on different shards of a
+    /// data set.
+    ///
+    /// Both histograms must have exactly the same edges (e.g. by building them with the same `Binning::Edges`); if they
+    /// don't, `false` is returned and this histogram is left unchanged. Otherwise, `true` is returned.
+    ///
+    /// ```ignore
+    /// let binning = Binning::Edges(array![0.0, 1.0, 2.0]);
+    ///
+    /// let mut histogram = Histogram::new(&array![0.5, 1.5], binning.clone()).unwrap();
+    /// histogram.merge(&Histogram::new(&array![1.2], binning).unwrap());
+    ///
+    /// println!("{}", histogram.counts()); // prints "[1, 2]"
+    /// ```
+    pub fn merge(&mut self, other: &Histogram) -> bool {
+        if self.edges != other.edges {
+            return false;
+        }
+
+        self.counts += &other.counts;
+        self.total += other.total;
+
+        true
+    }
+
+    /// Returns the piecewise-constant distribution described by the histogram, or an `EmptyData` error if the histogram is
+    /// empty.
+    ///
+    /// ```ignore
+    /// let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+    /// let dist = histogram.to_dist().unwrap();
+    ///
+    /// println!("{}", dist.pdf(2.2)); // prints "0.5"
+    /// println!("{}", dist.cdf(2.5)); // prints "0.5"
+    /// ```
+    pub fn to_dist(&self) -> Result<HistogramDist, PensivError> {
+        if self.total == 0 {
+            return Err(PensivError::EmptyData);
+        }
+
+        let probabilities = self.counts.mapv(|c| c as f64 / self.total as f64);
+        Ok(HistogramDist::with_probabilities(self.edges.clone(), probabilities))
+    }
+
+    /// Returns the index of the bin containing `value`, or `None` if `value` is outside of the edges.
+    fn bin_of(&self, value: f64) -> Option<usize> {
+        bin_of(&self.edges, value)
+    }
+}
+
+
+/// A piecewise-constant continuous distribution, i.e. the distribution described by a histogram.
+///
+/// Within each bin, values are uniformly distributed; the probability of each bin is its share of the histogram's
+/// total count. Create one with `Histogram::to_dist()`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct HistogramDist {
+    edges: Array<f64, Ix1>,
+    probabilities: Array<f64, Ix1>,
+    cumulative: Array<f64, Ix1>,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(HistogramDist, |dist| {
+    edges: Vec<f64> = &dist.edges.to_vec(),
+    probabilities: Vec<f64> = &dist.probabilities.to_vec(),
+} => HistogramDist::from_probabilities(Array::from(edges), &Array::from(probabilities)));
+
+impl HistogramDist {
+    /// Returns the distribution with bins of the given edges & probabilities, which are assumed to be valid.
+    fn with_probabilities(edges: Array<f64, Ix1>, probabilities: Array<f64, Ix1>) -> HistogramDist {
+        let mut cumulative = Array::<f64, Ix1>::zeros(edges.len());
+
+        for (i, p) in probabilities.iter().enumerate() {
+            cumulative[i + 1] = cumulative[i] + p;
+        }
+
+        // avoid a final cumulative probability of 1 - epsilon due to rounding error
+        cumulative[edges.len() - 1] = 1.0;
+
+        HistogramDist { edges, probabilities, cumulative }
+    }
+
+    /// Returns the distribution with bins of the given edges & probabilities, checking the edges as `Histogram::new()`
+    /// does and the probabilities as `CategoricalDist::new()` does (so they're normalized to sum to `1`).
+    #[cfg(feature = "serde")]
+    fn from_probabilities(edges: Array<f64, Ix1>, probabilities: &Array<f64, Ix1>) -> Result<HistogramDist, PensivError> {
+        check_edges(&edges)?;
+
+        if probabilities.len() + 1 != edges.len() {
+            return Err(PensivError::LengthMismatch { expected: edges.len() - 1, found: probabilities.len() });
+        }
+
+        let probabilities = CategoricalDist::new(probabilities)?.probabilities().clone();
+        Ok(HistogramDist::with_probabilities(edges, probabilities))
+    }
+
+    /// Returns the edges of the distribution's bins, in ascending order.
+    pub fn edges(&self) -> &Array<f64, Ix1> {
+        &self.edges
+    }
+
+    /// Returns the probability of each of the distribution's bins.
+    pub fn probabilities(&self) -> &Array<f64, Ix1> {
+        &self.probabilities
+    }
+
+    /// Returns the width of the `i`-th bin.
+    fn width(&self, i: usize) -> f64 {
+        self.edges[i + 1] - self.edges[i]
+    }
+}
+
+impl ContinuousDist<f64> for HistogramDist {
+    /// Returns the PDF of `value`, i.e. the probability of its bin divided by the bin's width, or `0` if `value` is
+    /// outside of the bins.
+    fn pdf(&self, value: f64) -> f64 {
+        match bin_of(&self.edges, value) {
+            Some(i) => self.probabilities[i] / self.width(i),
+            None => 0.0,
+        }
+    }
+
+    /// Returns the CDF of `value`, which is linear within each bin.
+    fn cdf(&self, value: f64) -> f64 {
+        match bin_of(&self.edges, value) {
+            Some(i) => self.cumulative[i] + self.probabilities[i] * (value - self.edges[i]) / self.width(i),
+            None if value < self.edges[0] => 0.0,
+            None => 1.0,
+        }
+    }
+
+    /// Returns the mean of the distribution, i.e. the average of the bins' midpoints weighted by their probabilities.
+    fn mean(&self) -> f64 {
+        (0..self.probabilities.len()).map(|i| self.probabilities[i] * (self.edges[i] + self.edges[i + 1]) / 2.0).sum()
+    }
+
+    /// Returns the variance of the distribution, which includes both the spread of the bins' midpoints and the spread of
+    /// values within each bin.
+    fn variance(&self) -> f64 {
+        let mean = self.mean();
+
+        (0..self.probabilities.len()).map(|i| {
+            let midpoint = (self.edges[i] + self.edges[i + 1]) / 2.0;
+            self.probabilities[i] * ((midpoint - mean).powi(2) + self.width(i).powi(2) / 12.0)
+        }).sum()
+    }
+
+    /// Draws a random value by choosing a bin according to the bins' probabilities, then a uniformly random value within
+    /// it.
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        let u = 1.0 - rng.gen::<f64>(); // in (0, 1] so that bins with 0 probability are never drawn
+        let i = self.cumulative.as_slice().unwrap().partition_point(|c| *c < u) - 1;
+
+        self.edges[i] + rng.gen::<f64>() * self.width(i)
+    }
+
+    /// Returns the quantile of `p`, found by inverting the CDF within the bin in which it reaches `p`.
+    ///
+    /// Returns `None` if `p` isn't a valid probability.
+    ///
+    /// ```ignore
+    /// let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+    /// let dist = histogram.to_dist().unwrap();
+    ///
+    /// println!("{:?}", dist.ppf(0.5)); // prints "Some(2.5)"
+    /// ```
+    fn ppf(&self, p: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let i = (0..self.probabilities.len()).find(|i| quantile_reached(self.cumulative[i + 1], p))?;
+        let fraction = ((p - self.cumulative[i]) / self.probabilities[i]).clamp(0.0, 1.0);
+
+        Some(self.edges[i] + fraction * self.width(i))
+    }
+
+    /// Returns the mode of the distribution, i.e. the left edge of the bin with the largest density (the leftmost such bin,
+    /// if there are several).
+    fn mode(&self) -> Option<f64> {
+        let densities = (0..self.probabilities.len()).map(|i| self.probabilities[i] / self.width(i)).collect::<Vec<f64>>();
+        let best = (0..densities.len()).fold(0, |best, i| if densities[i] > densities[best] { i } else { best });
+
+        Some(self.edges[best])
+    }
+
+    /// Returns the entropy of the distribution, i.e. `-sum(p ln(p / width))` over the bins' probabilities & widths.
+    fn entropy(&self) -> f64 {
+        -(0..self.probabilities.len())
+            .filter(|i| self.probabilities[*i] > 0.0)
+            .map(|i| self.probabilities[i] * (self.probabilities[i] / self.width(i)).ln())
+            .sum::<f64>()
+    }
+}
+
+
+/// Returns an `InvalidInput` error unless `edges` are finite & strictly increasing, with at least 2 of them.
+fn check_edges(edges: &Array<f64, Ix1>) -> Result<(), PensivError> {
+    let increasing = edges.windows(2).into_iter().all(|w| w[0] < w[1]);
+
+    if edges.len() < 2 || !increasing || edges.iter().any(|e| !e.is_finite()) {
+        return Err(PensivError::InvalidInput("edges must be finite & strictly increasing, with at least 2"));
+    }
+
+    Ok(())
+}
+
+/// Returns the index of the bin of `edges` containing `value`, or `None` if `value` is outside of the edges.
+fn bin_of(edges: &Array<f64, Ix1>, value: f64) -> Option<usize> {
+    let last = edges.len() - 1;
+
+    if !(edges[0] <= value && value <= edges[last]) {
+        return None;
+    }
+
+    let bin = edges.as_slice().unwrap().partition_point(|e| *e <= value) - 1;
+    Some(bin.min(last - 1))
+}
+
+/// Returns the smallest & largest elements of `data`, or `None` if it's empty.
+fn data_range(data: &Array<f64, Ix1>) -> Option<(f64, f64)> {
+    let min = data.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    if data.is_empty() { None } else { Some((min, max)) }
+}
+
+/// Returns the number of bins of width (approximately) `width` needed to span the range of `data`, clamped to `MAX_BINS`;
+/// if `width` isn't positive (or `data` is empty), a single bin is used.
+fn bins_of_width(data: &Array<f64, Ix1>, width: f64) -> usize {
+    match data_range(data) {
+        Some((min, max)) if width > 0.0 && max > min => ((max - min) / width).ceil().clamp(1.0, MAX_BINS as f64) as usize,
+        _ => 1,
+    }
+}
+
+/// Returns the edges of `bins` bins of equal width spanning the range of `data` (or of a single bin of width `1` if every
+/// element of `data` is equal), or `None` if `data` is empty.
+fn equal_width_edges(data: &Array<f64, Ix1>, mut bins: usize) -> Option<Array<f64, Ix1>> {
+    let (mut min, mut max) = data_range(data)?;
+
+    if min == max {
+        min -= 0.5;
+        max += 0.5;
+        bins = 1;
+    }
+
+    let mut edges = Array::linspace(min, max, bins + 1);
+    edges[bins] = max;
+
+    Some(edges)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn histogram_fixed_width_correct() {
+        let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+
+        assert_eq!(*histogram.edges(), array![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(*histogram.counts(), array![1, 2, 1]);
+        assert_eq!(histogram.total(), 4);
+        assert_eq!(histogram.densities(), array![0.25, 0.5, 0.25]);
+
+        let histogram = Histogram::new(&array![0.0, 0.3], Binning::FixedWidth(0.1)).unwrap();
+        assert!(histogram.edges()[histogram.edges().len() - 1] >= 0.3);
+        assert_eq!(histogram.total(), 2);
+    }
+
+    #[test]
+    fn histogram_explicit_edges_correct() {
+        let edges = array![0.0, 1.0, 5.0];
+        let histogram = Histogram::new(&array![-1.0, 0.0, 0.5, 1.0, 5.0, 6.0], Binning::Edges(edges.clone())).unwrap();
+
+        assert_eq!(*histogram.edges(), edges);
+        assert_eq!(*histogram.counts(), array![2, 2]);
+        assert_eq!(histogram.total(), 4);
+        assert_eq!(histogram.densities(), array![0.5, 0.125]);
+
+        let empty = Histogram::new(&array![], Binning::Edges(edges)).unwrap();
+        assert_eq!(empty.total(), 0);
+        assert_eq!(empty.to_dist(), Err(PensivError::EmptyData));
+    }
+
+    #[test]
+    fn histogram_rule_binnings_correct() {
+        let data = Array::range(0.0, 100.0, 1.0);
+
+        // ceil(log2(100)) + 1 = 8 bins
+        let sturges = Histogram::new(&data, Binning::Sturges).unwrap();
+        assert_eq!(sturges.counts().len(), 8);
+
+        // width 3.49 * 29.01 / 100^(1/3) = 21.8, so ceil(99 / 21.8) = 5 bins
+        let scott = Histogram::new(&data, Binning::Scott).unwrap();
+        assert_eq!(scott.counts().len(), 5);
+
+        // width 2 * 49.5 / 100^(1/3) = 21.3, so ceil(99 / 21.3) = 5 bins
+        let fd = Histogram::new(&data, Binning::FreedmanDiaconis).unwrap();
+        assert_eq!(fd.counts().len(), 5);
+
+        for histogram in [sturges, scott, fd].iter() {
+            assert_eq!(histogram.edges()[0], 0.0);
+            assert_eq!(histogram.edges()[histogram.edges().len() - 1], 99.0);
+            assert_eq!(histogram.total(), 100);
+        }
+    }
+
+    #[test]
+    fn histogram_quantile_binning_correct() {
+        let histogram = Histogram::new(&Array::range(0.0, 12.0, 1.0), Binning::Quantile(4)).unwrap();
+
+        assert_eq!(*histogram.edges(), array![0.0, 2.75, 5.5, 8.25, 11.0]);
+        assert_eq!(*histogram.counts(), array![3, 3, 3, 3]);
+
+        let histogram = Histogram::new(&array![1.0, 1.0, 1.0, 1.0, 2.0], Binning::Quantile(4)).unwrap();
+        assert_eq!(*histogram.edges(), array![1.0, 2.0]);
+    }
+
+    #[test]
+    fn histogram_constant_data_uses_unit_bin() {
+        for binning in [Binning::Sturges, Binning::Scott, Binning::FreedmanDiaconis, Binning::Quantile(3)].iter() {
+            let histogram = Histogram::new(&array![2.0, 2.0, 2.0], binning.clone()).unwrap();
+
+            assert_eq!(*histogram.edges(), array![1.5, 2.5]);
+            assert_eq!(*histogram.counts(), array![3]);
+        }
+    }
+
+    #[test]
+    fn histogram_invalid_creation_fails() {
+        assert_eq!(Histogram::new(&array![1.0, f64::NAN], Binning::Sturges), Err(PensivError::NonFiniteData));
+        let infinite = Histogram::new(&array![1.0, f64::INFINITY], Binning::Edges(array![0.0, 1.0]));
+        assert_eq!(infinite, Err(PensivError::NonFiniteData));
+        assert_eq!(Histogram::new(&array![], Binning::Sturges), Err(PensivError::EmptyData));
+        assert_eq!(Histogram::new(&array![], Binning::FreedmanDiaconis), Err(PensivError::EmptyData));
+        assert!(Histogram::new(&array![1.0], Binning::FixedWidth(0.0)).is_err());
+        assert!(Histogram::new(&array![1.0], Binning::Quantile(0)).is_err());
+        assert!(Histogram::new(&array![1.0], Binning::Quantile(MAX_BINS + 1)).is_err());
+        assert!(Histogram::new(&array![1.0], Binning::Edges(array![0.0])).is_err());
+        assert!(Histogram::new(&array![1.0], Binning::Edges(array![0.0, 1.0, 1.0])).is_err());
+    }
+
+    #[test]
+    fn histogram_bin_count_limited() {
+        let data = array![1.0, 1.0 + 1e-12, 1.0 + 2e-12, 1.0 + 3e-12, 1e12];
+
+        // a tiny width is rejected, rather than allocating an enormous number of edges
+        let result = Histogram::new(&data, Binning::FixedWidth(1e-3));
+        assert!(matches!(result, Err(PensivError::InvalidParameter { name: "width", .. })));
+
+        // whereas the rules, which would choose a tiny width from the tiny IQR, are clamped
+        for binning in [Binning::FreedmanDiaconis, Binning::Scott].iter() {
+            let histogram = Histogram::new(&data, binning.clone()).unwrap();
+
+            assert!(histogram.counts().len() <= MAX_BINS);
+            assert_eq!(histogram.total(), 5);
+        }
+
+        assert_eq!(Histogram::new(&array![0.0, 1.0], Binning::FixedWidth(1e-6)).unwrap().counts().len(), MAX_BINS);
+    }
+
+    #[test]
+    fn histogram_merge_correct() {
+        let binning = Binning::Edges(array![0.0, 1.0, 2.0]);
+
+        let mut histogram = Histogram::new(&array![0.5, 1.5], binning.clone()).unwrap();
+        assert!(histogram.merge(&Histogram::new(&array![1.2, 1.7], binning).unwrap()));
+
+        assert_eq!(*histogram.counts(), array![1, 3]);
+        assert_eq!(histogram.total(), 4);
+
+        let other = Histogram::new(&array![0.5], Binning::Edges(array![0.0, 2.0])).unwrap();
+        assert!(!histogram.merge(&other));
+        assert_eq!(histogram.total(), 4);
+    }
+
+    #[test]
+    fn histogram_dist_correct() {
+        let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+        let dist = histogram.to_dist().unwrap();
+
+        assert_eq!(*dist.probabilities(), array![0.25, 0.5, 0.25]);
+        assert_eq!(dist.pdf(2.2), 0.5);
+        assert_eq!(dist.pdf(4.0), 0.25);
+        assert_eq!(dist.pdf(4.5), 0.0);
+        assert_eq!(dist.cdf(0.0), 0.0);
+        assert_eq!(dist.cdf(2.5), 0.5);
+        assert_eq!(dist.cdf(5.0), 1.0);
+
+        // midpoints 1.5, 2.5, 3.5 around a mean of 2.5, plus 1/12 within each bin
+        assert_eq!(dist.mean(), 2.5);
+        assert!((dist.variance() - (0.5 + 1.0 / 12.0)).abs() < 1e-15);
+
+        assert_eq!(dist.ppf(0.5), Some(2.5));
+        assert_eq!(dist.ppf(0.0), Some(1.0));
+        assert_eq!(dist.ppf(1.0), Some(4.0));
+        assert_eq!(dist.ppf(1.5), None);
+    }
+
+    #[test]
+    fn histogram_dist_mode_entropy() {
+        let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+        let dist = histogram.to_dist().unwrap();
+
+        assert_eq!(dist.mode(), Some(2.0));
+        // -(0.25 ln(0.25) + 0.5 ln(0.5) + 0.25 ln(0.25)), since every bin has width 1
+        assert!((dist.entropy() - 1.5 * 2_f64.ln()).abs() < 1e-15);
+
+        let histogram = Histogram::new(&array![0.5, 2.5], Binning::Edges(array![0.0, 1.0, 3.0])).unwrap();
+        let dist = histogram.to_dist().unwrap();
+
+        assert_eq!(dist.mode(), Some(0.0));
+        assert!((dist.entropy() - (0.5 * 2_f64.ln() + 0.5 * 4_f64.ln())).abs() < 1e-15);
+    }
+
+    #[test]
+    fn histogram_dist_skips_empty_bins() {
+        let histogram = Histogram::new(&array![0.5, 2.5], Binning::Edges(array![0.0, 1.0, 2.0, 3.0])).unwrap();
+        let dist = histogram.to_dist().unwrap();
+
+        assert_eq!(dist.ppf(0.5), Some(1.0));
+        assert_eq!(dist.ppf(0.75), Some(2.5));
+
+        let mut rng = StdRng::seed_from_u64(14);
+        let samples = dist.sample_n(&mut rng, 1000);
+
+        assert!(samples.iter().all(|x| (0.0..1.0).contains(x) || (2.0..3.0).contains(x)));
+        assert!((samples.mean().unwrap() - 1.5).abs() < 0.1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn histogram_dist_serde_round_trip() {
+        let histogram = Histogram::new(&array![1.0, 2.0, 2.5, 4.0], Binning::FixedWidth(1.0)).unwrap();
+        let dist = histogram.to_dist().unwrap();
+
+        assert_eq!(crate::utils::json_round_trip(&dist).unwrap(), dist);
+
+        let invalid = [
+            r#"{"edges":[0.0,2.0,1.0],"probabilities":[0.5,0.5]}"#,
+            r#"{"edges":[0.0,1.0,2.0],"probabilities":[1.0]}"#,
+            r#"{"edges":[0.0,1.0,2.0],"probabilities":[-0.5,1.5]}"#,
+        ];
+
+        for json in invalid.iter() {
+            assert!(serde_json::from_str::<HistogramDist>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,714 @@
+//! Information-theoretic measures of distributions and data sets.
+//!
+//! The entropy of a single distribution is available as `DiscreteDist::entropy()` or `ContinuousDist::entropy()`; this
+//! module compares distributions with each other. The Kullback-Leibler (KL) divergence between 2 distributions of the
+//! same family is available through the `KlDivergence` trait, in closed form where one is known, and the KL &
+//! Jensen-Shannon divergences between any 2 distributions can be computed numerically with the `continuous_*` &
+//! `discrete_*` functions.
+//!
+//! For raw data, `entropy()`, `joint_entropy()`, `conditional_entropy()`, `mutual_information()` and
+//! `mutual_information_matrix()` are plug-in estimates, i.e. the exact values for the empirical distribution of the
+//! data (which, for a single variable, is also available as `EmpiricalDist::entropy()`). They treat each distinct value
+//! as its own category, so they're intended for discrete data, e.g. class labels or binned features.
+//!
+//! All quantities are in nats, i.e. computed with natural logarithms; divide by `ln(2)` to convert them to bits.
+
+use ndarray::prelude::*;
+
+use num_traits::Num;
+use num_traits::NumCast;
+
+use std::collections::BTreeMap;
+
+use crate::utils::ComparableFloat;
+
+use crate::numeric::{integrate_piecewise, quantile_breakpoints};
+use super::{special, summation_range, x_ln_x};
+use super::{
+    BernoulliDist, BetaDist, BinomDist, CategoricalDist, ChiSquaredDist, ContinuousDist, ContinuousUniformDist,
+    DiscreteDist, DiscreteUniformDist, EmpiricalDist, ExponentialDist, FDist, GammaDist, GeometricDist,
+    HypergeometricDist, NegativeBinomialDist, NormalDist, PoissonDist, StudentTDist,
+};
+
+/// The number of occurrences of each distinct value of a variable.
+type Counts<K> = BTreeMap<K, usize>;
+
+/// The counts of each distinct pair of values of two variables, along with the counts of each variable's values.
+type JointCounts = (Counts<(ComparableFloat, ComparableFloat)>, Counts<ComparableFloat>, Counts<ComparableFloat>);
+
+
+/// The Kullback-Leibler divergence between 2 distributions, usually of the same family.
+///
+/// The KL divergence `KL(P || Q) = E_P[ln(p(X) / q(X))]` measures how much information is lost when `Q` is used to
+/// approximate `P`. It's non-negative, `0` if & only if the distributions are equal, and infinite if `P` puts mass
+/// where `Q` doesn't; it isn't symmetric.
+///
+/// ```ignore
+/// let p = NormalDist::new(0.0, 1.0).unwrap();
+/// let q = NormalDist::new(1.0, 2.0).unwrap();
+///
+/// println!("{}", p.kl_divergence(&q)); // prints approximately "0.4431"
+/// ```
+pub trait KlDivergence<Rhs: ?Sized = Self> {
+    /// Returns `KL(self || other)`, in nats.
+    fn kl_divergence(&self, other: &Rhs) -> f64;
+}
+
+impl KlDivergence for DiscreteUniformDist {
+    /// Returns `ln(n_other / n_self)`, where `n` is the number of values in a support, if the support of `self` is
+    /// contained in that of `other`, and infinity otherwise.
+    fn kl_divergence(&self, other: &DiscreteUniformDist) -> f64 {
+        if other.lower_bound() <= self.lower_bound() && self.upper_bound() <= other.upper_bound() {
+            (other.range() as f64 / self.range() as f64).ln()
+        }
+        else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl KlDivergence for BernoulliDist {
+    /// Returns `p ln(p / p') + (1 - p) ln((1 - p) / (1 - p'))`, where `p` & `p'` are the success probabilities of
+    /// `self` & `other`.
+    fn kl_divergence(&self, other: &BernoulliDist) -> f64 {
+        bernoulli_kl_divergence(self.p_success(), other.p_success())
+    }
+}
+
+impl KlDivergence for BinomDist {
+    /// Returns `n` times the KL divergence between the corresponding Bernoulli distributions if both distributions have
+    /// the same number of trials `n`; otherwise, the divergence is computed by summing over the support.
+    fn kl_divergence(&self, other: &BinomDist) -> f64 {
+        if self.trials() != other.trials() {
+            return discrete_kl_divergence(self, other);
+        }
+
+        self.trials() as f64 * bernoulli_kl_divergence(self.p_success(), other.p_success())
+    }
+}
+
+impl KlDivergence for GeometricDist {
+    /// Returns `ln(p / p') + (1 / p - 1) ln((1 - p) / (1 - p'))`, where `p` & `p'` are the success probabilities of
+    /// `self` & `other`.
+    fn kl_divergence(&self, other: &GeometricDist) -> f64 {
+        let (p, q) = (self.p_success(), other.p_success());
+
+        if p == 1.0 {
+            -q.ln()
+        }
+        else if q == 1.0 {
+            f64::INFINITY
+        }
+        else {
+            (p / q).ln() + (1.0 / p - 1.0) * (self.p_failure() / other.p_failure()).ln()
+        }
+    }
+}
+
+impl KlDivergence for PoissonDist {
+    /// Returns `rate ln(rate / rate') + rate' - rate`, where `rate` & `rate'` are the rates of `self` & `other`.
+    fn kl_divergence(&self, other: &PoissonDist) -> f64 {
+        self.rate() * (self.rate() / other.rate()).ln() + other.rate() - self.rate()
+    }
+}
+
+impl KlDivergence for NegativeBinomialDist {
+    /// Returns the KL divergence computed by summing over the support, since it has no closed form.
+    fn kl_divergence(&self, other: &NegativeBinomialDist) -> f64 {
+        discrete_kl_divergence(self, other)
+    }
+}
+
+impl KlDivergence for HypergeometricDist {
+    /// Returns the KL divergence computed by summing over the support, since it has no closed form.
+    fn kl_divergence(&self, other: &HypergeometricDist) -> f64 {
+        discrete_kl_divergence(self, other)
+    }
+}
+
+impl KlDivergence for CategoricalDist {
+    /// Returns `sum(p ln(p / p'))` over the probabilities `p` & `p'` of each category under `self` & `other`;
+    /// categories that `other` doesn't have are taken to have probability `0`.
+    fn kl_divergence(&self, other: &CategoricalDist) -> f64 {
+        self.probabilities().iter().enumerate()
+            .map(|(i, p)| relative_term(*p, other.probabilities().get(i).copied().unwrap_or(0.0)))
+            .sum()
+    }
+}
+
+impl KlDivergence for EmpiricalDist {
+    /// Returns `sum(p ln(p / p'))` over the distinct values of `self`, where `p` & `p'` are the fractions of the data
+    /// sets of `self` & `other` equal to each value, or `NaN` if `self` is empty.
+    fn kl_divergence(&self, other: &EmpiricalDist) -> f64 {
+        if self.data_len == 0 {
+            return f64::NAN;
+        }
+
+        self.counts.iter()
+            .map(|(value, count)| relative_term(*count as f64 / self.data_len as f64, other.pmf(value.value())))
+            .sum()
+    }
+}
+
+impl KlDivergence for ContinuousUniformDist {
+    /// Returns `ln(range_other / range_self)` if the support of `self` is contained in that of `other`, and infinity
+    /// otherwise.
+    fn kl_divergence(&self, other: &ContinuousUniformDist) -> f64 {
+        if other.lower_bound() <= self.lower_bound() && self.upper_bound() <= other.upper_bound() {
+            (other.range() / self.range()).ln()
+        }
+        else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl KlDivergence for ExponentialDist {
+    /// Returns `ln(rate / rate') + rate' / rate - 1`, where `rate` & `rate'` are the rates of `self` & `other`.
+    fn kl_divergence(&self, other: &ExponentialDist) -> f64 {
+        let ratio = other.rate_param() / self.rate_param();
+        ratio - ratio.ln() - 1.0
+    }
+}
+
+impl KlDivergence for NormalDist {
+    /// Returns `ln(scale' / scale) + (scale^2 + (loc - loc')^2) / (2 scale'^2) - 1/2`, where the primed parameters are
+    /// those of `other`.
+    fn kl_divergence(&self, other: &NormalDist) -> f64 {
+        let ratio = self.scale() / other.scale();
+        let shift = (self.loc() - other.loc()) / other.scale();
+
+        (ratio * ratio + shift * shift) / 2.0 - ratio.ln() - 0.5
+    }
+}
+
+impl KlDivergence for GammaDist {
+    /// Returns `(k - k') digamma(k) - ln_gamma(k) + ln_gamma(k') + k' ln(rate / rate') + k (rate' - rate) / rate`,
+    /// where `k` & `rate` are the shape & rate of `self` and the primed parameters are those of `other`.
+    fn kl_divergence(&self, other: &GammaDist) -> f64 {
+        gamma_kl_divergence(self.shape(), self.rate_param(), other.shape(), other.rate_param())
+    }
+}
+
+impl KlDivergence for BetaDist {
+    /// Returns `ln_beta(a', b') - ln_beta(a, b) + (a - a') digamma(a) + (b - b') digamma(b) + (a' - a + b' - b)
+    /// digamma(a + b)`, where `a` & `b` are the parameters of `self` and the primed parameters are those of `other`.
+    fn kl_divergence(&self, other: &BetaDist) -> f64 {
+        let (a, b) = (self.alpha(), self.beta());
+        let (a2, b2) = (other.alpha(), other.beta());
+
+        special::ln_beta(a2, b2) - special::ln_beta(a, b)
+            + (a - a2) * special::digamma(a)
+            + (b - b2) * special::digamma(b)
+            + (a2 - a + b2 - b) * special::digamma(a + b)
+    }
+}
+
+impl KlDivergence for ChiSquaredDist {
+    /// Returns the KL divergence between the equivalent gamma distributions, i.e. those with shape `dof / 2` and rate
+    /// `1/2`.
+    fn kl_divergence(&self, other: &ChiSquaredDist) -> f64 {
+        gamma_kl_divergence(self.dof() / 2.0, 0.5, other.dof() / 2.0, 0.5)
+    }
+}
+
+impl KlDivergence for StudentTDist {
+    /// Returns the KL divergence computed by numerical integration, since it has no closed form.
+    fn kl_divergence(&self, other: &StudentTDist) -> f64 {
+        continuous_kl_divergence(self, other)
+    }
+}
+
+impl KlDivergence for FDist {
+    /// Returns the KL divergence computed by numerical integration, since it has no closed form.
+    fn kl_divergence(&self, other: &FDist) -> f64 {
+        continuous_kl_divergence(self, other)
+    }
+}
+
+
+/// Returns the KL divergence `KL(p || q)` between 2 continuous distributions, computed by numerical integration.
+///
+/// The distributions don't need to be of the same family. The integral is split at several quantiles of both
+/// distributions, so it's accurate as long as their densities are smooth between them. Returns infinity if `p` has
+/// density where `q` doesn't.
+///
+/// ```ignore
+/// let p = NormalDist::new(0.0, 1.0).unwrap();
+/// let q = StudentTDist::new(3.0).unwrap();
+///
+/// println!("{}", continuous_kl_divergence(&p, &q)); // prints approximately "0.0692"
+/// ```
+pub fn continuous_kl_divergence<P, Q>(p: &P, q: &Q) -> f64
+    where P: ContinuousDist<f64> + ?Sized, Q: ContinuousDist<f64> + ?Sized
+{
+    integrate_piecewise(
+        |x| {
+            let density = p.pdf(x);
+            if density > 0.0 { density * (p.log_pdf(x) - q.log_pdf(x)) } else { 0.0 }
+        },
+        &joint_breakpoints(p, q),
+    )
+}
+
+/// Returns the Jensen-Shannon divergence between 2 continuous distributions, computed by numerical integration.
+///
+/// The Jensen-Shannon divergence is the mean of `KL(p || m)` and `KL(q || m)`, where `m` is the equal mixture of `p` &
+/// `q`. Unlike the KL divergence, it's symmetric and always finite, bounded above by `ln(2)`.
+///
+/// ```ignore
+/// let p = NormalDist::new(0.0, 1.0).unwrap();
+/// let q = NormalDist::new(1.0, 1.0).unwrap();
+///
+/// println!("{}", continuous_js_divergence(&p, &q)); // prints approximately "0.1114"
+/// ```
+pub fn continuous_js_divergence<P, Q>(p: &P, q: &Q) -> f64
+    where P: ContinuousDist<f64> + ?Sized, Q: ContinuousDist<f64> + ?Sized
+{
+    integrate_piecewise(|x| js_term(p.pdf(x), q.pdf(x)), &joint_breakpoints(p, q))
+}
+
+/// Returns the KL divergence `KL(p || q)` between 2 discrete distributions over the integers, computed by summing over
+/// the support of `p`.
+///
+/// The distributions don't need to be of the same family. The sum covers the range outside of which `p` has negligible
+/// mass (`1e-16` in each tail); returns `NaN` if that range can't be found or is too large to sum over, and infinity if
+/// `p` has mass where `q` doesn't.
+///
+/// ```ignore
+/// let p = BinomDist::new(20, 0.25).unwrap();
+/// let q = PoissonDist::new(5.0).unwrap();
+///
+/// println!("{}", discrete_kl_divergence(&p, &q)); // prints approximately "0.0192"
+/// ```
+pub fn discrete_kl_divergence<N, P, Q>(p: &P, q: &Q) -> f64
+    where N: Num + NumCast + Copy, P: DiscreteDist<N> + ?Sized, Q: DiscreteDist<N> + ?Sized
+{
+    match summation_range(p) {
+        Some(range) => range
+            .filter_map(|k| N::from(k))
+            .map(|k| {
+                let (mass, log_mass) = (p.pmf(k), p.log_pmf(k));
+                if mass > 0.0 { mass * (log_mass - q.log_pmf(k)) } else { 0.0 }
+            })
+            .sum(),
+        None => f64::NAN,
+    }
+}
+
+/// Returns the Jensen-Shannon divergence between 2 discrete distributions over the integers, computed by summing over
+/// the supports of both.
+///
+/// See `continuous_js_divergence()` for the definition. Returns `NaN` if the range of either support can't be found or
+/// is too large to sum over.
+///
+/// ```ignore
+/// let p = BernoulliDist::new(0.5).unwrap();
+/// let q = BernoulliDist::new(1.0).unwrap();
+///
+/// println!("{}", discrete_js_divergence(&p, &q)); // prints approximately "0.2158"
+/// ```
+pub fn discrete_js_divergence<N, P, Q>(p: &P, q: &Q) -> f64
+    where N: Num + NumCast + Copy, P: DiscreteDist<N> + ?Sized, Q: DiscreteDist<N> + ?Sized
+{
+    match (summation_range(p), summation_range(q)) {
+        (Some(p_range), Some(q_range)) => {
+            (*p_range.start().min(q_range.start())..=*p_range.end().max(q_range.end()))
+                .filter_map(|k| N::from(k))
+                .map(|k| js_term(p.pmf(k), q.pmf(k)))
+                .sum()
+        },
+        _ => f64::NAN,
+    }
+}
+
+
+/// Returns the plug-in estimate of the entropy of the variable whose observations are `data`, i.e. `-sum(p ln(p))` over
+/// the fraction `p` of `data` equal to each distinct value.
+///
+/// Returns `None` if `data` is empty or contains `NaN` or infinite values.
+///
+/// ```ignore
+/// println!("{:?}", entropy(&array![1.0, 1.0, 2.0, 2.0])); // prints approximately "Some(0.6931)", i.e. ln(2)
+/// ```
+pub fn entropy(data: &Array<f64, Ix1>) -> Option<f64> {
+    let counts = count(data.iter().map(|x| ComparableFloat::new(*x).ok()))?;
+    Some(entropy_of_counts(&counts, data.len()))
+}
+
+/// Returns the plug-in estimate of the joint entropy of the variables in `data`, i.e. the entropy of the tuples formed
+/// by each observation of all of the variables.
+///
+/// As with `describe::covariance()`, `axis` is the axis along which observations lie: with `axis = Axis(0)`, each row
+/// of `data` is an observation and each column is a variable. Returns `None` if there are no observations or variables,
+/// or if `data` contains `NaN` or infinite values.
+///
+/// ```ignore
+/// let data = array![[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]];
+/// println!("{:?}", joint_entropy(&data, Axis(0))); // prints approximately "Some(1.3863)", i.e. ln(4)
+/// ```
+pub fn joint_entropy(data: &Array<f64, Ix2>, axis: Axis) -> Option<f64> {
+    let observations = if axis == Axis(0) { data.view() } else { data.t() };
+    if observations.ncols() == 0 {
+        return None;
+    }
+
+    let rows = observations.genrows().into_iter()
+        .map(|row| row.iter().map(|x| ComparableFloat::new(*x).ok()).collect::<Option<Vec<ComparableFloat>>>());
+    let counts = count(rows)?;
+    Some(entropy_of_counts(&counts, observations.nrows()))
+}
+
+/// Returns the plug-in estimate of the conditional entropy `H(X | Y)` of the variable whose observations are `x` given
+/// that whose observations are `y`, i.e. `H(X, Y) - H(Y)`.
+///
+/// `x` & `y` are paired by index. Returns `None` if they're empty or of different lengths, or contain `NaN` or infinite
+/// values.
+///
+/// ```ignore
+/// let x = array![0.0, 0.0, 1.0, 1.0];
+/// let y = array![0.0, 1.0, 0.0, 1.0];
+///
+/// println!("{:?}", conditional_entropy(&x, &y)); // prints approximately "Some(0.6931)", since x & y are independent
+/// ```
+pub fn conditional_entropy(x: &Array<f64, Ix1>, y: &Array<f64, Ix1>) -> Option<f64> {
+    let (joint, _, y_counts) = joint_counts(x, y)?;
+    let n = x.len();
+
+    // clamped since rounding error can make it slightly negative when x is a function of y
+    Some((entropy_of_counts(&joint, n) - entropy_of_counts(&y_counts, n)).max(0.0))
+}
+
+/// Returns the plug-in estimate of the mutual information `I(X; Y)` between the variables whose observations are `x` &
+/// `y`, i.e. `sum(p(x, y) ln(p(x, y) / (p(x) p(y))))` over the distinct pairs of values.
+///
+/// Mutual information is symmetric, non-negative, and `0` if & only if the (empirical) variables are independent; it's
+/// the information gain used to choose splits in decision trees. `x` & `y` are paired by index. Returns `None` if
+/// they're empty or of different lengths, or contain `NaN` or infinite values.
+///
+/// ```ignore
+/// let x = array![0.0, 0.0, 1.0, 1.0];
+/// let y = array![0.0, 0.0, 1.0, 1.0];
+///
+/// println!("{:?}", mutual_information(&x, &y)); // prints approximately "Some(0.6931)", since y determines x
+/// ```
+pub fn mutual_information(x: &Array<f64, Ix1>, y: &Array<f64, Ix1>) -> Option<f64> {
+    let (joint, x_counts, y_counts) = joint_counts(x, y)?;
+    let n = x.len() as f64;
+
+    let information = joint.iter()
+        .map(|((a, b), count)| {
+            let count = *count as f64;
+            count / n * (count * n / (x_counts[a] as f64 * y_counts[b] as f64)).ln()
+        })
+        .sum::<f64>();
+
+    Some(information.max(0.0))
+}
+
+/// Returns the matrix of plug-in estimates of the mutual information between each pair of variables in `data`.
+///
+/// As with `joint_entropy()`, `axis` is the axis along which observations lie. Entry `(i, j)` of the result is the
+/// mutual information between variables `i` & `j`, so the diagonal holds the entropy of each variable. Returns `None`
+/// if there are no observations or variables, or if `data` contains `NaN` or infinite values.
+///
+/// ```ignore
+/// let data = array![[0.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]];
+/// let information = mutual_information_matrix(&data, Axis(0)).unwrap();
+///
+/// println!("{}", information[[0, 2]]); // prints approximately "0.6931", since the 3rd variable determines the 1st
+/// println!("{}", information[[0, 1]]); // prints "0", since the 1st & 2nd variables are independent
+/// ```
+pub fn mutual_information_matrix(data: &Array<f64, Ix2>, axis: Axis) -> Option<Array<f64, Ix2>> {
+    let observations = if axis == Axis(0) { data.view() } else { data.t() };
+    let variables = observations.ncols();
+
+    if observations.nrows() == 0 || variables == 0 {
+        return None;
+    }
+
+    let columns = observations.gencolumns().into_iter().map(|column| column.to_owned()).collect::<Vec<_>>();
+    let mut information = Array::zeros((variables, variables));
+
+    for i in 0..variables {
+        for j in i..variables {
+            information[[i, j]] = mutual_information(&columns[i], &columns[j])?;
+            information[[j, i]] = information[[i, j]];
+        }
+    }
+
+    Some(information)
+}
+
+
+/// Returns `a ln(a / b)`, taking it to be `0` if `a = 0` and infinite if `b = 0 < a`, as in the definition of the KL
+/// divergence.
+fn relative_term(a: f64, b: f64) -> f64 {
+    if a == 0.0 {
+        0.0
+    }
+    else if b == 0.0 {
+        f64::INFINITY
+    }
+    else {
+        a * (a / b).ln()
+    }
+}
+
+/// Returns the KL divergence between Bernoulli distributions with success probabilities `p` & `q`.
+fn bernoulli_kl_divergence(p: f64, q: f64) -> f64 {
+    relative_term(p, q) + relative_term(1.0 - p, 1.0 - q)
+}
+
+/// Returns the contribution of a point with probabilities (or densities) `p` & `q` to the Jensen-Shannon divergence.
+fn js_term(p: f64, q: f64) -> f64 {
+    let m = (p + q) / 2.0;
+    if m > 0.0 { (relative_term(p, m) + relative_term(q, m)) / 2.0 } else { 0.0 }
+}
+
+/// Returns the KL divergence between gamma distributions with shapes `k1` & `k2` and rates `rate1` & `rate2`.
+fn gamma_kl_divergence(k1: f64, rate1: f64, k2: f64, rate2: f64) -> f64 {
+    (k1 - k2) * special::digamma(k1) - special::ln_gamma(k1) + special::ln_gamma(k2)
+        + k2 * (rate1 / rate2).ln() + k1 * (rate2 - rate1) / rate1
+}
+
+/// Returns the points at which to split the real line when integrating over both `p` & `q`, i.e. the union of their
+/// quantile breakpoints.
+fn joint_breakpoints<P, Q>(p: &P, q: &Q) -> Vec<f64>
+    where P: ContinuousDist<f64> + ?Sized, Q: ContinuousDist<f64> + ?Sized
+{
+    let mut points = quantile_breakpoints(|x| p.ppf(x), f64::NEG_INFINITY, f64::INFINITY);
+    points.extend(quantile_breakpoints(|x| q.ppf(x), f64::NEG_INFINITY, f64::INFINITY));
+
+    points.sort_by(f64::total_cmp);
+    points.dedup();
+
+    points
+}
+
+/// Returns the number of occurrences of each distinct key, or `None` if there are no keys or any key is `None`.
+fn count<K: Ord, I: Iterator<Item = Option<K>>>(keys: I) -> Option<Counts<K>> {
+    let mut counts = BTreeMap::new();
+
+    for key in keys {
+        *counts.entry(key?).or_insert(0) += 1;
+    }
+
+    if counts.is_empty() { None } else { Some(counts) }
+}
+
+/// Returns the number of occurrences of each distinct pair of values of `x` & `y`, and of each distinct value of each,
+/// or `None` if they're empty or of different lengths, or contain `NaN` or infinite values.
+fn joint_counts(x: &Array<f64, Ix1>, y: &Array<f64, Ix1>) -> Option<JointCounts> {
+    if x.len() != y.len() {
+        return None;
+    }
+
+    let pairs = x.iter().zip(y.iter()).map(|(a, b)| Some((ComparableFloat::new(*a).ok()?, ComparableFloat::new(*b).ok()?)));
+
+    let joint = count(pairs)?;
+    let x_counts = count(x.iter().map(|a| ComparableFloat::new(*a).ok()))?;
+    let y_counts = count(y.iter().map(|b| ComparableFloat::new(*b).ok()))?;
+
+    Some((joint, x_counts, y_counts))
+}
+
+/// Returns `-sum(p ln(p))` over the fractions `p = count / n`.
+fn entropy_of_counts<K>(counts: &Counts<K>, n: usize) -> f64 {
+    -counts.values().map(|count| x_ln_x(*count as f64 / n as f64)).sum::<f64>()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f64::consts::LN_2;
+
+    // reference values were computed with mpmath
+
+    #[test]
+    fn continuous_closed_forms() {
+        let normal = NormalDist::new(0.0, 1.0).unwrap();
+        assert!((normal.kl_divergence(&NormalDist::new(1.0, 2.0).unwrap()) - 0.443_147_180_559_945_3).abs() < 1e-15);
+        assert_eq!(normal.kl_divergence(&normal), 0.0);
+
+        let exponential = ExponentialDist::new(2.0).unwrap();
+        let other = ExponentialDist::new(0.5).unwrap();
+        assert!((exponential.kl_divergence(&other) - 0.636_294_361_119_890_6).abs() < 1e-15);
+
+        let gamma = GammaDist::new(2.5, 1.5).unwrap();
+        assert!((gamma.kl_divergence(&GammaDist::new(3.0, 2.0).unwrap()) - 0.027_173_105_742_395_058).abs() < 1e-13);
+        assert!(gamma.kl_divergence(&gamma).abs() < 1e-15);
+
+        let beta = BetaDist::new(2.0, 3.0).unwrap();
+        assert!((beta.kl_divergence(&BetaDist::new(4.0, 2.0).unwrap()) - 1.072_507_709_567_342_7).abs() < 1e-13);
+
+        let chi_squared = ChiSquaredDist::new(3.0).unwrap();
+        let other = ChiSquaredDist::new(5.0).unwrap();
+        assert!((chi_squared.kl_divergence(&other) - 0.368_975_134_129_587_9).abs() < 1e-13);
+
+        let uniform = ContinuousUniformDist::new(0.0, 1.0).unwrap();
+        assert!((uniform.kl_divergence(&ContinuousUniformDist::new(-1.0, 3.0).unwrap()) - 4_f64.ln()).abs() < 1e-15);
+        assert_eq!(uniform.kl_divergence(&ContinuousUniformDist::new(0.5, 3.0).unwrap()), f64::INFINITY);
+    }
+
+    #[test]
+    fn continuous_numeric() {
+        let t = StudentTDist::new(3.0).unwrap();
+        assert!((t.kl_divergence(&StudentTDist::new(10.0).unwrap()) - 0.082_266_593_331_721_35).abs() < 1e-10);
+
+        let f = FDist::new(5.0, 10.0).unwrap();
+        assert!((f.kl_divergence(&FDist::new(8.0, 12.0).unwrap()) - 0.046_333_962_558_322_63).abs() < 1e-10);
+
+        // the numeric divergence agrees with the closed forms, including across families
+        let (p, q) = (GammaDist::new(2.5, 1.5).unwrap(), GammaDist::new(3.0, 2.0).unwrap());
+        assert!((continuous_kl_divergence(&p, &q) - p.kl_divergence(&q)).abs() < 1e-12);
+
+        let normal = NormalDist::new(0.0, 1.0).unwrap();
+        assert!((continuous_kl_divergence(&normal, &t) - 0.069_151_598_485_176_23).abs() < 1e-10);
+        assert_eq!(continuous_kl_divergence(&normal, &GammaDist::new(2.5, 1.5).unwrap()), f64::INFINITY);
+    }
+
+    #[test]
+    fn continuous_js() {
+        let (p, q) = (NormalDist::new(0.0, 1.0).unwrap(), NormalDist::new(1.0, 1.0).unwrap());
+        assert!((continuous_js_divergence(&p, &q) - 0.111_421_482_184_736_18).abs() < 1e-12);
+        assert!((continuous_js_divergence(&q, &p) - continuous_js_divergence(&p, &q)).abs() < 1e-15);
+        assert!(continuous_js_divergence(&p, &p).abs() < 1e-15);
+
+        let gamma = GammaDist::new(2.5, 1.5).unwrap();
+        assert!((continuous_js_divergence(&gamma, &q) - 0.087_171_276_566_569_38).abs() < 1e-10);
+
+        // disjoint supports reach the upper bound
+        let (a, b) = (ContinuousUniformDist::new(0.0, 1.0).unwrap(), ContinuousUniformDist::new(2.0, 3.0).unwrap());
+        assert!((continuous_js_divergence(&a, &b) - LN_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn discrete_closed_forms() {
+        let bernoulli = BernoulliDist::new(0.3).unwrap();
+        let expected = 0.3 * (0.3_f64 / 0.6).ln() + 0.7 * (0.7_f64 / 0.4).ln();
+        assert!((bernoulli.kl_divergence(&BernoulliDist::new(0.6).unwrap()) - expected).abs() < 1e-15);
+        assert_eq!(bernoulli.kl_divergence(&BernoulliDist::new(1.0).unwrap()), f64::INFINITY);
+        assert!((BernoulliDist::new(1.0).unwrap().kl_divergence(&bernoulli) + 0.3_f64.ln()).abs() < 1e-15);
+
+        let binom = BinomDist::new(10, 0.3).unwrap();
+        assert!((binom.kl_divergence(&BinomDist::new(10, 0.5).unwrap()) - 0.822_828_785_050_518_5).abs() < 1e-14);
+        assert!((binom.kl_divergence(&BinomDist::new(12, 0.3).unwrap()) - 0.075_309_318_941_734_01).abs() < 1e-13);
+        assert_eq!(BinomDist::new(12, 0.3).unwrap().kl_divergence(&binom), f64::INFINITY);
+
+        let geometric = GeometricDist::new(0.4).unwrap();
+        assert!((geometric.kl_divergence(&GeometricDist::new(0.2).unwrap()) - 0.261_624_071_882_273_9).abs() < 1e-15);
+
+        let poisson = PoissonDist::new(3.0).unwrap();
+        assert!((poisson.kl_divergence(&PoissonDist::new(5.0).unwrap()) - 0.467_523_128_702_027_95).abs() < 1e-15);
+
+        let uniform = DiscreteUniformDist::new(1, 4).unwrap();
+        assert!((uniform.kl_divergence(&DiscreteUniformDist::new(1, 6).unwrap()) - 1.5_f64.ln()).abs() < 1e-15);
+        assert_eq!(DiscreteUniformDist::new(1, 6).unwrap().kl_divergence(&uniform), f64::INFINITY);
+
+        let categorical = CategoricalDist::new(&array![1.0, 1.0]).unwrap();
+        let other = CategoricalDist::new(&array![1.0, 2.0, 1.0]).unwrap();
+        assert!((categorical.kl_divergence(&other) - (0.5 * 2_f64.ln() + 0.5 * 1_f64.ln())).abs() < 1e-15);
+        assert_eq!(other.kl_divergence(&categorical), f64::INFINITY);
+    }
+
+    #[test]
+    fn discrete_numeric() {
+        let negative_binomial = NegativeBinomialDist::new(3.0, 0.4).unwrap();
+        let other = NegativeBinomialDist::new(2.0, 0.5).unwrap();
+        assert!((negative_binomial.kl_divergence(&other) - 0.508_071_767_871_378_6).abs() < 1e-13);
+
+        let hypergeometric = HypergeometricDist::new(50, 10, 5).unwrap();
+        let other = HypergeometricDist::new(50, 20, 5).unwrap();
+        assert!((hypergeometric.kl_divergence(&other) - 0.494_557_178_616_484_4).abs() < 1e-13);
+
+        let (binom, poisson) = (BinomDist::new(20, 0.25).unwrap(), PoissonDist::new(5.0).unwrap());
+        assert!((discrete_kl_divergence(&binom, &poisson) - 0.019_200_511_141_952_504).abs() < 1e-14);
+        assert_eq!(discrete_kl_divergence(&poisson, &binom), f64::INFINITY);
+
+        let (p, q) = (PoissonDist::new(3.0).unwrap(), PoissonDist::new(5.0).unwrap());
+        assert!((discrete_kl_divergence(&p, &q) - p.kl_divergence(&q)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn discrete_js() {
+        let (p, q) = (PoissonDist::new(3.0).unwrap(), PoissonDist::new(5.0).unwrap());
+        assert!((discrete_js_divergence(&p, &q) - 0.112_766_448_624_117_54).abs() < 1e-14);
+        assert!((discrete_js_divergence(&q, &p) - discrete_js_divergence(&p, &q)).abs() < 1e-15);
+
+        let (a, b) = (BernoulliDist::new(0.5).unwrap(), BernoulliDist::new(1.0).unwrap());
+        let expected = (0.5 * (0.5_f64 / 0.75).ln() + 0.5 * 2_f64.ln() + (1.0_f64 / 0.75).ln()) / 2.0;
+        assert!((discrete_js_divergence(&a, &b) - expected).abs() < 1e-15);
+
+        let (a, b) = (DiscreteUniformDist::new(0, 2).unwrap(), DiscreteUniformDist::new(5, 7).unwrap());
+        assert!((discrete_js_divergence(&a, &b) - LN_2).abs() < 1e-15);
+    }
+
+    #[test]
+    fn empirical_kl() {
+        let p = EmpiricalDist::new(&array![1.0, 1.0, 2.0, 3.0]).unwrap();
+        let q = EmpiricalDist::new(&array![1.0, 2.0, 2.0, 3.0]).unwrap();
+
+        let expected = 0.5 * 2_f64.ln() + 0.25 * 0.5_f64.ln();
+        assert!((p.kl_divergence(&q) - expected).abs() < 1e-15);
+        assert_eq!(p.kl_divergence(&EmpiricalDist::new(&array![1.0, 2.0]).unwrap()), f64::INFINITY);
+        assert!(EmpiricalDist::new(&array![]).unwrap().kl_divergence(&p).is_nan());
+    }
+
+    #[test]
+    fn plug_in_entropy() {
+        assert!((entropy(&array![1.0, 1.0, 2.0, 2.0]).unwrap() - LN_2).abs() < 1e-15);
+        assert_eq!(entropy(&array![3.0, 3.0]), Some(0.0));
+
+        let data = array![5.0, 2.0, 1.0, 3.0, 2.0];
+        let dist = EmpiricalDist::new(&data).unwrap();
+        assert!((entropy(&data).unwrap() - DiscreteDist::entropy(&dist)).abs() < 1e-15);
+
+        assert_eq!(entropy(&array![]), None);
+        assert_eq!(entropy(&array![1.0, f64::NAN]), None);
+        assert_eq!(entropy(&array![1.0, f64::INFINITY]), None);
+
+        let data = array![[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0], [1.0, 1.0], [1.0, 1.0]];
+        let expected = -(0.5 * (1.0_f64 / 6.0).ln() + 0.5 * 0.5_f64.ln());
+        assert!((joint_entropy(&data, Axis(0)).unwrap() - expected).abs() < 1e-15);
+        assert!((joint_entropy(&data.t().to_owned(), Axis(1)).unwrap() - expected).abs() < 1e-15);
+        assert_eq!(joint_entropy(&Array::zeros((0, 2)), Axis(0)), None);
+        assert_eq!(joint_entropy(&Array::zeros((2, 0)), Axis(0)), None);
+    }
+
+    #[test]
+    fn plug_in_mutual_information() {
+        let x = array![0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 0.0];
+        let y = array![0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+
+        assert!((mutual_information(&x, &y).unwrap() - 0.042_474_759_198_849_24).abs() < 1e-15);
+        assert!((mutual_information(&y, &x).unwrap() - 0.042_474_759_198_849_24).abs() < 1e-15);
+        assert!((conditional_entropy(&x, &y).unwrap() - 1.039_720_770_839_918).abs() < 1e-15);
+
+        // y is a function of x, so knowing x leaves no uncertainty in y
+        let y = x.mapv(|a| if a > 0.0 { 1.0 } else { 0.0 });
+        assert_eq!(conditional_entropy(&y, &x), Some(0.0));
+        assert!((mutual_information(&x, &y).unwrap() - entropy(&y).unwrap()).abs() < 1e-15);
+
+        assert_eq!(mutual_information(&array![1.0, 2.0], &array![1.0]), None);
+        assert_eq!(mutual_information(&array![], &array![]), None);
+        assert_eq!(conditional_entropy(&array![f64::NAN], &array![1.0]), None);
+    }
+
+    #[test]
+    fn plug_in_mutual_information_matrix() {
+        let data = array![[0.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]];
+        let information = mutual_information_matrix(&data, Axis(0)).unwrap();
+
+        assert_eq!(information.dim(), (3, 3));
+        assert!(information.diag().iter().all(|h| (h - LN_2).abs() < 1e-15));
+        assert!((information[[0, 2]] - LN_2).abs() < 1e-15);
+        assert_eq!(information[[0, 1]], 0.0);
+        assert_eq!(information, information.t());
+
+        assert_eq!(mutual_information_matrix(&data.t().to_owned(), Axis(1)), Some(information));
+        assert_eq!(mutual_information_matrix(&Array::zeros((0, 3)), Axis(0)), None);
+    }
+}
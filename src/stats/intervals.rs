@@ -0,0 +1,270 @@
+//! Confidence intervals for proportions, means, and variances.
+//!
+//! Each function takes the observed data (or counts) and a confidence level in `(0, 1)`, e.g. `0.95`, and returns a
+//! `ConfidenceInterval` that contains the true value of the parameter with (approximately) that probability over repeated
+//! sampling. Functions return `None` when the level isn't in `(0, 1)` or the interval is undefined for their input.
+
+use ndarray::prelude::*;
+
+use super::{describe, BetaDist, ChiSquaredDist, ContinuousDist, NormalDist, StudentTDist};
+
+
+/// A confidence interval, i.e. a range of plausible values of a parameter at a given confidence level.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ConfidenceInterval {
+    pub(super) lower: f64,
+    pub(super) upper: f64,
+    pub(super) level: f64,
+}
+
+impl ConfidenceInterval {
+    /// Returns the lower bound of the interval.
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Returns the upper bound of the interval.
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Returns the confidence level of the interval.
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    /// Returns the width of the interval, i.e. the difference between its bounds.
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Returns whether `value` is within the interval (inclusive).
+    pub fn contains(&self, value: f64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+}
+
+
+/// A method of computing a confidence interval for a proportion.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ProportionMethod {
+    /// The Wald interval, `p ± z sqrt(p(1 - p) / n)`, which is simple but has poor coverage for small samples or
+    /// proportions near `0` or `1` (and has zero width when `p` is `0` or `1`).
+    Wald,
+    /// The Wilson score interval, which inverts the score test and has good coverage even for small samples; this is a
+    /// good default.
+    Wilson,
+    /// The Clopper-Pearson "exact" interval, which inverts binomial tests using beta quantiles; its coverage is always at
+    /// least the confidence level, so it's conservative.
+    ClopperPearson,
+}
+
+
+/// Returns a confidence interval for the success probability of a binomial distribution (e.g. `BinomDist`), given
+/// `successes` out of `trials` observed trials.
+///
+/// For Bernoulli data, i.e. an array of `0`s & `1`s as accepted by `BernoulliDist::fit()`, `successes` is the sum of the
+/// data and `trials` is its length. The interval is clamped to `[0, 1]`. Returns `None` if `trials` isn't positive, if
+/// `successes` isn't in `[0, trials]`, or if `level` isn't in `(0, 1)`.
+///
+/// ```ignore
+/// let interval = proportion_interval(42, 100, 0.95, ProportionMethod::Wilson).unwrap();
+/// println!("[{}, {}]", interval.lower(), interval.upper()); // prints approximately "[0.3280, 0.5179]"
+/// ```
+pub fn proportion_interval(successes: i32, trials: i32, level: f64, method: ProportionMethod) -> Option<ConfidenceInterval> {
+    if trials <= 0 || !(0..=trials).contains(&successes) {
+        return None;
+    }
+
+    let alpha = significance(level)?;
+    let n = trials as f64;
+    let p = successes as f64 / n;
+
+    let (lower, upper) = match method {
+        ProportionMethod::Wald => {
+            let half_width = normal_quantile(alpha) * (p * (1.0 - p) / n).sqrt();
+            (p - half_width, p + half_width)
+        },
+        ProportionMethod::Wilson => {
+            let z = normal_quantile(alpha);
+            let z2 = z * z;
+
+            let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+            let half_width = z / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+
+            (center - half_width, center + half_width)
+        },
+        ProportionMethod::ClopperPearson => {
+            let (x, n) = (successes as f64, trials as f64);
+
+            let lower = if successes == 0 { 0.0 } else { BetaDist::new(x, n - x + 1.0).ok()?.ppf(alpha / 2.0)? };
+            let upper = if successes == trials { 1.0 } else { BetaDist::new(x + 1.0, n - x).ok()?.ppf(1.0 - alpha / 2.0)? };
+
+            (lower, upper)
+        },
+    };
+
+    Some(ConfidenceInterval { lower: lower.max(0.0), upper: upper.min(1.0), level })
+}
+
+/// Returns a confidence interval for the mean of the population from which `data` was drawn, based on the t-distribution.
+///
+/// The interval is `sample mean ± t sd / sqrt(n)`, where `t` is the quantile of the t-distribution with `n - 1` degrees
+/// of freedom; it's exact for normally distributed data and approximately correct for other data by the central limit
+/// theorem. Returns `None` if `data` has fewer than 2 elements or if `level` isn't in `(0, 1)`.
+///
+/// ```ignore
+/// let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+/// let interval = mean_interval(&data, 0.95).unwrap();
+///
+/// println!("{}", interval.contains(5.0)); // prints "false"
+/// ```
+pub fn mean_interval(data: &Array<f64, Ix1>, level: f64) -> Option<ConfidenceInterval> {
+    let alpha = significance(level)?;
+    let n = data.len() as f64;
+
+    let mean = describe::mean(data)?;
+    let std_error = (describe::variance(data, 1)? / n).sqrt();
+    let t = StudentTDist::new(n - 1.0).ok()?.ppf(1.0 - alpha / 2.0)?;
+
+    Some(ConfidenceInterval { lower: mean - t * std_error, upper: mean + t * std_error, level })
+}
+
+/// Returns a confidence interval for the mean of the normal population from which `data` was drawn, given that the
+/// population's standard deviation is that of `dist`, based on the standard normal distribution (i.e. a z-interval).
+///
+/// Only the scale of `dist` is used; the interval is `sample mean ± z scale / sqrt(n)`. Returns `None` if `data` is empty
+/// or if `level` isn't in `(0, 1)`.
+///
+/// ```ignore
+/// let data = array![102.0, 98.5, 105.1, 101.3];
+/// let interval = known_variance_mean_interval(&data, &NormalDist::new(100.0, 3.0).unwrap(), 0.95).unwrap();
+/// ```
+pub fn known_variance_mean_interval(data: &Array<f64, Ix1>, dist: &NormalDist, level: f64) -> Option<ConfidenceInterval> {
+    let alpha = significance(level)?;
+
+    let mean = describe::mean(data)?;
+    let half_width = normal_quantile(alpha) * dist.scale() / (data.len() as f64).sqrt();
+
+    Some(ConfidenceInterval { lower: mean - half_width, upper: mean + half_width, level })
+}
+
+/// Returns a confidence interval for the variance of the normal population from which `data` was drawn, based on the
+/// chi-squared distribution.
+///
+/// The interval is `[(n - 1) s^2 / q_upper, (n - 1) s^2 / q_lower]`, where `s^2` is the sample variance and `q_lower`
+/// & `q_upper` are the lower & upper quantiles of the chi-squared distribution with `n - 1` degrees of freedom. Unlike
+/// `mean_interval()`, this is sensitive to departures from normality. Take the square root of the bounds for an interval
+/// for the standard deviation. Returns `None` if `data` has fewer than 2 elements or if `level` isn't in `(0, 1)`.
+///
+/// ```ignore
+/// let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+/// let interval = variance_interval(&data, 0.95).unwrap();
+/// ```
+pub fn variance_interval(data: &Array<f64, Ix1>, level: f64) -> Option<ConfidenceInterval> {
+    let alpha = significance(level)?;
+    let dof = data.len() as f64 - 1.0;
+
+    let sum_squares = describe::variance(data, 1)? * dof;
+    let chi_squared = ChiSquaredDist::new(dof).ok()?;
+
+    let lower = sum_squares / chi_squared.ppf(1.0 - alpha / 2.0)?;
+    let upper = sum_squares / chi_squared.ppf(alpha / 2.0)?;
+
+    Some(ConfidenceInterval { lower, upper, level })
+}
+
+
+/// Returns the significance `1 - level` corresponding to the confidence level `level`, or `None` if `level` isn't in
+/// `(0, 1)`.
+pub(super) fn significance(level: f64) -> Option<f64> {
+    if level > 0.0 && level < 1.0 { Some(1.0 - level) } else { None }
+}
+
+/// Returns the upper `alpha / 2` quantile of the standard normal distribution, i.e. the critical value of a two-sided
+/// interval with significance `alpha`.
+fn normal_quantile(alpha: f64) -> f64 {
+    NormalDist::std().ppf(1.0 - alpha / 2.0).unwrap()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_interval(interval: ConfidenceInterval, lower: f64, upper: f64, tol: f64) {
+        assert!((interval.lower() - lower).abs() < tol, "{} != {}", interval.lower(), lower);
+        assert!((interval.upper() - upper).abs() < tol, "{} != {}", interval.upper(), upper);
+    }
+
+    #[test]
+    fn confidence_interval_accessors_correct() {
+        let interval = ConfidenceInterval { lower: 1.0, upper: 3.0, level: 0.9 };
+
+        assert_eq!(interval.width(), 2.0);
+        assert_eq!(interval.level(), 0.9);
+        assert!(interval.contains(1.0));
+        assert!(interval.contains(2.5));
+        assert!(!interval.contains(3.5));
+    }
+
+    #[test]
+    fn proportion_intervals_correct() {
+        let wald = proportion_interval(42, 100, 0.95, ProportionMethod::Wald).unwrap();
+        let wilson = proportion_interval(42, 100, 0.95, ProportionMethod::Wilson).unwrap();
+        let exact = proportion_interval(42, 100, 0.95, ProportionMethod::ClopperPearson).unwrap();
+
+        assert_interval(wald, 0.3232643101683206, 0.5167356898316794, 1e-9);
+        assert_interval(wilson, 0.32798382674354736, 0.5179351329695703, 1e-9);
+        assert_interval(exact, 0.3219855393547969, 0.5228808042760862, 1e-9);
+        assert_eq!(wilson.level(), 0.95);
+    }
+
+    #[test]
+    fn proportion_intervals_at_bounds() {
+        let wald = proportion_interval(0, 20, 0.95, ProportionMethod::Wald).unwrap();
+        let wilson = proportion_interval(0, 20, 0.95, ProportionMethod::Wilson).unwrap();
+        let exact = proportion_interval(20, 20, 0.9, ProportionMethod::ClopperPearson).unwrap();
+
+        assert_eq!((wald.lower(), wald.upper()), (0.0, 0.0));
+        assert_interval(wilson, 0.0, 0.16112515805281938, 1e-9);
+
+        // with all successes, the one-sided bound is the alpha / 2 root of 1, i.e. 0.05^(1/20)
+        assert_interval(exact, 0.05_f64.powf(1.0 / 20.0), 1.0, 1e-9);
+    }
+
+    #[test]
+    fn proportion_invalid_intervals_fail() {
+        assert_eq!(proportion_interval(5, 0, 0.95, ProportionMethod::Wilson), None);
+        assert_eq!(proportion_interval(11, 10, 0.95, ProportionMethod::Wilson), None);
+        assert_eq!(proportion_interval(-1, 10, 0.95, ProportionMethod::Wilson), None);
+        assert_eq!(proportion_interval(5, 10, 1.0, ProportionMethod::Wilson), None);
+        assert_eq!(proportion_interval(5, 10, 0.0, ProportionMethod::Wilson), None);
+    }
+
+    #[test]
+    fn mean_intervals_correct() {
+        let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+
+        let interval = mean_interval(&data, 0.95).unwrap();
+        assert_interval(interval, 5.006000659855426, 5.893999340144574, 1e-9);
+
+        let dist = NormalDist::new(0.0, 0.5).unwrap();
+        let interval = known_variance_mean_interval(&data, &dist, 0.9).unwrap();
+        assert_interval(interval, 5.114245659366888, 5.785754340633112, 1e-9);
+
+        assert_eq!(mean_interval(&array![1.0], 0.95), None);
+        assert_eq!(mean_interval(&data, 1.5), None);
+        assert_eq!(known_variance_mean_interval(&array![], &dist, 0.95), None);
+    }
+
+    #[test]
+    fn variance_interval_correct() {
+        let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+
+        let interval = variance_interval(&data, 0.95).unwrap();
+        assert_interval(interval, 0.06974477778506282, 1.0767414524512786, 1e-9);
+
+        assert_eq!(variance_interval(&array![1.0], 0.95), None);
+    }
+}
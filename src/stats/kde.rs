@@ -0,0 +1,622 @@
+//! Kernel density estimation.
+//!
+//! A kernel density estimate smooths a data set into a continuous distribution by placing a small, symmetric "kernel"
+//! density at each element of the data set and averaging them. Unlike `EmpiricalDist`, whose density is zero everywhere
+//! except at the observed values, the result has a smooth density, so it can be used for density plots or likelihoods.
+
+use ndarray::prelude::*;
+
+use rand::Rng;
+use rand::RngCore;
+
+use std::f64::consts::PI;
+
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+use super::{describe, special, ContinuousDist};
+use super::{std_gamma_sample, std_normal_sample};
+
+/// Standardized distance beyond which the Gaussian kernel underflows to `0`, so that data further away can be skipped.
+const GAUSSIAN_CUTOFF: f64 = 39.0;
+
+/// Standardized distance at which the Gaussian kernel is truncated by `KernelDensityDist::pdf_grid()`; beyond it, the
+/// kernel is less than `1e-13` of its peak.
+const BINNED_GAUSSIAN_CUTOFF: f64 = 8.0;
+
+/// Number of candidate bandwidths in the initial grid search for cross-validation.
+const CROSS_VALIDATION_GRID_POINTS: usize = 40;
+
+/// Number of golden-section iterations used to refine the best bandwidth of the cross-validation grid search.
+const CROSS_VALIDATION_REFINEMENTS: usize = 40;
+
+
+/// A kernel, i.e. the symmetric density placed at each element of the data set by a kernel density estimate.
+///
+/// Each kernel is described below in its standard form, with density `k(u)`; all except `Gaussian` have support
+/// `[-1, 1]`. When used in a `KernelDensityDist`, each kernel is rescaled so that its standard deviation is the
+/// distribution's bandwidth.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kernel {
+    /// The standard normal density, `k(u) = exp(-u^2 / 2) / sqrt(2 pi)`.
+    Gaussian,
+    /// `k(u) = 3/4 (1 - u^2)`, which is the most efficient kernel in terms of mean integrated squared error.
+    Epanechnikov,
+    /// The uniform density, `k(u) = 1/2`.
+    Tophat,
+    /// `k(u) = 1 - |u|`.
+    Triangular,
+    /// `k(u) = 15/16 (1 - u^2)^2`, also known as the quartic kernel.
+    Biweight,
+    /// `k(u) = pi/4 cos(pi u / 2)`.
+    Cosine,
+}
+
+impl Kernel {
+    /// Returns the density of the standard kernel at `u`.
+    fn density(&self, u: f64) -> f64 {
+        if *self != Kernel::Gaussian && u.abs() > 1.0 {
+            return 0.0;
+        }
+
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * PI).sqrt(),
+            Kernel::Epanechnikov => 0.75 * (1.0 - u * u),
+            Kernel::Tophat => 0.5,
+            Kernel::Triangular => 1.0 - u.abs(),
+            Kernel::Biweight => 15.0 / 16.0 * (1.0 - u * u).powi(2),
+            Kernel::Cosine => PI / 4.0 * (PI * u / 2.0).cos(),
+        }
+    }
+
+    /// Returns the CDF of the standard kernel at `u`.
+    fn cdf(&self, u: f64) -> f64 {
+        if *self == Kernel::Gaussian {
+            return special::erfc(-u / 2_f64.sqrt()) / 2.0;
+        }
+
+        let u = u.clamp(-1.0, 1.0);
+
+        match self {
+            Kernel::Epanechnikov => (2.0 + 3.0 * u - u.powi(3)) / 4.0,
+            Kernel::Tophat => (u + 1.0) / 2.0,
+            Kernel::Triangular if u < 0.0 => (1.0 + u).powi(2) / 2.0,
+            Kernel::Triangular => 1.0 - (1.0 - u).powi(2) / 2.0,
+            Kernel::Biweight => 0.5 + 15.0 / 16.0 * (u - 2.0 * u.powi(3) / 3.0 + u.powi(5) / 5.0),
+            _ => (1.0 + (PI * u / 2.0).sin()) / 2.0,
+        }
+    }
+
+    /// Returns the variance of the standard kernel.
+    fn variance(&self) -> f64 {
+        match self {
+            Kernel::Gaussian => 1.0,
+            Kernel::Epanechnikov => 0.2,
+            Kernel::Tophat => 1.0 / 3.0,
+            Kernel::Triangular => 1.0 / 6.0,
+            Kernel::Biweight => 1.0 / 7.0,
+            Kernel::Cosine => 1.0 - 8.0 / (PI * PI),
+        }
+    }
+
+    /// Returns the distance from `0` beyond which the standard kernel's density is (numerically) `0`.
+    fn radius(&self) -> f64 {
+        if *self == Kernel::Gaussian { GAUSSIAN_CUTOFF } else { 1.0 }
+    }
+
+    /// Draws a random value from the standard kernel.
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        match self {
+            Kernel::Gaussian => std_normal_sample(rng),
+            Kernel::Epanechnikov => {
+                // Devroye's method: the median-magnitude of three uniforms, with its sign
+                let u1: f64 = rng.gen_range(-1.0, 1.0);
+                let u2: f64 = rng.gen_range(-1.0, 1.0);
+                let u3: f64 = rng.gen_range(-1.0, 1.0);
+
+                if u3.abs() >= u2.abs() && u3.abs() >= u1.abs() { u2 } else { u3 }
+            },
+            Kernel::Tophat => rng.gen_range(-1.0, 1.0),
+            Kernel::Triangular => rng.gen::<f64>() + rng.gen::<f64>() - 1.0,
+            Kernel::Biweight => {
+                // (1 + u) / 2 follows a Beta(3, 3) distribution
+                let x = std_gamma_sample(3.0, rng);
+                let y = std_gamma_sample(3.0, rng);
+
+                2.0 * x / (x + y) - 1.0
+            },
+            Kernel::Cosine => 2.0 / PI * (2.0 * rng.gen::<f64>() - 1.0).asin(),
+        }
+    }
+}
+
+
+/// A method of choosing the bandwidth of a `KernelDensityDist`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb, `0.9 min(sd, IQR / 1.34) n^(-1/5)`, which is robust to outliers & skew.
+    Silverman,
+    /// Scott's rule of thumb, `1.06 sd n^(-1/5)`, which is optimal for normally distributed data.
+    Scott,
+    /// The bandwidth that maximizes the leave-one-out log-likelihood of the data set; this adapts to the shape of the
+    /// data, but takes time quadratic in the size of the data set.
+    CrossValidation,
+    /// A fixed bandwidth, which must be positive & finite.
+    Fixed(f64),
+}
+
+
+/// A kernel density estimate of the distribution of a data set.
+///
+/// The density is the average of the kernel densities centered at each element of the data set, where each kernel is
+/// rescaled so that its standard deviation is the distribution's bandwidth (the convention used by R's `density()`).
+/// Evaluating the PDF or CDF at a single point takes time proportional to the number of elements within the kernel's
+/// reach of the point (plus a logarithmic search); use `pdf_grid()` to evaluate the PDF over a large grid.
+#[derive(Debug, PartialEq, Clone)]
+pub struct KernelDensityDist {
+    data: Array<f64, Ix1>,
+    kernel: Kernel,
+    bandwidth: f64,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(KernelDensityDist, |dist| {
+    data: Vec<f64> = &dist.data.to_vec(),
+    kernel: Kernel = &dist.kernel,
+    bandwidth: f64 = &dist.bandwidth,
+} => KernelDensityDist::new(&Array::from(data), kernel, Bandwidth::Fixed(bandwidth)));
+
+impl KernelDensityDist {
+    /// Creates and returns a kernel density estimate of `data` using `kernel`, with its bandwidth chosen by `bandwidth`.
+    ///
+    /// Returns an `EmptyData` or `NonFiniteData` error if `data` is empty or contains non-finite values, or an error if a
+    /// bandwidth can't be chosen: `Fixed` bandwidths must be positive & finite (an `InvalidParameter` error), and the other
+    /// methods require at least 2 distinct values in the data set (an `InvalidInput` error).
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 2.0, 2.5, 4.0];
+    ///
+    /// let dist = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Fixed(0.5)).unwrap();
+    /// println!("{}", dist.bandwidth()); // prints "0.5"
+    ///
+    /// let dist = KernelDensityDist::new(&data, Kernel::Epanechnikov, Bandwidth::Silverman).unwrap();
+    /// println!("{}", dist.pdf(2.0));
+    /// ```
+    ///
+    /// ```ignore
+    /// let data = array![1.0, 1.0, 1.0];
+    /// println!("{}", KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Scott).is_err()); // prints "true"
+    /// ```
+    pub fn new(data: &Array<f64, Ix1>, kernel: Kernel, bandwidth: Bandwidth) -> Result<KernelDensityDist, PensivError> {
+        if data.is_empty() {
+            return Err(PensivError::EmptyData);
+        }
+        else if data.iter().any(|x| !x.is_finite()) {
+            return Err(PensivError::NonFiniteData);
+        }
+
+        let mut sorted = data.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let data = Array::from(sorted);
+
+        let chosen = match bandwidth {
+            Bandwidth::Silverman => silverman_bandwidth(&data),
+            Bandwidth::Scott => describe::std(&data, 1).map(|std| 1.06 * std * (data.len() as f64).powf(-0.2)),
+            Bandwidth::CrossValidation => cross_validation_bandwidth(&data, kernel),
+            Bandwidth::Fixed(h) => {
+                if !(h > 0.0 && h.is_finite()) {
+                    return Err(PensivError::InvalidParameter {
+                        name: "bandwidth",
+                        value: h,
+                        expected: "a positive, finite number",
+                    });
+                }
+
+                Some(h)
+            },
+        };
+
+        match chosen {
+            Some(bandwidth) if bandwidth > 0.0 && bandwidth.is_finite() => {
+                Ok(KernelDensityDist { data, kernel, bandwidth })
+            },
+            _ => Err(PensivError::InvalidInput("a bandwidth can't be chosen without at least 2 distinct values")),
+        }
+    }
+
+    /// Returns the distribution's data set, sorted in ascending order.
+    pub fn data(&self) -> &Array<f64, Ix1> {
+        &self.data
+    }
+
+    /// Returns the distribution's kernel.
+    pub fn kernel(&self) -> Kernel {
+        self.kernel
+    }
+
+    /// Returns the distribution's bandwidth, i.e. the standard deviation of each kernel.
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    /// Returns the PDF evaluated at each of `points` equally spaced values from `lower` to `upper` (inclusive), i.e. at
+    /// `Array::linspace(lower, upper, points)`.
+    ///
+    /// Rather than evaluating each kernel at each point, the data set is linearly binned onto the grid and the bin counts
+    /// are convolved with the kernel, which takes time proportional to the size of the data set plus the number of points
+    /// times the number of points covered by a kernel. This is an approximation whose error shrinks quadratically with
+    /// the spacing of the grid; the Gaussian kernel is also truncated at 8 standard deviations. Returns `None` if `lower`
+    /// & `upper` aren't finite with `lower < upper`, or if `points < 2`.
+    ///
+    /// ```ignore
+    /// let dist = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+    ///
+    /// let grid = Array::linspace(-5.0, 5.0, 1000);
+    /// let densities = dist.pdf_grid(-5.0, 5.0, 1000).unwrap(); // approximately grid.mapv(|x| dist.pdf(x))
+    /// ```
+    pub fn pdf_grid(&self, lower: f64, upper: f64, points: usize) -> Option<Array<f64, Ix1>> {
+        if !(lower < upper && lower.is_finite() && upper.is_finite()) || points < 2 {
+            return None;
+        }
+
+        let scale = self.scale();
+        let step = (upper - lower) / (points - 1) as f64;
+        let radius = self.kernel.radius().min(BINNED_GAUSSIAN_CUTOFF) * scale;
+
+        // extend the grid by the kernel's radius on each side so that data just outside it is still counted
+        let offset = (radius / step).ceil() as usize;
+        let bins = points + 2 * offset;
+        let origin = lower - offset as f64 * step;
+
+        let mut counts = Array::<f64, Ix1>::zeros(bins);
+
+        for x in self.data.iter() {
+            let t = (x - origin) / step;
+            if t < 0.0 || t > (bins - 1) as f64 {
+                continue;
+            }
+
+            let j = t.floor() as usize;
+            let w = t - j as f64;
+
+            counts[j] += 1.0 - w;
+            if j + 1 < bins {
+                counts[j + 1] += w;
+            }
+        }
+
+        let weights = (0..=offset).map(|k| self.kernel.density(k as f64 * step / scale)).collect::<Vec<f64>>();
+        let norm = self.data.len() as f64 * scale;
+
+        Some((0..points).map(|i| {
+            let center = i + offset;
+            let mut density = counts[center] * weights[0];
+
+            for (k, weight) in weights.iter().enumerate().skip(1) {
+                density += (counts[center - k] + counts[center + k]) * weight;
+            }
+
+            density / norm
+        }).collect())
+    }
+
+    /// Returns the factor by which the standard kernel is stretched so that its standard deviation is the bandwidth.
+    fn scale(&self) -> f64 {
+        self.bandwidth / self.kernel.variance().sqrt()
+    }
+
+    /// Returns the range of indices of the data set within `radius` of `value`.
+    fn neighbors(&self, value: f64, radius: f64) -> (usize, usize) {
+        let data = self.data.as_slice().unwrap();
+
+        (data.partition_point(|x| *x < value - radius), data.partition_point(|x| *x <= value + radius))
+    }
+}
+
+impl ContinuousDist<f64> for KernelDensityDist {
+    /// Returns the PDF of the kernel density estimate at `value`, i.e. the average of the kernel densities at `value`.
+    ///
+    /// ```ignore
+    /// let dist = KernelDensityDist::new(&array![0.0, 1.0], Kernel::Gaussian, Bandwidth::Fixed(1.0)).unwrap();
+    /// println!("{}", dist.pdf(0.0)); // prints approximately "0.3204"
+    /// ```
+    fn pdf(&self, value: f64) -> f64 {
+        let scale = self.scale();
+        let (lower, upper) = self.neighbors(value, self.kernel.radius() * scale);
+
+        let sum = self.data.slice(s![lower..upper]).iter().map(|x| self.kernel.density((value - x) / scale)).sum::<f64>();
+        sum / (self.data.len() as f64 * scale)
+    }
+
+    /// Returns the CDF of the kernel density estimate at `value`, i.e. the average of the kernel CDFs at `value`.
+    ///
+    /// ```ignore
+    /// let dist = KernelDensityDist::new(&array![0.0, 1.0], Kernel::Tophat, Bandwidth::Fixed(1.0)).unwrap();
+    /// println!("{}", dist.cdf(0.5)); // prints "0.5"
+    /// ```
+    fn cdf(&self, value: f64) -> f64 {
+        let scale = self.scale();
+        let (lower, upper) = self.neighbors(value, self.kernel.radius() * scale);
+
+        // every kernel centered below the neighbors is entirely below `value`
+        let sum = self.data.slice(s![lower..upper]).iter().map(|x| self.kernel.cdf((value - x) / scale)).sum::<f64>();
+        (lower as f64 + sum) / self.data.len() as f64
+    }
+
+    /// Returns the mean of the kernel density estimate, which is the mean of the data set.
+    fn mean(&self) -> f64 {
+        describe::mean(&self.data).unwrap_or(0.0)
+    }
+
+    /// Returns the variance of the kernel density estimate, which is the population variance of the data set plus the
+    /// variance of the kernel (the squared bandwidth).
+    fn variance(&self) -> f64 {
+        describe::variance(&self.data, 0).unwrap_or(0.0) + self.bandwidth.powi(2)
+    }
+
+    /// Draws a random value from the kernel density estimate by drawing a random element of the data set and adding
+    /// noise drawn from the kernel.
+    ///
+    /// ```ignore
+    /// let dist = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let smoothed_bootstrap_sample = dist.sample_n(&mut rng, data.len());
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        let center = self.data[rng.gen_range(0, self.data.len())];
+        center + self.scale() * self.kernel.sample(rng)
+    }
+}
+
+
+/// Returns Silverman's rule-of-thumb bandwidth for `data`, falling back to the standard deviation if the IQR is `0`.
+fn silverman_bandwidth(data: &Array<f64, Ix1>) -> Option<f64> {
+    let std = describe::std(data, 1)?;
+    let iqr = describe::iqr(data)? / 1.34;
+    let spread = if iqr > 0.0 { std.min(iqr) } else { std };
+
+    Some(0.9 * spread * (data.len() as f64).powf(-0.2))
+}
+
+/// Returns the bandwidth that maximizes the leave-one-out log-likelihood of `data` (which must be sorted) under `kernel`.
+///
+/// The log-likelihood is first evaluated over a logarithmic grid of bandwidths around Silverman's rule of thumb, and the
+/// best bandwidth is then refined by golden-section search between its neighbors in the grid.
+fn cross_validation_bandwidth(data: &Array<f64, Ix1>, kernel: Kernel) -> Option<f64> {
+    let reference = silverman_bandwidth(data)?;
+    if reference <= 0.0 {
+        return None;
+    }
+
+    let score = |ln_bandwidth: f64| leave_one_out_log_likelihood(data, kernel, ln_bandwidth.exp());
+
+    let lowest = (reference / 20.0).ln();
+    let highest = (reference * 5.0).ln();
+    let step = (highest - lowest) / (CROSS_VALIDATION_GRID_POINTS - 1) as f64;
+
+    let scores = (0..CROSS_VALIDATION_GRID_POINTS).map(|i| score(lowest + i as f64 * step)).collect::<Vec<f64>>();
+    let best = (0..scores.len()).max_by(|i, j| scores[*i].partial_cmp(&scores[*j]).unwrap())?;
+
+    if scores[best] == f64::NEG_INFINITY {
+        return None;
+    }
+
+    let ratio = (5_f64.sqrt() - 1.0) / 2.0;
+    let mut a = lowest + best.saturating_sub(1) as f64 * step;
+    let mut b = lowest + (best + 1).min(scores.len() - 1) as f64 * step;
+
+    for _ in 0..CROSS_VALIDATION_REFINEMENTS {
+        let c = b - ratio * (b - a);
+        let d = a + ratio * (b - a);
+
+        if score(c) >= score(d) {
+            b = d;
+        }
+        else {
+            a = c;
+        }
+    }
+
+    Some(((a + b) / 2.0).exp())
+}
+
+/// Returns the sum over `data` (which must be sorted) of the log-density of each element under the kernel density
+/// estimate of the rest of the data set.
+fn leave_one_out_log_likelihood(data: &Array<f64, Ix1>, kernel: Kernel, bandwidth: f64) -> f64 {
+    let dist = KernelDensityDist { data: data.clone(), kernel, bandwidth };
+    let scale = dist.scale();
+    let n = data.len() as f64;
+
+    data.iter().map(|x| {
+        // remove the element's own kernel from the full-data density
+        let density = (dist.pdf(*x) * n * scale - kernel.density(0.0)).max(0.0) / ((n - 1.0) * scale);
+        density.ln()
+    }).sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const KERNELS: [Kernel; 6] = [
+        Kernel::Gaussian, Kernel::Epanechnikov, Kernel::Tophat, Kernel::Triangular, Kernel::Biweight, Kernel::Cosine,
+    ];
+
+    /// Integrates `f` over `[lower, upper]` with the midpoint rule.
+    fn integrate<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> f64 {
+        let steps = 20000;
+        let h = (upper - lower) / steps as f64;
+
+        (0..steps).map(|i| f(lower + (i as f64 + 0.5) * h)).sum::<f64>() * h
+    }
+
+    #[test]
+    fn kernels_are_standardized_densities() {
+        for kernel in KERNELS.iter() {
+            let r = kernel.radius().min(10.0);
+
+            assert!((integrate(|u| kernel.density(u), -r, r) - 1.0).abs() < 1e-8, "{:?}", kernel);
+            assert!((integrate(|u| u * u * kernel.density(u), -r, r) - kernel.variance()).abs() < 1e-8, "{:?}", kernel);
+
+            for &u in [-0.7, 0.0, 0.3, 0.95].iter() {
+                let cdf = integrate(|t| kernel.density(t), -r, u);
+                assert!((kernel.cdf(u) - cdf).abs() < 1e-8, "{:?}", kernel);
+            }
+
+            assert!(kernel.cdf(-r - 1.0) < 1e-15);
+            assert_eq!(kernel.cdf(r + 1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn kernel_samples_match_variance() {
+        let mut rng = StdRng::seed_from_u64(13);
+
+        for kernel in KERNELS.iter() {
+            let samples = (0..40000).map(|_| kernel.sample(&mut rng)).collect::<Array<f64, Ix1>>();
+
+            assert!(samples.iter().all(|u| u.abs() <= kernel.radius()));
+            assert!(samples.mean().unwrap().abs() < 0.02, "{:?}", kernel);
+            assert!((describe::variance(&samples, 0).unwrap() / kernel.variance() - 1.0).abs() < 0.03, "{:?}", kernel);
+        }
+    }
+
+    #[test]
+    fn kde_valid_created_correctly() {
+        let dist = KernelDensityDist::new(&array![2.0, 0.0, 1.0], Kernel::Tophat, Bandwidth::Fixed(0.5)).unwrap();
+
+        assert_eq!(*dist.data(), array![0.0, 1.0, 2.0]);
+        assert_eq!(dist.kernel(), Kernel::Tophat);
+        assert_eq!(dist.bandwidth(), 0.5);
+    }
+
+    #[test]
+    fn kde_invalid_creation_fails() {
+        assert!(KernelDensityDist::new(&array![], Kernel::Gaussian, Bandwidth::Fixed(1.0)).is_err());
+        assert!(KernelDensityDist::new(&array![1.0, f64::NAN], Kernel::Gaussian, Bandwidth::Fixed(1.0)).is_err());
+        assert!(KernelDensityDist::new(&array![1.0], Kernel::Gaussian, Bandwidth::Fixed(0.0)).is_err());
+        assert!(KernelDensityDist::new(&array![1.0], Kernel::Gaussian, Bandwidth::Fixed(f64::INFINITY)).is_err());
+        assert!(KernelDensityDist::new(&array![1.0], Kernel::Gaussian, Bandwidth::Silverman).is_err());
+        assert!(KernelDensityDist::new(&array![1.0, 1.0], Kernel::Gaussian, Bandwidth::Scott).is_err());
+        assert!(KernelDensityDist::new(&array![1.0, 1.0], Kernel::Gaussian, Bandwidth::CrossValidation).is_err());
+    }
+
+    #[test]
+    fn kde_rule_of_thumb_bandwidths_correct() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 10.0];
+        let std = 12.5_f64.sqrt();
+
+        let scott = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Scott).unwrap();
+        let silverman = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+
+        // the IQR is 2, so its normal-scaled spread (1.49) is less than the standard deviation
+        assert!((scott.bandwidth() - 1.06 * std * 5_f64.powf(-0.2)).abs() < 1e-14);
+        assert!((silverman.bandwidth() - 0.9 * 2.0 / 1.34 * 5_f64.powf(-0.2)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn kde_cross_validation_bandwidth_reasonable() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let data = (0..300).map(|_| std_normal_sample(&mut rng)).collect::<Array<f64, Ix1>>();
+
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov].iter() {
+            let dist = KernelDensityDist::new(&data, *kernel, Bandwidth::CrossValidation).unwrap();
+
+            // the optimal bandwidth for 300 standard normal values is about 0.34
+            assert!(dist.bandwidth() > 0.15 && dist.bandwidth() < 0.7, "{:?}: {}", kernel, dist.bandwidth());
+        }
+    }
+
+    #[test]
+    fn kde_correct_pdf() {
+        let dist = KernelDensityDist::new(&array![0.0, 1.0], Kernel::Gaussian, Bandwidth::Fixed(1.0)).unwrap();
+        let phi = |x: f64| (-0.5 * x * x).exp() / (2.0 * PI).sqrt();
+
+        assert!((dist.pdf(0.0) - (phi(0.0) + phi(1.0)) / 2.0).abs() < 1e-15);
+        assert!((dist.pdf(-2.5) - (phi(2.5) + phi(3.5)) / 2.0).abs() < 1e-15);
+        assert_eq!(dist.pdf(1000.0), 0.0);
+
+        // a tophat with standard deviation 1 / sqrt(3) has half-width 1
+        let dist = KernelDensityDist::new(&array![0.0, 1.0], Kernel::Tophat, Bandwidth::Fixed(3_f64.sqrt().recip())).unwrap();
+
+        assert!((dist.pdf(0.5) - 0.5).abs() < 1e-15);
+        assert!((dist.pdf(1.5) - 0.25).abs() < 1e-15);
+        assert_eq!(dist.pdf(2.5), 0.0);
+    }
+
+    #[test]
+    fn kde_cdf_integrates_pdf() {
+        let data = array![-1.0, 0.0, 0.2, 0.3, 2.0, 5.0];
+
+        for kernel in KERNELS.iter() {
+            let dist = KernelDensityDist::new(&data, *kernel, Bandwidth::Fixed(0.6)).unwrap();
+
+            // the midpoint rule converges slowly across the jumps in the tophat's density
+            let tolerance = if *kernel == Kernel::Tophat { 1e-3 } else { 1e-7 };
+
+            for &x in [-2.0, 0.1, 1.0, 4.5].iter() {
+                let cdf = integrate(|t| dist.pdf(t), -10.0, x);
+                assert!((dist.cdf(x) - cdf).abs() < tolerance, "{:?}", kernel);
+            }
+
+            assert_eq!(dist.cdf(-50.0), 0.0);
+            assert_eq!(dist.cdf(50.0), 1.0);
+
+            let median = dist.ppf(0.5).unwrap();
+            assert!((dist.cdf(median) - 0.5).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn kde_moments_correct() {
+        let data = array![1.0, 2.0, 3.0, 6.0];
+        let dist = KernelDensityDist::new(&data, Kernel::Epanechnikov, Bandwidth::Fixed(0.5)).unwrap();
+
+        assert_eq!(dist.mean(), 3.0);
+        assert_eq!(dist.variance(), 3.5 + 0.25);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples = dist.sample_n(&mut rng, 40000);
+
+        assert!((samples.mean().unwrap() - 3.0).abs() < 0.05);
+        assert!((describe::variance(&samples, 0).unwrap() / 3.75 - 1.0).abs() < 0.03);
+    }
+
+    #[test]
+    fn kde_pdf_grid_approximates_pdf() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let data = (0..500).map(|_| std_normal_sample(&mut rng)).collect::<Array<f64, Ix1>>();
+        let grid = Array::linspace(-4.0, 4.0, 801);
+
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov, Kernel::Biweight].iter() {
+            let dist = KernelDensityDist::new(&data, *kernel, Bandwidth::Silverman).unwrap();
+            let approximate = dist.pdf_grid(-4.0, 4.0, 801).unwrap();
+
+            for (x, density) in grid.iter().zip(approximate.iter()) {
+                assert!((density - dist.pdf(*x)).abs() < 1e-3, "{:?} at {}", kernel, x);
+            }
+        }
+
+        let dist = KernelDensityDist::new(&data, Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+
+        assert_eq!(dist.pdf_grid(1.0, 1.0, 10), None);
+        assert_eq!(dist.pdf_grid(0.0, 1.0, 1), None);
+        assert_eq!(dist.pdf_grid(f64::NEG_INFINITY, 1.0, 10), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn kernel_density_dist_serde_round_trip() {
+        let dist = KernelDensityDist::new(&array![1.0, 2.0, 2.5, 4.0], Kernel::Epanechnikov, Bandwidth::Silverman).unwrap();
+        assert_eq!(crate::utils::json_round_trip(&dist).unwrap(), dist);
+
+        let json = r#"{"data":[1.0,2.0],"kernel":"Gaussian","bandwidth":0.0}"#;
+        assert!(serde_json::from_str::<KernelDensityDist>(json).is_err());
+    }
+}
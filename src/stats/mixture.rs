@@ -0,0 +1,540 @@
+//! Mixture distributions, i.e. weighted combinations of other distributions.
+//!
+//! A `MixtureDist` draws a value by first choosing one of its components at random according to their weights, then
+//! drawing from that component. Its components are boxed, so they can be trait objects, letting a single mixture combine
+//! distributions of different types, or all of one concrete type. A mixture of continuous components, like
+//! `MixtureDist<dyn ContinuousDist<f64>>` or `MixtureDist<NormalDist>`, is itself a `ContinuousDist`, and a mixture of
+//! discrete components, like `MixtureDist<dyn DiscreteDist<i32>>`, is itself a `DiscreteDist`.
+
+use ndarray::prelude::*;
+
+use num_complex::Complex;
+
+use num_traits::Float;
+use num_traits::Num;
+use num_traits::NumCast;
+
+use rand::RngCore;
+
+use std::marker::PhantomData;
+
+use crate::utils::{cast, to_f64};
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+use super::{CategoricalDist, ContinuousDist, DiscreteDist};
+
+
+/// A mixture distribution, i.e. a weighted combination of component distributions.
+///
+/// The PDF/PMF & CDF of a mixture are the weighted sums of those of its components, and its mean is the weighted sum of
+/// their means. `D` is the type of the components, which is either a trait object like `dyn ContinuousDist<f64>` or a
+/// concrete distribution like `NormalDist`. The weights are kept in double precision, so a mixture of
+/// `ContinuousDist<f32>` (or `DiscreteDist<i32, f32>`) components sums over them in double precision & rounds the result
+/// to `f32`.
+///
+/// With the `serde` feature enabled, a mixture of components of a single concrete type, like `MixtureDist<NormalDist>`,
+/// can be serialized as its components & weights; a mixture of trait objects can't, since the concrete types of its
+/// components aren't known.
+///
+/// ```ignore
+/// let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![
+///     Box::new(NormalDist::new(0.0, 1.0).unwrap()),
+///     Box::new(ExponentialDist::new(0.5).unwrap()),
+/// ];
+/// let dist = MixtureDist::new(components, &array![3.0, 1.0]).unwrap();
+///
+/// println!("{}", dist.weights()); // prints "[0.75, 0.25]"
+/// println!("{}", dist.mean()); // prints "0.5"
+/// ```
+pub struct MixtureDist<D: ?Sized> {
+    components: Vec<Box<D>>,
+    weights: CategoricalDist,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(MixtureDist<D>, |dist| {
+    components: Vec<Box<D>> = &dist.components,
+    weights: Vec<f64> = &dist.weights().to_vec(),
+} => MixtureDist::new(components, &Array::from(weights)));
+
+impl<D: ?Sized> MixtureDist<D> {
+    /// Creates and returns a new mixture distribution, with component `components[i]` having weight `weights[i]`.
+    ///
+    /// As with `CategoricalDist`, the weights don't need to sum to `1`; they're normalized when the mixture is created.
+    /// Returns a `LengthMismatch` error if the number of weights doesn't match the number of components, and otherwise the
+    /// same errors as `CategoricalDist::new`, i.e. if there are no components, if any weight is negative or not finite, or
+    /// if all weights are `0`.
+    pub fn new(components: Vec<Box<D>>, weights: &Array<f64, Ix1>) -> Result<MixtureDist<D>, PensivError> {
+        if components.len() != weights.len() {
+            return Err(PensivError::LengthMismatch { expected: components.len(), found: weights.len() });
+        }
+
+        let weights = CategoricalDist::new(weights)?;
+        Ok(MixtureDist { components, weights })
+    }
+
+    /// Returns the components of the mixture.
+    pub fn components(&self) -> &[Box<D>] {
+        &self.components
+    }
+
+    /// Returns the (normalized) weight of each component.
+    pub fn weights(&self) -> &Array<f64, Ix1> {
+        self.weights.probabilities()
+    }
+
+    /// Returns the sum of `f` of each component with positive weight, weighted by the component's weight.
+    ///
+    /// Components with weight `0` are skipped, so that they can't contribute infinite or `NaN` values.
+    fn weighted_sum<F: Fn(&D) -> f64>(&self, f: F) -> f64 {
+        self.weighted_components().map(|(component, weight)| weight * f(component)).sum()
+    }
+
+    /// Returns the variance of the mixture, given the mean & variance of each component.
+    fn weighted_variance<F: Fn(&D) -> (f64, f64)>(&self, moments: F) -> f64 {
+        let mean = self.weighted_sum(|component| moments(component).0);
+
+        // the law of total variance: the mean of the variances plus the variance of the means
+        self.weighted_sum(|component| {
+            let (component_mean, component_variance) = moments(component);
+            component_variance + (component_mean - mean).powi(2)
+        })
+    }
+
+    /// Returns the log of the weighted density of each component at a value, given the log density of each component.
+    fn log_weighted_densities<F: Fn(&D) -> f64>(&self, log_density: F) -> Vec<f64> {
+        self.components.iter().zip(self.weights().iter())
+            .map(|(component, weight)| if *weight > 0.0 { weight.ln() + log_density(component) } else { f64::NEG_INFINITY })
+            .collect()
+    }
+
+    /// Returns the posterior probability of each component given the log of its weighted density at a value, or `None` if
+    /// every weighted density is `0`.
+    fn responsibilities_from(log_densities: Vec<f64>) -> Option<Array<f64, Ix1>> {
+        let total = log_sum_exp(&log_densities);
+        if !total.is_finite() {
+            return None;
+        }
+
+        Some(log_densities.iter().map(|log_density| (log_density - total).exp()).collect())
+    }
+
+    /// Returns a component chosen at random according to the weights.
+    fn sample_component(&self, rng: &mut dyn RngCore) -> &D {
+        &self.components[self.weights.sample(rng) as usize]
+    }
+
+    /// Returns the weighted sum of the MGFs of the components at `t`, or `None` if any of them doesn't exist.
+    fn weighted_mgf<F: Fn(&D) -> Option<f64>>(&self, mgf: F) -> Option<f64> {
+        self.weighted_components().map(|(component, weight)| Some(weight * mgf(component)?)).sum()
+    }
+
+    /// Returns the weighted sum of the characteristic functions of the components at `t`.
+    fn weighted_cf<F: Fn(&D) -> Complex<f64>>(&self, cf: F) -> Complex<f64> {
+        self.weighted_components().fold(Complex::new(0.0, 0.0), |sum, (component, weight)| sum + cf(component) * weight)
+    }
+
+    /// Returns an iterator over the components with positive weight, paired with their weights.
+    fn weighted_components(&self) -> impl Iterator<Item = (&D, f64)> {
+        self.components.iter().zip(self.weights().iter())
+            .filter(|(_, weight)| **weight > 0.0)
+            .map(|(component, weight)| (component.as_ref(), *weight))
+    }
+}
+
+impl<D: ?Sized> MixtureDist<D> {
+    /// Returns the responsibility of each component for `value`, i.e. the posterior probability that `value` was drawn
+    /// from that component.
+    ///
+    /// Responsibilities are computed from the log PDFs (or log PMFs) of the components, so they remain accurate far into
+    /// the tails where every PDF underflows. Returns `None` if `value` is outside the support of every component with
+    /// positive weight.
+    ///
+    /// ```ignore
+    /// let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![
+    ///     Box::new(NormalDist::new(0.0, 1.0).unwrap()),
+    ///     Box::new(NormalDist::new(4.0, 1.0).unwrap()),
+    /// ];
+    /// let dist = MixtureDist::new(components, &array![1.0, 1.0]).unwrap();
+    ///
+    /// println!("{:?}", dist.responsibilities(2.0)); // prints "Some([0.5, 0.5])"
+    /// ```
+    pub fn responsibilities<V: Copy, K>(&self, value: V) -> Option<Array<f64, Ix1>> where D: LogDensity<V, K> {
+        Self::responsibilities_from(self.log_weighted_densities(|component| component.log_density(value)))
+    }
+
+    /// Returns the index of the component most likely to have produced `value`, i.e. the one with the largest
+    /// responsibility, or `None` if `value` is outside the support of every component with positive weight.
+    pub fn most_likely_component<V: Copy, K>(&self, value: V) -> Option<usize> where D: LogDensity<V, K> {
+        most_likely(&self.responsibilities(value)?)
+    }
+}
+
+impl<N: Float, D: ContinuousDist<N> + ?Sized> ContinuousDist<N> for MixtureDist<D> {
+    /// Returns the weighted sum of the PDFs of the components.
+    fn pdf(&self, value: N) -> N {
+        cast(self.weighted_sum(|component| to_f64(component.pdf(value))))
+    }
+
+    /// Returns the log of the PDF, computed from the log PDFs of the components so that it stays finite in the tails.
+    fn log_pdf(&self, value: N) -> N {
+        cast(log_sum_exp(&self.log_weighted_densities(|component| to_f64(component.log_pdf(value)))))
+    }
+
+    /// Returns the weighted sum of the CDFs of the components.
+    fn cdf(&self, value: N) -> N {
+        cast(self.weighted_sum(|component| to_f64(component.cdf(value))))
+    }
+
+    /// Returns the weighted sum of the interval CDFs of the components.
+    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> N {
+        cast(self.weighted_sum(|component| to_f64(component.interval_cdf(lower_bound, upper_bound))))
+    }
+
+    /// Returns the weighted sum of the means of the components.
+    fn mean(&self) -> N {
+        cast(self.weighted_sum(|component| to_f64(component.mean())))
+    }
+
+    /// Returns the variance of the mixture, i.e. the weighted sum of the variances of the components plus the weighted
+    /// variance of their means.
+    fn variance(&self) -> N {
+        cast(self.weighted_variance(|component| (to_f64(component.mean()), to_f64(component.variance()))))
+    }
+
+    /// Draws a random value by choosing a component according to the weights, then drawing a value from it.
+    ///
+    /// ```ignore
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let values = dist.sample_n(&mut rng, 1000);
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> N {
+        self.sample_component(rng).sample(rng)
+    }
+
+    /// Returns the weighted sum of the MGFs of the components, or `None` if the MGF of any component doesn't exist at `t`.
+    fn mgf(&self, t: N) -> Option<N> {
+        let value: N = cast(self.weighted_mgf(|component| component.mgf(t).map(to_f64))?);
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the weighted sum of the characteristic functions of the components.
+    fn cf(&self, t: N) -> Complex<N> {
+        let value = self.weighted_cf(|component| {
+            let cf = component.cf(t);
+            Complex::new(to_f64(cf.re), to_f64(cf.im))
+        });
+
+        Complex::new(cast(value.re), cast(value.im))
+    }
+}
+
+impl<N: Num + Copy, F: Float, D: DiscreteDist<N, F> + ?Sized> DiscreteDist<N, F> for MixtureDist<D> {
+    /// Returns the weighted sum of the PMFs of the components.
+    fn pmf(&self, value: N) -> F {
+        cast(self.weighted_sum(|component| to_f64(component.pmf(value))))
+    }
+
+    /// Returns the log of the PMF, computed from the log PMFs of the components so that it stays finite in the tails.
+    fn log_pmf(&self, value: N) -> F {
+        cast(log_sum_exp(&self.log_weighted_densities(|component| to_f64(component.log_pmf(value)))))
+    }
+
+    /// Returns the weighted sum of the CDFs of the components.
+    fn cdf(&self, value: N) -> F {
+        cast(self.weighted_sum(|component| to_f64(component.cdf(value))))
+    }
+
+    /// Returns the weighted sum of the interval CDFs of the components.
+    fn interval_cdf(&self, lower_bound: N, upper_bound: N) -> F {
+        cast(self.weighted_sum(|component| to_f64(component.interval_cdf(lower_bound, upper_bound))))
+    }
+
+    /// Returns the weighted sum of the means of the components.
+    fn mean(&self) -> F {
+        cast(self.weighted_sum(|component| to_f64(component.mean())))
+    }
+
+    /// Returns the variance of the mixture, i.e. the weighted sum of the variances of the components plus the weighted
+    /// variance of their means.
+    fn variance(&self) -> F {
+        cast(self.weighted_variance(|component| (to_f64(component.mean()), to_f64(component.variance()))))
+    }
+
+    /// Draws a random value by choosing a component according to the weights, then drawing a value from it.
+    fn sample(&self, rng: &mut dyn RngCore) -> N {
+        self.sample_component(rng).sample(rng)
+    }
+
+    /// Returns the weighted sum of the MGFs of the components, or `None` if the MGF of any component doesn't exist at `t`.
+    fn mgf(&self, t: F) -> Option<F> where N: NumCast {
+        let value: F = cast(self.weighted_mgf(|component| component.mgf(t).map(to_f64))?);
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the weighted sum of the characteristic functions of the components.
+    fn cf(&self, t: F) -> Complex<F> where N: NumCast {
+        let value = self.weighted_cf(|component| {
+            let cf = component.cf(t);
+            Complex::new(to_f64(cf.re), to_f64(cf.im))
+        });
+
+        Complex::new(cast(value.re), cast(value.im))
+    }
+}
+
+
+/// The log density of a distribution at a value, i.e. its log PDF if it's continuous or its log PMF if it's discrete, from
+/// which the responsibilities of the components of a mixture are computed.
+///
+/// This is implemented for every `ContinuousDist<N>` (with `Kind = Continuous`) and every `DiscreteDist<N, F>` (with
+/// `Kind = Discrete<F>`). `Kind` only keeps those two implementations apart, and is always inferred.
+pub trait LogDensity<V, Kind> {
+    /// Returns the log density of `value`, in double precision.
+    fn log_density(&self, value: V) -> f64;
+}
+
+/// The `Kind` of the implementation of `LogDensity` for continuous distributions.
+pub enum Continuous {}
+
+/// The `Kind` of the implementation of `LogDensity` for discrete distributions whose probabilities are of type `F`.
+pub struct Discrete<F>(PhantomData<F>);
+
+impl<N: Float, D: ContinuousDist<N> + ?Sized> LogDensity<N, Continuous> for D {
+    fn log_density(&self, value: N) -> f64 {
+        to_f64(self.log_pdf(value))
+    }
+}
+
+impl<N: Num, F: Float, D: DiscreteDist<N, F> + ?Sized> LogDensity<N, Discrete<F>> for D {
+    fn log_density(&self, value: N) -> f64 {
+        to_f64(self.log_pmf(value))
+    }
+}
+
+
+/// Returns `ln(sum(exp(terms)))`, computed without overflow or underflow by factoring out the largest term.
+fn log_sum_exp(terms: &[f64]) -> f64 {
+    let max = terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if !max.is_finite() {
+        return max;
+    }
+
+    max + terms.iter().map(|term| (term - max).exp()).sum::<f64>().ln()
+}
+
+/// Returns the index of the largest responsibility (the first, in case of ties).
+fn most_likely(responsibilities: &Array<f64, Ix1>) -> Option<usize> {
+    responsibilities.iter().enumerate()
+        .fold(None, |best: Option<(usize, f64)>, (i, r)| match best {
+            Some((_, max)) if max >= *r => best,
+            _ => Some((i, *r)),
+        })
+        .map(|(i, _)| i)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::stats::{describe, ExponentialDist, NormalDist, PoissonDist};
+
+    fn bimodal() -> MixtureDist<dyn ContinuousDist<f64>> {
+        let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![
+            Box::new(NormalDist::new(0.0, 1.0).unwrap()),
+            Box::new(NormalDist::new(5.0, 2.0).unwrap()),
+        ];
+
+        MixtureDist::new(components, &array![3.0, 1.0]).unwrap()
+    }
+
+    fn counts() -> MixtureDist<dyn DiscreteDist<i32>> {
+        let components: Vec<Box<dyn DiscreteDist<i32>>> = vec![
+            Box::new(PoissonDist::new(2.0).unwrap()),
+            Box::new(PoissonDist::new(10.0).unwrap()),
+        ];
+
+        MixtureDist::new(components, &array![0.5, 0.5]).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn mixture_dist_serde_round_trip() {
+        let components = vec![Box::new(NormalDist::new(0.0, 1.0).unwrap()), Box::new(NormalDist::new(5.0, 2.0).unwrap())];
+        let dist = MixtureDist::new(components, &array![3.0, 1.0]).unwrap();
+        let round_tripped = crate::utils::json_round_trip(&dist).unwrap();
+
+        assert_eq!(round_tripped.components(), dist.components());
+        assert_eq!(round_tripped.weights(), dist.weights());
+
+        for x in [-1.0, 0.5, 4.0].iter() {
+            assert_eq!(round_tripped.pdf(*x), bimodal().pdf(*x));
+            assert_eq!(round_tripped.cdf(*x), bimodal().cdf(*x));
+        }
+
+        assert_eq!(round_tripped.most_likely_component(6.0), Some(1));
+
+        let invalid = [
+            r#"{"components":[{"loc":0.0,"scale":1.0}],"weights":[0.5,0.5]}"#,
+            r#"{"components":[{"loc":0.0,"scale":1.0}],"weights":[-1.0]}"#,
+            r#"{"components":[],"weights":[]}"#,
+        ];
+
+        for json in invalid.iter() {
+            assert!(serde_json::from_str::<MixtureDist<NormalDist>>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn mixture_of_concrete_components_matches_trait_objects() {
+        let continuous = MixtureDist::new(
+            vec![Box::new(NormalDist::new(0.0, 1.0).unwrap()), Box::new(NormalDist::new(5.0, 2.0).unwrap())],
+            &array![3.0, 1.0]
+        ).unwrap();
+
+        assert_eq!(continuous.pdf(1.5), bimodal().pdf(1.5));
+        assert_eq!(continuous.variance(), bimodal().variance());
+        assert_eq!(continuous.responsibilities(2.0), bimodal().responsibilities(2.0));
+
+        let discrete = MixtureDist::new(
+            vec![Box::new(PoissonDist::new(2.0).unwrap()), Box::new(PoissonDist::new(10.0).unwrap())],
+            &array![0.5, 0.5]
+        ).unwrap();
+
+        assert_eq!(discrete.pmf(4), counts().pmf(4));
+        assert_eq!(discrete.cdf(7), counts().cdf(7));
+        assert_eq!(discrete.most_likely_component(12), Some(1));
+    }
+
+    #[test]
+    fn mixture_new() {
+        let dist = bimodal();
+        assert_eq!(dist.components().len(), 2);
+        assert_eq!(dist.weights(), &array![0.75, 0.25]);
+
+        let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![Box::new(NormalDist::std())];
+        let error = PensivError::LengthMismatch { expected: 1, found: 2 };
+        assert_eq!(MixtureDist::new(components, &array![1.0, 1.0]).err(), Some(error));
+
+        let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![Box::new(NormalDist::std())];
+        assert!(MixtureDist::new(components, &array![-1.0]).is_err());
+
+        let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![];
+        assert_eq!(MixtureDist::new(components, &array![]).err(), Some(PensivError::EmptyData));
+    }
+
+    #[test]
+    fn continuous_mixture_pdf_cdf() {
+        let dist = bimodal();
+        let (a, b) = (NormalDist::new(0.0, 1.0).unwrap(), NormalDist::new(5.0, 2.0).unwrap());
+
+        for x in [-2.0, 0.0, 1.5, 4.0, 9.0].iter() {
+            assert!((dist.pdf(*x) - (0.75 * a.pdf(*x) + 0.25 * b.pdf(*x))).abs() < 1e-15);
+            assert!((dist.cdf(*x) - (0.75 * a.cdf(*x) + 0.25 * b.cdf(*x))).abs() < 1e-15);
+            assert!((dist.log_pdf(*x) - dist.pdf(*x).ln()).abs() < 1e-12);
+        }
+
+        assert!((dist.interval_cdf(-1.0, 1.0) - (dist.cdf(1.0) - dist.cdf(-1.0))).abs() < 1e-15);
+
+        // far in the tail, the PDF underflows but its log doesn't
+        assert_eq!(dist.pdf(-100.0), 0.0);
+        assert!(dist.log_pdf(-100.0).is_finite());
+    }
+
+    #[test]
+    fn continuous_mixture_moments() {
+        let dist = bimodal();
+
+        assert!((dist.mean() - 1.25).abs() < 1e-15);
+        // 0.75 * 1 + 0.25 * 4 + 0.75 * 1.25^2 + 0.25 * 3.75^2
+        assert!((dist.variance() - 6.4375).abs() < 1e-12);
+    }
+
+    #[test]
+    fn continuous_mixture_mgf_cf() {
+        let dist = bimodal();
+
+        let expected = 0.75 * 0.125_f64.exp() + 0.25 * 3_f64.exp();
+        assert!((dist.mgf(0.5).unwrap() - expected).abs() < 1e-14);
+        assert_eq!(dist.mgf(1000.0), None);
+
+        let expected = Complex::from_polar(&(0.75 * (-0.125_f64).exp()), &0.0)
+            + Complex::from_polar(&(0.25 * (-0.5_f64).exp()), &2.5);
+        assert!((dist.cf(0.5) - expected).norm() < 1e-15);
+    }
+
+    #[test]
+    fn continuous_mixture_ppf() {
+        let dist = bimodal();
+
+        for p in [0.01, 0.25, 0.5, 0.9, 0.999].iter() {
+            let x = dist.ppf(*p).unwrap();
+            assert!((dist.cdf(x) - p).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn continuous_mixture_responsibilities() {
+        let dist = bimodal();
+
+        let responsibilities = dist.responsibilities(0.0).unwrap();
+        assert!((responsibilities.sum() - 1.0).abs() < 1e-15);
+        assert!(responsibilities[0] > 0.99);
+        assert_eq!(dist.most_likely_component(0.0), Some(0));
+        assert_eq!(dist.most_likely_component(6.0), Some(1));
+
+        // the wider component dominates both tails, even where both PDFs underflow
+        assert_eq!(dist.most_likely_component(-100.0), Some(1));
+        assert!((dist.responsibilities(-100.0).unwrap()[1] - 1.0).abs() < 1e-15);
+
+        let components: Vec<Box<dyn ContinuousDist<f64>>> = vec![Box::new(ExponentialDist::new(1.0).unwrap())];
+        let dist = MixtureDist::new(components, &array![1.0]).unwrap();
+        assert_eq!(dist.responsibilities(-1.0), None);
+    }
+
+    #[test]
+    fn continuous_mixture_sample() {
+        let dist = bimodal();
+        let mut rng = StdRng::seed_from_u64(42);
+        let values = dist.sample_n(&mut rng, 20000);
+
+        assert!((values.mean().unwrap() - dist.mean()).abs() < 0.1);
+        assert!((describe::variance(&values, 0).unwrap() - dist.variance()).abs() < 0.3);
+
+        let near_first = values.iter().filter(|x| **x < 2.0).count() as f64 / 20000.0;
+        assert!((near_first - dist.cdf(2.0)).abs() < 0.02);
+    }
+
+    #[test]
+    fn discrete_mixture() {
+        let dist = counts();
+        let (a, b) = (PoissonDist::new(2.0).unwrap(), PoissonDist::new(10.0).unwrap());
+
+        for k in 0..20 {
+            assert!((dist.pmf(k) - (0.5 * a.pmf(k) + 0.5 * b.pmf(k))).abs() < 1e-15);
+            assert!((dist.cdf(k) - (0.5 * a.cdf(k) + 0.5 * b.cdf(k))).abs() < 1e-15);
+        }
+
+        assert!((dist.mean() - 6.0).abs() < 1e-15);
+        // 0.5 * 2 + 0.5 * 10 + 16
+        assert!((dist.variance() - 22.0).abs() < 1e-12);
+
+        let expected = 0.5 * (2.0 * 0.5_f64.exp_m1()).exp() + 0.5 * (10.0 * 0.5_f64.exp_m1()).exp();
+        assert!((dist.mgf(0.5).unwrap() - expected).abs() < 1e-12);
+        assert!((dist.cf(0.0) - Complex::new(1.0, 0.0)).norm() < 1e-15);
+
+        assert_eq!(dist.ppf(0.5), Some(5));
+        assert_eq!(dist.most_likely_component(1), Some(0));
+        assert_eq!(dist.most_likely_component(12), Some(1));
+        assert_eq!(dist.responsibilities(-1), None);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let values = dist.sample_n(&mut rng, 20000).mapv(|k| k as f64);
+        assert!((values.mean().unwrap() - 6.0).abs() < 0.1);
+    }
+}
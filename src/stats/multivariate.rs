@@ -0,0 +1,465 @@
+//! Multivariate distributions over `ndarray` vectors.
+//!
+//! Unlike the distributions in `stats`, whose support is a set of scalars, the distributions here describe random vectors;
+//! their densities take and their samples return 1-dimensional arrays. Currently implemented is the multivariate normal
+//! distribution.
+
+use ndarray::prelude::*;
+
+use rand::RngCore;
+
+use std::f64::consts::PI;
+
+use crate::linalg;
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+
+use super::std_normal_sample;
+
+/// Relative tolerance within which a covariance matrix is considered symmetric.
+const SYMMETRY_TOLERANCE: f64 = 1e-10;
+
+
+/// A multivariate normal (Gaussian) distribution.
+///
+/// Multivariate normal distributions are parameterized by a mean vector and a covariance matrix, which must be symmetric
+/// and positive-definite. The support of the multivariate normal distribution is the set of all real vectors with the same
+/// dimension as the mean.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MultivariateNormalDist {
+    mean: Array1<f64>,
+    covariance: Array2<f64>,
+    cholesky: Array2<f64>,
+    ln_det: f64,
+}
+
+// the covariance matrix is (de)serialized as a list of its rows
+#[cfg(feature = "serde")]
+serde_via_params!(MultivariateNormalDist, |dist| {
+    mean: Vec<f64> = &dist.mean.to_vec(),
+    covariance: Vec<Vec<f64>> = &dist.covariance.outer_iter().map(|row| row.to_vec()).collect(),
+} => {
+    matrix_from_rows(covariance, mean.len()).and_then(|covariance| MultivariateNormalDist::new(&Array::from(mean), &covariance))
+});
+
+impl MultivariateNormalDist {
+    /// Creates and returns a new multivariate normal distribution with the given mean and covariance.
+    ///
+    /// The covariance matrix is factored using the Cholesky decomposition when the distribution is created, which both
+    /// validates it and makes evaluating the density and sampling cheap. Returns an `EmptyData` error if `mean` is empty, a
+    /// `LengthMismatch` error if the dimensions of `mean` and `covariance` don't match, a `NonFiniteData` error if any
+    /// value of `mean` isn't finite, or a `NotPositiveDefinite` error if `covariance` isn't symmetric and
+    /// positive-definite (including if any of its values isn't finite). Otherwise, returns the created distribution.
+    ///
+    /// ```ignore
+    /// let mean = array![0.0, 1.0];
+    /// let covariance = array![[1.0, 0.5], [0.5, 2.0]];
+    /// let dist = MultivariateNormalDist::new(&mean, &covariance).unwrap();
+    ///
+    /// println!("{}", dist.dim()); // prints "2"
+    /// println!("{}", dist.mean()); // prints "[0, 1]"
+    /// ```
+    ///
+    /// ```ignore
+    /// let mean = array![0.0, 1.0];
+    /// let covariance = array![[1.0, 2.0], [2.0, 1.0]]; // not positive-definite
+    /// let dist = MultivariateNormalDist::new(&mean, &covariance);
+    ///
+    /// println!("{:?}", dist.err()); // prints "Some(NotPositiveDefinite)"
+    /// ```
+    pub fn new(mean: &Array1<f64>, covariance: &Array2<f64>) -> Result<MultivariateNormalDist, PensivError> {
+        let dim = mean.len();
+
+        if dim == 0 {
+            return Err(PensivError::EmptyData);
+        }
+
+        let (rows, columns) = covariance.dim();
+        if rows != dim || columns != dim {
+            let found = if rows != dim { rows } else { columns };
+            return Err(PensivError::LengthMismatch { expected: dim, found });
+        }
+
+        if mean.iter().any(|x| !x.is_finite()) {
+            return Err(PensivError::NonFiniteData);
+        }
+
+        for i in 0..dim {
+            for j in 0..i {
+                let (a, b) = (covariance[[i, j]], covariance[[j, i]]);
+                let scale = (covariance[[i, i]] * covariance[[j, j]]).abs().sqrt();
+
+                if (a - b).abs() > SYMMETRY_TOLERANCE * scale { // non-finite values are rejected by the decomposition below
+                    return Err(PensivError::NotPositiveDefinite);
+                }
+            }
+        }
+
+        let cholesky = linalg::cholesky(covariance).ok_or(PensivError::NotPositiveDefinite)?;
+        let ln_det = linalg::cholesky_ln_det(&cholesky);
+
+        Ok(MultivariateNormalDist { mean: mean.clone(), covariance: covariance.clone(), cholesky, ln_det })
+    }
+
+    /// Returns the dimension of the distribution, i.e. the length of the vectors it describes.
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the mean vector of the distribution.
+    pub fn mean(&self) -> &Array1<f64> {
+        &self.mean
+    }
+
+    /// Returns the covariance matrix of the distribution.
+    pub fn covariance(&self) -> &Array2<f64> {
+        &self.covariance
+    }
+
+    /// Returns the PDF of `value`.
+    ///
+    /// The PDF is calculated as the exponential of `log_pdf()`; see its documentation for details. Panics if `value`
+    /// doesn't have the same dimension as the distribution.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 0.0], &Array::eye(2)).unwrap();
+    /// println!("{}", dist.pdf(&array![0.0, 0.0])); // prints approximately "0.1592", i.e. 1 / (2 * pi)
+    /// ```
+    pub fn pdf(&self, value: &Array1<f64>) -> f64 {
+        self.log_pdf(value).exp()
+    }
+
+    /// Returns the natural log of the PDF of `value`.
+    ///
+    /// The log PDF is calculated as:
+    /// `-(d ln(2 * pi) + ln|S| + (x - m)^T S^-1 (x - m)) / 2` where `m` is the mean, `S` is the covariance, `d` is the
+    /// dimension, and `x = value`
+    ///
+    /// The quadratic form is evaluated with a triangular solve against the Cholesky factor of the covariance rather than
+    /// by inverting it. Panics if `value` doesn't have the same dimension as the distribution.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 0.0], &Array::eye(2)).unwrap();
+    /// println!("{}", dist.log_pdf(&array![0.0, 0.0])); // prints approximately "-1.8379", i.e. -ln(2 * pi)
+    /// ```
+    pub fn log_pdf(&self, value: &Array1<f64>) -> f64 {
+        assert_eq!(value.len(), self.dim(), "value must have the same dimension as the distribution");
+
+        let z = linalg::solve_lower_triangular(&self.cholesky, &(value - &self.mean));
+        -0.5 * (self.dim() as f64 * (2.0 * PI).ln() + self.ln_det + z.dot(&z))
+    }
+
+
+    /// Returns the log-likelihood of `data` under the distribution, i.e. the sum of the log PDF of each row of `data`.
+    ///
+    /// Each row of `data` is treated as one observation. Panics if `data` doesn't have one column per dimension of the
+    /// distribution.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 0.0], &Array::eye(2)).unwrap();
+    /// let data = array![[0.0, 0.0], [1.0, -1.0]];
+    ///
+    /// println!("{}", dist.log_likelihood(&data)); // prints approximately "-4.6758"
+    /// ```
+    pub fn log_likelihood(&self, data: &Array2<f64>) -> f64 {
+        data.outer_iter().map(|row| self.log_pdf(&row.to_owned())).sum()
+    }
+
+    /// Returns the marginal distribution of the components at `indices`, in the given order.
+    ///
+    /// The marginal distribution of a multivariate normal is itself multivariate normal, with the corresponding entries of
+    /// the mean and covariance. Returns an `InvalidInput` error if `indices` is empty, contains an out-of-bounds index, or
+    /// repeats an index.
+    ///
+    /// ```ignore
+    /// let mean = array![0.0, 1.0, 2.0];
+    /// let covariance = array![[1.0, 0.5, 0.0], [0.5, 2.0, 0.3], [0.0, 0.3, 3.0]];
+    /// let dist = MultivariateNormalDist::new(&mean, &covariance).unwrap();
+    ///
+    /// let marginal = dist.marginal(&[2, 0]).unwrap();
+    /// println!("{}", marginal.mean()); // prints "[2, 0]"
+    /// println!("{}", marginal.covariance()); // prints "[[3, 0], [0, 1]]"
+    /// ```
+    pub fn marginal(&self, indices: &[usize]) -> Result<MultivariateNormalDist, PensivError> {
+        self.check_indices(indices)?;
+
+        let mean = self.mean.select(Axis(0), indices);
+        let covariance = self.covariance.select(Axis(0), indices).select(Axis(1), indices);
+
+        MultivariateNormalDist::new(&mean, &covariance)
+    }
+
+    /// Returns the conditional distribution of the remaining components, given that the components at `given` take the
+    /// corresponding values in `values`.
+    ///
+    /// The conditional distribution of a multivariate normal is itself multivariate normal. Writing `a` for the remaining
+    /// components and `b` for the given ones, its mean is `m_a + S_ab S_bb^-1 (values - m_b)` and its covariance is
+    /// `S_aa - S_ab S_bb^-1 S_ba`. The remaining components keep their original order.
+    ///
+    /// Returns an `InvalidInput` error if `given` contains an out-of-bounds or repeated index or if no components would
+    /// remain, or a `LengthMismatch` error if `values` doesn't have one value per given index.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 0.0], &array![[1.0, 0.5], [0.5, 1.0]]).unwrap();
+    /// let conditional = dist.conditional(&[1], &array![2.0]).unwrap();
+    ///
+    /// println!("{}", conditional.mean()); // prints "[1]"
+    /// println!("{}", conditional.covariance()); // prints "[[0.75]]"
+    /// ```
+    pub fn conditional(&self, given: &[usize], values: &Array1<f64>) -> Result<MultivariateNormalDist, PensivError> {
+        if values.len() != given.len() {
+            return Err(PensivError::LengthMismatch { expected: given.len(), found: values.len() });
+        }
+        else if given.is_empty() {
+            return Ok(self.clone());
+        }
+
+        self.check_indices(given)?;
+        if given.len() == self.dim() {
+            return Err(PensivError::InvalidInput("at least one component must remain after conditioning"));
+        }
+
+        let remaining: Vec<usize> = (0..self.dim()).filter(|i| !given.contains(i)).collect();
+
+        let sigma_aa = self.covariance.select(Axis(0), &remaining).select(Axis(1), &remaining);
+        let sigma_ab = self.covariance.select(Axis(0), &remaining).select(Axis(1), given);
+        let sigma_bb = self.covariance.select(Axis(0), given).select(Axis(1), given);
+
+        let l_bb = linalg::cholesky(&sigma_bb).ok_or(PensivError::NotPositiveDefinite)?;
+        let residual = values - &self.mean.select(Axis(0), given);
+
+        let mean = self.mean.select(Axis(0), &remaining) + sigma_ab.dot(&linalg::cholesky_solve(&l_bb, &residual));
+
+        // S_ab S_bb^-1 S_ba = W^T W where W = L_bb^-1 S_ba
+        let mut w = Array2::<f64>::zeros((given.len(), remaining.len()));
+        for (j, column) in sigma_ab.outer_iter().enumerate() {
+            w.column_mut(j).assign(&linalg::solve_lower_triangular(&l_bb, &column.to_owned()));
+        }
+
+        let mut covariance = sigma_aa - w.t().dot(&w);
+
+        // keep the result exactly symmetric despite rounding error
+        let covariance_t = covariance.t().to_owned();
+        covariance = (covariance + covariance_t) / 2.0;
+
+        MultivariateNormalDist::new(&mean, &covariance)
+    }
+
+    /// Draws a random vector from the distribution, using `rng` as the source of randomness.
+    ///
+    /// Vectors are drawn as `m + L z`, where `L` is the Cholesky factor of the covariance and `z` is a vector of independent
+    /// standard normal values.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 1.0], &array![[1.0, 0.5], [0.5, 2.0]]).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let x = dist.sample(&mut rng); // a vector of length 2
+    /// ```
+    pub fn sample(&self, rng: &mut dyn RngCore) -> Array1<f64> {
+        let z: Array1<f64> = (0..self.dim()).map(|_| std_normal_sample(rng)).collect();
+        &self.mean + &self.cholesky.dot(&z)
+    }
+
+    /// Draws `n` independent random vectors from the distribution and returns them as the rows of a 2-dimensional array.
+    ///
+    /// ```ignore
+    /// let dist = MultivariateNormalDist::new(&array![0.0, 1.0], &array![[1.0, 0.5], [0.5, 2.0]]).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// let samples = dist.sample_n(&mut rng, 100); // a 100 x 2 array
+    /// ```
+    pub fn sample_n(&self, rng: &mut dyn RngCore, n: usize) -> Array2<f64> {
+        let mut samples = Array2::<f64>::zeros((n, self.dim()));
+
+        for mut row in samples.outer_iter_mut() {
+            row.assign(&self.sample(rng));
+        }
+
+        samples
+    }
+
+    /// Returns an `InvalidInput` error unless `indices` is a nonempty list of distinct, in-bounds component indices.
+    fn check_indices(&self, indices: &[usize]) -> Result<(), PensivError> {
+        let valid = !indices.is_empty()
+            && indices.iter().all(|&i| i < self.dim())
+            && indices.iter().enumerate().all(|(n, i)| !indices[..n].contains(i));
+
+        if !valid {
+            return Err(PensivError::InvalidInput("indices must be nonempty, distinct & less than the dimension"));
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Returns the matrix with the given rows, or a `LengthMismatch` error if any of them doesn't have `columns` entries.
+#[cfg(feature = "serde")]
+fn matrix_from_rows(rows: Vec<Vec<f64>>, columns: usize) -> Result<Array2<f64>, PensivError> {
+    if let Some(row) = rows.iter().find(|row| row.len() != columns) {
+        return Err(PensivError::LengthMismatch { expected: columns, found: row.len() });
+    }
+
+    let shape = (rows.len(), columns);
+    Ok(Array2::from_shape_vec(shape, rows.into_iter().flatten().collect()).unwrap())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::stats::{ContinuousDist, NormalDist};
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn example_dist() -> MultivariateNormalDist {
+        let mean = array![1.0, -1.0, 0.5];
+        let covariance = array![[2.0, 0.6, -0.4], [0.6, 1.0, 0.2], [-0.4, 0.2, 1.5]];
+
+        MultivariateNormalDist::new(&mean, &covariance).unwrap()
+    }
+
+    #[test]
+    fn multivariate_normal_dist_valid_created_correctly() {
+        let mean = array![0.0, 1.0];
+        let covariance = array![[1.0, 0.5], [0.5, 2.0]];
+        let dist = MultivariateNormalDist::new(&mean, &covariance).unwrap();
+
+        assert_eq!(dist.dim(), 2);
+        assert_eq!(dist.mean(), &mean);
+        assert_eq!(dist.covariance(), &covariance);
+    }
+
+    #[test]
+    fn multivariate_normal_dist_invalid_creation_fails() {
+        let mean = array![0.0, 1.0];
+
+        let not_positive_definite = Err(PensivError::NotPositiveDefinite);
+        assert_eq!(MultivariateNormalDist::new(&mean, &array![[1.0, 2.0], [2.0, 1.0]]), not_positive_definite);
+        assert_eq!(MultivariateNormalDist::new(&mean, &array![[1.0, 0.5], [0.4, 1.0]]), not_positive_definite);
+        assert_eq!(MultivariateNormalDist::new(&mean, &array![[1.0, f64::NAN], [f64::NAN, 1.0]]), not_positive_definite);
+
+        let mismatch = Err(PensivError::LengthMismatch { expected: 2, found: 3 });
+        assert_eq!(MultivariateNormalDist::new(&mean, &Array::eye(3)), mismatch);
+        assert_eq!(MultivariateNormalDist::new(&mean, &Array::zeros((2, 3))), mismatch);
+
+        assert_eq!(MultivariateNormalDist::new(&array![0.0, f64::NAN], &Array::eye(2)), Err(PensivError::NonFiniteData));
+        assert_eq!(MultivariateNormalDist::new(&array![], &Array::eye(0)), Err(PensivError::EmptyData));
+    }
+
+    #[test]
+    fn multivariate_normal_dist_correct_pdf() {
+        let dist = example_dist();
+
+        // reference values computed with mpmath
+        assert!((dist.pdf(&array![1.0, -1.0, 0.5]) - 0.04356652706692637).abs() < 1e-15);
+        assert!((dist.pdf(&array![0.0, 0.0, 0.0]) - 0.006896877608621095).abs() < 1e-15);
+        assert!((dist.log_pdf(&array![10.0, -10.0, 10.0]) + 204.03506690459955).abs() < 1e-11);
+    }
+
+    #[test]
+    fn multivariate_normal_dist_diagonal_pdf_matches_univariate() {
+        let dist = MultivariateNormalDist::new(&array![1.0, -2.0], &array![[4.0, 0.0], [0.0, 0.25]]).unwrap();
+        let x = NormalDist::new(1.0, 2.0).unwrap();
+        let y = NormalDist::new(-2.0, 0.5).unwrap();
+
+        for &(a, b) in [(0.0, 0.0), (1.0, -2.0), (3.5, -1.5)].iter() {
+            assert!((dist.pdf(&array![a, b]) - x.pdf(a) * y.pdf(b)).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn multivariate_normal_dist_wrong_dimension_pdf_panics() {
+        example_dist().pdf(&array![0.0, 0.0]);
+    }
+
+
+    #[test]
+    fn multivariate_normal_dist_correct_log_likelihood() {
+        let dist = example_dist();
+        let data = array![[1.0, -1.0, 0.5], [0.0, 0.0, 0.0], [10.0, -10.0, 10.0]];
+
+        let expected: f64 = data.outer_iter().map(|row| dist.log_pdf(&row.to_owned())).sum();
+
+        assert_eq!(dist.log_likelihood(&data), expected);
+        assert!(expected.is_finite());
+    }
+
+    #[test]
+    fn multivariate_normal_dist_correct_marginal() {
+        let dist = example_dist();
+        let marginal = dist.marginal(&[2, 0]).unwrap();
+
+        assert_eq!(marginal.mean(), &array![0.5, 1.0]);
+        assert_eq!(marginal.covariance(), &array![[1.5, -0.4], [-0.4, 2.0]]);
+
+        let invalid_indices = Err(PensivError::InvalidInput("indices must be nonempty, distinct & less than the dimension"));
+        assert_eq!(dist.marginal(&[]), invalid_indices);
+        assert_eq!(dist.marginal(&[0, 3]), invalid_indices);
+        assert_eq!(dist.marginal(&[1, 1]), invalid_indices);
+    }
+
+    #[test]
+    fn multivariate_normal_dist_correct_conditional() {
+        let dist = MultivariateNormalDist::new(&array![0.0, 0.0], &array![[1.0, 0.5], [0.5, 1.0]]).unwrap();
+        let conditional = dist.conditional(&[1], &array![2.0]).unwrap();
+
+        assert_eq!(conditional.mean(), &array![1.0]);
+        assert_eq!(conditional.covariance(), &array![[0.75]]);
+
+        // reference values computed with mpmath
+        let conditional = example_dist().conditional(&[1], &array![0.0]).unwrap();
+        let expected_mean = array![1.6, 0.7];
+        let expected_covariance = array![[1.64, -0.52], [-0.52, 1.46]];
+
+        assert!(conditional.mean().iter().zip(expected_mean.iter()).all(|(a, b)| (a - b).abs() < 1e-14));
+        assert!(conditional.covariance().iter().zip(expected_covariance.iter()).all(|(a, b)| (a - b).abs() < 1e-14));
+    }
+
+    #[test]
+    fn multivariate_normal_dist_invalid_conditional_fails() {
+        let dist = example_dist();
+
+        let none_remaining = Err(PensivError::InvalidInput("at least one component must remain after conditioning"));
+        assert_eq!(dist.conditional(&[0, 1, 2], &array![0.0, 0.0, 0.0]), none_remaining);
+        assert_eq!(dist.conditional(&[0], &array![0.0, 0.0]), Err(PensivError::LengthMismatch { expected: 1, found: 2 }));
+        assert!(matches!(dist.conditional(&[5], &array![0.0]), Err(PensivError::InvalidInput(_))));
+        assert_eq!(dist.conditional(&[], &array![]), Ok(dist.clone()));
+    }
+
+    #[test]
+    fn multivariate_normal_dist_sample_moments_close() {
+        let dist = example_dist();
+
+        let mut rng = StdRng::seed_from_u64(25);
+        let samples = dist.sample_n(&mut rng, 20000);
+
+        assert_eq!(samples.dim(), (20000, 3));
+
+        let mean = samples.mean_axis(Axis(0)).unwrap();
+        let centered = &samples - &mean;
+        let covariance = centered.t().dot(&centered) / 20000.0;
+
+        assert!(mean.iter().zip(dist.mean().iter()).all(|(a, b)| (a - b).abs() < 0.05));
+        assert!(covariance.iter().zip(dist.covariance().iter()).all(|(a, b)| (a - b).abs() < 0.08));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn multivariate_normal_dist_serde_round_trip() {
+        let dist = example_dist();
+        assert_eq!(crate::utils::json_round_trip(&dist).unwrap(), dist);
+
+        // a ragged or non-positive-definite covariance matrix is rejected
+        let ragged = r#"{"mean":[0.0,0.0],"covariance":[[1.0,0.0],[0.0]]}"#;
+        let singular = r#"{"mean":[0.0,0.0],"covariance":[[1.0,1.0],[1.0,1.0]]}"#;
+
+        assert!(serde_json::from_str::<MultivariateNormalDist>(ragged).is_err());
+        assert!(serde_json::from_str::<MultivariateNormalDist>(singular).is_err());
+    }
+}
@@ -0,0 +1,497 @@
+//! Resampling methods, i.e. the bootstrap & permutation tests.
+//!
+//! Both methods repeatedly evaluate a statistic on data resampled from the observed data. The randomness they need is
+//! drawn from a caller-provided `rand::RngCore`, so results are reproducible given a seeded generator: each resample is
+//! drawn from its own generator, whose seed is taken from the caller's generator before any resampling is done. With the
+//! `parallel` feature enabled, `par_bootstrap()` & `par_permutation_test()` evaluate the resamples across threads (via
+//! `rayon`), and return exactly the same results as their serial counterparts given the same seed.
+
+use ndarray::prelude::*;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore, SeedableRng};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::intervals::{significance, ConfidenceInterval};
+use super::tests::{p_value, Alternative, TestResult};
+use super::{describe, ContinuousDist, DiscreteDist, EmpiricalDist, NormalDist};
+
+
+/// The bootstrap distribution of a statistic, i.e. the distribution of its values over data sets resampled (with
+/// replacement) from the observed data, as returned by `bootstrap()`.
+///
+/// Along with the bootstrap distribution itself, this retains the statistic's value on the observed data & the
+/// jackknife estimate of its acceleration, from which it computes percentile, basic, & BCa confidence intervals.
+#[derive(Debug)]
+pub struct Bootstrap {
+    estimate: f64,
+    replicates: Array<f64, Ix1>,
+    dist: EmpiricalDist,
+    acceleration: f64,
+}
+
+impl Bootstrap {
+    /// Creates a bootstrap distribution from the statistic's value on the observed data, its values on each resample, &
+    /// its values on each jackknife sample (i.e. with each element of the data left out in turn).
+    fn new(estimate: f64, replicates: Vec<f64>, jackknife: Vec<f64>) -> Option<Bootstrap> {
+        let replicates = Array::from(replicates);
+        let dist = EmpiricalDist::new(&replicates).ok()?;
+        let jackknife_mean = jackknife.iter().sum::<f64>() / jackknife.len() as f64;
+
+        let (sum_squares, sum_cubes) = jackknife.iter().fold((0.0, 0.0), |(squares, cubes), value| {
+            let deviation = jackknife_mean - value;
+            (squares + deviation * deviation, cubes + deviation * deviation * deviation)
+        });
+
+        // a statistic that's the same on every jackknife sample has no skewness to correct for; a statistic that's
+        // undefined on them (e.g. when the data has 1 element) leaves the acceleration NaN
+        let acceleration = if sum_squares == 0.0 { 0.0 } else { sum_cubes / (6.0 * sum_squares.powf(1.5)) };
+
+        Some(Bootstrap { estimate, replicates, dist, acceleration })
+    }
+
+    /// Returns the statistic's value on the observed data.
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Returns the bootstrap distribution, i.e. the empirical distribution of the statistic's values on each resample.
+    pub fn dist(&self) -> &EmpiricalDist {
+        &self.dist
+    }
+
+    /// Returns the statistic's value on each resample, in the order in which they were drawn.
+    pub fn replicates(&self) -> ArrayView1<'_, f64> {
+        self.replicates.view()
+    }
+
+    /// Returns the jackknife estimate of the acceleration, i.e. the rate at which the statistic's standard error changes
+    /// with its true value, as used by `bca_interval()`.
+    ///
+    /// This is `NaN` if the statistic is undefined when any element is left out of the data.
+    pub fn acceleration(&self) -> f64 {
+        self.acceleration
+    }
+
+    /// Returns the bootstrap estimate of the statistic's standard error, i.e. the sample standard deviation of the
+    /// replicates, or `None` if there's only 1 replicate.
+    pub fn std_error(&self) -> Option<f64> {
+        describe::std(&self.replicates().to_owned(), 1)
+    }
+
+    /// Returns the bootstrap estimate of the statistic's bias, i.e. the mean of the replicates minus the estimate.
+    pub fn bias(&self) -> f64 {
+        self.dist.mean() - self.estimate
+    }
+
+    /// Returns the percentile confidence interval for the statistic, whose bounds are the `(1 - level) / 2` &
+    /// `(1 + level) / 2` quantiles of the replicates.
+    ///
+    /// Returns `None` if `level` isn't in `(0, 1)`.
+    ///
+    /// ```ignore
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let bootstrap = bootstrap(&data, |x| describe::median(x).unwrap(), 2000, &mut rng).unwrap();
+    ///
+    /// let interval = bootstrap.percentile_interval(0.95).unwrap();
+    /// ```
+    pub fn percentile_interval(&self, level: f64) -> Option<ConfidenceInterval> {
+        let alpha = significance(level)?;
+
+        let lower = self.dist.quantile(alpha / 2.0)?;
+        let upper = self.dist.quantile(1.0 - alpha / 2.0)?;
+
+        Some(ConfidenceInterval { lower, upper, level })
+    }
+
+    /// Returns the basic (or "reverse percentile") confidence interval for the statistic, which reflects the percentile
+    /// interval about the estimate, i.e. `[2 estimate - upper, 2 estimate - lower]`.
+    ///
+    /// Returns `None` if `level` isn't in `(0, 1)`.
+    pub fn basic_interval(&self, level: f64) -> Option<ConfidenceInterval> {
+        let percentile = self.percentile_interval(level)?;
+
+        Some(ConfidenceInterval {
+            lower: 2.0 * self.estimate - percentile.upper,
+            upper: 2.0 * self.estimate - percentile.lower,
+            level,
+        })
+    }
+
+    /// Returns the bias-corrected & accelerated (BCa) confidence interval for the statistic.
+    ///
+    /// Like the percentile interval, its bounds are quantiles of the replicates, but the quantiles are adjusted for the
+    /// median bias of the replicates and for the acceleration (see `acceleration()`), which makes its coverage more
+    /// accurate for biased or skewed statistics. Returns `None` if `level` isn't in `(0, 1)`, if the estimate is below or
+    /// above every replicate (so the bias can't be estimated), or if the acceleration is undefined or too large for the
+    /// adjustment to be valid.
+    pub fn bca_interval(&self, level: f64) -> Option<ConfidenceInterval> {
+        let alpha = significance(level)?;
+        let replicates = self.replicates();
+
+        let below = replicates.iter().filter(|value| **value < self.estimate).count() as f64;
+        let equal = replicates.iter().filter(|value| **value == self.estimate).count() as f64;
+        let proportion = (below + equal / 2.0) / replicates.len() as f64;
+
+        if proportion <= 0.0 || proportion >= 1.0 || !self.acceleration.is_finite() {
+            return None;
+        }
+
+        let normal = NormalDist::std();
+        let bias_correction = normal.ppf(proportion)?;
+        let critical_value = normal.ppf(1.0 - alpha / 2.0)?;
+
+        let adjusted_quantile = |z: f64| {
+            let shifted = bias_correction + z;
+            let denominator = 1.0 - self.acceleration * shifted;
+
+            if denominator > 0.0 { Some(normal.cdf(bias_correction + shifted / denominator)) } else { None }
+        };
+
+        let lower = self.dist.quantile(adjusted_quantile(-critical_value)?)?;
+        let upper = self.dist.quantile(adjusted_quantile(critical_value)?)?;
+
+        Some(ConfidenceInterval { lower, upper, level })
+    }
+}
+
+
+/// Returns the bootstrap distribution of `statistic` over `resamples` data sets resampled with replacement from `data`.
+///
+/// Each resample has the same length as `data`. The statistic is also evaluated on `data` itself & on each of its
+/// jackknife samples (for the acceleration used by `Bootstrap::bca_interval()`), so it's evaluated
+/// `resamples + data.len() + 1` times in total. To bootstrap the data set of an `EmpiricalDist`, pass
+/// `&dist.data()`. Returns `None` if `data` is empty, if `resamples` is `0`, or if the statistic is `NaN` or
+/// infinite on any resample.
+///
+/// ```ignore
+/// let data = array![2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9];
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let bootstrap = bootstrap(&data, |x| describe::mean(x).unwrap(), 2000, &mut rng).unwrap();
+/// println!("{:?}", bootstrap.std_error()); // prints approximately "Some(0.43)"
+/// ```
+pub fn bootstrap<F>(data: &Array<f64, Ix1>, statistic: F, resamples: usize, rng: &mut dyn RngCore) -> Option<Bootstrap>
+    where F: Fn(&Array<f64, Ix1>) -> f64
+{
+    if data.is_empty() || resamples == 0 {
+        return None;
+    }
+
+    let replicates = seeds(resamples, rng).iter().map(|seed| statistic(&resample(data, *seed))).collect();
+    let jackknife = (0..data.len()).map(|i| statistic(&leave_out(data, i))).collect();
+
+    Bootstrap::new(statistic(data), replicates, jackknife)
+}
+
+/// Returns the bootstrap distribution of `statistic` like `bootstrap()`, but evaluates the resamples in parallel.
+///
+/// Given a generator in the same state, the result is identical to that of `bootstrap()`.
+#[cfg(feature = "parallel")]
+pub fn par_bootstrap<F>(data: &Array<f64, Ix1>, statistic: F, resamples: usize, rng: &mut dyn RngCore) -> Option<Bootstrap>
+    where F: Fn(&Array<f64, Ix1>) -> f64 + Sync
+{
+    if data.is_empty() || resamples == 0 {
+        return None;
+    }
+
+    let replicates = seeds(resamples, rng).par_iter().map(|seed| statistic(&resample(data, *seed))).collect();
+    let jackknife = (0..data.len()).into_par_iter().map(|i| statistic(&leave_out(data, i))).collect();
+
+    Bootstrap::new(statistic(data), replicates, jackknife)
+}
+
+
+/// Tests whether the populations from which `a` & `b` were drawn have the same distribution, using a permutation test
+/// of `statistic`.
+///
+/// `statistic` compares two samples, e.g. the difference of their means. The pooled data is randomly split into samples
+/// of the same sizes as `a` & `b` `permutations` times; the p-value is the proportion of splits whose statistic is at
+/// least as extreme as the observed one, counting the observed split itself (so it's never smaller than
+/// `1 / (permutations + 1)`). `Greater` means that the statistic is larger than it would be under the null hypothesis.
+/// The result has no degrees of freedom. Returns `None` if either sample is empty, if `permutations` is `0`, or if the
+/// observed statistic is `NaN`.
+///
+/// ```ignore
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let difference = |a: &Array1<f64>, b: &Array1<f64>| describe::mean(a).unwrap() - describe::mean(b).unwrap();
+///
+/// let result = permutation_test(&a, &b, difference, 9999, Alternative::TwoSided, &mut rng).unwrap();
+/// ```
+pub fn permutation_test<F>(
+    a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, statistic: F, permutations: usize, alternative: Alternative,
+    rng: &mut dyn RngCore,
+) -> Option<TestResult>
+    where F: Fn(&Array<f64, Ix1>, &Array<f64, Ix1>) -> f64
+{
+    if a.is_empty() || b.is_empty() || permutations == 0 {
+        return None;
+    }
+
+    let observed = statistic(a, b);
+    if observed.is_nan() {
+        return None;
+    }
+
+    let pooled = a.iter().chain(b.iter()).cloned().collect::<Vec<f64>>();
+    let permuted = seeds(permutations, rng).iter()
+        .map(|seed| permuted_statistic(&pooled, a.len(), &statistic, *seed))
+        .collect::<Vec<f64>>();
+
+    Some(permutation_result(observed, &permuted, alternative))
+}
+
+/// Tests whether the populations from which `a` & `b` were drawn have the same distribution like `permutation_test()`,
+/// but evaluates the permutations in parallel.
+///
+/// Given a generator in the same state, the result is identical to that of `permutation_test()`.
+#[cfg(feature = "parallel")]
+pub fn par_permutation_test<F>(
+    a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, statistic: F, permutations: usize, alternative: Alternative,
+    rng: &mut dyn RngCore,
+) -> Option<TestResult>
+    where F: Fn(&Array<f64, Ix1>, &Array<f64, Ix1>) -> f64 + Sync
+{
+    if a.is_empty() || b.is_empty() || permutations == 0 {
+        return None;
+    }
+
+    let observed = statistic(a, b);
+    if observed.is_nan() {
+        return None;
+    }
+
+    let pooled = a.iter().chain(b.iter()).cloned().collect::<Vec<f64>>();
+    let permuted = seeds(permutations, rng).par_iter()
+        .map(|seed| permuted_statistic(&pooled, a.len(), &statistic, *seed))
+        .collect::<Vec<f64>>();
+
+    Some(permutation_result(observed, &permuted, alternative))
+}
+
+
+/// Returns `count` seeds drawn from `rng`, one per resample.
+fn seeds(count: usize, rng: &mut dyn RngCore) -> Vec<u64> {
+    (0..count).map(|_| rng.next_u64()).collect()
+}
+
+/// Returns a resample of `data` (with replacement), drawn by a generator seeded with `seed`.
+fn resample(data: &Array<f64, Ix1>, seed: u64) -> Array<f64, Ix1> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n = data.len();
+
+    Array::from((0..n).map(|_| data[rng.gen_range(0, n)]).collect::<Vec<f64>>())
+}
+
+/// Returns `data` without its `i`-th element, i.e. its `i`-th jackknife sample.
+fn leave_out(data: &Array<f64, Ix1>, i: usize) -> Array<f64, Ix1> {
+    Array::from(data.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, value)| *value).collect::<Vec<f64>>())
+}
+
+/// Returns `statistic` of a random split of `pooled` into samples of `split` & `pooled.len() - split` elements, drawn by
+/// a generator seeded with `seed`.
+fn permuted_statistic<F>(pooled: &[f64], split: usize, statistic: &F, seed: u64) -> f64
+    where F: Fn(&Array<f64, Ix1>, &Array<f64, Ix1>) -> f64
+{
+    let mut shuffled = pooled.to_vec();
+    shuffled.shuffle(&mut StdRng::seed_from_u64(seed));
+
+    let (a, b) = shuffled.split_at(split);
+    statistic(&Array::from(a.to_vec()), &Array::from(b.to_vec()))
+}
+
+/// Returns the result of a permutation test with statistic `observed`, given the statistic of each permutation.
+fn permutation_result(observed: f64, permuted: &[f64], alternative: Alternative) -> TestResult {
+    let total = permuted.len() as f64 + 1.0;
+    let at_most = permuted.iter().filter(|value| **value <= observed).count() as f64 + 1.0;
+    let at_least = permuted.iter().filter(|value| **value >= observed).count() as f64 + 1.0;
+
+    let p_value = p_value(alternative, at_most / total, at_least / total);
+    TestResult { statistic: observed, p_value, dof: None, alternative }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mean(data: &Array<f64, Ix1>) -> f64 {
+        describe::mean(data).unwrap()
+    }
+
+    fn mean_difference(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>) -> f64 {
+        mean(a) - mean(b)
+    }
+
+    fn sequence(n: usize) -> Array<f64, Ix1> {
+        Array::range(1.0, n as f64 + 1.0, 1.0)
+    }
+
+    #[test]
+    fn bootstrap_invalid() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert!(bootstrap(&array![], mean, 100, &mut rng).is_none());
+        assert!(bootstrap(&sequence(10), mean, 0, &mut rng).is_none());
+        assert!(bootstrap(&sequence(10), |_| f64::NAN, 100, &mut rng).is_none());
+    }
+
+    #[test]
+    fn bootstrap_is_seedable() {
+        let data = sequence(20);
+
+        let first = bootstrap(&data, mean, 500, &mut StdRng::seed_from_u64(42)).unwrap();
+        let second = bootstrap(&data, mean, 500, &mut StdRng::seed_from_u64(42)).unwrap();
+        let other = bootstrap(&data, mean, 500, &mut StdRng::seed_from_u64(7)).unwrap();
+
+        assert_eq!(first.replicates(), second.replicates());
+        assert_ne!(first.replicates(), other.replicates());
+    }
+
+    #[test]
+    fn bootstrap_mean() {
+        let data = sequence(20);
+        let bootstrap = bootstrap(&data, mean, 4000, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert_eq!(bootstrap.estimate(), 10.5);
+        assert_eq!(bootstrap.replicates().len(), 4000);
+        assert!(bootstrap.replicates().iter().all(|value| (1.0..=20.0).contains(value)));
+
+        // the standard error of the mean is sd / sqrt(n), with the population sd of the data
+        let std_error = (describe::variance(&data, 0).unwrap() / 20.0).sqrt();
+        assert!((bootstrap.std_error().unwrap() - std_error).abs() < 0.05);
+        assert!(bootstrap.bias().abs() < 0.05);
+
+        // the data is symmetric, so its mean has no acceleration
+        assert!(bootstrap.acceleration().abs() < 1e-12);
+    }
+
+    #[test]
+    fn bootstrap_intervals() {
+        let data = sequence(20);
+        let bootstrap = bootstrap(&data, mean, 4000, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        let percentile = bootstrap.percentile_interval(0.95).unwrap();
+        let basic = bootstrap.basic_interval(0.95).unwrap();
+        let bca = bootstrap.bca_interval(0.95).unwrap();
+
+        // approximately mean ± 1.96 sd / sqrt(n)
+        for interval in [percentile, basic, bca].iter() {
+            assert_eq!(interval.level(), 0.95);
+            assert!((interval.lower() - 7.97).abs() < 0.2, "{}", interval.lower());
+            assert!((interval.upper() - 13.03).abs() < 0.2, "{}", interval.upper());
+        }
+
+        assert!((basic.lower() - (21.0 - percentile.upper())).abs() < 1e-12);
+        assert!((basic.upper() - (21.0 - percentile.lower())).abs() < 1e-12);
+
+        assert!(bootstrap.percentile_interval(1.0).is_none());
+        assert!(bootstrap.basic_interval(0.0).is_none());
+        assert!(bootstrap.bca_interval(1.5).is_none());
+    }
+
+    #[test]
+    fn bootstrap_bca_skewed() {
+        // the variance of right-skewed data is itself right-skewed, which BCa corrects for by shifting the interval up
+        let data = array![0.1, 0.2, 0.2, 0.3, 0.4, 0.5, 0.7, 0.9, 1.2, 1.6, 2.3, 3.1, 4.5, 6.8, 9.9];
+        let variance = |x: &Array<f64, Ix1>| describe::variance(x, 1).unwrap();
+        let bootstrap = bootstrap(&data, variance, 4000, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        let percentile = bootstrap.percentile_interval(0.9).unwrap();
+        let bca = bootstrap.bca_interval(0.9).unwrap();
+
+        assert!(bootstrap.acceleration() > 0.0);
+        assert!(bca.lower() > percentile.lower());
+        assert!(bca.upper() > percentile.upper());
+        assert!(bca.contains(bootstrap.estimate()));
+    }
+
+    #[test]
+    fn bootstrap_bca_undefined() {
+        // the data is distinct, so every resample (with replacement) has fewer distinct values than the data
+        let data = sequence(20);
+        let distinct = |x: &Array<f64, Ix1>| {
+            let mut values = x.to_vec();
+            values.sort_by(f64::total_cmp);
+            values.dedup();
+
+            values.len() as f64
+        };
+        let bootstrap = bootstrap(&data, distinct, 200, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert!(bootstrap.replicates().iter().all(|value| *value < 20.0));
+        assert!(bootstrap.percentile_interval(0.95).is_some());
+        assert!(bootstrap.bca_interval(0.95).is_none());
+
+        // a single element leaves nothing for the jackknife
+        let mean = |x: &Array<f64, Ix1>| describe::mean(x).unwrap_or(f64::NAN);
+        let bootstrap = super::bootstrap(&array![1.0], mean, 100, &mut StdRng::seed_from_u64(42)).unwrap();
+        assert!(bootstrap.acceleration().is_nan());
+        assert!(bootstrap.bca_interval(0.95).is_none());
+    }
+
+    #[test]
+    fn permutation_test_invalid() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert!(permutation_test(&array![], &sequence(5), mean_difference, 100, Alternative::TwoSided, &mut rng).is_none());
+        assert!(permutation_test(&sequence(5), &sequence(5), mean_difference, 0, Alternative::TwoSided, &mut rng).is_none());
+        assert!(permutation_test(&sequence(5), &sequence(5), |_, _| f64::NAN, 100, Alternative::TwoSided, &mut rng).is_none());
+    }
+
+    #[test]
+    fn permutation_test_shift() {
+        let a = array![5.2, 6.1, 5.8, 6.5, 5.9, 6.3, 5.5, 6.0];
+        let b = array![4.1, 4.8, 4.5, 5.0, 4.3, 4.9, 4.6, 4.2];
+
+        let greater = permutation_test(&a, &b, mean_difference, 999, Alternative::Greater, &mut StdRng::seed_from_u64(42)).unwrap();
+        let less = permutation_test(&a, &b, mean_difference, 999, Alternative::Less, &mut StdRng::seed_from_u64(42)).unwrap();
+        let two_sided = permutation_test(&a, &b, mean_difference, 999, Alternative::TwoSided, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert!((greater.statistic() - 1.3625).abs() < 1e-12);
+        assert_eq!(greater.dof(), None);
+        assert_eq!(greater.alternative(), Alternative::Greater);
+
+        // the samples don't overlap, so no permutation is as extreme as the observed split
+        assert_eq!(greater.p_value(), 0.001);
+        assert_eq!(less.p_value(), 1.0);
+        assert_eq!(two_sided.p_value(), 0.002);
+    }
+
+    #[test]
+    fn permutation_test_null() {
+        let a = array![4.9, 5.6, 5.1, 4.4, 5.3, 5.0, 4.7, 5.5];
+        let b = array![5.2, 4.6, 5.4, 4.8, 5.0, 5.7, 4.5, 5.1];
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = permutation_test(&a, &b, mean_difference, 1999, Alternative::TwoSided, &mut rng).unwrap();
+        assert!(result.p_value() > 0.5, "{}", result.p_value());
+
+        let first = permutation_test(&a, &b, mean_difference, 500, Alternative::Less, &mut StdRng::seed_from_u64(7)).unwrap();
+        let second = permutation_test(&a, &b, mean_difference, 500, Alternative::Less, &mut StdRng::seed_from_u64(7)).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_serial() {
+        let data = sequence(30);
+
+        let serial = bootstrap(&data, mean, 1000, &mut StdRng::seed_from_u64(42)).unwrap();
+        let parallel = par_bootstrap(&data, mean, 1000, &mut StdRng::seed_from_u64(42)).unwrap();
+
+        assert_eq!(serial.replicates(), parallel.replicates());
+        assert_eq!(serial.acceleration(), parallel.acceleration());
+        assert_eq!(serial.bca_interval(0.95), parallel.bca_interval(0.95));
+
+        let a = array![4.9, 5.6, 5.1, 4.4, 5.3, 5.0, 4.7, 5.5];
+        let b = array![5.2, 4.6, 5.4, 4.8, 5.0, 5.7, 4.5, 5.1];
+
+        let serial = permutation_test(&a, &b, mean_difference, 999, Alternative::TwoSided, &mut StdRng::seed_from_u64(42));
+        let parallel = par_permutation_test(&a, &b, mean_difference, 999, Alternative::TwoSided, &mut StdRng::seed_from_u64(42));
+        assert_eq!(serial, parallel);
+    }
+}
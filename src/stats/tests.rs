@@ -0,0 +1,754 @@
+//! Statistical hypothesis tests.
+//!
+//! Each test takes the data to test (and, where relevant, a hypothesized value or distribution) and returns a
+//! `TestResult` describing the test statistic, its p-value under the null hypothesis, its degrees of freedom (if any), and
+//! the alternative hypothesis that was tested. Tests return `None` when their statistic is undefined for their input,
+//! e.g. when a sample is too small or has no spread.
+//!
+//! Currently implemented are one-sample, two-sample, & paired t-tests, the z-test, chi-squared goodness-of-fit &
+//! independence tests, one- & two-sample Kolmogorov-Smirnov tests, the Mann-Whitney U test, and the Wilcoxon signed-rank
+//! test.
+
+use ndarray::prelude::*;
+
+use std::f64::consts::PI;
+
+use super::{describe, special, ContinuousDist, EmpiricalDist, NormalDist, StudentTDist};
+
+/// Largest number of terms summed when evaluating the Kolmogorov distribution.
+const MAX_KOLMOGOROV_TERMS: usize = 100;
+
+
+/// The alternative hypothesis of a test, i.e. the direction in which the data may depart from the null hypothesis.
+///
+/// For tests comparing a sample to a value or distribution, `Less` & `Greater` describe the sample; for tests comparing
+/// two samples, they describe the first sample relative to the second. See each test for details.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Alternative {
+    /// The data departs from the null hypothesis in either direction.
+    TwoSided,
+    /// The data is less than the null hypothesis describes.
+    Less,
+    /// The data is greater than the null hypothesis describes.
+    Greater,
+}
+
+
+/// The result of a hypothesis test.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TestResult {
+    pub(super) statistic: f64,
+    pub(super) p_value: f64,
+    pub(super) dof: Option<f64>,
+    pub(super) alternative: Alternative,
+}
+
+impl TestResult {
+    /// Returns the test statistic.
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value, i.e. the probability under the null hypothesis of a statistic at least as extreme (in the
+    /// direction of the alternative hypothesis) as the one observed.
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    /// Returns the degrees of freedom of the statistic's null distribution, or `None` if it doesn't have any.
+    pub fn dof(&self) -> Option<f64> {
+        self.dof
+    }
+
+    /// Returns the alternative hypothesis that was tested.
+    pub fn alternative(&self) -> Alternative {
+        self.alternative
+    }
+}
+
+
+/// Tests whether the mean of the population from which `data` was drawn is `mean`, using Student's t-test.
+///
+/// The statistic is `t = (sample mean - mean) / (sd / sqrt(n))`, which follows a t-distribution with `n - 1` degrees of
+/// freedom under the null hypothesis if the population is normal. Returns `None` if `data` has fewer than 2 elements or
+/// no spread.
+///
+/// ```ignore
+/// let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+/// let result = one_sample_t_test(&data, 5.0, Alternative::Greater).unwrap();
+///
+/// println!("{}", result.p_value()); // prints approximately "0.024"
+/// ```
+pub fn one_sample_t_test(data: &Array<f64, Ix1>, mean: f64, alternative: Alternative) -> Option<TestResult> {
+    let n = data.len() as f64;
+    let std_error = (describe::variance(data, 1)? / n).sqrt();
+
+    t_result((describe::mean(data)? - mean) / std_error, n - 1.0, alternative)
+}
+
+/// Tests whether the populations from which `a` & `b` were drawn have the same mean, using a two-sample t-test.
+///
+/// If `equal_variances` is true, this is Student's t-test, which pools the two samples' variances and has
+/// `n_a + n_b - 2` degrees of freedom. Otherwise, it's Welch's t-test, which doesn't assume that the populations have the
+/// same variance and has (fractional) degrees of freedom given by the Welch-Satterthwaite equation; this is the safer
+/// choice unless the variances are known to be equal. `Greater` tests whether the mean of `a` is greater than that of
+/// `b`. Returns `None` if either sample has fewer than 2 elements or if both have no spread.
+///
+/// ```ignore
+/// let a = array![19.1, 20.3, 18.7, 21.2, 20.0];
+/// let b = array![17.5, 18.2, 16.9, 18.8];
+///
+/// let result = two_sample_t_test(&a, &b, false, Alternative::TwoSided).unwrap();
+/// ```
+pub fn two_sample_t_test(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, equal_variances: bool, alternative: Alternative) -> Option<TestResult> {
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let (var_a, var_b) = (describe::variance(a, 1)?, describe::variance(b, 1)?);
+    let difference = describe::mean(a)? - describe::mean(b)?;
+
+    if equal_variances {
+        let dof = n_a + n_b - 2.0;
+        let pooled = ((n_a - 1.0) * var_a + (n_b - 1.0) * var_b) / dof;
+
+        t_result(difference / (pooled * (1.0 / n_a + 1.0 / n_b)).sqrt(), dof, alternative)
+    }
+    else {
+        let (se_a, se_b) = (var_a / n_a, var_b / n_b);
+        let dof = (se_a + se_b).powi(2) / (se_a * se_a / (n_a - 1.0) + se_b * se_b / (n_b - 1.0));
+
+        t_result(difference / (se_a + se_b).sqrt(), dof, alternative)
+    }
+}
+
+/// Tests whether the mean difference between paired observations `a` & `b` is `0`, using a paired t-test.
+///
+/// This is the one-sample t-test of the differences `a - b` against a mean of `0`; `Greater` tests whether `a` tends to
+/// be greater than `b`. Returns `None` if `a` & `b` have different lengths, fewer than 2 elements, or if the differences
+/// have no spread.
+///
+/// ```ignore
+/// let before = array![200.0, 190.0, 210.0, 220.0, 205.0];
+/// let after = array![195.0, 186.0, 204.0, 218.0, 199.0];
+///
+/// let result = paired_t_test(&before, &after, Alternative::Greater).unwrap();
+/// ```
+pub fn paired_t_test(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, alternative: Alternative) -> Option<TestResult> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    one_sample_t_test(&(a - b), 0.0, alternative)
+}
+
+/// Tests whether `data` was drawn from a population with the mean of `dist`, assuming that the population's standard
+/// deviation is that of `dist`, using the z-test.
+///
+/// The statistic is `z = (sample mean - mean) / (scale / sqrt(n))`, which follows the standard normal distribution under
+/// the null hypothesis. Returns `None` if `data` is empty or the scale of `dist` is `0`.
+///
+/// ```ignore
+/// let data = array![102.0, 98.5, 105.1, 101.3];
+/// let result = z_test(&data, &NormalDist::new(100.0, 3.0).unwrap(), Alternative::TwoSided).unwrap();
+/// ```
+pub fn z_test(data: &Array<f64, Ix1>, dist: &NormalDist, alternative: Alternative) -> Option<TestResult> {
+    let std_error = dist.scale() / (data.len() as f64).sqrt();
+    if std_error <= 0.0 {
+        return None;
+    }
+
+    let z = (describe::mean(data)? - dist.loc()) / std_error;
+    let p_value = p_value(alternative, normal_cdf(z), normal_cdf(-z));
+
+    Some(TestResult { statistic: z, p_value, dof: None, alternative })
+}
+
+/// Tests whether the category counts in `observed` follow the expected frequencies in `expected`, using Pearson's
+/// chi-squared goodness-of-fit test.
+///
+/// `expected` can hold counts or relative frequencies (e.g. probabilities); it's rescaled to the total of `observed`.
+/// The statistic is `sum((observed - expected)^2 / expected)`, which approximately follows a chi-squared distribution
+/// with `k - 1 - ddof` degrees of freedom, where `k` is the number of categories and `ddof` is the number of parameters
+/// estimated from the data to compute `expected`. The approximation is poor if any expected count is less than about 5.
+/// The test is always one-sided (large statistics are evidence against the null hypothesis), so the result's alternative
+/// is `Greater`. Returns `None` if `observed` & `expected` have different lengths, if any count is negative or expected
+/// frequency isn't positive, or if there are no degrees of freedom.
+///
+/// ```ignore
+/// let observed = array![18.0, 22.0, 20.0, 40.0];
+/// let result = chi_squared_test(&observed, &array![0.25, 0.25, 0.25, 0.25], 0).unwrap();
+///
+/// println!("{:?}", result.dof()); // prints "Some(3.0)"
+/// ```
+pub fn chi_squared_test(observed: &Array<f64, Ix1>, expected: &Array<f64, Ix1>, ddof: usize) -> Option<TestResult> {
+    let categories = observed.len();
+    let valid = observed.iter().all(|o| *o >= 0.0 && o.is_finite()) && expected.iter().all(|e| *e > 0.0 && e.is_finite());
+
+    if expected.len() != categories || !valid || categories < ddof + 2 {
+        return None;
+    }
+
+    let expected = expected * (observed.sum() / expected.sum());
+    let statistic = observed.iter().zip(expected.iter()).map(|(o, e)| (o - e).powi(2) / e).sum();
+
+    chi_squared_result(statistic, (categories - 1 - ddof) as f64)
+}
+
+/// Tests whether the row & column variables of the contingency table `table` are independent, using Pearson's
+/// chi-squared test of independence.
+///
+/// The expected count of each cell is the product of its row & column totals divided by the table's total, and the
+/// statistic is `sum((observed - expected)^2 / expected)`, which approximately follows a chi-squared distribution with
+/// `(rows - 1)(columns - 1)` degrees of freedom. No continuity correction is applied. As with `chi_squared_test()`, the
+/// result's alternative is `Greater`. Returns `None` if the table has fewer than 2 rows or columns, if any count is
+/// negative, or if any row or column total is `0`.
+///
+/// ```ignore
+/// let table = array![[20.0, 15.0], [30.0, 35.0]];
+/// let result = chi_squared_independence_test(&table).unwrap();
+///
+/// println!("{:?}", result.dof()); // prints "Some(1.0)"
+/// ```
+pub fn chi_squared_independence_test(table: &Array<f64, Ix2>) -> Option<TestResult> {
+    let (rows, columns) = table.dim();
+    if rows < 2 || columns < 2 || table.iter().any(|x| !(*x >= 0.0 && x.is_finite())) {
+        return None;
+    }
+
+    let row_totals = table.sum_axis(Axis(1));
+    let column_totals = table.sum_axis(Axis(0));
+    let total = table.sum();
+
+    if row_totals.iter().chain(column_totals.iter()).any(|t| *t <= 0.0) {
+        return None;
+    }
+
+    let mut statistic = 0.0;
+
+    for ((i, j), observed) in table.indexed_iter() {
+        let expected = row_totals[i] * column_totals[j] / total;
+        statistic += (observed - expected).powi(2) / expected;
+    }
+
+    chi_squared_result(statistic, ((rows - 1) * (columns - 1)) as f64)
+}
+
+/// Tests whether `data` was drawn from `dist`, using the one-sample Kolmogorov-Smirnov test.
+///
+/// With `TwoSided`, the statistic is the largest absolute difference between the empirical CDF of `data` & the CDF of
+/// `dist`, and its p-value is computed from the asymptotic Kolmogorov distribution with Stephens' small-sample
+/// correction. With `Greater` (resp. `Less`), the statistic is the largest amount by which the empirical CDF falls short
+/// of (resp. exceeds) the CDF of `dist`, i.e. evidence that the data tends to be larger (resp. smaller) than `dist`
+/// describes; its p-value is exact. Returns `None` if `data` is empty or contains `NaN`.
+///
+/// ```ignore
+/// let data = array![0.12, 0.55, 0.31, 0.87, 0.46, 0.68, 0.21, 0.93];
+/// let result = ks_test(&data, &ContinuousUniformDist::new(0.0, 1.0).unwrap(), Alternative::TwoSided).unwrap();
+/// ```
+pub fn ks_test<D: ContinuousDist<f64>>(data: &Array<f64, Ix1>, dist: &D, alternative: Alternative) -> Option<TestResult> {
+    let n = data.len();
+    if n == 0 || data.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut d_plus = 0_f64;
+    let mut d_minus = 0_f64;
+
+    for (i, x) in sorted.iter().enumerate() {
+        let cdf = dist.cdf(*x);
+
+        d_plus = d_plus.max((i + 1) as f64 / n as f64 - cdf);
+        d_minus = d_minus.max(cdf - i as f64 / n as f64);
+    }
+
+    let (statistic, p_value) = match alternative {
+        Alternative::TwoSided => {
+            let statistic = d_plus.max(d_minus);
+            (statistic, kolmogorov_sf(stephens_scale(n as f64) * statistic))
+        },
+        Alternative::Greater => (d_minus, smirnov_sf(n, d_minus)),
+        Alternative::Less => (d_plus, smirnov_sf(n, d_plus)),
+    };
+
+    Some(TestResult { statistic, p_value, dof: None, alternative })
+}
+
+/// Tests whether `a` & `b` were drawn from the same distribution, using the two-sample Kolmogorov-Smirnov test.
+///
+/// The statistic compares the empirical CDFs of the samples (as `EmpiricalDist`s): with `TwoSided`, it's their largest
+/// absolute difference, and with `Greater` (resp. `Less`), it's the largest amount by which the empirical CDF of `a`
+/// falls short of (resp. exceeds) that of `b`, i.e. evidence that `a` tends to be larger (resp. smaller) than `b`. p-values are asymptotic, using the effective sample size
+/// `n_a n_b / (n_a + n_b)`. Returns `None` if either sample is empty or contains non-finite values.
+///
+/// ```ignore
+/// let a = array![1.2, 3.4, 2.2, 5.1, 0.7];
+/// let b = array![4.4, 6.1, 5.9, 3.8, 7.2, 5.5];
+///
+/// let result = two_sample_ks_test(&a, &b, Alternative::TwoSided).unwrap();
+/// ```
+pub fn two_sample_ks_test(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, alternative: Alternative) -> Option<TestResult> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let dist_a = EmpiricalDist::new(a).ok()?;
+    let dist_b = EmpiricalDist::new(b).ok()?;
+
+    let pooled = Array::from(a.iter().chain(b.iter()).cloned().collect::<Vec<f64>>());
+    let differences = dist_a.cdf_many(&pooled) - dist_b.cdf_many(&pooled);
+
+    let d_plus = differences.iter().cloned().fold(0.0, f64::max);
+    let d_minus = differences.iter().map(|d| -d).fold(0.0, f64::max);
+
+    let effective_n = (a.len() * b.len()) as f64 / (a.len() + b.len()) as f64;
+
+    let (statistic, p_value) = match alternative {
+        Alternative::TwoSided => {
+            let statistic = d_plus.max(d_minus);
+            (statistic, kolmogorov_sf(stephens_scale(effective_n) * statistic))
+        },
+        Alternative::Greater => (d_minus, (-2.0 * effective_n * d_minus * d_minus).exp()),
+        Alternative::Less => (d_plus, (-2.0 * effective_n * d_plus * d_plus).exp()),
+    };
+
+    Some(TestResult { statistic, p_value, dof: None, alternative })
+}
+
+/// Tests whether values from the population of `a` tend to be greater or less than values from the population of `b`,
+/// using the Mann-Whitney U test (also known as the Wilcoxon rank-sum test).
+///
+/// The statistic is `U`, the number of pairs `(x, y)` with `x` from `a` & `y` from `b` for which `x > y` (with ties
+/// counting as half). Its p-value uses the normal approximation, corrected for ties & for continuity; it's accurate
+/// unless both samples are very small. `Greater` tests whether values from `a` tend to be greater than values from `b`.
+/// Returns `None` if either sample is empty, if either contains `NaN`, or if every value is tied.
+///
+/// ```ignore
+/// let a = array![3.1, 4.5, 2.8, 5.0, 4.1];
+/// let b = array![2.0, 1.8, 3.3, 2.5];
+///
+/// let result = mann_whitney_u_test(&a, &b, Alternative::Greater).unwrap();
+/// println!("{}", result.statistic()); // prints "18"
+/// ```
+pub fn mann_whitney_u_test(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, alternative: Alternative) -> Option<TestResult> {
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let pooled = a.iter().chain(b.iter()).cloned().collect::<Vec<f64>>();
+    let (ranks, ties) = ranks(&pooled)?;
+
+    let rank_sum = ranks[..a.len()].iter().sum::<f64>();
+    let u = rank_sum - n_a * (n_a + 1.0) / 2.0;
+
+    let n = n_a + n_b;
+    let mean = n_a * n_b / 2.0;
+    let variance = n_a * n_b / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)));
+
+    normal_rank_result(u, mean, variance, alternative)
+}
+
+/// Tests whether the differences between paired observations `a` & `b` are symmetric about `0`, using the Wilcoxon
+/// signed-rank test.
+///
+/// Zero differences are discarded, the remaining differences are ranked by absolute value (averaging the ranks of
+/// ties), and the statistic is `W`, the sum of the ranks of the positive differences. Its p-value uses the normal
+/// approximation, corrected for ties & for continuity. `Greater` tests whether `a` tends to be greater than `b`. Unlike
+/// the paired t-test, this doesn't assume that the differences are normally distributed. Returns `None` if `a` & `b` have
+/// different lengths, contain `NaN`, or if every difference is `0`.
+///
+/// ```ignore
+/// let before = array![125.0, 115.0, 130.0, 140.0, 140.0, 115.0, 140.0, 125.0, 140.0, 135.0];
+/// let after = array![110.0, 122.0, 125.0, 120.0, 140.0, 124.0, 123.0, 137.0, 135.0, 145.0];
+///
+/// let result = wilcoxon_signed_rank_test(&before, &after, Alternative::TwoSided).unwrap();
+/// println!("{}", result.statistic()); // prints "27"
+/// ```
+pub fn wilcoxon_signed_rank_test(a: &Array<f64, Ix1>, b: &Array<f64, Ix1>, alternative: Alternative) -> Option<TestResult> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    let differences = (a - b).iter().cloned().filter(|d| *d != 0.0).collect::<Vec<f64>>();
+    let magnitudes = differences.iter().map(|d| d.abs()).collect::<Vec<f64>>();
+    let (ranks, ties) = ranks(&magnitudes)?;
+
+    let w = differences.iter().zip(ranks.iter()).filter(|(d, _)| **d > 0.0).map(|(_, rank)| rank).sum::<f64>();
+
+    let n = differences.len() as f64;
+    let mean = n * (n + 1.0) / 4.0;
+    let variance = n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - ties / 48.0;
+
+    normal_rank_result(w, mean, variance, alternative)
+}
+
+
+/// Returns the p-value for `alternative`, given the probabilities under the null hypothesis of a statistic at most
+/// (`lower`) & at least (`upper`) as large as the one observed.
+pub(super) fn p_value(alternative: Alternative, lower: f64, upper: f64) -> f64 {
+    match alternative {
+        Alternative::TwoSided => (2.0 * lower.min(upper)).min(1.0),
+        Alternative::Less => lower,
+        Alternative::Greater => upper,
+    }
+}
+
+/// Returns the CDF of the standard normal distribution at `z`, accurately in both tails.
+fn normal_cdf(z: f64) -> f64 {
+    special::erfc(-z / 2_f64.sqrt()) / 2.0
+}
+
+/// Returns the result of a t-test with statistic `t` & `dof` degrees of freedom, or `None` if `t` is undefined or infinite
+/// (i.e. if the data has no spread).
+fn t_result(t: f64, dof: f64, alternative: Alternative) -> Option<TestResult> {
+    if !t.is_finite() {
+        return None;
+    }
+
+    // the t-distribution is symmetric, so both tails can be computed without subtracting from 1
+    let dist = StudentTDist::new(dof).ok()?;
+    let p_value = p_value(alternative, dist.cdf(t), dist.cdf(-t));
+
+    Some(TestResult { statistic: t, p_value, dof: Some(dof), alternative })
+}
+
+/// Returns the result of an upper-tailed chi-squared test with statistic `statistic` & `dof` degrees of freedom.
+fn chi_squared_result(statistic: f64, dof: f64) -> Option<TestResult> {
+    let p_value = special::gamma_q(dof / 2.0, statistic / 2.0);
+    Some(TestResult { statistic, p_value, dof: Some(dof), alternative: Alternative::Greater })
+}
+
+/// Returns the result of a rank test whose statistic has the given mean & variance under the null hypothesis, using the
+/// normal approximation with a continuity correction; returns `None` if `variance` isn't positive.
+fn normal_rank_result(statistic: f64, mean: f64, variance: f64, alternative: Alternative) -> Option<TestResult> {
+    if variance <= 0.0 {
+        return None;
+    }
+
+    let std = variance.sqrt();
+    let lower = normal_cdf((statistic - mean + 0.5) / std);
+    let upper = normal_cdf(-(statistic - mean - 0.5) / std);
+
+    Some(TestResult { statistic, p_value: p_value(alternative, lower, upper), dof: None, alternative })
+}
+
+/// Returns the ranks of `values` (starting from 1, with tied values sharing the average of their ranks), along with the
+/// tie correction `sum(t^3 - t)` over the sizes `t` of the groups of ties; returns `None` if `values` is empty or contains
+/// `NaN`.
+fn ranks(values: &[f64]) -> Option<(Vec<f64>, f64)> {
+    if values.is_empty() || values.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+
+    let mut order = (0..values.len()).collect::<Vec<usize>>();
+    order.sort_by(|i, j| values[*i].partial_cmp(&values[*j]).unwrap());
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut ties = 0.0;
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+
+        // ranks start + 1 through end, inclusive
+        let rank = (start + end + 1) as f64 / 2.0;
+        for i in &order[start..end] {
+            ranks[*i] = rank;
+        }
+
+        let t = (end - start) as f64;
+        ties += t * t * t - t;
+        start = end;
+    }
+
+    Some((ranks, ties))
+}
+
+/// Returns the factor by which a Kolmogorov-Smirnov statistic is scaled before evaluating the Kolmogorov distribution,
+/// i.e. `sqrt(n)` with Stephens' small-sample correction.
+fn stephens_scale(n: f64) -> f64 {
+    n.sqrt() + 0.12 + 0.11 / n.sqrt()
+}
+
+/// Returns the survival function of the Kolmogorov distribution, i.e. the asymptotic distribution of `sqrt(n) D` for the
+/// two-sided Kolmogorov-Smirnov statistic `D`.
+fn kolmogorov_sf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+
+    if x < 1.18 {
+        // this series converges quickly for small x, where the alternating series below doesn't
+        for k in 1..=MAX_KOLMOGOROV_TERMS {
+            let term = (-((2 * k - 1) as f64).powi(2) * PI * PI / (8.0 * x * x)).exp();
+            sum += term;
+
+            if term < 1e-17 * sum {
+                break;
+            }
+        }
+
+        (1.0 - (2.0 * PI).sqrt() / x * sum).clamp(0.0, 1.0)
+    }
+    else {
+        for k in 1..=MAX_KOLMOGOROV_TERMS {
+            let term = (-2.0 * (k * k) as f64 * x * x).exp();
+            sum += if k % 2 == 1 { term } else { -term };
+
+            if term < 1e-17 * sum.abs() {
+                break;
+            }
+        }
+
+        (2.0 * sum).clamp(0.0, 1.0)
+    }
+}
+
+/// Returns the exact probability that the one-sided Kolmogorov-Smirnov statistic of `n` samples is at least `d`, using
+/// the Birnbaum-Tingey formula.
+fn smirnov_sf(n: usize, d: f64) -> f64 {
+    if d <= 0.0 {
+        return 1.0;
+    }
+    else if d >= 1.0 {
+        return 0.0;
+    }
+
+    let nf = n as f64;
+    let last = (nf * (1.0 - d)).floor() as usize;
+
+    let sum = (0..=last.min(n)).map(|j| {
+        let jf = j as f64;
+        let ln_term = super::ln_choose(n as u64, j as u64) + (nf - jf) * (1.0 - d - jf / nf).ln() + (jf - 1.0) * (d + jf / nf).ln();
+
+        ln_term.exp()
+    }).sum::<f64>();
+
+    (d * sum).clamp(0.0, 1.0)
+}
+
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    use crate::stats::ContinuousUniformDist;
+
+    fn assert_close(actual: f64, expected: f64, tol: f64) {
+        assert!((actual - expected).abs() <= tol * expected.abs(), "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn one_sample_t_test_correct() {
+        let data = array![5.1, 4.9, 5.6, 5.8, 6.0, 5.3];
+
+        let result = one_sample_t_test(&data, 5.0, Alternative::Greater).unwrap();
+        assert_close(result.statistic(), 2.605323299939319, 1e-12);
+        assert_close(result.p_value(), 0.02396996233316715, 1e-9);
+        assert_eq!(result.dof(), Some(5.0));
+        assert_eq!(result.alternative(), Alternative::Greater);
+
+        let two_sided = one_sample_t_test(&data, 5.0, Alternative::TwoSided).unwrap();
+        let less = one_sample_t_test(&data, 5.0, Alternative::Less).unwrap();
+
+        assert_close(two_sided.p_value(), 2.0 * result.p_value(), 1e-12);
+        assert_close(less.p_value(), 1.0 - result.p_value(), 1e-12);
+
+        assert_eq!(one_sample_t_test(&array![1.0], 0.0, Alternative::TwoSided), None);
+        assert_eq!(one_sample_t_test(&array![1.0, 1.0], 0.0, Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn two_sample_t_tests_correct() {
+        let a = array![19.1, 20.3, 18.7, 21.2, 20.0];
+        let b = array![17.5, 18.2, 16.9, 18.8];
+
+        let student = two_sample_t_test(&a, &b, true, Alternative::TwoSided).unwrap();
+        assert_close(student.statistic(), 3.241273034779901, 1e-12);
+        assert_close(student.p_value(), 0.01422797159164467, 1e-9);
+        assert_eq!(student.dof(), Some(7.0));
+
+        let welch = two_sample_t_test(&a, &b, false, Alternative::TwoSided).unwrap();
+        assert_close(welch.statistic(), 3.315940896601742, 1e-12);
+        assert_close(welch.dof().unwrap(), 6.962402962306028, 1e-12);
+        assert_close(welch.p_value(), 0.01293688428132883, 1e-9);
+
+        assert_eq!(two_sample_t_test(&a, &array![1.0], false, Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn paired_t_test_correct() {
+        let before = array![200.0, 190.0, 210.0, 220.0, 205.0];
+        let after = array![195.0, 186.0, 204.0, 218.0, 199.0];
+
+        let paired = paired_t_test(&before, &after, Alternative::Greater).unwrap();
+        let differences = one_sample_t_test(&(&before - &after), 0.0, Alternative::Greater).unwrap();
+
+        assert_eq!(paired, differences);
+        assert_close(paired.statistic(), 6.147008563985761, 1e-12);
+        assert_close(paired.p_value(), 0.0017761473010683693, 1e-9);
+
+        assert_eq!(paired_t_test(&before, &array![1.0, 2.0], Alternative::Greater), None);
+    }
+
+    #[test]
+    fn z_test_correct() {
+        let data = array![102.0, 98.5, 105.1, 101.3];
+        let dist = NormalDist::new(100.0, 3.0).unwrap();
+
+        let result = z_test(&data, &dist, Alternative::TwoSided).unwrap();
+        assert_close(result.statistic(), 1.15, 1e-12);
+        assert_close(result.p_value(), 0.2501438712743011, 1e-9);
+        assert_eq!(result.dof(), None);
+
+        // far in the tail, the p-value shouldn't be rounded to 0
+        let result = z_test(&array![130.0], &dist, Alternative::Greater).unwrap();
+        assert_close(result.p_value(), 7.619853024160526e-24, 1e-8);
+
+        assert_eq!(z_test(&array![], &dist, Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn chi_squared_test_correct() {
+        let observed = array![18.0, 22.0, 20.0, 40.0];
+
+        let result = chi_squared_test(&observed, &array![0.25, 0.25, 0.25, 0.25], 0).unwrap();
+        assert_close(result.statistic(), 12.32, 1e-12);
+        assert_close(result.p_value(), 0.006363629995195265, 1e-9);
+        assert_eq!(result.dof(), Some(3.0));
+        assert_eq!(result.alternative(), Alternative::Greater);
+
+        let estimated = chi_squared_test(&observed, &array![25.0, 25.0, 25.0, 25.0], 1).unwrap();
+        assert_close(estimated.statistic(), 12.32, 1e-12);
+        assert_eq!(estimated.dof(), Some(2.0));
+
+        assert_eq!(chi_squared_test(&observed, &array![1.0, 1.0], 0), None);
+        assert_eq!(chi_squared_test(&observed, &array![1.0, 1.0, 1.0, 0.0], 0), None);
+        assert_eq!(chi_squared_test(&array![1.0, 2.0], &array![1.0, 1.0], 1), None);
+    }
+
+    #[test]
+    fn chi_squared_independence_test_correct() {
+        let table = array![[20.0, 15.0], [30.0, 35.0]];
+
+        let result = chi_squared_independence_test(&table).unwrap();
+        assert_close(result.statistic(), 1.098901098901099, 1e-12);
+        assert_close(result.p_value(), 0.2945073936801102, 1e-9);
+        assert_eq!(result.dof(), Some(1.0));
+
+        let table = array![[10.0, 20.0, 30.0], [6.0, 9.0, 17.0]];
+        assert_eq!(chi_squared_independence_test(&table).unwrap().dof(), Some(2.0));
+
+        assert_eq!(chi_squared_independence_test(&array![[1.0, 2.0]]), None);
+        assert_eq!(chi_squared_independence_test(&array![[1.0, 0.0], [2.0, 0.0]]), None);
+    }
+
+    #[test]
+    fn ks_test_correct() {
+        let data = array![0.12, 0.55, 0.31, 0.87, 0.46, 0.68, 0.21, 0.93];
+        let dist = ContinuousUniformDist::new(0.0, 1.0).unwrap();
+
+        let result = ks_test(&data, &dist, Alternative::TwoSided).unwrap();
+        assert_close(result.statistic(), 0.12, 1e-12);
+        assert_close(result.p_value(), kolmogorov_sf(stephens_scale(8.0) * 0.12), 1e-15);
+        assert!(result.p_value() > 0.99);
+
+        // exact one-sided p-values for n = 8
+        let greater = ks_test(&data, &dist, Alternative::Greater).unwrap();
+        let less = ks_test(&data, &dist, Alternative::Less).unwrap();
+
+        assert_close(greater.statistic(), 0.12, 1e-12);
+        assert_close(greater.p_value(), 0.7347182311112704, 1e-9);
+        assert_close(less.statistic(), 0.075, 1e-12);
+        assert_close(less.p_value(), 0.8755713144956971, 1e-9);
+
+        // data shifted towards 0 is smaller than the uniform distribution describes
+        let shifted = data.mapv(|x| x / 2.0);
+        assert!(ks_test(&shifted, &dist, Alternative::TwoSided).unwrap().p_value() < 0.05);
+        assert!(ks_test(&shifted, &dist, Alternative::Less).unwrap().p_value() < 0.05);
+        assert!(ks_test(&shifted, &dist, Alternative::Greater).unwrap().p_value() > 0.5);
+
+        assert_eq!(ks_test(&array![], &dist, Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn kolmogorov_sf_correct() {
+        assert_eq!(kolmogorov_sf(0.0), 1.0);
+        assert_close(kolmogorov_sf(0.5), 0.9639452436648751, 1e-12);
+        assert_close(kolmogorov_sf(1.0), 0.26999967167735456, 1e-12);
+        assert_close(kolmogorov_sf(1.358098), 0.05, 1e-5);
+        assert_close(kolmogorov_sf(2.0), 0.0006709252557796953, 1e-12);
+    }
+
+    #[test]
+    fn two_sample_ks_test_correct() {
+        let a = array![1.2, 3.4, 2.2, 5.1, 0.7];
+        let b = array![4.4, 6.1, 5.9, 3.8, 7.2, 5.5];
+
+        let result = two_sample_ks_test(&a, &b, Alternative::TwoSided).unwrap();
+        assert_close(result.statistic(), 0.8, 1e-12);
+
+        let effective_n = 30.0 / 11.0;
+        assert_close(result.p_value(), kolmogorov_sf(stephens_scale(effective_n) * 0.8), 1e-15);
+
+        let greater = two_sample_ks_test(&a, &b, Alternative::Greater).unwrap();
+        let less = two_sample_ks_test(&a, &b, Alternative::Less).unwrap();
+
+        // `a` tends to be smaller than `b`
+        assert_close(less.statistic(), 0.8, 1e-12);
+        assert_close(less.p_value(), (-2.0 * effective_n * 0.64_f64).exp(), 1e-12);
+        assert_eq!(greater.statistic(), 0.0);
+        assert_eq!(greater.p_value(), 1.0);
+
+        assert_eq!(two_sample_ks_test(&a, &array![], Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn mann_whitney_u_test_correct() {
+        let a = array![3.1, 4.5, 2.8, 5.0, 4.1];
+        let b = array![2.0, 1.8, 3.3, 2.5];
+
+        let result = mann_whitney_u_test(&a, &b, Alternative::Greater).unwrap();
+        assert_eq!(result.statistic(), 18.0);
+        assert_close(result.p_value(), 0.03309628986109672, 1e-9);
+
+        // with ties
+        let a = array![1.0, 2.0, 2.0, 3.0, 4.0];
+        let b = array![2.0, 3.0, 3.0, 5.0, 6.0, 6.0];
+
+        let result = mann_whitney_u_test(&a, &b, Alternative::TwoSided).unwrap();
+        assert_eq!(result.statistic(), 6.0);
+        assert_close(result.p_value(), 0.11304997885632291, 1e-9);
+
+        assert_eq!(mann_whitney_u_test(&array![1.0, 1.0], &array![1.0], Alternative::TwoSided), None);
+        assert_eq!(mann_whitney_u_test(&array![], &array![1.0], Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn wilcoxon_signed_rank_test_correct() {
+        let before = array![125.0, 115.0, 130.0, 140.0, 140.0, 115.0, 140.0, 125.0, 140.0, 135.0];
+        let after = array![110.0, 122.0, 125.0, 120.0, 140.0, 124.0, 123.0, 137.0, 135.0, 145.0];
+
+        let result = wilcoxon_signed_rank_test(&before, &after, Alternative::TwoSided).unwrap();
+        assert_eq!(result.statistic(), 27.0);
+        assert_close(result.p_value(), 0.6352893188352069, 1e-9);
+
+        assert_eq!(wilcoxon_signed_rank_test(&before, &before, Alternative::TwoSided), None);
+        assert_eq!(wilcoxon_signed_rank_test(&before, &array![1.0], Alternative::TwoSided), None);
+    }
+
+    #[test]
+    fn ranks_average_ties() {
+        let (ranks, ties) = ranks(&[3.0, 1.0, 3.0, 2.0, 3.0]).unwrap();
+
+        assert_eq!(ranks, vec![4.0, 1.0, 4.0, 2.0, 4.0]);
+        assert_eq!(ties, 24.0);
+        assert_eq!(super::ranks(&[]), None);
+    }
+}
@@ -0,0 +1,745 @@
+//! Distributions derived from other continuous distributions, i.e. truncated, shifted & scaled, and censored ones.
+//!
+//! Each wrapper takes ownership of any `ContinuousDist<f64>` and is itself a `ContinuousDist<f64>`, so wrappers can be
+//! nested, e.g. a shifted exponential distribution truncated to an interval. Properties with closed forms in terms of the
+//! wrapped distribution are computed from it directly; the means & variances of truncated & censored distributions are
+//! computed by numerical integration.
+
+use num_complex::Complex;
+
+use rand::Rng;
+use rand::RngCore;
+
+use crate::numeric::{integrate_piecewise, quantile_breakpoints};
+#[cfg(feature = "serde")]
+use crate::utils::serde_via_params;
+use crate::PensivError;
+use super::{standardized_moment, ContinuousDist};
+
+/// Smallest probability mass within the bounds of a truncated distribution for which sampling is done by rejection
+/// rather than by inverting the CDF.
+const MIN_REJECTION_MASS: f64 = 0.25;
+
+
+/// A truncated distribution, i.e. a distribution restricted to an interval `[lower, upper]`.
+///
+/// The PDF of a truncated distribution is that of the wrapped distribution within the bounds, divided by the probability
+/// mass between them so that it integrates to `1`, and `0` outside of them. Either bound may be infinite, e.g. to
+/// truncate a distribution on only one side.
+///
+/// ```ignore
+/// let dist = Truncated::new(NormalDist::std(), -1.0, 2.0).unwrap();
+///
+/// println!("{}", dist.cdf(2.0)); // prints "1"
+/// println!("{}", dist.mean()); // prints approximately "0.2296"
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Truncated<D> {
+    dist: D,
+    lower: f64,
+    upper: f64,
+    lower_cdf: f64,
+    mass: f64,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(Truncated<D: ContinuousDist<f64>>, |wrapped| {
+    dist: D = &wrapped.dist,
+    lower: f64 = &wrapped.lower,
+    upper: f64 = &wrapped.upper,
+} => Truncated::new(dist, lower, upper));
+
+impl<D: ContinuousDist<f64>> Truncated<D> {
+    /// Creates and returns a new distribution by truncating `dist` to `[lower, upper]`.
+    ///
+    /// Returns an `InvalidBounds` error if either bound is `NaN` or if `lower >= upper`, or an `InvalidInput` error if the
+    /// probability mass of `dist` between the bounds is `0` (including when it's too small to be computed from the CDF of
+    /// `dist`, e.g. far into an upper tail).
+    pub fn new(dist: D, lower: f64, upper: f64) -> Result<Truncated<D>, PensivError> {
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(PensivError::InvalidBounds { lower, upper });
+        }
+
+        let lower_cdf = if lower == f64::NEG_INFINITY { 0.0 } else { dist.cdf(lower) };
+        let upper_cdf = if upper == f64::INFINITY { 1.0 } else { dist.cdf(upper) };
+        let mass = upper_cdf - lower_cdf;
+
+        if mass <= 0.0 || mass.is_nan() {
+            return Err(PensivError::InvalidInput("the distribution has no mass between the bounds"));
+        }
+
+        Ok(Truncated { dist, lower, upper, lower_cdf, mass })
+    }
+
+    /// Returns the wrapped distribution.
+    pub fn dist(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the lower bound of the truncation.
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Returns the upper bound of the truncation.
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Returns the probability mass of the wrapped distribution between the bounds, i.e. the normalizing constant of the
+    /// truncated distribution.
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+
+    /// Returns whether `value` is within the bounds.
+    fn contains(&self, value: f64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+}
+
+impl<D: ContinuousDist<f64>> ContinuousDist<f64> for Truncated<D> {
+    /// Returns the PDF of the wrapped distribution at `value` divided by the mass between the bounds, or `0` if `value` is
+    /// outside of them.
+    fn pdf(&self, value: f64) -> f64 {
+        if self.contains(value) { self.dist.pdf(value) / self.mass } else { 0.0 }
+    }
+
+    /// Returns the log PDF of `value`, computed from the log PDF of the wrapped distribution.
+    fn log_pdf(&self, value: f64) -> f64 {
+        if self.contains(value) { self.dist.log_pdf(value) - self.mass.ln() } else { f64::NEG_INFINITY }
+    }
+
+    /// Returns the CDF of `value`, i.e. the mass of the wrapped distribution between the lower bound & `value` as a
+    /// fraction of the mass between the bounds.
+    fn cdf(&self, value: f64) -> f64 {
+        if value < self.lower {
+            0.0
+        }
+        else if value >= self.upper {
+            1.0
+        }
+        else {
+            ((self.dist.cdf(value) - self.lower_cdf) / self.mass).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Returns the mean of the truncated distribution, computed by numerical integration.
+    fn mean(&self) -> f64 {
+        integrate_piecewise(|x| x * self.pdf(x), &quantile_breakpoints(|p| self.ppf(p), self.lower, self.upper))
+    }
+
+    /// Returns the variance of the truncated distribution, computed by numerical integration.
+    fn variance(&self) -> f64 {
+        let mean = self.mean();
+        integrate_piecewise(|x| (x - mean).powi(2) * self.pdf(x), &quantile_breakpoints(|p| self.ppf(p), self.lower, self.upper))
+    }
+
+    /// Draws a random value from the truncated distribution.
+    ///
+    /// If at least a quarter of the wrapped distribution's mass is within the bounds, values are drawn from the wrapped
+    /// distribution until one is within the bounds (i.e. by rejection sampling); otherwise, values are drawn by inverting
+    /// the CDF, so that sampling remains fast when truncating to a distant tail.
+    ///
+    /// ```ignore
+    /// let dist = Truncated::new(NormalDist::std(), 3.0, f64::INFINITY).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// println!("{}", dist.sample(&mut rng) >= 3.0); // prints "true"
+    /// ```
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        if self.mass >= MIN_REJECTION_MASS {
+            loop {
+                let value = self.dist.sample(rng);
+
+                if self.contains(value) {
+                    return value;
+                }
+            }
+        }
+
+        self.ppf(rng.gen::<f64>()).unwrap_or(self.lower)
+    }
+
+    /// Returns the quantile of `p`, i.e. the quantile of the wrapped distribution at the corresponding probability
+    /// between the bounds.
+    fn ppf(&self, p: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        else if p == 0.0 {
+            return Some(self.lower);
+        }
+        else if p == 1.0 {
+            return Some(self.upper);
+        }
+
+        Some(self.dist.ppf(self.lower_cdf + p * self.mass)?.clamp(self.lower, self.upper))
+    }
+}
+
+
+/// A shifted & scaled distribution, i.e. the distribution of `loc + scale X` where `X` is drawn from the wrapped
+/// distribution.
+///
+/// ```ignore
+/// let dist = Affine::new(ExponentialDist::new(1.0).unwrap(), 2.0, 3.0).unwrap();
+///
+/// println!("{}", dist.mean()); // prints "5"
+/// println!("{}", dist.cdf(2.0)); // prints "0"
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Affine<D> {
+    dist: D,
+    loc: f64,
+    scale: f64,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(Affine<D: ContinuousDist<f64>>, |wrapped| {
+    dist: D = &wrapped.dist,
+    loc: f64 = &wrapped.loc,
+    scale: f64 = &wrapped.scale,
+} => Affine::new(dist, loc, scale));
+
+impl<D: ContinuousDist<f64>> Affine<D> {
+    /// Creates and returns a new distribution by shifting `dist` by `loc` & scaling it by `scale`.
+    ///
+    /// Returns an `InvalidParameter` error if `loc` isn't finite or if `scale` isn't positive & finite.
+    pub fn new(dist: D, loc: f64, scale: f64) -> Result<Affine<D>, PensivError> {
+        if !loc.is_finite() {
+            return Err(PensivError::InvalidParameter { name: "loc", value: loc, expected: "a finite number" });
+        }
+        else if !(scale > 0.0 && scale.is_finite()) {
+            let expected = "a positive, finite number";
+            return Err(PensivError::InvalidParameter { name: "scale", value: scale, expected });
+        }
+
+        Ok(Affine { dist, loc, scale })
+    }
+
+    /// Returns the wrapped distribution.
+    pub fn dist(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the shift of the distribution.
+    pub fn loc(&self) -> f64 {
+        self.loc
+    }
+
+    /// Returns the scale of the distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the value of the wrapped distribution corresponding to `value`, i.e. `(value - loc) / scale`.
+    fn standardize(&self, value: f64) -> f64 {
+        (value - self.loc) / self.scale
+    }
+}
+
+impl<D: ContinuousDist<f64>> ContinuousDist<f64> for Affine<D> {
+    /// Returns the PDF of `value`, i.e. the PDF of the wrapped distribution at the corresponding value divided by the
+    /// scale.
+    fn pdf(&self, value: f64) -> f64 {
+        self.dist.pdf(self.standardize(value)) / self.scale
+    }
+
+    /// Returns the log PDF of `value`, computed from the log PDF of the wrapped distribution.
+    fn log_pdf(&self, value: f64) -> f64 {
+        self.dist.log_pdf(self.standardize(value)) - self.scale.ln()
+    }
+
+    /// Returns the CDF of the wrapped distribution at the value corresponding to `value`.
+    fn cdf(&self, value: f64) -> f64 {
+        self.dist.cdf(self.standardize(value))
+    }
+
+    /// Returns the interval CDF of the wrapped distribution between the values corresponding to the bounds.
+    fn interval_cdf(&self, lower_bound: f64, upper_bound: f64) -> f64 {
+        self.dist.interval_cdf(self.standardize(lower_bound), self.standardize(upper_bound))
+    }
+
+    /// Returns `loc + scale mean`, where `mean` is the mean of the wrapped distribution.
+    fn mean(&self) -> f64 {
+        self.loc + self.scale * self.dist.mean()
+    }
+
+    /// Returns `scale^2 variance`, where `variance` is the variance of the wrapped distribution.
+    fn variance(&self) -> f64 {
+        self.scale * self.scale * self.dist.variance()
+    }
+
+    /// Returns `scale std`, where `std` is the standard deviation of the wrapped distribution.
+    fn std(&self) -> f64 {
+        self.scale * self.dist.std()
+    }
+
+    /// Draws a random value from the wrapped distribution, then shifts & scales it.
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        self.loc + self.scale * self.dist.sample(rng)
+    }
+
+    /// Returns the quantile of `p`, i.e. the shifted & scaled quantile of the wrapped distribution.
+    fn ppf(&self, p: f64) -> Option<f64> {
+        Some(self.loc + self.scale * self.dist.ppf(p)?)
+    }
+
+    /// Returns the shifted & scaled median of the wrapped distribution.
+    fn median(&self) -> Option<f64> {
+        Some(self.loc + self.scale * self.dist.median()?)
+    }
+
+    /// Returns the shifted & scaled mode of the wrapped distribution.
+    fn mode(&self) -> Option<f64> {
+        Some(self.loc + self.scale * self.dist.mode()?)
+    }
+
+    /// Returns the skewness of the wrapped distribution, which is unchanged by shifting & scaling.
+    fn skewness(&self) -> f64 {
+        self.dist.skewness()
+    }
+
+    /// Returns the excess kurtosis of the wrapped distribution, which is unchanged by shifting & scaling.
+    fn kurtosis(&self) -> f64 {
+        self.dist.kurtosis()
+    }
+
+    /// Returns `entropy + ln(scale)`, where `entropy` is the entropy of the wrapped distribution.
+    fn entropy(&self) -> f64 {
+        self.dist.entropy() + self.scale.ln()
+    }
+
+    /// Returns `e^(loc t) mgf(scale t)`, where `mgf` is the MGF of the wrapped distribution.
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let value = (self.loc * t).exp() * self.dist.mgf(self.scale * t)?;
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns `e^(i loc t) cf(scale t)`, where `cf` is the characteristic function of the wrapped distribution.
+    fn cf(&self, t: f64) -> Complex<f64> {
+        Complex::new((self.loc * t).cos(), (self.loc * t).sin()) * self.dist.cf(self.scale * t)
+    }
+}
+
+
+/// A censored distribution, i.e. the distribution of values drawn from the wrapped distribution & clamped to
+/// `[lower, upper]`.
+///
+/// Unlike a truncated distribution, a censored distribution keeps the mass of the wrapped distribution outside of the
+/// bounds, as point masses at the bounds; this describes measurements that are recorded as the bound whenever they fall
+/// beyond it, e.g. by an instrument with a limited range. Either bound may be infinite, to censor only one side.
+///
+/// ```ignore
+/// let dist = Censored::new(NormalDist::std(), -1.0, 1.0).unwrap();
+///
+/// println!("{}", dist.lower_mass()); // prints approximately "0.1587"
+/// println!("{}", dist.cdf(-1.0)); // prints approximately "0.1587"
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Censored<D> {
+    dist: D,
+    lower: f64,
+    upper: f64,
+    lower_mass: f64,
+    upper_mass: f64,
+}
+
+#[cfg(feature = "serde")]
+serde_via_params!(Censored<D: ContinuousDist<f64>>, |wrapped| {
+    dist: D = &wrapped.dist,
+    lower: f64 = &wrapped.lower,
+    upper: f64 = &wrapped.upper,
+} => Censored::new(dist, lower, upper));
+
+impl<D: ContinuousDist<f64>> Censored<D> {
+    /// Creates and returns a new distribution by censoring `dist` to `[lower, upper]`.
+    ///
+    /// Returns an `InvalidBounds` error if either bound is `NaN` or if `lower >= upper`.
+    pub fn new(dist: D, lower: f64, upper: f64) -> Result<Censored<D>, PensivError> {
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(PensivError::InvalidBounds { lower, upper });
+        }
+
+        let lower_mass = if lower == f64::NEG_INFINITY { 0.0 } else { dist.cdf(lower) };
+        let upper_mass = if upper == f64::INFINITY { 0.0 } else { 1.0 - dist.cdf(upper) };
+
+        Ok(Censored { dist, lower, upper, lower_mass, upper_mass })
+    }
+
+    /// Returns the wrapped distribution.
+    pub fn dist(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the lower bound of the censoring.
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Returns the upper bound of the censoring.
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Returns the point mass at the lower bound, i.e. the probability that the wrapped distribution is at most the lower
+    /// bound.
+    pub fn lower_mass(&self) -> f64 {
+        self.lower_mass
+    }
+
+    /// Returns the point mass at the upper bound, i.e. the probability that the wrapped distribution exceeds the upper
+    /// bound.
+    pub fn upper_mass(&self) -> f64 {
+        self.upper_mass
+    }
+
+    /// Returns the expectation of `f` of the censored distribution, i.e. the contributions of the point masses plus the
+    /// integral of `f` times the PDF of the wrapped distribution between the bounds.
+    fn expectation<F: Fn(f64) -> f64>(&self, f: F) -> f64 {
+        let point_mass = |mass: f64, bound: f64| if mass > 0.0 { mass * f(bound) } else { 0.0 };
+        let breakpoints = quantile_breakpoints(|p| self.dist.ppf(p), self.lower, self.upper);
+
+        point_mass(self.lower_mass, self.lower)
+            + integrate_piecewise(|x| f(x) * self.dist.pdf(x), &breakpoints)
+            + point_mass(self.upper_mass, self.upper)
+    }
+}
+
+impl<D: ContinuousDist<f64>> ContinuousDist<f64> for Censored<D> {
+    /// Returns the PDF of `value`, i.e. the PDF of the wrapped distribution strictly between the bounds and `0` outside of
+    /// them.
+    ///
+    /// At a bound with a positive point mass, the point mass itself is returned rather than a density; this is the usual
+    /// convention for the likelihood of censored observations (e.g. in Tobit models), so `log_likelihood()` of censored
+    /// data is correct.
+    fn pdf(&self, value: f64) -> f64 {
+        if value < self.lower || value > self.upper {
+            0.0
+        }
+        else if value == self.lower && self.lower_mass > 0.0 {
+            self.lower_mass
+        }
+        else if value == self.upper && self.upper_mass > 0.0 {
+            self.upper_mass
+        }
+        else {
+            self.dist.pdf(value)
+        }
+    }
+
+    /// Returns the log PDF of `value`, with the same convention at the bounds as `pdf()`.
+    fn log_pdf(&self, value: f64) -> f64 {
+        if value < self.lower || value > self.upper {
+            f64::NEG_INFINITY
+        }
+        else if value == self.lower && self.lower_mass > 0.0 {
+            self.lower_mass.ln()
+        }
+        else if value == self.upper && self.upper_mass > 0.0 {
+            self.upper_mass.ln()
+        }
+        else {
+            self.dist.log_pdf(value)
+        }
+    }
+
+    /// Returns the CDF of `value`, which jumps by the point masses at the bounds.
+    fn cdf(&self, value: f64) -> f64 {
+        if value < self.lower {
+            0.0
+        }
+        else if value >= self.upper {
+            1.0
+        }
+        else {
+            self.dist.cdf(value)
+        }
+    }
+
+    /// Returns the mean of the censored distribution, computed by numerical integration.
+    fn mean(&self) -> f64 {
+        self.expectation(|x| x)
+    }
+
+    /// Returns the variance of the censored distribution, computed by numerical integration.
+    fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.expectation(|x| (x - mean).powi(2))
+    }
+
+    /// Draws a random value from the wrapped distribution and clamps it to the bounds.
+    fn sample(&self, rng: &mut dyn RngCore) -> f64 {
+        self.dist.sample(rng).clamp(self.lower, self.upper)
+    }
+
+    /// Returns the quantile of `p`, i.e. the quantile of the wrapped distribution clamped to the bounds.
+    fn ppf(&self, p: f64) -> Option<f64> {
+        Some(self.dist.ppf(p)?.clamp(self.lower, self.upper))
+    }
+
+    /// Returns the mode of the wrapped distribution clamped to the bounds.
+    ///
+    /// This is the mode of the continuous part of the distribution; it doesn't take the point masses into account, since
+    /// they aren't comparable to densities.
+    fn mode(&self) -> Option<f64> {
+        Some(self.dist.mode()?.clamp(self.lower, self.upper))
+    }
+
+    /// Returns the skewness of the censored distribution, computed by numerical integration.
+    fn skewness(&self) -> f64 {
+        standardized_moment(self.mean(), self.std(), 3, |f| self.expectation(f))
+    }
+
+    /// Returns the excess kurtosis of the censored distribution, computed by numerical integration.
+    fn kurtosis(&self) -> f64 {
+        standardized_moment(self.mean(), self.std(), 4, |f| self.expectation(f)) - 3.0
+    }
+
+    /// Returns the entropy of the wrapped distribution if neither bound has a point mass, and `NaN` otherwise, since the
+    /// differential entropy of a distribution with point masses is undefined.
+    fn entropy(&self) -> f64 {
+        if self.lower_mass > 0.0 || self.upper_mass > 0.0 { f64::NAN } else { self.dist.entropy() }
+    }
+
+    /// Returns the MGF of `t`, computed by numerical integration, or `None` if the integral overflows.
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let value = self.expectation(|x| (t * x).exp());
+        if value.is_finite() { Some(value) } else { None }
+    }
+
+    /// Returns the characteristic function of `t`, computed by numerical integration.
+    fn cf(&self, t: f64) -> Complex<f64> {
+        Complex::new(self.expectation(|x| (t * x).cos()), self.expectation(|x| (t * x).sin()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::stats::{ExponentialDist, NormalDist};
+
+    fn sample_mean<D: ContinuousDist<f64>>(dist: &D, n: usize) -> f64 {
+        let mut rng = StdRng::seed_from_u64(42);
+        dist.sample_n(&mut rng, n).mean().unwrap()
+    }
+
+    #[test]
+    fn truncated_new() {
+        let dist = Truncated::new(NormalDist::std(), -1.0, 2.0).unwrap();
+        assert_eq!(dist.lower(), -1.0);
+        assert_eq!(dist.upper(), 2.0);
+        assert!((dist.mass() - 0.818_594_614_120_363_7).abs() < 1e-14);
+
+        assert!(Truncated::new(NormalDist::std(), 1.0, 1.0).is_err());
+        let error = PensivError::InvalidBounds { lower: 2.0, upper: 1.0 };
+        assert_eq!(Truncated::new(NormalDist::std(), 2.0, 1.0).err(), Some(error));
+        assert!(Truncated::new(NormalDist::std(), f64::NAN, 1.0).is_err());
+        assert!(Truncated::new(NormalDist::std(), 40.0, 50.0).is_err());
+        assert!(Truncated::new(ExponentialDist::new(1.0).unwrap(), -2.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn truncated_normal() {
+        let dist = Truncated::new(NormalDist::std(), -1.0, 2.0).unwrap();
+
+        assert!((dist.pdf(0.0) - 0.48735023846953063).abs() < 1e-14);
+        assert_eq!(dist.pdf(-1.5), 0.0);
+        assert_eq!(dist.pdf(2.5), 0.0);
+        assert!((dist.log_pdf(0.0) - 0.48735023846953063_f64.ln()).abs() < 1e-14);
+        assert_eq!(dist.log_pdf(3.0), f64::NEG_INFINITY);
+
+        assert_eq!(dist.cdf(-1.0), 0.0);
+        assert!((dist.cdf(1.0) - 0.833_977_502_857_971_7).abs() < 1e-14);
+        assert_eq!(dist.cdf(2.0), 1.0);
+
+        assert!((dist.mean() - 0.22963717909132897).abs() < 1e-10);
+        assert!((dist.variance() - 0.519_762_539_211_533_9).abs() < 1e-10);
+
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0].iter() {
+            assert!((dist.cdf(dist.ppf(*p).unwrap()) - p).abs() < 1e-12);
+        }
+
+        assert!((sample_mean(&dist, 20000) - dist.mean()).abs() < 0.02);
+    }
+
+    #[test]
+    fn truncated_higher_moments() {
+        let dist = Truncated::new(NormalDist::std(), -1.0, 2.0).unwrap();
+
+        assert!((dist.median().unwrap() - dist.ppf(0.5).unwrap()).abs() < 1e-15);
+        // the PDF is flat at its peak, so the mode can only be located to about the square root of machine precision
+        assert!(dist.mode().unwrap().abs() < 1e-7);
+        assert!((dist.skewness() - 0.32254591283309152).abs() < 1e-10);
+        assert!((dist.kurtosis() + 0.719_827_542_077_103_4).abs() < 1e-10);
+        assert!((dist.entropy() - 1.0050201254964887).abs() < 1e-10);
+        assert!((dist.mgf(1.0).unwrap() - 1.6487212707001281).abs() < 1e-10);
+        assert!((dist.cf(1.0) - Complex::new(0.748_281_461_552_751_7, 0.15720504713621927)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn truncated_tail() {
+        // the exponential distribution is memoryless, so truncating it below shifts it
+        let dist = Truncated::new(ExponentialDist::new(1.0).unwrap(), 1.0, f64::INFINITY).unwrap();
+        assert!((dist.mean() - 2.0).abs() < 1e-10);
+        assert!((dist.variance() - 1.0).abs() < 1e-10);
+
+        // little of the mass is within the bounds, so values are drawn by inversion
+        let dist = Truncated::new(NormalDist::std(), 3.0, f64::INFINITY).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert!(dist.sample_n(&mut rng, 1000).iter().all(|x| *x >= 3.0));
+        assert!((dist.mean() - 3.2830986549304365).abs() < 1e-8);
+        assert!((sample_mean(&dist, 20000) - dist.mean()).abs() < 0.01);
+    }
+
+    #[test]
+    fn affine_exponential() {
+        let dist = Affine::new(ExponentialDist::new(1.0).unwrap(), 2.0, 3.0).unwrap();
+
+        assert_eq!(dist.loc(), 2.0);
+        assert_eq!(dist.scale(), 3.0);
+        assert!((dist.pdf(5.0) - (-1.0_f64).exp() / 3.0).abs() < 1e-15);
+        assert!((dist.log_pdf(5.0) - (-1.0 - 3.0_f64.ln())).abs() < 1e-15);
+        assert_eq!(dist.pdf(1.0), 0.0);
+        assert_eq!(dist.cdf(2.0), 0.0);
+        assert!((dist.cdf(5.0) - (1.0 - (-1.0_f64).exp())).abs() < 1e-15);
+
+        assert!((dist.mean() - 5.0).abs() < 1e-15);
+        assert!((dist.variance() - 9.0).abs() < 1e-15);
+        assert!((dist.std() - 3.0).abs() < 1e-15);
+        assert!((sample_mean(&dist, 20000) - 5.0).abs() < 0.1);
+
+        assert!(Affine::new(ExponentialDist::new(1.0).unwrap(), 0.0, 0.0).is_err());
+        assert!(Affine::new(ExponentialDist::new(1.0).unwrap(), 0.0, -1.0).is_err());
+        assert!(Affine::new(ExponentialDist::new(1.0).unwrap(), f64::INFINITY, 1.0).is_err());
+    }
+
+    #[test]
+    fn affine_normal() {
+        let dist = Affine::new(NormalDist::std(), 1.5, 2.0).unwrap();
+        let expected = NormalDist::new(1.5, 2.0).unwrap();
+
+        for x in [-3.0, 0.0, 1.5, 4.0].iter() {
+            assert!((dist.pdf(*x) - expected.pdf(*x)).abs() < 1e-15);
+            assert!((dist.cdf(*x) - expected.cdf(*x)).abs() < 1e-15);
+        }
+
+        assert!((dist.interval_cdf(0.0, 3.0) - expected.interval_cdf(0.0, 3.0)).abs() < 1e-15);
+        assert!((dist.ppf(0.975).unwrap() - expected.ppf(0.975).unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn affine_higher_moments() {
+        let dist = Affine::new(NormalDist::std(), 1.5, 2.0).unwrap();
+        let expected = NormalDist::new(1.5, 2.0).unwrap();
+
+        assert_eq!(dist.median(), Some(1.5));
+        assert_eq!(dist.mode(), Some(1.5));
+        assert_eq!(dist.skewness(), 0.0);
+        assert_eq!(dist.kurtosis(), 0.0);
+        assert!((dist.entropy() - expected.entropy()).abs() < 1e-15);
+        assert!((dist.mgf(0.5).unwrap() - expected.mgf(0.5).unwrap()).abs() < 1e-14);
+        assert!((dist.cf(0.5) - expected.cf(0.5)).norm() < 1e-15);
+
+        let dist = Affine::new(ExponentialDist::new(1.0).unwrap(), 2.0, 3.0).unwrap();
+        assert_eq!(dist.skewness(), 2.0);
+        assert_eq!(dist.mode(), Some(2.0));
+        assert_eq!(dist.mgf(0.5), None);
+    }
+
+    #[test]
+    fn censored_normal() {
+        let dist = Censored::new(NormalDist::std(), -1.0, 1.0).unwrap();
+        let tail = 0.15865525393145705;
+
+        assert!((dist.lower_mass() - tail).abs() < 1e-15);
+        assert!((dist.upper_mass() - tail).abs() < 1e-15);
+
+        assert!((dist.pdf(-1.0) - tail).abs() < 1e-15);
+        assert!((dist.pdf(0.5) - NormalDist::std().pdf(0.5)).abs() < 1e-15);
+        assert_eq!(dist.pdf(1.5), 0.0);
+        assert!((dist.log_pdf(1.0) - tail.ln()).abs() < 1e-14);
+
+        assert_eq!(dist.cdf(-1.5), 0.0);
+        assert!((dist.cdf(-1.0) - tail).abs() < 1e-15);
+        assert_eq!(dist.cdf(1.0), 1.0);
+
+        assert!(dist.mean().abs() < 1e-12);
+        assert!((dist.variance() - 0.516_058_550_961_713_3).abs() < 1e-10);
+
+        assert_eq!(dist.ppf(0.1), Some(-1.0));
+        assert_eq!(dist.ppf(0.5), Some(0.0));
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let values = dist.sample_n(&mut rng, 10000);
+        assert!(values.iter().all(|x| (-1.0..=1.0).contains(x)));
+
+        let at_lower = values.iter().filter(|x| **x == -1.0).count() as f64 / 10000.0;
+        assert!((at_lower - tail).abs() < 0.01);
+
+        assert!(Censored::new(NormalDist::std(), 1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn censored_higher_moments() {
+        let dist = Censored::new(NormalDist::std(), -1.0, 1.0).unwrap();
+
+        assert_eq!(dist.mode(), Some(0.0));
+        assert!(dist.skewness().abs() < 1e-10);
+        assert!((dist.kurtosis() + 1.386_831_817_646_536).abs() < 1e-10);
+        assert!(dist.entropy().is_nan());
+        assert!((dist.mgf(1.0).unwrap() - 1.2764877088025194).abs() < 1e-10);
+        assert!(dist.cf(1.0).im.abs() < 1e-10);
+
+        // without point masses, the entropy is that of the wrapped distribution
+        let uncensored = Censored::new(NormalDist::std(), f64::NEG_INFINITY, f64::INFINITY).unwrap();
+        assert_eq!(uncensored.entropy(), NormalDist::std().entropy());
+    }
+
+    #[test]
+    fn censored_exponential() {
+        let dist = Censored::new(ExponentialDist::new(1.0).unwrap(), f64::NEG_INFINITY, 2.0).unwrap();
+
+        assert_eq!(dist.lower_mass(), 0.0);
+        assert!((dist.upper_mass() - (-2.0_f64).exp()).abs() < 1e-15);
+        assert!((dist.mean() - 0.864_664_716_763_387_3).abs() < 1e-10);
+        assert!((dist.variance() - 0.440_343_228_164_815).abs() < 1e-10);
+    }
+
+    #[test]
+    fn nested_wrappers() {
+        let dist = Truncated::new(Affine::new(ExponentialDist::new(1.0).unwrap(), 2.0, 1.0).unwrap(), 3.0, f64::INFINITY);
+        let dist = dist.unwrap();
+
+        assert!((dist.mean() - 4.0).abs() < 1e-10);
+        assert!((dist.cdf(4.0) - (1.0 - (-1.0_f64).exp())).abs() < 1e-14);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn wrappers_serde_round_trip() {
+        use crate::utils::json_round_trip;
+
+        let truncated = Truncated::new(NormalDist::std(), -1.0, 2.0).unwrap();
+        let affine = Affine::new(ExponentialDist::new(0.5).unwrap(), 1.0, 2.0).unwrap();
+        let censored = Censored::new(NormalDist::new(1.0, 2.0).unwrap(), 0.0, 3.0).unwrap();
+
+        assert_eq!(json_round_trip(&truncated).unwrap(), truncated);
+        assert_eq!(json_round_trip(&affine).unwrap(), affine);
+        assert_eq!(json_round_trip(&censored).unwrap(), censored);
+
+        // both the wrapper's & the wrapped distribution's parameters are validated
+        let json = r#"{"dist":{"loc":0.0,"scale":1.0},"lower":2.0,"upper":-1.0}"#;
+        assert!(serde_json::from_str::<Truncated<NormalDist>>(json).is_err());
+
+        let json = r#"{"dist":{"loc":0.0,"scale":-1.0},"lower":-1.0,"upper":2.0}"#;
+        assert!(serde_json::from_str::<Censored<NormalDist>>(json).is_err());
+    }
+}
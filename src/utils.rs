@@ -1,31 +1,357 @@
 use std::cmp::{Eq, Ord, Ordering};
-use std::convert::Into;
 
+use num_traits::{Float, NumCast};
 
-#[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
-pub struct ComparableFloat(f64);
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-impl ComparableFloat {
-    pub fn new(f: f64) -> Option<ComparableFloat> {
-        if f.is_nan() || f.is_infinite() { None }
-        else { Some(ComparableFloat(f)) }
+use crate::PensivError;
+
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ComparableFloat<F: Float = f64>(F);
+
+impl<F: Float> ComparableFloat<F> {
+    pub fn new(f: F) -> Result<ComparableFloat<F>, PensivError> {
+        if f.is_nan() || f.is_infinite() {
+            Err(PensivError::InvalidParameter { name: "value", value: to_f64(f), expected: "a finite number" })
+        }
+        else { Ok(ComparableFloat(f)) }
     }
 
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> F {
         self.0
     }
 }
 
-impl Eq for ComparableFloat { }
+impl<F: Float> Eq for ComparableFloat<F> { }
+
+impl<F: Float> PartialOrd for ComparableFloat<F> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
 
-impl Ord for ComparableFloat {
+// the wrapped value is always finite, so it's never unordered
+impl<F: Float> Ord for ComparableFloat<F> {
     fn cmp(&self, rhs: &Self) -> Ordering {
-        self.partial_cmp(rhs).unwrap()
+        self.0.partial_cmp(&rhs.0).unwrap()
     }
 }
 
-impl Into<f64> for ComparableFloat {
-    fn into(self) -> f64 {
-        self.0
+impl From<ComparableFloat<f64>> for f64 {
+    fn from(f: ComparableFloat<f64>) -> f64 {
+        f.0
+    }
+}
+
+impl From<ComparableFloat<f32>> for f32 {
+    fn from(f: ComparableFloat<f32>) -> f32 {
+        f.0
+    }
+}
+
+
+/// Implements `Serialize` & `Deserialize` for a type by way of a plain struct of its parameters.
+///
+/// Each field is given as `name: Type = expr`, where `expr` is a reference to the field's value computed from the value
+/// being serialized (bound to the identifier before the braces). Deserialization reads the fields into local variables
+/// of the same names, then evaluates the expression after `=>`, which must return a `Result<Self, PensivError>`;
+/// typically, this is the type's constructor, so that invalid parameters are rejected just as they are on construction.
+/// The type parameter (if any) doesn't need to appear in the fields, e.g. for a distribution with integer parameters
+/// whose float type only affects the values it returns.
+///
+/// ```ignore
+/// serde_via_params!(NormalDist<F: Float>, |dist| {
+///     loc: F = &dist.loc,
+///     scale: F = &dist.scale,
+/// } => NormalDist::new(loc, scale));
+/// ```
+#[cfg(feature = "serde")]
+macro_rules! serde_via_params {
+    (
+        $dist:ident $(<$param:ident $(: $bound:path)?>)?,
+        |$value:ident| { $($field:ident: $ty:ty = $get:expr),* $(,)? } => $new:expr
+    ) => {
+        const _: () = {
+            use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+            #[derive(Serialize)]
+            struct ParamsRef<'a, $($param)?> {
+                $($field: &'a $ty,)*
+                $(#[serde(skip)] precision: std::marker::PhantomData<$param>,)?
+            }
+
+            #[derive(Deserialize)]
+            struct Params<$($param)?> {
+                $($field: $ty,)*
+                $(#[serde(skip)] precision: std::marker::PhantomData<$param>,)?
+            }
+
+            impl<$($param: $($bound +)? Serialize)?> Serialize for $dist<$($param)?> {
+                fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                    let $value = self;
+                    ParamsRef { $($field: $get,)* $(precision: std::marker::PhantomData::<$param>,)? }.serialize(serializer)
+                }
+            }
+
+            impl<'de, $($param: $($bound +)? Deserialize<'de>)?> Deserialize<'de> for $dist<$($param)?> {
+                fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+                    let Params { $($field,)* .. } = Params::<$($param)?>::deserialize(deserializer)?;
+                    let result: Result<Self, crate::PensivError> = $new;
+
+                    result.map_err(de::Error::custom)
+                }
+            }
+        };
+    };
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use serde_via_params;
+
+// a `ComparableFloat` is (de)serialized as the float it wraps, rejecting `NaN` & infinite values like `new()`
+#[cfg(feature = "serde")]
+impl<F: Float + Serialize> Serialize for ComparableFloat<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for ComparableFloat<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ComparableFloat::new(F::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+
+/// Serializes `value` to JSON & deserializes it back, for testing (de)serialization.
+#[cfg(all(test, feature = "serde"))]
+pub(crate) fn json_round_trip<T: Serialize + de::DeserializeOwned>(value: &T) -> Result<T, serde_json::Error> {
+    serde_json::from_str(&serde_json::to_string(value)?)
+}
+
+
+/// Converts the `f64` constant `x` to the float type `F`, rounding it to the precision of `F`.
+///
+/// Conversions between float types never fail (values out of the range of `F` saturate to infinity), so this is only a
+/// shorthand for writing numeric constants in code that's generic over `F`.
+pub fn cast<F: Float>(x: f64) -> F {
+    <F as NumCast>::from(x).unwrap()
+}
+
+/// Converts `x` to an `f64`, or `NaN` if it can't be represented by one (which never happens for `f32` or `f64`).
+pub fn to_f64<F: Float>(x: F) -> f64 {
+    x.to_f64().unwrap_or(f64::NAN)
+}
+
+
+/// A set of distinct finite values, each with a positive count, that answers queries about their cumulative counts (the
+/// total count of a value & all smaller values) in logarithmic time, even as the counts change.
+///
+/// This is a treap whose nodes also store the total count of their subtrees. Each node's priority is a hash of its value
+/// rather than a random number, so the shape of the tree doesn't depend on the order in which values are added.
+#[derive(Debug)]
+pub(crate) struct CountTree<F: Float = f64> {
+    root: Subtree<F>,
+}
+
+type Subtree<F> = Option<Box<CountNode<F>>>;
+
+#[derive(Debug)]
+struct CountNode<F: Float> {
+    value: ComparableFloat<F>,
+    count: usize,
+    total: usize,
+    priority: u64,
+    left: Subtree<F>,
+    right: Subtree<F>,
+}
+
+impl<F: Float> CountTree<F> {
+    pub(crate) fn new() -> CountTree<F> {
+        CountTree { root: None }
+    }
+
+    /// Adds `delta` to the count of `value`, adding the value to the tree if it isn't there yet & removing it if its count
+    /// drops to `0`. The count must not become negative.
+    pub(crate) fn add(&mut self, value: ComparableFloat<F>, delta: isize) {
+        let (below, rest) = split(self.root.take(), |v| v < value);
+        let (node, above) = split(rest, |v| v <= value);
+
+        let (value, count) = node.map_or((value, 0), |node| (node.value, node.count));
+        let count = count as isize + delta;
+        let node = if count > 0 { Some(CountNode::leaf(value, count as usize)) } else { None };
+
+        self.root = join(join(below, node), above);
+    }
+
+    /// Returns the total count of the values for which `is_below` is true, assuming that it's true for every value below
+    /// some point & false for every value above it.
+    pub(crate) fn count_while<P: Fn(F) -> bool>(&self, is_below: P) -> usize {
+        let mut node = &self.root;
+        let mut count = 0;
+
+        while let Some(current) = node {
+            if is_below(current.value.value()) {
+                count += total(&current.left) + current.count;
+                node = &current.right;
+            }
+            else {
+                node = &current.left;
+            }
+        }
+
+        count
+    }
+
+    /// Returns the smallest value whose cumulative count satisfies `reached`, assuming that `reached` is monotonic, or
+    /// `None` if no value does.
+    pub(crate) fn first_reaching<P: Fn(usize) -> bool>(&self, reached: P) -> Option<F> {
+        let mut node = &self.root;
+        let mut count_before = 0;
+        let mut found = None;
+
+        while let Some(current) = node {
+            let cumulative_count = count_before + total(&current.left) + current.count;
+
+            if reached(cumulative_count) {
+                found = Some(current.value.value());
+                node = &current.left;
+            }
+            else {
+                count_before = cumulative_count;
+                node = &current.right;
+            }
+        }
+
+        found
+    }
+}
+
+impl<F: Float> CountNode<F> {
+    fn leaf(value: ComparableFloat<F>, count: usize) -> Box<CountNode<F>> {
+        // the splitmix64 finalizer, which scrambles the bits of the value into a well-distributed priority
+        let mut priority = to_f64(value.value()).to_bits().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        priority = (priority ^ (priority >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        priority = (priority ^ (priority >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        Box::new(CountNode { value, count, total: count, priority: priority ^ (priority >> 31), left: None, right: None })
+    }
+
+    fn update_total(&mut self) {
+        self.total = total(&self.left) + self.count + total(&self.right);
+    }
+}
+
+fn total<F: Float>(node: &Subtree<F>) -> usize {
+    node.as_ref().map_or(0, |node| node.total)
+}
+
+/// Splits a subtree into the nodes whose values satisfy `goes_left` & the rest, assuming that `goes_left` is true for
+/// every value below some point & false for every value above it.
+fn split<F: Float, P: Fn(ComparableFloat<F>) -> bool + Copy>(node: Subtree<F>, goes_left: P) -> (Subtree<F>, Subtree<F>) {
+    match node {
+        None => (None, None),
+        Some(mut node) => {
+            if goes_left(node.value) {
+                let (left, right) = split(node.right.take(), goes_left);
+                node.right = left;
+                node.update_total();
+                (Some(node), right)
+            }
+            else {
+                let (left, right) = split(node.left.take(), goes_left);
+                node.left = right;
+                node.update_total();
+                (left, Some(node))
+            }
+        },
     }
-}
\ No newline at end of file
+}
+
+/// Joins two subtrees, assuming that every value in `left` is smaller than every value in `right`.
+fn join<F: Float>(left: Subtree<F>, right: Subtree<F>) -> Subtree<F> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = join(left.right.take(), Some(right));
+                left.update_total();
+                Some(left)
+            }
+            else {
+                right.left = join(Some(left), right.left.take());
+                right.update_total();
+                Some(right)
+            }
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparable_float_orders_values() {
+        let mut values = [ComparableFloat::new(2.5_f32).unwrap(), ComparableFloat::new(-1.0).unwrap()];
+        values.sort();
+
+        assert_eq!(values.iter().map(|f| f.value()).collect::<Vec<f32>>(), vec![-1.0, 2.5]);
+        let value: f64 = ComparableFloat::new(0.5).unwrap().into();
+        assert_eq!(value, 0.5);
+        assert!(ComparableFloat::new(f32::NAN).is_err());
+        assert!(ComparableFloat::new(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn comparable_float_serde_round_trip() {
+        let value = ComparableFloat::new(2.5).unwrap();
+
+        assert_eq!(serde_json::to_string(&value).unwrap(), "2.5");
+        assert_eq!(json_round_trip(&value).unwrap(), value);
+        assert_eq!(json_round_trip(&ComparableFloat::new(-1.5_f32).unwrap()).unwrap().value(), -1.5);
+
+        // 1e39 overflows to infinity as an f32
+        assert!(serde_json::from_str::<ComparableFloat<f32>>("1e39").is_err());
+    }
+
+    #[test]
+    fn count_tree_tracks_cumulative_counts() {
+        let value = |x: f64| ComparableFloat::new(x).unwrap();
+        let mut tree = CountTree::new();
+
+        // cumulative counts: 1.0 -> 1, 2.0 -> 3, ..., 100.0 -> 100 * 101 / 2, where each value x is added x times
+        for x in (1..=100).rev() {
+            tree.add(value(x as f64), x);
+        }
+
+        assert_eq!(tree.count_while(|x| x <= 2.0), 3);
+        assert_eq!(tree.count_while(|x| x < 2.0), 1);
+        assert_eq!(tree.count_while(|x| x <= 1000.0), 5050);
+        assert_eq!(tree.count_while(|_| false), 0);
+        assert_eq!(tree.first_reaching(|count| count > 3), Some(3.0));
+        assert_eq!(tree.first_reaching(|count| count >= 5050), Some(100.0));
+        assert_eq!(tree.first_reaching(|count| count > 5050), None);
+
+        tree.add(value(2.0), -2);
+        tree.add(value(2.5), 1);
+
+        assert_eq!(tree.count_while(|x| x < 2.5), 1);
+        assert_eq!(tree.count_while(|x| x <= 2.5), 2);
+        assert_eq!(tree.first_reaching(|count| count >= 2), Some(2.5));
+        assert_eq!(tree.count_while(|x| x <= 1000.0), 5049);
+
+        for x in (1..=100).filter(|x| *x != 2) {
+            tree.add(value(x as f64), -x);
+        }
+
+        tree.add(value(2.5), -1);
+        assert!(tree.root.is_none());
+    }
+}